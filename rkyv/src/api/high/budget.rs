@@ -0,0 +1,303 @@
+//! Validation which stops after checking a limited number of subtrees.
+
+use core::{alloc::Layout, any::TypeId, fmt, ops::Range};
+
+use bytecheck::CheckBytes;
+use rancor::{fail, Source, Strategy};
+
+use crate::{
+    api::{access_pos_unchecked, check_pos_with_context, root_position},
+    validation::{
+        archive::ArchiveValidator, shared::SharedValidator, ArchiveContext,
+        SharedContext, Validator,
+    },
+    Portable,
+};
+
+#[derive(Debug)]
+struct BudgetExhausted {
+    max_checks: usize,
+}
+
+impl fmt::Display for BudgetExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "validation budget of {} subtree checks was exhausted",
+            self.max_checks,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BudgetExhausted {}
+
+/// A validator which fails once it has checked a maximum number of subtrees.
+///
+/// This validator is only constructed by [`access_budgeted`].
+#[derive(Debug)]
+pub struct BudgetValidator<'a> {
+    inner: Validator<ArchiveValidator<'a>, SharedValidator>,
+    max_checks: usize,
+    checked: usize,
+    exhausted: bool,
+}
+
+impl<'a> BudgetValidator<'a> {
+    fn new(bytes: &'a [u8], max_checks: usize) -> Self {
+        Self {
+            inner: Validator::new(
+                ArchiveValidator::new(bytes),
+                SharedValidator::new(),
+            ),
+            max_checks,
+            checked: 0,
+            exhausted: false,
+        }
+    }
+}
+
+unsafe impl<E: Source> ArchiveContext<E> for BudgetValidator<'_> {
+    fn check_subtree_ptr(
+        &mut self,
+        ptr: *const u8,
+        layout: &Layout,
+    ) -> Result<(), E> {
+        self.inner.check_subtree_ptr(ptr, layout)
+    }
+
+    unsafe fn push_subtree_range(
+        &mut self,
+        root: *const u8,
+        end: *const u8,
+    ) -> Result<Range<usize>, E> {
+        if self.checked == self.max_checks {
+            self.exhausted = true;
+            fail!(BudgetExhausted {
+                max_checks: self.max_checks,
+            });
+        }
+        self.checked += 1;
+
+        // SAFETY: This just forwards the call to the inner validator, which has
+        // the same safety requirements.
+        unsafe { self.inner.push_subtree_range(root, end) }
+    }
+
+    unsafe fn pop_subtree_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<(), E> {
+        // SAFETY: This just forwards the call to the inner validator, which has
+        // the same safety requirements.
+        unsafe { self.inner.pop_subtree_range(range) }
+    }
+
+    fn archive_pos(&self, ptr: *const u8) -> Option<usize> {
+        ArchiveContext::<E>::archive_pos(&self.inner, ptr)
+    }
+}
+
+impl<E: Source> SharedContext<E> for BudgetValidator<'_> {
+    fn register_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        self.inner.register_shared_ptr(address, type_id)
+    }
+
+    fn start_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        self.inner.start_shared_ptr(address, type_id)
+    }
+
+    fn finish_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<(), E> {
+        self.inner.finish_shared_ptr(address, type_id)
+    }
+}
+
+/// The result of validating an archive with a limited budget.
+///
+/// Returned by [`access_budgeted`].
+#[derive(Debug)]
+pub enum Budgeted<T> {
+    /// Validation finished within the budget.
+    Ready(T),
+    /// The budget ran out before validation finished.
+    ///
+    /// Validation can't be resumed, so it must be restarted from the beginning
+    /// with a larger budget.
+    Pending(ValidationPending),
+}
+
+impl<T> Budgeted<T> {
+    /// Returns the value if validation finished, or `None` if it is pending.
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            Self::Pending(_) => None,
+        }
+    }
+
+    /// Returns whether validation ran out of budget before finishing.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending(_))
+    }
+}
+
+/// Information about a validation which ran out of budget.
+///
+/// This is not a token for resuming validation. It only reports how far
+/// validation got before stopping, and any later call to [`access_budgeted`]
+/// starts over from the root.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationPending {
+    checked: usize,
+}
+
+impl ValidationPending {
+    /// Returns the number of subtrees that were checked before the budget ran
+    /// out.
+    ///
+    /// This is always equal to the budget that was exhausted. Restarting
+    /// validation with a budget of at most this many checks will run out
+    /// again.
+    pub fn checked(&self) -> usize {
+        self.checked
+    }
+}
+
+/// Accesses an archived value from the given byte slice by calculating the root
+/// position after checking its validity, checking at most `max_checks`
+/// subtrees.
+///
+/// The root value and each out-of-line value (e.g. the contents of a string,
+/// box, or vec) count as one subtree check each. If the budget runs out before
+/// validation finishes, [`Budgeted::Pending`] is returned instead of an error.
+/// This bounds the time spent validating untrusted archives of unknown size.
+///
+/// Validation can't be paused partway through a value, so a pending validation
+/// can't be resumed where it stopped. To finish validating the archive, call
+/// `access_budgeted` again with a larger budget (e.g. later or on another
+/// thread) or use [`access`](crate::api::high::access). The checks made by
+/// earlier calls are not remembered, so each call repeats them.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{api::high::access_budgeted, rancor::Error, to_bytes, Archived};
+///
+/// let value = vec![
+///     "the first string, which is stored out of line".to_string(),
+///     "the second string, which is stored out of line".to_string(),
+/// ];
+/// let bytes = to_bytes::<Error>(&value).unwrap();
+///
+/// // The root, the vec's elements, and each of the strings are checked as
+/// // subtrees.
+/// let result = access_budgeted::<Archived<Vec<String>>, Error>(&bytes, 3);
+/// assert!(result.unwrap().is_pending());
+///
+/// let result = access_budgeted::<Archived<Vec<String>>, Error>(&bytes, 4);
+/// assert_eq!(result.unwrap().ready().unwrap(), &value);
+/// ```
+pub fn access_budgeted<T, E>(
+    bytes: &[u8],
+    max_checks: usize,
+) -> Result<Budgeted<&T>, E>
+where
+    T: Portable + for<'a> CheckBytes<Strategy<BudgetValidator<'a>, E>>,
+    E: Source,
+{
+    let pos = root_position::<T>(bytes.len());
+    let mut validator = BudgetValidator::new(bytes, max_checks);
+    match check_pos_with_context::<T, _, E>(bytes, pos, &mut validator) {
+        Ok(()) => {
+            // SAFETY: The value at `pos` was checked and no errors were found.
+            unsafe {
+                Ok(Budgeted::Ready(access_pos_unchecked::<T>(bytes, pos)))
+            }
+        }
+        Err(_) if validator.exhausted => {
+            Ok(Budgeted::Pending(ValidationPending {
+                checked: validator.checked,
+            }))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rancor::Failure;
+
+    use super::{access_budgeted, Budgeted};
+    use crate::{
+        alloc::{string::String, vec, vec::Vec},
+        api::high::to_bytes,
+        Archived,
+    };
+
+    fn strings() -> Vec<String> {
+        vec![
+            String::from("the first string, which is stored out of line"),
+            String::from("the second string, which is stored out of line"),
+            String::from("the third string, which is stored out of line"),
+        ]
+    }
+
+    #[test]
+    fn budget_pending_then_ready() {
+        let value = strings();
+        let bytes = to_bytes::<Failure>(&value).unwrap();
+
+        // The root, the vec's elements, and each of the three strings
+        for max_checks in 0..5 {
+            let result = access_budgeted::<Archived<Vec<String>>, Failure>(
+                &bytes, max_checks,
+            )
+            .unwrap();
+            match result {
+                Budgeted::Pending(pending) => {
+                    assert_eq!(pending.checked(), max_checks)
+                }
+                Budgeted::Ready(_) => panic!("validation finished"),
+            }
+        }
+
+        let archived =
+            access_budgeted::<Archived<Vec<String>>, Failure>(&bytes, 5)
+                .unwrap()
+                .ready()
+                .unwrap();
+        assert_eq!(archived, &value);
+    }
+
+    #[test]
+    fn budget_invalid() {
+        let value = strings();
+        let mut bytes = to_bytes::<Failure>(&value).unwrap();
+        let pos = bytes
+            .windows(9)
+            .position(|window| window == b"the first")
+            .unwrap();
+        bytes[pos] = 0xff;
+
+        access_budgeted::<Archived<Vec<String>>, Failure>(&bytes, 100)
+            .unwrap_err();
+        // The invalid string is never reached with a budget of 2
+        assert!(access_budgeted::<Archived<Vec<String>>, Failure>(&bytes, 2)
+            .unwrap()
+            .is_pending());
+    }
+}
@@ -32,9 +32,43 @@ fn validator(bytes: &[u8]) -> Validator<ArchiveValidator<'_>, SharedValidator> {
 /// Accesses an archived value from the given byte slice at the given position
 /// after checking its validity.
 ///
+/// Use this instead of [`access`] when the root of the archive is not at the
+/// end of the byte slice. For example, the archive may have been written by a
+/// producer which places the root elsewhere, or may be embedded in a larger
+/// file. An error is returned if a `T` at `pos` would not be in bounds or
+/// properly aligned. Relative pointers in the archive may point anywhere in
+/// `bytes`.
+///
 /// This is a safe alternative to
 /// [`access_pos_unchecked`](crate::api::access_pos_unchecked) and is part of
 /// the [high-level API](crate::api::high).
+///
+/// # Examples
+/// ```
+/// use rkyv::{
+///     api::{high::access_pos, root_position},
+///     rancor::Error,
+///     to_bytes,
+///     util::AlignedVec,
+///     Archived,
+/// };
+///
+/// let value = vec![1, 2, 3, 4];
+/// let archive = to_bytes::<Error>(&value).unwrap();
+///
+/// // Embed the archive in a larger buffer, followed by some other data.
+/// let mut file = AlignedVec::<16>::new();
+/// file.extend_from_slice(&archive);
+/// file.extend_from_slice(b"some trailing data");
+///
+/// let pos = root_position::<Archived<Vec<i32>>>(archive.len());
+/// let archived = access_pos::<Archived<Vec<i32>>, Error>(&file, pos).unwrap();
+/// assert_eq!(archived, &value);
+///
+/// // Positions which are out of bounds are rejected.
+/// let result = access_pos::<Archived<Vec<i32>>, Error>(&file, file.len());
+/// assert!(result.is_err());
+/// ```
 pub fn access_pos<T, E>(bytes: &[u8], pos: usize) -> Result<&T, E>
 where
     T: Portable + for<'a> CheckBytes<HighValidator<'a, E>>,
@@ -46,6 +80,9 @@ where
 /// Accesses an archived value from the given byte slice by calculating the root
 /// position after checking its validity.
 ///
+/// An [`Unaligned`](crate::validation::archive::Unaligned) error is returned if
+/// `bytes` is not properly aligned for a `T`.
+///
 /// This is a safe alternative to
 /// [`access_unchecked`](crate::api::access_unchecked) and is part of the
 /// [high-level API](crate::api::high).
@@ -105,6 +142,9 @@ where
 /// Mutably accesses an archived value from the given byte slice by calculating
 /// the root position after checking its validity.
 ///
+/// An [`Unaligned`](crate::validation::archive::Unaligned) error is returned if
+/// `bytes` is not properly aligned for a `T`.
+///
 /// This is a safe alternative to
 /// [`access_unchecked`](crate::api::access_unchecked) and is part of the
 /// [high-level API](crate::api::high).
@@ -0,0 +1,369 @@
+//! Checksum trailers for detecting corrupted archives.
+
+use core::fmt;
+
+#[cfg(feature = "bytecheck")]
+use bytecheck::CheckBytes;
+use rancor::{fail, Source};
+
+#[cfg(feature = "bytecheck")]
+use crate::{
+    api::high::{access, HighValidator},
+    Portable,
+};
+use crate::{
+    api::high::{to_bytes, HighSerializer},
+    ser::allocator::ArenaHandle,
+    util::AlignedVec,
+    Serialize,
+};
+
+/// The length of the checksum trailer in bytes.
+pub const CHECKSUM_TRAILER_LEN: usize = 8;
+
+/// A checksum algorithm which can be used to write and verify checksum
+/// trailers.
+///
+/// Checksums are always computed and stored the same way on every platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Checksum {
+    /// CRC-32C (Castagnoli).
+    Crc32c,
+    /// 64-bit xxHash with a seed of 0.
+    Xxh64,
+}
+
+impl Checksum {
+    /// Computes the checksum of the given bytes.
+    ///
+    /// 32-bit checksums are zero-extended to 64 bits.
+    pub fn compute(self, bytes: &[u8]) -> u64 {
+        match self {
+            Self::Crc32c => crc32c(bytes) as u64,
+            Self::Xxh64 => xxh64(bytes),
+        }
+    }
+}
+
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        let index = (crc ^ *byte as u32) & 0xff;
+        crc = CRC32C_TABLE[index as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+const PRIME64_1: u64 = 0x9e37_79b1_85eb_ca87;
+const PRIME64_2: u64 = 0xc2b2_ae3d_27d4_eb4f;
+const PRIME64_3: u64 = 0x1656_67b1_9e37_79f9;
+const PRIME64_4: u64 = 0x85eb_ca77_c2b2_ae63;
+const PRIME64_5: u64 = 0x27d4_eb2f_1656_67c5;
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut word = [0; 8];
+    word.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(word)
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    let mut word = [0; 4];
+    word.copy_from_slice(&bytes[..4]);
+    u32::from_le_bytes(word)
+}
+
+fn xxh64_round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+fn xxh64_merge_round(acc: u64, value: u64) -> u64 {
+    (acc ^ xxh64_round(0, value))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+fn xxh64(bytes: &[u8]) -> u64 {
+    let mut rest = bytes;
+
+    let mut hash = if bytes.len() >= 32 {
+        let mut lanes = [
+            PRIME64_1.wrapping_add(PRIME64_2),
+            PRIME64_2,
+            0,
+            0u64.wrapping_sub(PRIME64_1),
+        ];
+        while rest.len() >= 32 {
+            for (i, lane) in lanes.iter_mut().enumerate() {
+                *lane = xxh64_round(*lane, read_u64(&rest[i * 8..]));
+            }
+            rest = &rest[32..];
+        }
+
+        let mut hash = lanes[0]
+            .rotate_left(1)
+            .wrapping_add(lanes[1].rotate_left(7))
+            .wrapping_add(lanes[2].rotate_left(12))
+            .wrapping_add(lanes[3].rotate_left(18));
+        for lane in lanes {
+            hash = xxh64_merge_round(hash, lane);
+        }
+        hash
+    } else {
+        PRIME64_5
+    };
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while rest.len() >= 8 {
+        hash ^= xxh64_round(0, read_u64(rest));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        rest = &rest[8..];
+    }
+    if rest.len() >= 4 {
+        hash ^= (read_u32(rest) as u64).wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        rest = &rest[4..];
+    }
+    for byte in rest {
+        hash ^= (*byte as u64).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^= hash >> 32;
+    hash
+}
+
+/// An error indicating that some bytes failed checksum verification.
+///
+/// This error is distinct from the errors produced while checking the
+/// structure of an archive, so it can be used to tell corrupted bytes apart
+/// from invalid ones.
+#[derive(Debug)]
+pub enum ChecksumError {
+    /// The bytes were too short to contain a checksum trailer.
+    MissingTrailer {
+        /// The length of the bytes.
+        len: usize,
+    },
+    /// The checksum in the trailer did not match the computed checksum.
+    Mismatch {
+        /// The checksum stored in the trailer.
+        expected: u64,
+        /// The checksum computed from the bytes.
+        actual: u64,
+    },
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTrailer { len } => write!(
+                f,
+                "{} bytes is too short to contain a checksum trailer of {} \
+                 bytes",
+                len, CHECKSUM_TRAILER_LEN,
+            ),
+            Self::Mismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {:#018x} but computed {:#018x}",
+                expected, actual,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumError {}
+
+/// Serializes the given value and returns the resulting bytes with a checksum
+/// trailer appended.
+///
+/// The trailer is the little-endian checksum of all of the serialized bytes.
+/// Use [`verify_checksum`] or [`access_verified`] to check it.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     api::high::{access_verified, to_bytes_checked, Checksum},
+///     rancor::Error,
+///     Archived,
+/// };
+///
+/// let value = vec![1, 2, 3, 4];
+///
+/// let bytes = to_bytes_checked::<Error>(&value, Checksum::Crc32c).unwrap();
+/// let archived =
+///     access_verified::<Archived<Vec<i32>>, Error>(&bytes, Checksum::Crc32c)
+///         .unwrap();
+///
+/// assert_eq!(archived, &value);
+/// ```
+pub fn to_bytes_checked<E>(
+    value: &impl for<'a> Serialize<
+        HighSerializer<'a, AlignedVec, ArenaHandle<'a>, E>,
+    >,
+    checksum: Checksum,
+) -> Result<AlignedVec, E>
+where
+    E: Source,
+{
+    let mut bytes = to_bytes(value)?;
+    let trailer = checksum.compute(&bytes).to_le_bytes();
+    bytes.extend_from_slice(&trailer);
+    Ok(bytes)
+}
+
+/// Verifies the checksum trailer of the given bytes and returns the bytes
+/// without the trailer.
+///
+/// This only checks for corruption. The returned bytes still need to be
+/// validated before they can be safely accessed.
+///
+/// This is part of the [high-level API](crate::api::high).
+pub fn verify_checksum<E>(bytes: &[u8], checksum: Checksum) -> Result<&[u8], E>
+where
+    E: Source,
+{
+    let Some(len) = bytes.len().checked_sub(CHECKSUM_TRAILER_LEN) else {
+        fail!(ChecksumError::MissingTrailer { len: bytes.len() });
+    };
+    let (bytes, trailer) = bytes.split_at(len);
+
+    let expected = read_u64(trailer);
+    let actual = checksum.compute(bytes);
+    if expected != actual {
+        fail!(ChecksumError::Mismatch { expected, actual });
+    }
+
+    Ok(bytes)
+}
+
+/// Verifies the checksum trailer of the given bytes, then accesses the
+/// archived value from them after checking its validity.
+///
+/// Returns a [`ChecksumError`] if the checksum trailer doesn't match. See
+/// [`to_bytes_checked`] for an example.
+///
+/// This is part of the [high-level API](crate::api::high).
+#[cfg(feature = "bytecheck")]
+pub fn access_verified<T, E>(bytes: &[u8], checksum: Checksum) -> Result<&T, E>
+where
+    T: Portable + for<'a> CheckBytes<HighValidator<'a, E>>,
+    E: Source,
+{
+    access::<T, E>(verify_checksum::<E>(bytes, checksum)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use rancor::{Failure, Panic};
+
+    use super::{
+        crc32c, to_bytes_checked, verify_checksum, xxh64, Checksum,
+        CHECKSUM_TRAILER_LEN,
+    };
+    use crate::{
+        alloc::{string::String, vec::Vec},
+        api::high::to_bytes,
+    };
+
+    #[test]
+    fn checksum_vectors() {
+        assert_eq!(crc32c(b""), 0);
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+
+        assert_eq!(xxh64(b""), 0xef46_db37_51d8_e999);
+        assert_eq!(xxh64(b"abc"), 0x44bc_2cf5_ad77_0999);
+        assert_eq!(
+            xxh64(b"Nobody inspects the spammish repetition"),
+            0xfbce_a83c_8a37_8bf1,
+        );
+    }
+
+    #[test]
+    fn checksum_trailer() {
+        let value = String::from("hello world");
+        let plain = to_bytes::<Panic>(&value).unwrap();
+
+        for checksum in [Checksum::Crc32c, Checksum::Xxh64] {
+            let bytes = to_bytes_checked::<Panic>(&value, checksum).unwrap();
+            assert_eq!(bytes.len(), plain.len() + CHECKSUM_TRAILER_LEN);
+
+            let verified = verify_checksum::<Panic>(&bytes, checksum).unwrap();
+            assert_eq!(verified, plain.as_slice());
+        }
+    }
+
+    #[test]
+    fn checksum_corrupted() {
+        let value = String::from("hello world");
+        let bytes = to_bytes_checked::<Panic>(&value, Checksum::Xxh64).unwrap();
+
+        let mut corrupted = Vec::from(bytes.as_slice());
+        corrupted[0] ^= 1;
+        verify_checksum::<Failure>(&corrupted, Checksum::Xxh64).unwrap_err();
+
+        verify_checksum::<Failure>(&bytes, Checksum::Crc32c).unwrap_err();
+        verify_checksum::<Failure>(&bytes[..4], Checksum::Xxh64).unwrap_err();
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn checksum_access() {
+        use super::access_verified;
+        use crate::Archived;
+
+        let value = String::from("hello world");
+        let bytes =
+            to_bytes_checked::<Panic>(&value, Checksum::Crc32c).unwrap();
+
+        let archived = access_verified::<Archived<String>, Failure>(
+            &bytes,
+            Checksum::Crc32c,
+        )
+        .unwrap();
+        assert_eq!(archived, "hello world");
+
+        let mut corrupted = bytes.clone();
+        corrupted[0] ^= 1;
+        access_verified::<Archived<String>, Failure>(
+            &corrupted,
+            Checksum::Crc32c,
+        )
+        .unwrap_err();
+    }
+}
@@ -0,0 +1,165 @@
+//! Access to archives which were written with an older layout.
+
+use bytecheck::CheckBytes;
+use rancor::Source;
+
+use crate::{
+    api::high::{access, HighValidator},
+    Portable,
+};
+
+/// Accesses an archived `Old` from the given byte slice after checking its
+/// validity, and converts it into a `New`.
+///
+/// This supports reading archives that were written before a type was renamed
+/// or had its fields changed. Keep a copy of the old type (or just derive
+/// `Archive` on a compatibility type with the same fields) and implement
+/// `From<&Old>` for the new type to convert the old archived layout.
+///
+/// # Validation
+///
+/// The bytes are validated entirely as an `Old`, exactly like
+/// [`access`](crate::api::high::access) does. Validation only guarantees that
+/// the bytes are safe to read as an `Old`, not that they were written as one.
+/// Archives don't record the type or version they were written with, so bytes
+/// written with a different layout may still pass validation and be converted.
+/// If archives with several layouts can be encountered, store a version number
+/// alongside them and use it to choose which `Old` to access.
+///
+/// The new type is never validated since it is produced by the conversion.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     api::high::access_compat, rancor::Error, to_bytes, Archive, Serialize,
+/// };
+///
+/// // The layout that old archives were written with.
+/// #[derive(Archive, Serialize)]
+/// #[rkyv(check_bytes)]
+/// struct PointV1 {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// // The type that new code uses.
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: i64,
+///     y: i64,
+///     z: i64,
+/// }
+///
+/// impl From<&ArchivedPointV1> for Point {
+///     fn from(old: &ArchivedPointV1) -> Self {
+///         Point {
+///             x: old.x.to_native().into(),
+///             y: old.y.to_native().into(),
+///             z: 0,
+///         }
+///     }
+/// }
+///
+/// let bytes = to_bytes::<Error>(&PointV1 { x: 1, y: 2 }).unwrap();
+/// let point =
+///     access_compat::<ArchivedPointV1, Point, Error>(&bytes).unwrap();
+/// assert_eq!(point, Point { x: 1, y: 2, z: 0 });
+/// ```
+pub fn access_compat<Old, New, E>(bytes: &[u8]) -> Result<New, E>
+where
+    Old: Portable + for<'a> CheckBytes<HighValidator<'a, E>>,
+    New: for<'a> From<&'a Old>,
+    E: Source,
+{
+    access::<Old, E>(bytes).map(New::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use rancor::Failure;
+
+    use super::access_compat;
+    use crate::{
+        alloc::{
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        api::high::{from_bytes, to_bytes},
+        Archive, Deserialize, Serialize,
+    };
+
+    #[derive(Archive, Serialize)]
+    #[rkyv(crate, check_bytes)]
+    struct UserV1 {
+        name: String,
+        age: u8,
+    }
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    #[rkyv(crate, check_bytes)]
+    struct User {
+        name: String,
+        age: u32,
+        tags: Vec<String>,
+    }
+
+    impl From<&ArchivedUserV1> for User {
+        fn from(old: &ArchivedUserV1) -> Self {
+            User {
+                name: old.name.to_string(),
+                age: old.age.into(),
+                tags: Vec::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn access_old_layout() {
+        let bytes = to_bytes::<Failure>(&UserV1 {
+            name: "Ada".to_string(),
+            age: 36,
+        })
+        .unwrap();
+
+        let user =
+            access_compat::<ArchivedUserV1, User, Failure>(&bytes).unwrap();
+        assert_eq!(
+            user,
+            User {
+                name: "Ada".to_string(),
+                age: 36,
+                tags: Vec::new(),
+            }
+        );
+
+        // New archives are read with the new layout.
+        let bytes = to_bytes::<Failure>(&User {
+            name: "Ada".to_string(),
+            age: 36,
+            tags: vec!["admin".to_string()],
+        })
+        .unwrap();
+        let user = from_bytes::<User, Failure>(&bytes).unwrap();
+        assert_eq!(user.tags, ["admin"]);
+    }
+
+    #[test]
+    fn access_invalid_old_layout() {
+        let mut bytes = to_bytes::<Failure>(&UserV1 {
+            name: "a name which is stored out of line".to_string(),
+            age: 36,
+        })
+        .unwrap();
+        let pos = bytes
+            .windows(6)
+            .position(|window| window == b"a name")
+            .unwrap();
+        bytes[pos] = 0xff;
+
+        access_compat::<ArchivedUserV1, User, Failure>(&bytes).unwrap_err();
+    }
+}
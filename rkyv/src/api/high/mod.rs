@@ -2,23 +2,71 @@
 //!
 //! These APIs have default writers, automatically manage allocators, and
 //! support shared pointers.
+//!
+//! # Choosing how much to validate
+//!
+//! The high-level API offers a few levels of validation. From safest to
+//! fastest:
+//!
+//! - `access` and `from_bytes` check the entire archive before using it. This
+//!   includes the bounds and alignment of every pointer, UTF-8 in strings, enum
+//!   tags, and any other invariants of the archived types. Any bytes that pass
+//!   validation are safe to use, even if they are malicious.
+//! - `access_budgeted` performs the same checks, but fails once it exceeds a
+//!   limit on the amount of work done.
+//! - `access_report` also performs the same checks. When an element of an
+//!   archived vec is invalid, it records the error and checks the rest of the
+//!   elements. Other errors still stop validation of the values containing
+//!   them. The archived value is only returned if no errors were found.
+//! - `access_verified` checks a checksum trailer before performing the same
+//!   checks. The checksum detects corruption but still leaves validation in
+//!   place.
+//! - [`access_unchecked`](crate::access_unchecked) and [`from_bytes_unchecked`]
+//!   perform no checks at all and are `unsafe`. They are intended for trusted
+//!   data, such as archives written by the same program. For trusted data that
+//!   may have been corrupted at rest, [`verify_checksum`] can be called first
+//!   to detect corruption without validating the archive's structure.
+//!
+//! The checked functions are only available with the `bytecheck` feature.
+//!
+//! Individual categories of checks (e.g. only the UTF-8 or enum tag checks)
+//! can't be turned off while keeping the others. Accessing a string with
+//! invalid UTF-8 or an enum with an invalid tag is just as undefined as
+//! following an out-of-bounds pointer, so skipping any check makes access
+//! `unsafe` anyway. Most of these checks are also performed by the
+//! `CheckBytes` implementations of the archived types themselves rather than by
+//! the validator, so they can't be configured per call. For these reasons,
+//! there is no validation policy parameter on `from_bytes` or `access`. Use one
+//! of the levels above instead.
 
+#[cfg(feature = "bytecheck")]
+mod budget;
 #[cfg(feature = "bytecheck")]
 mod checked;
+mod checksum;
+#[cfg(feature = "bytecheck")]
+mod compat;
+mod owned;
+#[cfg(feature = "bytecheck")]
+mod report;
+mod slice;
 
 use rancor::Strategy;
 
 #[cfg(feature = "bytecheck")]
-pub use self::checked::*;
+pub use self::{budget::*, checked::*, compat::*, report::*};
+pub use self::{checksum::*, owned::*, slice::*};
+#[cfg(feature = "std")]
+use crate::ser::writer::IoWriter;
 use crate::{
     access_unchecked,
-    api::{deserialize_with, serialize_with},
-    de::Pool,
+    api::{deserialize_into_with, deserialize_with, serialize_with},
+    de::{Pool, Unshared},
     ser::{
         allocator::ArenaHandle, sharing::Share, Allocator, Serializer, Writer,
     },
     util::{with_arena, AlignedVec},
-    Archive, Deserialize, Serialize,
+    Archive, Deserialize, DeserializeInto, Serialize,
 };
 
 /// A high-level serializer.
@@ -31,6 +79,12 @@ pub type HighSerializer<'a, W, A, E> = Strategy<Serializer<W, A, Share>, E>;
 /// This is part of the [high-level API](crate::api::high).
 pub type HighDeserializer<E> = Strategy<Pool, E>;
 
+/// A high-level deserializer which deserializes every shared pointer to a new
+/// allocation.
+///
+/// This is part of the [high-level API](crate::api::high).
+pub type UnsharedDeserializer<E> = Strategy<Unshared, E>;
+
 /// Serializes the given value and returns the resulting bytes in an
 /// [`AlignedVec`].
 ///
@@ -67,7 +121,34 @@ where
 
 /// Serializes the given value and writes the bytes to the given `writer`.
 ///
+/// Passing a mutable reference to an existing writer allows its allocation to
+/// be reused across many serializations. The writer is not cleared first, so
+/// clear it before each serialization to reuse only its allocation. To clear
+/// and reuse an [`AlignedVec`], see [`to_bytes_reusing`].
+///
+/// If the writer already contains bytes, the value is appended after them. The
+/// positions of the appended value are relative to the start of the writer, so
+/// the appended value starts at or after the writer's old length (it may be
+/// preceded by padding) and its root is at the end of the writer. The archived
+/// value can be accessed from all of the bytes of the writer, or from the bytes
+/// written after the old length.
+///
 /// This is part of the [high-level API](crate::api::high).
+///
+/// # Examples
+/// ```
+/// use rkyv::{api::high::to_bytes_in, rancor::Error, util::AlignedVec};
+///
+/// let mut bytes = AlignedVec::<16>::new();
+/// for i in 0..10 {
+///     bytes.clear();
+///     to_bytes_in::<_, Error>(&vec![i; 16], &mut bytes)
+///         .expect("failed to serialize vec");
+///     let deserialized = rkyv::from_bytes::<Vec<i32>, Error>(&bytes)
+///         .expect("failed to deserialize vec");
+///     assert_eq!(deserialized, vec![i; 16]);
+/// }
+/// ```
 pub fn to_bytes_in<W, E>(
     value: &impl for<'a> Serialize<HighSerializer<'a, W, ArenaHandle<'a>, E>>,
     writer: W,
@@ -79,6 +160,87 @@ where
     with_arena(|arena| to_bytes_in_with_alloc(value, writer, arena.acquire()))
 }
 
+/// Clears the given buffer and serializes the given value into it, reusing the
+/// buffer's allocation.
+///
+/// Returns the number of bytes written and the position of the root. The bytes
+/// of the buffer always start at position zero, so the archived value can be
+/// accessed from the whole buffer. The scratch space used for serialization is
+/// reset before each use, so the same buffer can be reused indefinitely.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Examples
+/// ```
+/// use rkyv::{api::high::to_bytes_reusing, rancor::Error, util::AlignedVec};
+///
+/// let mut bytes = AlignedVec::<16>::new();
+/// for i in 0..10 {
+///     let (len, _) = to_bytes_reusing::<Error, 16>(&vec![i; 16], &mut bytes)
+///         .expect("failed to serialize vec");
+///     assert_eq!(len, bytes.len());
+///     let deserialized = rkyv::from_bytes::<Vec<i32>, Error>(&bytes)
+///         .expect("failed to deserialize vec");
+///     assert_eq!(deserialized, vec![i; 16]);
+/// }
+/// ```
+pub fn to_bytes_reusing<'b, E, const ALIGNMENT: usize>(
+    value: &impl for<'a> Serialize<
+        HighSerializer<'a, &'b mut AlignedVec<ALIGNMENT>, ArenaHandle<'a>, E>,
+    >,
+    bytes: &'b mut AlignedVec<ALIGNMENT>,
+) -> Result<(usize, usize), E>
+where
+    E: rancor::Source,
+{
+    bytes.clear();
+    with_arena(|arena| {
+        let mut serializer =
+            Serializer::new(bytes, arena.acquire(), Share::new());
+        let root = serialize_with(value, &mut serializer)?;
+        Ok((serializer.into_writer().len(), root))
+    })
+}
+
+/// Serializes the given value and streams the bytes to the given
+/// [`io::Write`](std::io::Write) writer.
+///
+/// The bytes are written directly to `writer` as they are produced, so the
+/// serialized value is never held in memory all at once. See [`IoWriter`] for
+/// more details.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Examples
+/// ```
+/// use rkyv::{rancor::Error, util::AlignedVec};
+///
+/// let value = vec![1, 2, 3, 4];
+///
+/// let bytes = rkyv::api::high::to_writer::<_, Error>(&value, Vec::new())
+///     .expect("failed to serialize vec");
+///
+/// let mut aligned = AlignedVec::<16>::new();
+/// aligned.extend_from_slice(&bytes);
+/// let deserialized = rkyv::from_bytes::<Vec<i32>, Error>(&aligned)
+///     .expect("failed to deserialize vec");
+///
+/// assert_eq!(deserialized, value);
+/// ```
+#[cfg(feature = "std")]
+pub fn to_writer<W, E>(
+    value: &impl for<'a> Serialize<
+        HighSerializer<'a, IoWriter<W>, ArenaHandle<'a>, E>,
+    >,
+    writer: W,
+) -> Result<W, E>
+where
+    W: std::io::Write,
+    E: rancor::Source,
+{
+    to_bytes_in(value, IoWriter::new(writer)).map(IoWriter::into_inner)
+}
+
 /// Serializes the given value using the given allocator.
 ///
 /// This is part of the [high-level API](crate::api::high).
@@ -154,6 +316,64 @@ where
 /// Deserializes a value from the given archived value.
 ///
 /// This is part of the [high-level API](crate::api::high).
+///
+/// The archived value does not need to be the root of an archive. Any field of
+/// an archived type can be deserialized on its own, which leaves the rest of
+/// the archive untouched. Fields behind an `ArchivedBox` or `ArchivedOption`
+/// can be deserialized by first getting a reference to the archived value they
+/// contain. [`deserialize_field!`](crate::deserialize_field) can do this for a
+/// path of fields with any deserializer.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     access, deserialize, rancor::Error, to_bytes, Archive, Deserialize,
+///     Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+/// #[rkyv(check_bytes)]
+/// struct Network {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Config {
+///     primary: Box<Network>,
+///     fallback: Option<Network>,
+///     assets: Vec<Vec<u8>>,
+/// }
+///
+/// let config = Config {
+///     primary: Box::new(Network {
+///         host: "example.com".to_string(),
+///         port: 443,
+///     }),
+///     fallback: Some(Network {
+///         host: "localhost".to_string(),
+///         port: 8080,
+///     }),
+///     assets: vec![vec![0; 1024]; 16],
+/// };
+/// let bytes = to_bytes::<Error>(&config).unwrap();
+/// let archived = access::<ArchivedConfig, Error>(&bytes).unwrap();
+///
+/// // Only the networks are deserialized, the assets are never copied.
+/// let primary =
+///     deserialize::<Network, Error>(archived.primary.get()).unwrap();
+/// assert_eq!(primary, *config.primary);
+///
+/// let fallback = archived
+///     .fallback
+///     .as_ref()
+///     .map(deserialize::<Network, Error>)
+///     .transpose()
+///     .unwrap();
+/// assert_eq!(fallback, config.fallback);
+/// ```
 pub fn deserialize<T, E>(value: &T::Archived) -> Result<T, E>
 where
     T: Archive,
@@ -162,13 +382,80 @@ where
     deserialize_with(value, &mut Pool::new())
 }
 
+/// Deserializes a value from the given archived value without deduplicating
+/// shared pointers.
+///
+/// Every `Rc` and `Arc` is deserialized to a new allocation, even if it points
+/// to the same archived value as another shared pointer. This skips tracking
+/// deserialized shared pointers by address, but the deserialized value may use
+/// more memory than the original. See [`Unshared`] for more details.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use rkyv::{
+///     access, api::high::deserialize_unshared, rancor::Error, to_bytes,
+///     Archive, Archived, Deserialize, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Example {
+///     a: Rc<String>,
+///     b: Rc<String>,
+/// }
+///
+/// let shared = Rc::new("hello world".to_string());
+/// let value = Example {
+///     a: shared.clone(),
+///     b: shared,
+/// };
+///
+/// let bytes = to_bytes::<Error>(&value).unwrap();
+/// let archived = access::<Archived<Example>, Error>(&bytes).unwrap();
+/// let deserialized =
+///     deserialize_unshared::<Example, Error>(archived).unwrap();
+///
+/// assert_eq!(deserialized.a, deserialized.b);
+/// assert!(!Rc::ptr_eq(&deserialized.a, &deserialized.b));
+/// ```
+pub fn deserialize_unshared<T, E>(value: &T::Archived) -> Result<T, E>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, UnsharedDeserializer<E>>,
+{
+    deserialize_with(value, &mut Unshared::new())
+}
+
+/// Deserializes a value from the given archived value into an existing value.
+///
+/// See [`DeserializeInto`](trait@crate::DeserializeInto) for more details and
+/// an example.
+///
+/// This is part of the [high-level API](crate::api::high).
+pub fn deserialize_into<T, E>(
+    value: &T::Archived,
+    place: &mut T,
+) -> Result<(), E>
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, HighDeserializer<E>>,
+{
+    deserialize_into_with(value, place, &mut Pool::new())
+}
+
 #[cfg(test)]
 mod tests {
     use rancor::Panic;
 
     use crate::{
         alloc::{string::ToString, vec::Vec},
-        api::high::to_bytes_in,
+        api::high::{to_bytes_in, to_bytes_reusing},
+        util::AlignedVec,
     };
 
     #[test]
@@ -177,4 +464,80 @@ mod tests {
         let bytes = to_bytes_in::<_, Panic>(&value, Vec::new()).unwrap();
         assert!(!bytes.is_empty());
     }
+
+    #[test]
+    fn to_bytes_in_reused_vec() {
+        let mut bytes = AlignedVec::<16>::new();
+
+        to_bytes_in::<_, Panic>(&"hello world".to_string(), &mut bytes)
+            .unwrap();
+        let capacity = bytes.capacity();
+        let len = bytes.len();
+
+        bytes.clear();
+        to_bytes_in::<_, Panic>(&"hello world".to_string(), &mut bytes)
+            .unwrap();
+        assert_eq!(bytes.len(), len);
+        assert_eq!(bytes.capacity(), capacity);
+    }
+
+    #[test]
+    fn to_bytes_reusing_clears_buffer() {
+        let mut bytes = AlignedVec::<16>::new();
+        bytes.extend_from_slice(&[0xff; 64]);
+        let capacity = bytes.capacity();
+
+        let value = "hello world".to_string();
+        let (len, root) =
+            to_bytes_reusing::<Panic, 16>(&value, &mut bytes).unwrap();
+        let expected = to_bytes_in::<_, Panic>(&value, Vec::new()).unwrap();
+        assert_eq!(len, expected.len());
+        assert_eq!(bytes.as_slice(), expected.as_slice());
+        assert_eq!(bytes.capacity(), capacity);
+        assert!(root < len);
+
+        let (second_len, second_root) =
+            to_bytes_reusing::<Panic, 16>(&value, &mut bytes).unwrap();
+        assert_eq!((second_len, second_root), (len, root));
+        assert_eq!(bytes.as_slice(), expected.as_slice());
+        assert_eq!(bytes.capacity(), capacity);
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn to_bytes_in_appends() {
+        use crate::{api::high::access, string::ArchivedString};
+
+        let mut bytes = AlignedVec::<16>::new();
+        to_bytes_in::<_, Panic>(&"the first string".to_string(), &mut bytes)
+            .unwrap();
+        let first_len = bytes.len();
+
+        // Serializing again without clearing appends the second value.
+        to_bytes_in::<_, Panic>(&"the second string".to_string(), &mut bytes)
+            .unwrap();
+        assert!(bytes.len() > first_len);
+        assert_eq!(
+            access::<ArchivedString, Panic>(&bytes[..first_len]).unwrap(),
+            "the first string",
+        );
+        assert_eq!(
+            access::<ArchivedString, Panic>(&bytes).unwrap(),
+            "the second string",
+        );
+        assert_eq!(
+            access::<ArchivedString, Panic>(&bytes[first_len..]).unwrap(),
+            "the second string",
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_writer_vec() {
+        use crate::api::high::{to_bytes, to_writer};
+
+        let value = "hello world".to_string();
+        let bytes = to_writer::<_, Panic>(&value, Vec::new()).unwrap();
+        assert_eq!(bytes, to_bytes::<Panic>(&value).unwrap().as_slice());
+    }
 }
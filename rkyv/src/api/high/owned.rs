@@ -0,0 +1,188 @@
+use core::{fmt, marker::PhantomData, ops::Deref};
+
+#[cfg(feature = "bytecheck")]
+use bytecheck::CheckBytes;
+#[cfg(feature = "bytecheck")]
+use rancor::Source;
+
+#[cfg(feature = "bytecheck")]
+use crate::api::high::{access, HighValidator};
+use crate::{
+    access_unchecked,
+    alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec},
+    util::AlignedVec,
+    Archive,
+};
+
+/// An owner of bytes which do not move when the owner is moved.
+///
+/// # Safety
+///
+/// The slice returned from `deref` must always have the same address and
+/// length, even if the owner is moved. The bytes must not be modified for as
+/// long as the owner is alive.
+pub unsafe trait StableBytes: Deref<Target = [u8]> {}
+
+// SAFETY: A static reference always points to the same bytes, and they can't
+// be modified through a shared reference.
+unsafe impl StableBytes for &'static [u8] {}
+
+// SAFETY: The bytes of a `Box<[u8]>` are heap-allocated and can't be modified
+// without a mutable reference to the box.
+unsafe impl StableBytes for Box<[u8]> {}
+
+// SAFETY: The bytes of a `Vec<u8>` are heap-allocated and can't be modified
+// without a mutable reference to the vec.
+unsafe impl StableBytes for Vec<u8> {}
+
+// SAFETY: The bytes of an `AlignedVec` are heap-allocated and can't be modified
+// without a mutable reference to the vec.
+unsafe impl<const A: usize> StableBytes for AlignedVec<A> {}
+
+// SAFETY: The bytes of an `Rc<[u8]>` are heap-allocated and can't be modified
+// through an `Rc`.
+unsafe impl StableBytes for Rc<[u8]> {}
+
+// SAFETY: The bytes of an `Arc<[u8]>` are heap-allocated and can't be modified
+// through an `Arc`.
+unsafe impl StableBytes for Arc<[u8]> {}
+
+/// An archived value together with the bytes that it is stored in.
+///
+/// `OwnedArchive` dereferences to the archived value, so it can be stored and
+/// moved around without borrowing from a separate buffer. The buffer is
+/// validated once when the `OwnedArchive` is constructed.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{api::high::OwnedArchive, rancor::Error, to_bytes, Archive};
+///
+/// #[derive(Archive, rkyv::Serialize)]
+/// #[rkyv(check_bytes)]
+/// struct Example {
+///     name: String,
+///     value: i32,
+/// }
+///
+/// let value = Example {
+///     name: "pi".to_string(),
+///     value: 31415926,
+/// };
+///
+/// let bytes = to_bytes::<Error>(&value).unwrap();
+/// let archive = OwnedArchive::<Example, _>::new::<Error>(bytes).unwrap();
+///
+/// assert_eq!(archive.name, "pi");
+/// assert_eq!(archive.value, 31415926);
+/// ```
+pub struct OwnedArchive<T, O> {
+    owner: O,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Archive, O: StableBytes> OwnedArchive<T, O> {
+    /// Creates a new `OwnedArchive` from the given owner after checking that
+    /// its bytes contain a valid archived `T` at the root position.
+    #[cfg(feature = "bytecheck")]
+    pub fn new<E>(owner: O) -> Result<Self, E>
+    where
+        T::Archived: for<'a> CheckBytes<HighValidator<'a, E>>,
+        E: Source,
+    {
+        access::<T::Archived, E>(&owner)?;
+        Ok(Self {
+            owner,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Creates a new `OwnedArchive` from the given owner without checking its
+    /// bytes.
+    ///
+    /// # Safety
+    ///
+    /// The bytes of `owner` must contain a valid archived `T` at the root
+    /// position. See [`access_unchecked`] for more details.
+    pub unsafe fn new_unchecked(owner: O) -> Self {
+        Self {
+            owner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the archived value.
+    pub fn get(&self) -> &T::Archived {
+        // SAFETY: The bytes of `owner` were checked (or guaranteed by the
+        // caller) to contain a valid archived `T` at the root position when
+        // this `OwnedArchive` was created. `StableBytes` guarantees that they
+        // have not moved or changed since then.
+        unsafe { access_unchecked::<T::Archived>(&self.owner) }
+    }
+
+    /// Returns the owner of the bytes.
+    pub fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    /// Consumes the `OwnedArchive` and returns the owner of the bytes.
+    pub fn into_owner(self) -> O {
+        self.owner
+    }
+}
+
+impl<T: Archive, O: StableBytes> Deref for OwnedArchive<T, O> {
+    type Target = T::Archived;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T, O> fmt::Debug for OwnedArchive<T, O>
+where
+    T: Archive,
+    T::Archived: fmt::Debug,
+    O: StableBytes,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.get(), f)
+    }
+}
+
+#[cfg(all(test, feature = "bytecheck"))]
+mod tests {
+    use rancor::{Failure, Panic};
+
+    use super::OwnedArchive;
+    use crate::{
+        alloc::{string::String, sync::Arc, vec::Vec},
+        api::high::to_bytes,
+    };
+
+    #[test]
+    fn owned_archive() {
+        let value = String::from("hello world");
+        let bytes = to_bytes::<Panic>(&value).unwrap();
+
+        let archive = OwnedArchive::<String, _>::new::<Panic>(bytes).unwrap();
+        assert_eq!(archive.as_str(), "hello world");
+
+        // Moving the archive doesn't invalidate it.
+        let archives = Vec::from([archive]);
+        assert_eq!(archives[0].as_str(), "hello world");
+
+        let bytes = archives.into_iter().next().unwrap().into_owner();
+        let shared = Arc::<[u8]>::from(bytes.as_slice());
+        // The copied bytes may not be aligned, so only check them if they are.
+        if let Ok(archive) = OwnedArchive::<String, _>::new::<Failure>(shared) {
+            assert_eq!(archive.as_str(), "hello world");
+        }
+    }
+
+    #[test]
+    fn owned_archive_invalid() {
+        let bytes = Vec::from([0xffu8; 8]);
+        OwnedArchive::<bool, _>::new::<Failure>(bytes).unwrap_err();
+    }
+}
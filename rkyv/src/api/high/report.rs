@@ -0,0 +1,478 @@
+//! Validation which reports every error it finds.
+
+use core::{alloc::Layout, any::TypeId, fmt, ops::Range};
+
+use bytecheck::CheckBytes;
+use rancor::{fail, Error, Strategy};
+
+use crate::{
+    alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    },
+    api::{access_pos_unchecked, check_pos_with_context, root_position},
+    validation::{
+        archive::ArchiveValidator, shared::SharedValidator, ArchiveContext,
+        SharedContext, Validator,
+    },
+    Portable,
+};
+
+/// A validator which records errors in the elements of slices and continues
+/// validating the rest of the elements.
+///
+/// This validator is only constructed by [`access_report`].
+#[derive(Debug)]
+pub struct ReportValidator<'a> {
+    inner: Validator<ArchiveValidator<'a>, SharedValidator>,
+    // The subtree ranges which have been pushed and not yet popped.
+    subtrees: Vec<Subtree>,
+    issues: Vec<ValidationIssue>,
+    // The number of subtrees which were pushed when the last error was
+    // recorded.
+    recorded_depth: Option<usize>,
+}
+
+#[derive(Debug)]
+struct Subtree {
+    root: usize,
+    range: Range<usize>,
+}
+
+impl<'a> ReportValidator<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            inner: Validator::new(
+                ArchiveValidator::new(bytes),
+                SharedValidator::new(),
+            ),
+            subtrees: Vec::new(),
+            issues: Vec::new(),
+            recorded_depth: None,
+        }
+    }
+
+    fn pos(&self, address: usize) -> Option<usize> {
+        ArchiveContext::<Error>::archive_pos(&self.inner, address as *const u8)
+    }
+
+    fn check_registered(
+        &self,
+        address: usize,
+        is_new: bool,
+    ) -> Result<bool, Error> {
+        // After an error has been recovered, a shared pointer which was
+        // already registered may point to a value which failed to validate.
+        // It can't be skipped, and checking it again would claim its subtree
+        // twice.
+        if !is_new && !self.issues.is_empty() {
+            fail!(SharedPointerAfterError { address });
+        }
+        Ok(is_new)
+    }
+}
+
+#[derive(Debug)]
+struct SubtreeAlreadyPopped;
+
+impl fmt::Display for SubtreeAlreadyPopped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "subtree range was popped while recovering from an error")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SubtreeAlreadyPopped {}
+
+#[derive(Debug)]
+struct SharedPointerAfterError {
+    address: usize,
+}
+
+impl fmt::Display for SharedPointerAfterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "shared pointer to {:#x} may point to a value which failed to \
+             validate",
+            self.address,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SharedPointerAfterError {}
+
+// SAFETY: Every method except `recover_element` forwards to the inner
+// validator. `recover_element` only returns `Ok` after popping the subtree
+// ranges which were pushed while checking the invalid element, which restores
+// the inner validator to a valid state.
+unsafe impl ArchiveContext<Error> for ReportValidator<'_> {
+    fn check_subtree_ptr(
+        &mut self,
+        ptr: *const u8,
+        layout: &Layout,
+    ) -> Result<(), Error> {
+        self.inner.check_subtree_ptr(ptr, layout)
+    }
+
+    unsafe fn push_subtree_range(
+        &mut self,
+        root: *const u8,
+        end: *const u8,
+    ) -> Result<Range<usize>, Error> {
+        // SAFETY: This just forwards the call to the inner validator, which has
+        // the same safety requirements.
+        let range = unsafe { self.inner.push_subtree_range(root, end)? };
+        self.subtrees.push(Subtree {
+            root: root as usize,
+            range: range.clone(),
+        });
+        Ok(range)
+    }
+
+    unsafe fn pop_subtree_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        match self.subtrees.last() {
+            Some(subtree) if subtree.range == range => {
+                self.subtrees.pop();
+            }
+            _ => fail!(SubtreeAlreadyPopped),
+        }
+        // SAFETY: This just forwards the call to the inner validator, which has
+        // the same safety requirements.
+        unsafe { self.inner.pop_subtree_range(range) }
+    }
+
+    fn archive_pos(&self, ptr: *const u8) -> Option<usize> {
+        ArchiveContext::<Error>::archive_pos(&self.inner, ptr)
+    }
+
+    fn recover_element(
+        &mut self,
+        slice: *const u8,
+        element: *const u8,
+        error: Error,
+    ) -> Result<(), Error> {
+        // Find the subtree of the slice. Every subtree pushed after it was
+        // pushed while checking the invalid element.
+        let Some(index) = self
+            .subtrees
+            .iter()
+            .rposition(|subtree| subtree.root == slice as usize)
+        else {
+            return Err(error);
+        };
+        let depth = index + 1;
+
+        // Report the innermost subtree that failed to validate, or the element
+        // if the error wasn't in a subtree of it.
+        let address = self
+            .subtrees
+            .last()
+            .filter(|_| self.subtrees.len() > depth)
+            .map_or(element as usize, |subtree| subtree.root);
+        let offset = self.pos(address);
+
+        while self.subtrees.len() > depth {
+            let subtree = self.subtrees.pop().unwrap();
+            // SAFETY: `subtree.range` was returned from the inner validator
+            // and the subtrees are popped in reverse order.
+            let popped = unsafe {
+                ArchiveContext::<Error>::pop_subtree_range(
+                    &mut self.inner,
+                    subtree.range,
+                )
+            };
+            if popped.is_err() {
+                return Err(error);
+            }
+        }
+
+        // An error from an element which contains a slice with recovered
+        // errors was already recorded when those errors were recovered.
+        if !matches!(self.recorded_depth, Some(recorded) if recorded > depth) {
+            self.issues.push(ValidationIssue {
+                offset,
+                description: error.to_string(),
+            });
+        }
+        self.recorded_depth = Some(depth);
+
+        Ok(())
+    }
+}
+
+impl SharedContext<Error> for ReportValidator<'_> {
+    fn register_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, Error> {
+        let is_new = self.inner.register_shared_ptr(address, type_id)?;
+        self.check_registered(address, is_new)
+    }
+
+    fn start_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, Error> {
+        let is_new = self.inner.start_shared_ptr(address, type_id)?;
+        self.check_registered(address, is_new)
+    }
+
+    fn finish_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<(), Error> {
+        self.inner.finish_shared_ptr(address, type_id)
+    }
+}
+
+/// A single error found while validating an archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    offset: Option<usize>,
+    description: String,
+}
+
+impl ValidationIssue {
+    /// Returns the offset of the value that failed to validate, if it is
+    /// known.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns a description of the error.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(f, "at offset {:#x}: ", offset)?,
+            None => write!(f, "at unknown offset: ")?,
+        }
+        write!(f, "{}", self.description)
+    }
+}
+
+/// A report of every error found while validating an archive.
+///
+/// Returned by [`access_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns the errors found while validating, in the order they were
+    /// found.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Consumes the report and returns the errors found while validating.
+    pub fn into_issues(self) -> Vec<ValidationIssue> {
+        self.issues
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "found {} validation errors", self.issues.len())?;
+        for issue in self.issues.iter() {
+            write!(f, "\n- {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationReport {}
+
+/// Accesses an archived value from the given byte slice by calculating the root
+/// position after checking its validity, reporting every error found.
+///
+/// Unlike [`access`](crate::api::high::access), validation continues after an
+/// element of an archived vec fails to validate, so that the rest of the
+/// elements can be checked too. Any other error stops validation of the value
+/// it was found in, and of every value containing it up to the nearest vec
+/// element. The vec is still invalid after its elements are checked, so the
+/// archived value is only returned if no errors were found. All of the errors
+/// are returned together in a [`ValidationReport`].
+///
+/// Reporting every error may be slower than stopping at the first one. Once
+/// an error is found, later errors may be caused by the first one rather than
+/// being independent problems with the archive. In particular, shared pointers
+/// to values which were already checked are reported as errors after the first
+/// error, because the value they point to may have failed to validate.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{api::high::access_report, rancor::Error, to_bytes, Archived};
+///
+/// let value = vec![
+///     "the first string, which is stored out of line".to_string(),
+///     "the second string, which is stored out of line".to_string(),
+/// ];
+/// let mut bytes = to_bytes::<Error>(&value).unwrap();
+///
+/// // Replace the first byte of each string with invalid UTF-8.
+/// for needle in ["the first", "the second"] {
+///     let pos = bytes
+///         .windows(needle.len())
+///         .position(|window| window == needle.as_bytes())
+///         .unwrap();
+///     bytes[pos] = 0xff;
+/// }
+///
+/// let report = access_report::<Archived<Vec<String>>>(&bytes).unwrap_err();
+/// assert_eq!(report.issues().len(), 2);
+/// ```
+pub fn access_report<T>(bytes: &[u8]) -> Result<&T, ValidationReport>
+where
+    T: Portable + for<'a> CheckBytes<Strategy<ReportValidator<'a>, Error>>,
+{
+    let pos = root_position::<T>(bytes.len());
+    let mut validator = ReportValidator::new(bytes);
+    let result =
+        check_pos_with_context::<T, _, Error>(bytes, pos, &mut validator);
+
+    let mut issues = core::mem::take(&mut validator.issues);
+    // If any errors were recorded, then the final error is caused by them.
+    if let (Err(error), true) = (result, issues.is_empty()) {
+        let offset = validator
+            .subtrees
+            .last()
+            .map_or(Some(pos), |subtree| validator.pos(subtree.root));
+        issues.push(ValidationIssue {
+            offset,
+            description: error.to_string(),
+        });
+    }
+
+    if issues.is_empty() {
+        // SAFETY: The value at `pos` was checked and no errors were found.
+        unsafe { Ok(access_pos_unchecked::<T>(bytes, pos)) }
+    } else {
+        Err(ValidationReport { issues })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rancor::Failure;
+
+    use super::access_report;
+    use crate::{
+        alloc::{string::String, vec, vec::Vec},
+        api::high::{access, to_bytes},
+        Archived,
+    };
+
+    fn strings() -> Vec<String> {
+        vec![
+            String::from("the first string, which is stored out of line"),
+            String::from("the second string, which is stored out of line"),
+            String::from("the third string, which is stored out of line"),
+        ]
+    }
+
+    fn find(bytes: &[u8], needle: &[u8]) -> usize {
+        bytes
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .unwrap()
+    }
+
+    #[test]
+    fn report_valid() {
+        let value = strings();
+        let bytes = to_bytes::<Failure>(&value).unwrap();
+
+        let archived = access_report::<Archived<Vec<String>>>(&bytes).unwrap();
+        assert_eq!(archived, &value);
+    }
+
+    #[test]
+    fn report_multiple_errors() {
+        let value = strings();
+        let mut bytes = to_bytes::<Failure>(&value).unwrap();
+
+        let first = find(&bytes, b"the first");
+        let third = find(&bytes, b"the third");
+        bytes[first] = 0xff;
+        bytes[third] = 0xff;
+
+        access::<Archived<Vec<String>>, Failure>(&bytes).unwrap_err();
+
+        let report =
+            access_report::<Archived<Vec<String>>>(&bytes).unwrap_err();
+        let offsets = report
+            .issues()
+            .iter()
+            .map(|issue| issue.offset())
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![Some(first), Some(third)]);
+    }
+
+    #[test]
+    fn report_nested_errors_once() {
+        let value = vec![strings(), strings()];
+        let mut bytes = to_bytes::<Failure>(&value).unwrap();
+
+        let second = find(&bytes, b"the second");
+        bytes[second] = 0xff;
+
+        // The error is recovered by the inner vec, and the outer vec doesn't
+        // record the inner vec failing a second time.
+        let report =
+            access_report::<Archived<Vec<Vec<String>>>>(&bytes).unwrap_err();
+        assert_eq!(report.issues().len(), 1);
+        assert_eq!(report.issues()[0].offset(), Some(second));
+    }
+
+    #[test]
+    fn report_shared_after_error() {
+        use crate::alloc::rc::Rc;
+
+        let shared =
+            Rc::new(String::from("a shared string stored out of line"));
+        let value = vec![
+            (
+                String::from("an invalid string stored out of line"),
+                shared.clone(),
+            ),
+            (String::from("a valid string stored out of line"), shared),
+        ];
+        let mut bytes = to_bytes::<Failure>(&value).unwrap();
+
+        let invalid = find(&bytes, b"an invalid");
+        bytes[invalid] = 0xff;
+
+        // The second pointer to the shared string is refused because the
+        // string may have failed to validate.
+        let report =
+            access_report::<Archived<Vec<(String, Rc<String>)>>>(&bytes)
+                .unwrap_err();
+        assert_eq!(report.issues().len(), 2);
+        assert_eq!(report.issues()[0].offset(), Some(invalid));
+    }
+
+    #[test]
+    fn report_root_error() {
+        let report = access_report::<Archived<bool>>(&[2]).unwrap_err();
+        assert_eq!(report.issues().len(), 1);
+        assert_eq!(report.issues()[0].offset(), Some(0));
+    }
+}
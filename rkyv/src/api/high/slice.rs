@@ -0,0 +1,119 @@
+//! Serializing slices as standalone archived vecs.
+
+use rancor::{Fallible, Source};
+
+use crate::{
+    api::high::{to_bytes, HighSerializer},
+    ser::{allocator::ArenaHandle, Allocator, Writer},
+    util::AlignedVec,
+    vec::{ArchivedVec, VecResolver},
+    Archive, Place, Serialize,
+};
+
+// Archives a borrowed slice the same way as a `Vec` of its elements.
+struct SliceAsVec<'a, T>(&'a [T]);
+
+impl<T: Archive> Archive for SliceAsVec<'_, T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self.0, resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for SliceAsVec<'_, T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(
+        &self,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.0, serializer)
+    }
+}
+
+/// Serializes the given slice as an archived `Vec` and returns the resulting
+/// bytes in an [`AlignedVec`].
+///
+/// The bytes are the same as those produced by serializing a `Vec` with the
+/// same elements, so they can be accessed as an
+/// [`ArchivedVec`](crate::vec::ArchivedVec). This can be used to archive part
+/// of a `Vec` without first copying that part into a new `Vec`.
+///
+/// This is part of the [high-level API](crate::api::high).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     api::high::to_bytes_slice, rancor::Error, vec::ArchivedVec, Archived,
+/// };
+///
+/// let records = (0..100).collect::<Vec<u32>>();
+///
+/// let page = &records[20..30];
+/// let bytes = to_bytes_slice::<_, Error>(page).unwrap();
+/// let archived =
+///     rkyv::access::<ArchivedVec<Archived<u32>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.as_slice(), page);
+/// ```
+pub fn to_bytes_slice<T, E>(values: &[T]) -> Result<AlignedVec, E>
+where
+    T: for<'a> Serialize<HighSerializer<'a, AlignedVec, ArenaHandle<'a>, E>>,
+    E: Source,
+{
+    to_bytes(&SliceAsVec(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use rancor::Panic;
+
+    use super::to_bytes_slice;
+    use crate::{
+        access_unchecked,
+        alloc::{
+            string::{String, ToString},
+            vec::Vec,
+        },
+        api::high::to_bytes,
+        string::ArchivedString,
+        vec::ArchivedVec,
+    };
+
+    #[test]
+    fn to_bytes_slice_windows() {
+        let records = (0..20)
+            .map(|i| i.to_string().repeat(i))
+            .collect::<Vec<String>>();
+
+        for (offset, limit) in [(0, 0), (0, 5), (7, 6), (15, 5), (20, 0)] {
+            let window = &records[offset..offset + limit];
+            let bytes = to_bytes_slice::<_, Panic>(window).unwrap();
+
+            // The window is archived exactly like a `Vec` of its elements.
+            let expected = to_bytes::<Panic>(&window.to_vec()).unwrap();
+            assert_eq!(bytes.as_slice(), expected.as_slice());
+
+            let archived = unsafe {
+                access_unchecked::<ArchivedVec<ArchivedString>>(&bytes)
+            };
+            assert_eq!(archived.len(), limit);
+            assert!(archived.iter().zip(window).all(|(a, b)| a == b));
+        }
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn to_bytes_slice_checked() {
+        use crate::{api::high::from_bytes, rancor::Error};
+
+        let records = [1u64, 2, 3, 4, 5];
+        let bytes = to_bytes_slice::<_, Error>(&records[1..4]).unwrap();
+        let deserialized = from_bytes::<Vec<u64>, Error>(&bytes).unwrap();
+        assert_eq!(deserialized, [2, 3, 4]);
+    }
+}
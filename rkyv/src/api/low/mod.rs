@@ -12,9 +12,9 @@ use rancor::Strategy;
 pub use self::checked::*;
 use crate::{
     access_unchecked,
-    api::{deserialize_with, serialize_with},
+    api::{deserialize_into_with, deserialize_with, serialize_with},
     ser::{Allocator, Serializer, Writer},
-    Archive, Deserialize, Serialize,
+    Archive, Deserialize, DeserializeInto, Serialize,
 };
 
 /// A general-purpose serializer suitable for environments where allocations
@@ -78,3 +78,19 @@ where
 {
     deserialize_with(value, &mut ())
 }
+
+/// Deserializes a value from the given archived value into an existing value.
+///
+/// See [`DeserializeInto`](trait@crate::DeserializeInto) for more details.
+///
+/// This is part of the [low-level API](crate::api::low).
+pub fn deserialize_into<T, E>(
+    value: &T::Archived,
+    place: &mut T,
+) -> Result<(), E>
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, LowDeserializer<E>>,
+{
+    deserialize_into_with(value, place, &mut ())
+}
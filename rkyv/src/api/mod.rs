@@ -14,7 +14,10 @@ use rancor::Strategy;
 
 #[cfg(feature = "bytecheck")]
 pub use self::checked::*;
-use crate::{ser::Writer, Archive, Deserialize, Portable, SerializeUnsized};
+use crate::{
+    ser::Writer, Archive, Deserialize, DeserializeInto, Portable,
+    SerializeUnsized,
+};
 
 #[cfg(debug_assertions)]
 fn sanity_check_buffer<T: Portable>(ptr: *const u8, pos: usize, size: usize) {
@@ -63,9 +66,17 @@ pub fn root_position<T: Portable>(size: usize) -> usize {
 
 /// Accesses an archived value from the given byte slice at the given position.
 ///
+/// Use this instead of [`access_unchecked`] when the root of the archive is not
+/// at the end of the byte slice, for example when it is embedded in a larger
+/// file.
+///
 /// This function does not check that the data at the given position is valid.
-/// Use [`access_pos`](crate::api::access_pos_with_context) to validate the data
-/// instead.
+/// Use [`access_pos_with_context`](crate::api::access_pos_with_context) to
+/// validate the data instead.
+///
+/// When debug assertions are enabled, this function checks that the byte slice
+/// is large enough to contain a `T` at the given position and that the
+/// position is properly aligned for `T`.
 ///
 /// # Safety
 ///
@@ -85,13 +96,21 @@ pub unsafe fn access_pos_unchecked<T: Portable>(
 /// Accesses a mutable archived value from the given byte slice at the given
 /// position.
 ///
-/// This function does not check that the data at the given position is valid.
-/// Use [`access_pos_mut`](crate::api::access_pos_with_context) to validate
-/// the data instead.
+/// This function does not check that the data at the given position is valid,
+/// and does not require the `bytecheck` feature. Use
+/// [`access_pos_with_context_mut`](crate::api::access_pos_with_context_mut) to
+/// validate the data instead.
+///
+/// When debug assertions are enabled, this function checks that the byte slice
+/// is large enough to contain a `T` at the given position and that the
+/// position is properly aligned for `T`.
 ///
 /// # Safety
 ///
-/// A `T` must be located at the given position in the byte slice.
+/// - A valid `T` must be located at the given position in the byte slice.
+/// - Any modifications made through the returned reference must leave the
+///   archived value valid. For example, writing an out-of-range relative
+///   pointer offset would make later accesses undefined behavior.
 pub unsafe fn access_pos_unchecked_mut<T: Portable>(
     bytes: &mut [u8],
     pos: usize,
@@ -173,3 +192,19 @@ where
 {
     value.deserialize(Strategy::wrap(deserializer))
 }
+
+/// Deserializes a value from the given archived value into an existing value
+/// using the provided deserializer.
+///
+/// See [`DeserializeInto`](trait@crate::DeserializeInto) for more details.
+pub fn deserialize_into_with<T, D, E>(
+    value: &T::Archived,
+    place: &mut T,
+    deserializer: &mut D,
+) -> Result<(), E>
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, Strategy<D, E>>,
+{
+    value.deserialize_into(place, Strategy::wrap(deserializer))
+}
@@ -5,7 +5,7 @@ use crate::{
     de::Pool,
     ser::allocator::ArenaHandle,
     util::AlignedVec,
-    Archive, Deserialize, Serialize,
+    Archive, Deserialize, DeserializeInto, Serialize,
 };
 
 /// The serializer type for tests.
@@ -34,3 +34,18 @@ where
     crate::api::deserialize_with::<T, _, Panic>(value, &mut Pool::new())
         .expect("failed to deserialize value")
 }
+
+/// Deserializes the given value into an existing value using the test
+/// deserializer.
+pub fn deserialize_into<T>(value: &T::Archived, place: &mut T)
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, TestDeserializer>,
+{
+    crate::api::deserialize_into_with::<T, _, Panic>(
+        value,
+        place,
+        &mut Pool::new(),
+    )
+    .expect("failed to deserialize value")
+}
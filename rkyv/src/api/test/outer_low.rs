@@ -6,7 +6,7 @@ use crate::{
     api::low::{to_bytes_in_with_alloc, LowDeserializer, LowSerializer},
     ser::{allocator::SubAllocator, writer::Buffer},
     util::Align,
-    Archive, Deserialize, Serialize,
+    Archive, Deserialize, DeserializeInto, Serialize,
 };
 
 /// The serializer type for tests.
@@ -43,3 +43,14 @@ where
     crate::api::deserialize_with::<T, _, Panic>(value, &mut ())
         .expect("failed to deserialize value")
 }
+
+/// Deserializes the given value into an existing value using the test
+/// deserializer.
+pub fn deserialize_into<T>(value: &T::Archived, place: &mut T)
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, TestDeserializer>,
+{
+    crate::api::deserialize_into_with::<T, _, Panic>(value, place, &mut ())
+        .expect("failed to deserialize value")
+}
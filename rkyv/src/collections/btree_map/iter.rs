@@ -1,10 +1,18 @@
-use core::{marker::PhantomData, pin::Pin, ptr::addr_of_mut};
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+    pin::Pin,
+    ptr::{self, addr_of_mut},
+};
+
+use equivalent::Comparable;
 
 use crate::{
     alloc::vec::Vec,
     collections::btree_map::{
-        entries_to_height, ArchivedBTreeMap, InnerNode, LeafNode, Node,
-        NodeKind,
+        child_node, entries_to_height, entry, node_len, ArchivedBTreeMap,
+        InnerNode, LeafNode, Node, NodeKind,
     },
     RawRelPtr,
 };
@@ -54,6 +62,26 @@ impl<K, V, const E: usize> ArchivedBTreeMap<K, V, E> {
             _phantom: PhantomData,
         }
     }
+
+    /// Gets an iterator over a sub-range of entries in the map, sorted by key.
+    ///
+    /// The bounds of the range may be any type which is [`Comparable`] with the
+    /// keys of the map. The iterator starts by descending directly to the
+    /// first and last entries in the range, so it does not visit any entries
+    /// outside of the range and does not allocate. If the range is empty or its
+    /// start is greater than its end, the returned iterator yields no entries.
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V, E>
+    where
+        Q: Comparable<K> + ?Sized,
+        K: Ord,
+        R: RangeBounds<Q>,
+    {
+        let this = (self as *const Self).cast_mut();
+        Range {
+            inner: unsafe { RawRange::new(this, &range) },
+            _phantom: PhantomData,
+        }
+    }
 }
 
 /// An iterator over the entires of an `ArchivedBTreeMap`.
@@ -147,6 +175,33 @@ impl<'a, K, V, const E: usize> Iterator for ValuesPin<'a, K, V, E> {
     }
 }
 
+/// An iterator over a sub-range of entries of an `ArchivedBTreeMap`.
+///
+/// This struct is created by the [`range`](ArchivedBTreeMap::range) method on
+/// [`ArchivedBTreeMap`]. See its documentation for more.
+pub struct Range<'a, K, V, const E: usize> {
+    inner: RawRange<K, V, E>,
+    _phantom: PhantomData<&'a ArchivedBTreeMap<K, V, E>>,
+}
+
+impl<'a, K: Ord, V, const E: usize> Iterator for Range<'a, K, V, E> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(k, v)| (unsafe { &*k }, unsafe { &*v }))
+    }
+}
+
+impl<K: Ord, V, const E: usize> DoubleEndedIterator for Range<'_, K, V, E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|(k, v)| (unsafe { &*k }, unsafe { &*v }))
+    }
+}
+
 struct RawIter<K, V, const E: usize> {
     remaining: usize,
     stack: Vec<(*mut Node<K, V, E>, usize)>,
@@ -247,3 +302,212 @@ impl<K, V, const E: usize> Iterator for RawIter<K, V, E> {
         Some((k, v))
     }
 }
+
+// `front` and `back` are the next entries to yield from each end of the range.
+// Once they meet, both are cleared. Moving to the next or previous entry never
+// needs more than the current position: when the cursor runs off the end of a
+// node, it descends from the root again to find the adjacent key.
+struct RawRange<K, V, const E: usize> {
+    root: *mut Node<K, V, E>,
+    front: Option<(*mut Node<K, V, E>, usize)>,
+    back: Option<(*mut Node<K, V, E>, usize)>,
+    remaining: usize,
+}
+
+impl<K: Ord, V, const E: usize> RawRange<K, V, E> {
+    unsafe fn new<Q, R>(map: *mut ArchivedBTreeMap<K, V, E>, range: &R) -> Self
+    where
+        Q: Comparable<K> + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let remaining = unsafe { (*map).len.to_native() as usize };
+        if remaining == 0 {
+            return Self {
+                root: ptr::null_mut(),
+                front: None,
+                back: None,
+                remaining,
+            };
+        }
+
+        let root = unsafe {
+            RawRelPtr::as_ptr_raw(addr_of_mut!((*map).root))
+                .cast::<Node<K, V, E>>()
+        };
+
+        // Descend to the first entry greater than the start bound
+        let front = unsafe {
+            first_after(root, |k| match range.start_bound() {
+                Bound::Included(start) => start.compare(k) != Ordering::Greater,
+                Bound::Excluded(start) => start.compare(k) == Ordering::Less,
+                Bound::Unbounded => true,
+            })
+        };
+
+        // Descend to the last entry less than the end bound
+        let back = unsafe {
+            last_before(root, |k| match range.end_bound() {
+                Bound::Included(end) => end.compare(k) == Ordering::Less,
+                Bound::Excluded(end) => end.compare(k) != Ordering::Greater,
+                Bound::Unbounded => false,
+            })
+        };
+
+        let is_empty = match (front, back) {
+            (Some((f, fi)), Some((b, bi))) => unsafe {
+                *entry(f, fi).0 > *entry(b, bi).0
+            },
+            _ => true,
+        };
+        if is_empty {
+            return Self {
+                root,
+                front: None,
+                back: None,
+                remaining: 0,
+            };
+        }
+
+        Self {
+            root,
+            front,
+            back,
+            remaining,
+        }
+    }
+
+    unsafe fn successor(
+        &self,
+        current: *mut Node<K, V, E>,
+        i: usize,
+    ) -> Option<(*mut Node<K, V, E>, usize)> {
+        let next_i = i + 1;
+        if let Some(mut child) = unsafe { child_node(current, next_i) } {
+            loop {
+                match unsafe { child_node(child, 0) } {
+                    Some(next) => child = next,
+                    None => {
+                        let len = unsafe { node_len(child) };
+                        return (len > 0).then_some((child, 0));
+                    }
+                }
+            }
+        }
+        if next_i < unsafe { node_len(current) } {
+            return Some((current, next_i));
+        }
+
+        let key = unsafe { &*entry(current, i).0 };
+        unsafe { first_after(self.root, |k| k > key) }
+    }
+
+    unsafe fn predecessor(
+        &self,
+        current: *mut Node<K, V, E>,
+        i: usize,
+    ) -> Option<(*mut Node<K, V, E>, usize)> {
+        if let Some(mut child) = unsafe { child_node(current, i) } {
+            loop {
+                let len = unsafe { node_len(child) };
+                match unsafe { child_node(child, len) } {
+                    Some(next) => child = next,
+                    None => return len.checked_sub(1).map(|i| (child, i)),
+                }
+            }
+        }
+        if i > 0 {
+            return Some((current, i - 1));
+        }
+
+        let key = unsafe { &*entry(current, i).0 };
+        unsafe { last_before(self.root, |k| k >= key) }
+    }
+
+    fn next(&mut self) -> Option<(*mut K, *mut V)> {
+        let (current, i) = self.front?;
+        let result = unsafe { entry(current, i) };
+
+        self.remaining -= 1;
+        if self.remaining == 0 || self.back == Some((current, i)) {
+            self.front = None;
+            self.back = None;
+            return Some(result);
+        }
+
+        self.front = unsafe { self.successor(current, i) };
+        if self.front.is_none() {
+            self.back = None;
+        }
+
+        Some(result)
+    }
+
+    fn next_back(&mut self) -> Option<(*mut K, *mut V)> {
+        let (current, i) = self.back?;
+        let result = unsafe { entry(current, i) };
+
+        self.remaining -= 1;
+        if self.remaining == 0 || self.front == Some((current, i)) {
+            self.front = None;
+            self.back = None;
+            return Some(result);
+        }
+
+        self.back = unsafe { self.predecessor(current, i) };
+        if self.back.is_none() {
+            self.front = None;
+        }
+
+        Some(result)
+    }
+}
+
+/// Descends from `root` to the first entry whose key is after some point.
+///
+/// `is_after` must return `false` for all keys before that point and `true` for
+/// all keys after it.
+unsafe fn first_after<K, V, const E: usize>(
+    root: *mut Node<K, V, E>,
+    is_after: impl Fn(&K) -> bool,
+) -> Option<(*mut Node<K, V, E>, usize)> {
+    let mut result = None;
+    let mut current = root;
+    loop {
+        let len = unsafe { node_len(current) };
+        let i = (0..len)
+            .find(|&i| is_after(unsafe { &*entry(current, i).0 }))
+            .unwrap_or(len);
+        if i < len {
+            result = Some((current, i));
+        }
+        match unsafe { child_node(current, i) } {
+            Some(child) => current = child,
+            None => return result,
+        }
+    }
+}
+
+/// Descends from `root` to the last entry whose key is before some point.
+///
+/// `is_after` must return `false` for all keys before that point and `true` for
+/// all keys after it.
+unsafe fn last_before<K, V, const E: usize>(
+    root: *mut Node<K, V, E>,
+    is_after: impl Fn(&K) -> bool,
+) -> Option<(*mut Node<K, V, E>, usize)> {
+    let mut result = None;
+    let mut current = root;
+    loop {
+        let len = unsafe { node_len(current) };
+        let i = (0..len)
+            .find(|&i| is_after(unsafe { &*entry(current, i).0 }))
+            .unwrap_or(len);
+        if i > 0 {
+            result = Some((current, i - 1));
+        }
+        match unsafe { child_node(current, i) } {
+            Some(child) => current = child,
+            None => return result,
+        }
+    }
+}
@@ -147,6 +147,72 @@ struct InnerNode<K, V, const E: usize> {
     greater_node: RawRelPtr,
 }
 
+/// Returns the number of entries in the given node.
+///
+/// # Safety
+///
+/// `node` must point to a valid node.
+unsafe fn node_len<K, V, const E: usize>(node: *mut Node<K, V, E>) -> usize {
+    let kind = unsafe { (*node).kind };
+    match kind {
+        NodeKind::Leaf => {
+            let leaf = node.cast::<LeafNode<K, V, E>>();
+            unsafe { (*leaf).len.to_native() as usize }
+        }
+        NodeKind::Inner => E,
+    }
+}
+
+/// Returns the child node immediately preceding the `i`-th entry of the given
+/// node, or the greater node if `i` is `E`.
+///
+/// Returns `None` if the node is a leaf node or the child node is invalid.
+///
+/// # Safety
+///
+/// `node` must point to a valid node and `i` must be less than or equal to
+/// `E`.
+unsafe fn child_node<K, V, const E: usize>(
+    node: *mut Node<K, V, E>,
+    i: usize,
+) -> Option<*mut Node<K, V, E>> {
+    let kind = unsafe { (*node).kind };
+    match kind {
+        NodeKind::Leaf => None,
+        NodeKind::Inner => {
+            let inner = node.cast::<InnerNode<K, V, E>>();
+            let child = if i < E {
+                unsafe {
+                    addr_of_mut!((*inner).lesser_nodes)
+                        .cast::<RawRelPtr>()
+                        .add(i)
+                }
+            } else {
+                unsafe { addr_of_mut!((*inner).greater_node) }
+            };
+            let child_is_invalid = unsafe { RawRelPtr::is_invalid_raw(child) };
+            if child_is_invalid {
+                None
+            } else {
+                Some(unsafe { RawRelPtr::as_ptr_raw(child).cast() })
+            }
+        }
+    }
+}
+
+/// # Safety
+///
+/// `node` must point to a valid node and `i` must be less than the length of
+/// that node.
+unsafe fn entry<K, V, const E: usize>(
+    node: *mut Node<K, V, E>,
+    i: usize,
+) -> (*mut K, *mut V) {
+    let k = unsafe { addr_of_mut!((*node).keys).cast::<K>().add(i) };
+    let v = unsafe { addr_of_mut!((*node).values).cast::<V>().add(i) };
+    (k, v)
+}
+
 /// An archived [`BTreeMap`](std::collections::BTreeMap).
 #[derive(Portable)]
 #[cfg_attr(
@@ -235,6 +301,51 @@ impl<K, V, const E: usize> ArchivedBTreeMap<K, V, E> {
         })
     }
 
+    /// Returns the first key-value pair in the B-tree map, or `None` if the map
+    /// is empty.
+    ///
+    /// The key in this pair is the minimum key in the map.
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        let this = (self as *const Self).cast_mut();
+        Self::edge_key_value_raw(this, false)
+            .map(|(k, v)| (unsafe { &*k }, unsafe { &*v }))
+    }
+
+    /// Returns the last key-value pair in the B-tree map, or `None` if the map
+    /// is empty.
+    ///
+    /// The key in this pair is the maximum key in the map.
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        let this = (self as *const Self).cast_mut();
+        Self::edge_key_value_raw(this, true)
+            .map(|(k, v)| (unsafe { &*k }, unsafe { &*v }))
+    }
+
+    fn edge_key_value_raw(
+        this: *mut Self,
+        last: bool,
+    ) -> Option<(*mut K, *mut V)> {
+        let len = unsafe { (*this).len.to_native() };
+        if len == 0 {
+            return None;
+        }
+
+        let root_ptr = unsafe { addr_of_mut!((*this).root) };
+        let mut current =
+            unsafe { RawRelPtr::as_ptr_raw(root_ptr).cast::<Node<K, V, E>>() };
+        loop {
+            let len = unsafe { node_len(current) };
+            let i = if last { len } else { 0 };
+            match unsafe { child_node(current, i) } {
+                Some(child) => current = child,
+                None => {
+                    let i = if last { len - 1 } else { 0 };
+                    return Some(unsafe { entry(current, i) });
+                }
+            }
+        }
+    }
+
     fn get_key_value_raw<Q>(
         this: *mut Self,
         key: &Q,
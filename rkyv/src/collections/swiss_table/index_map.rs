@@ -242,12 +242,15 @@ impl<K, V, H: Hasher + Default> ArchivedIndexMap<K, V, H> {
     }
 
     /// Returns whether a key is present in the hash map.
+    ///
+    /// This only looks up the index of the key, and never forms a reference to
+    /// the value.
     pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized,
     {
-        self.get(key).is_some()
+        self.get_index_of(key).is_some()
     }
 
     /// Gets a key-value pair by index.
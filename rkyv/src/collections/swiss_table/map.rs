@@ -18,7 +18,7 @@ use crate::{
         swiss_table::table::{ArchivedHashTable, HashTableResolver, RawIter},
         util::{Entry, EntryAdapter},
     },
-    hash::{hash_value, FxHasher64},
+    hash::{hash_unordered, hash_value, FxHasher64},
     ser::{Allocator, Writer},
     Place, Portable, Serialize,
 };
@@ -192,13 +192,27 @@ impl<K, V, H: Hasher + Default> ArchivedHashMap<K, V, H> {
         Some(self.get_key_value_pin(key)?.1)
     }
 
+    /// Returns whether the hash map contains the given key using the given
+    /// comparison function.
+    pub fn contains_key_with<Q, C>(&self, key: &Q, cmp: C) -> bool
+    where
+        Q: Hash + Eq + ?Sized,
+        C: Fn(&Q, &K) -> bool,
+    {
+        self.table
+            .contains_with(hash_value::<Q, H>(key), |e| cmp(key, &e.key))
+    }
+
     /// Returns whether the hash map contains the given key.
+    ///
+    /// This is cheaper than `get(key).is_some()` because it never forms a
+    /// reference to the value.
     pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized,
     {
-        self.get(key).is_some()
+        self.contains_key_with(key, |q, k| q == k.borrow())
     }
 
     /// Serializes an iterator of key-value pairs as a hash map.
@@ -250,6 +264,14 @@ where
     }
 }
 
+/// Archived hash maps are hashed independently of the order of their entries,
+/// using [`hash_unordered`].
+impl<K: Hash, V: Hash, H> Hash for ArchivedHashMap<K, V, H> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        hash_unordered(self.iter(), state);
+    }
+}
+
 impl<K, V, H> Eq for ArchivedHashMap<K, V, H>
 where
     K: Hash + Eq,
@@ -11,7 +11,7 @@ use rancor::{Fallible, Source};
 
 use crate::{
     collections::swiss_table::map::{ArchivedHashMap, HashMapResolver, Keys},
-    hash::FxHasher64,
+    hash::{hash_unordered, FxHasher64},
     ser::{Allocator, Writer},
     Place, Portable, Serialize,
 };
@@ -101,6 +101,14 @@ impl<K: fmt::Debug, H> fmt::Debug for ArchivedHashSet<K, H> {
     }
 }
 
+/// Archived hash sets are hashed independently of the order of their keys,
+/// using [`hash_unordered`].
+impl<K: Hash, H> Hash for ArchivedHashSet<K, H> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        hash_unordered(self.iter(), state);
+    }
+}
+
 impl<K: Hash + Eq, H: Hasher + Default> PartialEq for ArchivedHashSet<K, H> {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
@@ -71,18 +71,27 @@ struct ProbeSeq {
 }
 
 impl ProbeSeq {
+    /// Returns the position of the `g`th group in the current probe.
+    ///
+    /// Probe positions range over the next power of two of the capacity.
+    /// Positions past the end of the table are wrapped back into it rather
+    /// than skipped, which guarantees that every bucket is visited within
+    /// `probe_count` probes.
     #[inline]
-    fn next_group(&mut self) {
-        self.pos += Group::WIDTH;
+    fn group_pos(&self, g: usize, capacity: usize) -> usize {
+        let pos = if self.pos < capacity {
+            self.pos
+        } else {
+            self.pos - capacity
+        };
+        pos + g * Group::WIDTH
     }
 
     #[inline]
     fn move_next(&mut self, bucket_mask: usize) {
-        loop {
-            self.pos += self.stride;
-            self.pos &= bucket_mask;
-            self.stride += MAX_GROUP_WIDTH;
-        }
+        self.stride += MAX_GROUP_WIDTH;
+        self.pos += self.stride;
+        self.pos &= bucket_mask;
     }
 }
 
@@ -123,6 +132,11 @@ impl<T> ArchivedHashTable<T> {
         capacity.checked_next_power_of_two().unwrap() - 1
     }
 
+    /// Returns the number of probes needed to visit every bucket.
+    fn probe_count(bucket_mask: usize) -> usize {
+        bucket_mask / MAX_GROUP_WIDTH + 1
+    }
+
     fn get_entry<C>(&self, hash: u64, cmp: C) -> Option<NonNull<T>>
     where
         C: Fn(&T) -> bool,
@@ -137,14 +151,17 @@ impl<T> ArchivedHashTable<T> {
         let capacity = self.capacity();
         let bucket_mask = Self::bucket_mask(capacity);
 
-        loop {
+        // Validation does not guarantee that the table has an empty bucket, so
+        // stop after every bucket has been probed.
+        for _ in 0..Self::probe_count(bucket_mask) {
             let mut any_empty = false;
 
-            for _ in 0..MAX_GROUP_WIDTH / Group::WIDTH {
-                let group = unsafe { Group::read(self.control(probe_seq.pos)) };
+            for g in 0..MAX_GROUP_WIDTH / Group::WIDTH {
+                let pos = probe_seq.group_pos(g, capacity);
+                let group = unsafe { Group::read(self.control(pos)) };
 
                 for bit in group.match_byte(h2_hash) {
-                    let index = (probe_seq.pos + bit) % capacity;
+                    let index = (pos + bit) % capacity;
                     let bucket_ptr = unsafe { self.bucket(index) };
                     let bucket = unsafe { bucket_ptr.as_ref() };
 
@@ -156,21 +173,16 @@ impl<T> ArchivedHashTable<T> {
 
                 // Opt: These can be marked as likely true on nightly.
                 any_empty = any_empty || group.match_empty().any_bit_set();
-
-                probe_seq.next_group();
             }
 
             if any_empty {
                 return None;
             }
 
-            loop {
-                probe_seq.move_next(bucket_mask);
-                if probe_seq.pos < self.capacity() {
-                    break;
-                }
-            }
+            probe_seq.move_next(bucket_mask);
         }
+
+        None
     }
 
     /// Returns the key-value pair corresponding to the supplied key.
@@ -182,6 +194,18 @@ impl<T> ArchivedHashTable<T> {
         Some(unsafe { ptr.as_ref() })
     }
 
+    /// Returns whether the hash table contains an entry matching the supplied
+    /// comparison function.
+    ///
+    /// Unlike `get_with`, this does not form a reference to the matching
+    /// entry.
+    pub fn contains_with<C>(&self, hash: u64, cmp: C) -> bool
+    where
+        C: Fn(&T) -> bool,
+    {
+        self.get_entry(hash, cmp).is_some()
+    }
+
     /// Returns the mutable key-value pair corresponding to the supplied key.
     pub fn get_pin_with<C>(
         self: Pin<&mut Self>,
@@ -345,71 +369,96 @@ impl<T> ArchivedHashTable<T> {
             return Ok(HashTableResolver { pos: 0 });
         }
 
-        // Serialize all items
-        SerVec::with_capacity(serializer, len, |resolvers, serializer| {
-            for i in items.clone() {
-                if resolvers.len() == len {
-                    fail!(IteratorLengthMismatch {
-                        expected: len,
-                        actual: len + items.count(),
-                    });
-                }
+        // Allocate scratch space for the hash table storage
+        let capacity = Self::capacity_from_len(len, load_factor)?;
+        let control_count = Self::control_count(capacity)?;
 
-                resolvers.push(i.serialize(serializer)?);
-            }
+        let (layout, control_offset) =
+            Self::memory_layout(capacity, control_count)?;
 
-            // Allocate scratch space for the hash table storage
-            let capacity = Self::capacity_from_len(len, load_factor)?;
-            let control_count = Self::control_count(capacity)?;
+        let alloc = unsafe { serializer.push_alloc(layout)?.cast::<u8>() };
 
-            let (layout, control_offset) =
-                Self::memory_layout(capacity, control_count)?;
-
-            let alloc = unsafe { serializer.push_alloc(layout)?.cast::<u8>() };
+        // Initialize all non-control bytes to zero
+        unsafe {
+            ptr::write_bytes(alloc.as_ptr(), 0, control_offset);
+        }
 
-            // Initialize all non-control bytes to zero
-            unsafe {
-                ptr::write_bytes(alloc.as_ptr(), 0, control_offset);
-            }
+        let ptr = unsafe { alloc.as_ptr().add(control_offset) };
 
-            let ptr = unsafe { alloc.as_ptr().add(control_offset) };
+        // Initialize all control bytes to EMPTY (0xFF)
+        unsafe {
+            ptr::write_bytes(ptr, 0xff, control_count);
+        }
 
-            // Initialize all control bytes to EMPTY (0xFF)
-            unsafe {
-                ptr::write_bytes(ptr, 0xff, control_count);
-            }
+        let bucket_mask = Self::bucket_mask(capacity);
 
-            let bucket_mask = Self::bucket_mask(capacity);
+        let pos =
+            SerVec::with_capacity(serializer, len, |entries, serializer| {
+                // Find a bucket for each item
+                for (i, hash) in items.clone().zip(hashes) {
+                    if entries.len() == len {
+                        fail!(IteratorLengthMismatch {
+                            expected: len,
+                            actual: len + items.count(),
+                        });
+                    }
 
-            let pos = serializer.align(layout.align())?;
+                    let h2_hash = h2(hash);
+                    let mut probe_seq = Self::probe_seq(hash, capacity);
 
-            for ((i, resolver), hash) in
-                items.zip(resolvers.drain()).zip(hashes)
-            {
-                let h2_hash = h2(hash);
-                let mut probe_seq = Self::probe_seq(hash, capacity);
+                    // The table always has at least one empty bucket, and
+                    // the probe sequence visits every bucket, so this
+                    // always finds a bucket for the item.
+                    'insert: loop {
+                        for g in 0..MAX_GROUP_WIDTH / Group::WIDTH {
+                            let pos = probe_seq.group_pos(g, capacity);
+                            let group = unsafe { Group::read(ptr.add(pos)) };
 
-                'insert: loop {
-                    for _ in 0..MAX_GROUP_WIDTH / Group::WIDTH {
-                        let group =
-                            unsafe { Group::read(ptr.add(probe_seq.pos)) };
+                            if let Some(bit) =
+                                group.match_empty().lowest_set_bit()
+                            {
+                                let index = (pos + bit) % capacity;
 
-                        if let Some(bit) = group.match_empty().lowest_set_bit()
-                        {
-                            let index = (probe_seq.pos + bit) % capacity;
-
-                            // Update control byte
-                            unsafe {
-                                ptr.add(index).write(h2_hash);
-                            }
-                            // If it's near the end of the group, update the
-                            // wraparound control byte
-                            if index < control_count - capacity {
+                                // Update control byte
                                 unsafe {
-                                    ptr.add(capacity + index).write(h2_hash);
+                                    ptr.add(index).write(h2_hash);
                                 }
+                                // If it's near the end of the group, update the
+                                // wraparound control byte
+                                if index < control_count - capacity {
+                                    unsafe {
+                                        ptr.add(capacity + index)
+                                            .write(h2_hash);
+                                    }
+                                }
+
+                                entries.push((index, i));
+
+                                break 'insert;
                             }
+                        }
+
+                        probe_seq.move_next(bucket_mask);
+                    }
+                }
+
+                // Serialize all items in bucket order, which is the order they
+                // are checked in during validation.
+                entries.sort_unstable_by_key(|(index, _)| *index);
+
+                SerVec::with_capacity(
+                    serializer,
+                    len,
+                    |resolvers, serializer| {
+                        for (_, i) in entries.iter() {
+                            resolvers.push(i.serialize(serializer)?);
+                        }
+
+                        let pos = serializer.align(layout.align())?;
 
+                        for ((index, i), resolver) in
+                            entries.iter().zip(resolvers.drain())
+                        {
                             let entry_offset =
                                 control_offset - (index + 1) * size_of::<T>();
                             let out = unsafe {
@@ -422,35 +471,25 @@ impl<T> ArchivedHashTable<T> {
                                 )
                             };
                             i.resolve(resolver, out);
-
-                            break 'insert;
                         }
 
-                        probe_seq.next_group();
-                    }
+                        Ok(pos)
+                    },
+                )?
+            })??;
 
-                    loop {
-                        probe_seq.move_next(bucket_mask);
-                        if probe_seq.pos < capacity {
-                            break;
-                        }
-                    }
-                }
-            }
-
-            // Write out-of-line data
-            let slice =
-                unsafe { slice::from_raw_parts(alloc.as_ptr(), layout.size()) };
-            serializer.write(slice)?;
+        // Write out-of-line data
+        let slice =
+            unsafe { slice::from_raw_parts(alloc.as_ptr(), layout.size()) };
+        serializer.write(slice)?;
 
-            unsafe {
-                serializer.pop_alloc(alloc, layout)?;
-            }
+        unsafe {
+            serializer.pop_alloc(alloc, layout)?;
+        }
 
-            Ok(HashTableResolver {
-                pos: pos + control_offset,
-            })
-        })?
+        Ok(HashTableResolver {
+            pos: pos + control_offset,
+        })
     }
 
     /// Resolves an archived hash table from a given length and parameters.
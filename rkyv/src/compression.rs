@@ -0,0 +1,344 @@
+//! Compression codecs and archived compressed bytes.
+//!
+//! See [`Compressed`](crate::with::Compressed) for more details.
+
+use core::{fmt, marker::PhantomData};
+
+use munge::munge;
+use rancor::{fail, Fallible, Source};
+
+use crate::{
+    alloc::vec::Vec,
+    primitive::ArchivedUsize,
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Place, Portable,
+};
+
+/// A compression codec which can be used with
+/// [`Compressed`](crate::with::Compressed).
+pub trait Codec {
+    /// A tag which identifies the codec.
+    ///
+    /// The tag is stored alongside the compressed bytes and checked during
+    /// validation, so each codec must have a unique tag.
+    const TAG: u8;
+
+    /// Returns the maximum number of compressed bytes that `len` uncompressed
+    /// bytes can compress to.
+    fn max_compressed_len(len: usize) -> usize;
+
+    /// Returns the maximum number of uncompressed bytes that `compressed_len`
+    /// compressed bytes can decompress to.
+    ///
+    /// Validation rejects archived lengths larger than this, so that invalid
+    /// archives can't make decompression allocate huge outputs.
+    fn max_decompressed_len(compressed_len: usize) -> usize;
+
+    /// Compresses the given bytes and appends them to `output`.
+    fn compress(input: &[u8], output: &mut Vec<u8>);
+
+    /// Decompresses the given bytes and appends them to `output`.
+    ///
+    /// `len` is the length of the uncompressed bytes.
+    fn decompress<E: Source>(
+        input: &[u8],
+        len: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), E>;
+}
+
+/// A codec which compresses bytes in the LZ4 block format.
+#[cfg(feature = "lz4_flex")]
+#[derive(Debug)]
+pub struct Lz4;
+
+#[derive(Debug)]
+struct LengthMismatch {
+    expected: usize,
+    actual: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "decompressed {} bytes but expected {} bytes",
+            self.actual, self.expected,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatch {}
+
+/// Archived bytes compressed with the codec `C`.
+///
+/// The compressed bytes must be decompressed before they can be used, so this
+/// type is not zero-copy. Use [`decompress_to`](Self::decompress_to) to get the
+/// original bytes.
+#[derive(Portable)]
+#[rkyv(crate)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "bytecheck",
+    derive(bytecheck::CheckBytes),
+    check_bytes(verify)
+)]
+pub struct ArchivedCompressed<C> {
+    tag: u8,
+    len: ArchivedUsize,
+    bytes: ArchivedVec<u8>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> ArchivedCompressed<C> {
+    /// Returns the length of the uncompressed bytes.
+    pub fn len(&self) -> usize {
+        self.len.to_native() as usize
+    }
+
+    /// Returns whether the uncompressed bytes are empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the compressed bytes.
+    pub fn compressed_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl<C: Codec> ArchivedCompressed<C> {
+    /// Decompresses the archived bytes and appends them to `output`.
+    ///
+    /// Returns an error if the bytes could not be decompressed, or if they
+    /// didn't decompress to the archived length. If an error is returned, the
+    /// contents of `output` past its original length are unspecified.
+    pub fn decompress_to<E: Source>(
+        &self,
+        output: &mut Vec<u8>,
+    ) -> Result<(), E> {
+        let start = output.len();
+        C::decompress(self.compressed_bytes(), self.len(), output)?;
+        let actual = output.len() - start;
+        if actual != self.len() {
+            fail!(LengthMismatch {
+                expected: self.len(),
+                actual,
+            });
+        }
+        Ok(())
+    }
+
+    /// Resolves an `ArchivedCompressed` from the given length, resolver, and
+    /// output place.
+    pub fn resolve_from_len(
+        len: usize,
+        resolver: CompressedResolver,
+        out: Place<Self>,
+    ) {
+        munge! {
+            let ArchivedCompressed { tag, len: out_len, bytes, _phantom: _ } =
+                out;
+        }
+        tag.write(C::TAG);
+        usize::resolve(&len, (), out_len);
+        ArchivedVec::resolve_from_len(
+            resolver.compressed_len,
+            resolver.bytes,
+            bytes,
+        );
+    }
+
+    /// Compresses and serializes the given bytes.
+    pub fn serialize_from_slice<S>(
+        slice: &[u8],
+        serializer: &mut S,
+    ) -> Result<CompressedResolver, S::Error>
+    where
+        S: Fallible + Allocator + Writer + ?Sized,
+    {
+        let mut compressed = Vec::new();
+        C::compress(slice, &mut compressed);
+        Ok(CompressedResolver {
+            compressed_len: compressed.len(),
+            bytes: ArchivedVec::<u8>::serialize_from_slice(
+                compressed.as_slice(),
+                serializer,
+            )?,
+        })
+    }
+}
+
+impl<C> fmt::Debug for ArchivedCompressed<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchivedCompressed")
+            .field("tag", &self.tag)
+            .field("len", &self.len())
+            .field("compressed_len", &self.bytes.len())
+            .finish()
+    }
+}
+
+/// The resolver for [`ArchivedCompressed`].
+pub struct CompressedResolver {
+    compressed_len: usize,
+    bytes: VecResolver,
+}
+
+#[cfg(feature = "bytecheck")]
+pub use self::verify::CompressedError;
+
+#[cfg(feature = "bytecheck")]
+mod verify {
+    use core::fmt;
+
+    use bytecheck::{
+        rancor::{Fallible, Source},
+        Verify,
+    };
+    use rancor::fail;
+
+    use super::{ArchivedCompressed, Codec};
+
+    /// An error resulting from invalid compressed bytes.
+    #[derive(Debug)]
+    pub enum CompressedError {
+        /// The stored codec tag did not match the expected codec.
+        InvalidTag {
+            /// The expected codec tag.
+            expected: u8,
+            /// The stored codec tag.
+            found: u8,
+        },
+        /// The number of compressed bytes was inconsistent with the stored
+        /// uncompressed length. Either there were more compressed bytes than
+        /// the codec could produce for the stored length, or the stored length
+        /// was more than the compressed bytes could decompress to.
+        InvalidLength {
+            /// The stored uncompressed length.
+            len: usize,
+            /// The number of compressed bytes.
+            compressed_len: usize,
+        },
+    }
+
+    impl fmt::Display for CompressedError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::InvalidTag { expected, found } => write!(
+                    f,
+                    "invalid codec tag: expected {expected} but found {found}",
+                ),
+                Self::InvalidLength {
+                    len,
+                    compressed_len,
+                } => write!(
+                    f,
+                    "{compressed_len} compressed bytes cannot decompress to \
+                     {len} bytes",
+                ),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for CompressedError {}
+
+    unsafe impl<Z, C> Verify<C> for ArchivedCompressed<Z>
+    where
+        Z: Codec,
+        C: Fallible + ?Sized,
+        C::Error: Source,
+    {
+        fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+            if self.tag != Z::TAG {
+                fail!(CompressedError::InvalidTag {
+                    expected: Z::TAG,
+                    found: self.tag,
+                });
+            }
+
+            let compressed_len = self.bytes.len();
+            if compressed_len > Z::max_compressed_len(self.len())
+                || self.len() > Z::max_decompressed_len(compressed_len)
+            {
+                fail!(CompressedError::InvalidLength {
+                    len: self.len(),
+                    compressed_len,
+                });
+            }
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bytecheck"))]
+mod tests {
+    use rancor::{Failure, Source};
+
+    use super::{ArchivedCompressed, Codec};
+    use crate::{
+        access_unchecked_mut,
+        alloc::{vec, vec::Vec},
+        api::high::{access, to_bytes},
+        primitive::ArchivedUsize,
+        with::Compressed,
+        Archive, Serialize,
+    };
+
+    struct Identity;
+
+    impl Codec for Identity {
+        const TAG: u8 = 0xff;
+
+        fn max_compressed_len(len: usize) -> usize {
+            len
+        }
+
+        fn max_decompressed_len(compressed_len: usize) -> usize {
+            compressed_len
+        }
+
+        fn compress(input: &[u8], output: &mut Vec<u8>) {
+            output.extend_from_slice(input);
+        }
+
+        fn decompress<E: Source>(
+            input: &[u8],
+            _: usize,
+            output: &mut Vec<u8>,
+        ) -> Result<(), E> {
+            output.extend_from_slice(input);
+            Ok(())
+        }
+    }
+
+    #[derive(Archive, Serialize)]
+    #[rkyv(crate, check_bytes)]
+    struct Test {
+        #[with(Compressed<Identity>)]
+        payload: Vec<u8>,
+    }
+
+    #[test]
+    fn reject_oversized_len() {
+        let mut bytes = to_bytes::<Failure>(&Test {
+            payload: vec![1, 2, 3, 4],
+        })
+        .unwrap();
+        access::<ArchivedTest, Failure>(&bytes).unwrap();
+
+        // Claim that the four compressed bytes decompress to far more bytes
+        // than the codec can produce.
+        let archived =
+            unsafe { access_unchecked_mut::<ArchivedTest>(&mut bytes) };
+        let payload: &mut ArchivedCompressed<Identity> =
+            unsafe { &mut archived.get_unchecked_mut().payload };
+        payload.len = ArchivedUsize::from_native(u16::MAX as _);
+
+        assert!(access::<ArchivedTest, Failure>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Deserializers that can borrow data directly from the archive.
+
+use core::{mem::size_of, str};
+
+use rancor::Strategy;
+
+#[cfg(feature = "alloc")]
+use crate::de::pooling::Pool;
+use crate::{
+    de::pooling::{ErasedPtr, Pooling, Unpool},
+    Portable,
+};
+
+/// A deserializer that can lend out data from the buffer it is deserializing.
+///
+/// This trait is required to deserialize borrowed data with
+/// [`AsCowStr`](crate::with::AsCowStr). Deserializers which are not
+/// deserializing from a known buffer return `None` from `buffer`, which causes
+/// borrowed data to be deserialized as owned data instead.
+pub trait Borrowing<'a> {
+    /// Returns the buffer that archived values are being deserialized from, if
+    /// it is known.
+    fn buffer(&self) -> Option<&'a [u8]>;
+}
+
+impl<'a, T, E> Borrowing<'a> for Strategy<T, E>
+where
+    T: Borrowing<'a> + ?Sized,
+{
+    fn buffer(&self) -> Option<&'a [u8]> {
+        T::buffer(self)
+    }
+}
+
+impl<'a> Borrowing<'a> for () {
+    fn buffer(&self) -> Option<&'a [u8]> {
+        None
+    }
+}
+
+impl<'a> Borrowing<'a> for Unpool {
+    fn buffer(&self) -> Option<&'a [u8]> {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Borrowing<'a> for Pool {
+    fn buffer(&self) -> Option<&'a [u8]> {
+        None
+    }
+}
+
+/// Helper methods for [`Borrowing`].
+pub trait BorrowingExt<'a>: Borrowing<'a> {
+    /// Returns the given bytes borrowed for the lifetime of the buffer.
+    ///
+    /// Returns `None` if the buffer is unknown or the bytes are not located
+    /// entirely inside of the buffer.
+    fn borrow_bytes(&self, bytes: &[u8]) -> Option<&'a [u8]> {
+        let buffer = self.buffer()?;
+        let start =
+            (bytes.as_ptr() as usize).checked_sub(buffer.as_ptr() as usize)?;
+        let end = start.checked_add(bytes.len())?;
+        buffer.get(start..end)
+    }
+
+    /// Returns the given string borrowed for the lifetime of the buffer.
+    ///
+    /// Returns `None` if the buffer is unknown or the string is not located
+    /// entirely inside of the buffer.
+    fn borrow_str(&self, s: &str) -> Option<&'a str> {
+        let bytes = self.borrow_bytes(s.as_bytes())?;
+        // SAFETY: `bytes` is located at the same address and has the same
+        // length as `s`, so it contains valid UTF-8.
+        Some(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Returns the given archived value borrowed for the lifetime of the
+    /// buffer.
+    ///
+    /// Returns `None` if the buffer is unknown or the value is not located
+    /// entirely inside of the buffer.
+    fn borrow_archived<T: Portable>(&self, value: &T) -> Option<&'a T> {
+        let buffer = self.buffer()?;
+        let start = (value as *const T as usize)
+            .checked_sub(buffer.as_ptr() as usize)?;
+        let end = start.checked_add(size_of::<T>())?;
+        if end > buffer.len() {
+            return None;
+        }
+        // SAFETY: `value` is located entirely inside of `buffer`, which is
+        // borrowed immutably for `'a`. So the pointer at the same position in
+        // `buffer` points to the same valid `T`, which can't be modified or
+        // freed for `'a`.
+        Some(unsafe { &*buffer.as_ptr().add(start).cast::<T>() })
+    }
+}
+
+impl<'a, T> BorrowingExt<'a> for T where T: Borrowing<'a> + ?Sized {}
+
+/// A deserializer that lends out data from a buffer and forwards all other
+/// capabilities to an inner deserializer.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use rkyv::{
+///     access,
+///     de::{Borrower, Pool},
+///     rancor::Error,
+///     to_bytes,
+///     with::AsCowStr,
+///     Archive, Archived, Deserialize, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Example<'a> {
+///     #[with(AsCowStr)]
+///     name: Cow<'a, str>,
+/// }
+///
+/// let value = Example {
+///     name: Cow::Borrowed("a very long name that would be costly to copy"),
+/// };
+/// let bytes = to_bytes::<Error>(&value).unwrap();
+/// let archived = access::<Archived<Example>, Error>(&bytes).unwrap();
+///
+/// let mut deserializer = Borrower::new(&bytes, Pool::new());
+/// let deserialized: Example<'_> =
+///     rkyv::api::deserialize_with::<_, _, Error>(archived, &mut deserializer)
+///         .unwrap();
+/// assert!(matches!(deserialized.name, Cow::Borrowed(_)));
+/// assert_eq!(deserialized.name, value.name);
+/// ```
+#[derive(Debug)]
+pub struct Borrower<'a, D = ()> {
+    buffer: &'a [u8],
+    inner: D,
+}
+
+impl<'a, D> Borrower<'a, D> {
+    /// Creates a new borrowing deserializer from a buffer and an inner
+    /// deserializer.
+    pub fn new(buffer: &'a [u8], inner: D) -> Self {
+        Self { buffer, inner }
+    }
+
+    /// Consumes the deserializer and returns the inner deserializer.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<'a, D> Borrowing<'a> for Borrower<'a, D> {
+    fn buffer(&self) -> Option<&'a [u8]> {
+        Some(self.buffer)
+    }
+}
+
+impl<D, E> Pooling<E> for Borrower<'_, D>
+where
+    D: Pooling<E>,
+{
+    fn get_shared_ptr(&mut self, address: usize) -> Option<ErasedPtr> {
+        self.inner.get_shared_ptr(address)
+    }
+
+    unsafe fn add_shared_ptr(
+        &mut self,
+        address: usize,
+        ptr: ErasedPtr,
+        drop: unsafe fn(ErasedPtr),
+    ) -> Result<(), E> {
+        // SAFETY: The safety requirements for `add_shared_ptr` are the same as
+        // the requirements for calling this function.
+        unsafe { self.inner.add_shared_ptr(address, ptr, drop) }
+    }
+}
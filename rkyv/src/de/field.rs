@@ -0,0 +1,168 @@
+/// Deserializes a single field of an archived value.
+///
+/// `deserialize_field!(archived, path.to.field, deserializer)` deserializes
+/// only the field at the given path. The rest of the archived value is left
+/// untouched, so deserializing a small part of a large archive doesn't do any
+/// work for the other parts. The deserializer must be a `&mut` reference to a
+/// [`Strategy`](rancor::Strategy) wrapping a deserializer.
+///
+/// Each segment of the path is a named field or a tuple index. Paths go through
+/// `ArchivedBox`es transparently because they dereference to the value they
+/// contain. A segment can be followed by `?` to go through an archived option:
+/// if the option is `None` then the result is `Ok(None)`, and otherwise the
+/// rest of the path is followed and the field is returned in a `Some`. This
+/// works for any archived option with an `as_ref` method, like
+/// [`ArchivedOption`](crate::option::ArchivedOption) and
+/// [`ArchivedOptionBox`](crate::niche::option_box::ArchivedOptionBox).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     access,
+///     de::Pool,
+///     deserialize_field,
+///     rancor::{Error, Strategy},
+///     to_bytes, Archive, Deserialize, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Network {
+///     host: String,
+///     ports: (u16, u16),
+/// }
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Config {
+///     primary: Box<Network>,
+///     fallback: Option<Network>,
+///     assets: Vec<Vec<u8>>,
+/// }
+///
+/// let config = Config {
+///     primary: Box::new(Network {
+///         host: "example.com".to_string(),
+///         ports: (80, 443),
+///     }),
+///     fallback: Some(Network {
+///         host: "localhost".to_string(),
+///         ports: (8080, 8443),
+///     }),
+///     assets: vec![vec![0; 1024]; 16],
+/// };
+/// let bytes = to_bytes::<Error>(&config).unwrap();
+/// let archived = access::<ArchivedConfig, Error>(&bytes).unwrap();
+///
+/// let mut pool = Pool::new();
+/// let deserializer = Strategy::<_, Error>::wrap(&mut pool);
+///
+/// // Only the requested fields are deserialized, the assets are never copied.
+/// let host: String =
+///     deserialize_field!(archived, primary.host, deserializer).unwrap();
+/// assert_eq!(host, "example.com");
+///
+/// let port: u16 =
+///     deserialize_field!(archived, primary.ports.1, deserializer).unwrap();
+/// assert_eq!(port, 443);
+///
+/// let fallback_host: Option<String> =
+///     deserialize_field!(archived, fallback?.host, deserializer).unwrap();
+/// assert_eq!(fallback_host.as_deref(), Some("localhost"));
+/// ```
+#[macro_export]
+macro_rules! deserialize_field {
+    (@field plain ($value:expr) , $deserializer:expr $(,)?) => {
+        $crate::Deserialize::deserialize($value, $deserializer)
+    };
+    (@field opt ($value:expr) , $deserializer:expr $(,)?) => {
+        $crate::Deserialize::deserialize($value, $deserializer)
+            .map(::core::option::Option::Some)
+    };
+    (@field $mode:ident ($value:expr) . $field:tt $($rest:tt)*) => {
+        $crate::deserialize_field!(@field $mode (&$value.$field) $($rest)*)
+    };
+    (@field $mode:ident ($value:expr) ? $($rest:tt)*) => {
+        match $value.as_ref() {
+            ::core::option::Option::Some(value) => {
+                $crate::deserialize_field!(@field opt (value) $($rest)*)
+            }
+            ::core::option::Option::None => {
+                ::core::result::Result::Ok(::core::option::Option::None)
+            }
+        }
+    };
+    ($archived:expr, $($path:tt)+) => {
+        $crate::deserialize_field!(@field plain ($archived) . $($path)+)
+    };
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use rancor::{Panic, Strategy};
+
+    use crate::{
+        alloc::{boxed::Box, string::String, vec, vec::Vec},
+        api::test::to_archived,
+        de::Pool,
+        Archive, Deserialize, Serialize,
+    };
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    #[rkyv(crate, check_bytes)]
+    struct Leaf {
+        name: String,
+        values: Vec<u32>,
+    }
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    #[rkyv(crate, check_bytes)]
+    struct Branch {
+        boxed: Box<Leaf>,
+        optional: Option<Leaf>,
+        optional_boxed: Option<Box<Leaf>>,
+        pair: (u8, Leaf),
+    }
+
+    fn leaf(name: &str) -> Leaf {
+        Leaf {
+            name: name.into(),
+            values: vec![1, 2, 3],
+        }
+    }
+
+    #[test]
+    fn deserialize_field_paths() {
+        let value = Branch {
+            boxed: Box::new(leaf("boxed")),
+            optional: Some(leaf("optional")),
+            optional_boxed: None,
+            pair: (1, leaf("pair")),
+        };
+
+        to_archived(&value, |archived| {
+            let archived = &*archived;
+            let mut pool = Pool::new();
+            let d = Strategy::<_, Panic>::wrap(&mut pool);
+
+            let boxed: Box<Leaf> =
+                deserialize_field!(archived, boxed, d).unwrap();
+            assert_eq!(boxed, value.boxed);
+            let name: String =
+                deserialize_field!(archived, boxed.name, d).unwrap();
+            assert_eq!(name, "boxed");
+
+            let optional: Option<Vec<u32>> =
+                deserialize_field!(archived, optional?.values, d).unwrap();
+            assert_eq!(optional, Some(vec![1, 2, 3]));
+
+            let optional_boxed: Option<String> =
+                deserialize_field!(archived, optional_boxed?.name, d).unwrap();
+            assert_eq!(optional_boxed, None);
+
+            let pair: Leaf = deserialize_field!(archived, pair.1, d).unwrap();
+            assert_eq!(pair, value.pair.1);
+        });
+    }
+}
@@ -1,6 +1,8 @@
 //! Deserialization traits, deserializers, and adapters.
 
+pub mod borrowing;
+mod field;
 pub mod pooling;
 
 #[doc(inline)]
-pub use self::pooling::*;
+pub use self::{borrowing::*, pooling::*};
@@ -4,6 +4,7 @@ use hashbrown::hash_map::{Entry, HashMap};
 use rancor::{fail, Source};
 
 use crate::{
+    alloc::vec::Vec,
     de::pooling::{ErasedPtr, Pooling},
     hash::FxHasher64,
 };
@@ -96,3 +97,44 @@ impl<E: Source> Pooling<E> for Pool {
         }
     }
 }
+
+/// A shared pointer strategy that deserializes every shared pointer to a new
+/// allocation.
+///
+/// Unlike [`Pool`], this does not look up shared pointers which have already
+/// been deserialized, so each `Rc` or `Arc` is deserialized as an independent
+/// copy of its value. This avoids hashing the address of every shared pointer,
+/// but uses more memory when many shared pointers point to the same value.
+///
+/// This does not change how shared pointers are archived. To avoid sharing in
+/// the archive as well, use [`Unshare`](crate::with::Unshare).
+#[derive(Debug, Default)]
+pub struct Unshared {
+    shared_pointers: Vec<SharedPointer>,
+}
+
+impl Unshared {
+    /// Creates a new unshared pointer strategy.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Pooling<E> for Unshared {
+    fn get_shared_ptr(&mut self, _: usize) -> Option<ErasedPtr> {
+        None
+    }
+
+    unsafe fn add_shared_ptr(
+        &mut self,
+        _: usize,
+        ptr: ErasedPtr,
+        drop: unsafe fn(ErasedPtr),
+    ) -> Result<(), E> {
+        // The shared pointer is still kept so that its reference is released
+        // when the strategy is dropped.
+        self.shared_pointers.push(SharedPointer { ptr, drop });
+        Ok(())
+    }
+}
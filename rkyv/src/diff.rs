@@ -0,0 +1,482 @@
+//! Structural diffs between archived values.
+//!
+//! [`archived_diff`] compares two archived values of the same type and returns
+//! a [`Patch`] which contains only the leaf values that changed. Applying the
+//! patch to the deserialized first value reconstructs the second value.
+//!
+//! `Diff` is implemented for `bool`, integers, floats, `char`, `String`, and
+//! `Vec<T>`, and can be derived for structs with
+//! [`#[derive(Diff)]`](macro@Diff).
+//!
+//! # Example
+//!
+//! ```
+//! use rkyv::{
+//!     access,
+//!     diff::{archived_diff, Diff},
+//!     rancor::Error,
+//!     to_bytes, Archive, Deserialize, Serialize,
+//! };
+//!
+//! #[derive(Archive, Serialize, Deserialize, Diff, Debug, PartialEq)]
+//! #[rkyv(check_bytes)]
+//! struct Sensor {
+//!     id: u32,
+//!     enabled: bool,
+//!     readings: Vec<f32>,
+//! }
+//!
+//! let old = Sensor {
+//!     id: 7,
+//!     enabled: true,
+//!     readings: vec![1.0, 2.0, 3.0],
+//! };
+//! let new = Sensor {
+//!     id: 7,
+//!     enabled: false,
+//!     readings: vec![1.0, 2.5],
+//! };
+//!
+//! let old_bytes = to_bytes::<Error>(&old).unwrap();
+//! let new_bytes = to_bytes::<Error>(&new).unwrap();
+//! let a = access::<ArchivedSensor, Error>(&old_bytes).unwrap();
+//! let b = access::<ArchivedSensor, Error>(&new_bytes).unwrap();
+//!
+//! // `enabled`, the length of `readings`, and `readings[1]` changed.
+//! let patch = archived_diff::<Sensor>(a, b);
+//! assert_eq!(patch.changes().len(), 3);
+//!
+//! let mut value = rkyv::deserialize::<Sensor, Error>(a).unwrap();
+//! patch.apply::<_, Error>(&mut value).unwrap();
+//! assert_eq!(value, new);
+//! ```
+
+use core::fmt;
+
+pub use ::rkyv_derive::Diff;
+use rancor::{fail, Source};
+
+use crate::{
+    alloc::{string::String, vec::Vec},
+    string::ArchivedString,
+    vec::ArchivedVec,
+    Archive, Deserialize, Serialize,
+};
+
+/// A type whose archived values can be diffed and patched.
+///
+/// Changes are recorded with a [`Differ`] as a path of field and element
+/// indices to a leaf, along with the new [`Value`] of that leaf.
+///
+/// This can be derived for structs with [`Diff`](macro@Diff).
+pub trait Diff: Archive {
+    /// Records the changes needed to turn `a` into `b`.
+    fn diff(a: &Self::Archived, b: &Self::Archived, differ: &mut Differ);
+
+    /// Records the changes needed to turn a default value into `b`.
+    ///
+    /// This is used for values which don't have a counterpart to diff against,
+    /// like the elements added to the end of a `Vec`.
+    fn diff_all(b: &Self::Archived, differ: &mut Differ);
+
+    /// Applies a change to the leaf at `path`.
+    ///
+    /// Returns an error if `path` does not lead to a leaf, or if `value` is not
+    /// the right kind of value for that leaf.
+    fn apply<E: Source>(
+        &mut self,
+        path: &[u64],
+        value: &Value,
+    ) -> Result<(), E>;
+}
+
+/// A new leaf value recorded in a [`Patch`].
+#[derive(Archive, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[rkyv(crate, check_bytes)]
+pub enum Value {
+    /// A new `bool`.
+    Bool(bool),
+    /// A new `i8`.
+    I8(i8),
+    /// A new `i16`.
+    I16(i16),
+    /// A new `i32`.
+    I32(i32),
+    /// A new `i64`.
+    I64(i64),
+    /// A new `i128`.
+    I128(i128),
+    /// A new `u8`.
+    U8(u8),
+    /// A new `u16`.
+    U16(u16),
+    /// A new `u32`.
+    U32(u32),
+    /// A new `u64`.
+    U64(u64),
+    /// A new `u128`.
+    U128(u128),
+    /// A new `f32`.
+    F32(f32),
+    /// A new `f64`.
+    F64(f64),
+    /// A new `char`.
+    Char(char),
+    /// A new `String`.
+    String(String),
+    /// A new length for a `Vec`.
+    ///
+    /// Elements are removed from the end or default-initialized as needed.
+    Len(u64),
+}
+
+/// A single change in a [`Patch`].
+#[derive(Archive, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[rkyv(crate, check_bytes)]
+pub struct Change {
+    path: Vec<u64>,
+    value: Value,
+}
+
+impl Change {
+    /// Returns the field and element indices which lead to the changed leaf.
+    pub fn path(&self) -> &[u64] {
+        &self.path
+    }
+
+    /// Returns the new value of the changed leaf.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+/// The changes needed to turn one archived value into another.
+///
+/// Patches can be archived, so they can be sent to replicas which hold the
+/// original value. Returned by [`archived_diff`].
+#[derive(Archive, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[rkyv(crate, check_bytes)]
+pub struct Patch {
+    changes: Vec<Change>,
+}
+
+impl Patch {
+    /// Returns the changes in the patch in the order they are applied.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Returns whether the patch has no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies the patch to the given value.
+    ///
+    /// Applying a patch from [`archived_diff`] to the deserialized first value
+    /// turns it into the second value. If an error is returned, some of the
+    /// changes may have already been applied.
+    pub fn apply<T: Diff, E: Source>(&self, value: &mut T) -> Result<(), E> {
+        for change in self.changes.iter() {
+            value.apply(&change.path, &change.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Records changes to a [`Patch`] while diffing.
+#[derive(Debug, Default)]
+pub struct Differ {
+    path: Vec<u64>,
+    patch: Patch,
+}
+
+impl Differ {
+    /// Records the changes made by `f` under the field or element at `index`.
+    pub fn field(&mut self, index: u64, f: impl FnOnce(&mut Self)) {
+        self.path.push(index);
+        f(self);
+        self.path.pop();
+    }
+
+    /// Records a new value for the leaf at the current path.
+    pub fn change(&mut self, value: Value) {
+        self.patch.changes.push(Change {
+            path: self.path.clone(),
+            value,
+        });
+    }
+}
+
+/// Returns the changes needed to turn `a` into `b`.
+///
+/// See the [module docs](self) for an example.
+pub fn archived_diff<T: Diff>(a: &T::Archived, b: &T::Archived) -> Patch {
+    let mut differ = Differ::default();
+    T::diff(a, b, &mut differ);
+    differ.patch
+}
+
+/// The error returned when a patch is applied to a value that it doesn't
+/// match.
+#[derive(Debug)]
+pub struct PatchMismatch;
+
+impl fmt::Display for PatchMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "patch does not match the value it was applied to")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchMismatch {}
+
+macro_rules! impl_diff_leaf {
+    ($($ty:ty: $variant:ident(|$b:ident| $native:expr)),* $(,)?) => {
+        $(
+            impl Diff for $ty {
+                fn diff(
+                    a: &Self::Archived,
+                    b: &Self::Archived,
+                    differ: &mut Differ,
+                ) {
+                    if a != b {
+                        Self::diff_all(b, differ);
+                    }
+                }
+
+                fn diff_all($b: &Self::Archived, differ: &mut Differ) {
+                    differ.change(Value::$variant($native));
+                }
+
+                fn apply<E: Source>(
+                    &mut self,
+                    path: &[u64],
+                    value: &Value,
+                ) -> Result<(), E> {
+                    match (path, value) {
+                        ([], Value::$variant(value)) => {
+                            *self = *value;
+                            Ok(())
+                        }
+                        _ => fail!(PatchMismatch),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_diff_leaf! {
+    bool: Bool(|b| *b),
+    i8: I8(|b| *b),
+    i16: I16(|b| b.to_native()),
+    i32: I32(|b| b.to_native()),
+    i64: I64(|b| b.to_native()),
+    i128: I128(|b| b.to_native()),
+    u8: U8(|b| *b),
+    u16: U16(|b| b.to_native()),
+    u32: U32(|b| b.to_native()),
+    u64: U64(|b| b.to_native()),
+    u128: U128(|b| b.to_native()),
+    f32: F32(|b| b.to_native()),
+    f64: F64(|b| b.to_native()),
+    char: Char(|b| b.to_native()),
+}
+
+impl Diff for String {
+    fn diff(a: &ArchivedString, b: &ArchivedString, differ: &mut Differ) {
+        if a.as_str() != b.as_str() {
+            Self::diff_all(b, differ);
+        }
+    }
+
+    fn diff_all(b: &ArchivedString, differ: &mut Differ) {
+        differ.change(Value::String(String::from(b.as_str())));
+    }
+
+    fn apply<E: Source>(
+        &mut self,
+        path: &[u64],
+        value: &Value,
+    ) -> Result<(), E> {
+        match (path, value) {
+            ([], Value::String(value)) => {
+                self.clone_from(value);
+                Ok(())
+            }
+            _ => fail!(PatchMismatch),
+        }
+    }
+}
+
+impl<T: Diff + Default> Diff for Vec<T> {
+    fn diff(
+        a: &ArchivedVec<T::Archived>,
+        b: &ArchivedVec<T::Archived>,
+        differ: &mut Differ,
+    ) {
+        if a.len() != b.len() {
+            differ.change(Value::Len(b.len() as u64));
+        }
+        for (i, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+            differ.field(i as u64, |differ| T::diff(a, b, differ));
+        }
+        for (i, b) in b.iter().enumerate().skip(a.len()) {
+            differ.field(i as u64, |differ| T::diff_all(b, differ));
+        }
+    }
+
+    fn diff_all(b: &ArchivedVec<T::Archived>, differ: &mut Differ) {
+        differ.change(Value::Len(b.len() as u64));
+        for (i, b) in b.iter().enumerate() {
+            differ.field(i as u64, |differ| T::diff_all(b, differ));
+        }
+    }
+
+    fn apply<E: Source>(
+        &mut self,
+        path: &[u64],
+        value: &Value,
+    ) -> Result<(), E> {
+        match (path.split_first(), value) {
+            (None, &Value::Len(len)) => {
+                let Ok(len) = usize::try_from(len) else {
+                    fail!(PatchMismatch);
+                };
+                self.resize_with(len, T::default);
+                Ok(())
+            }
+            (Some((&index, rest)), _) => {
+                let element = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.get_mut(index));
+                match element {
+                    Some(element) => element.apply(rest, value),
+                    None => fail!(PatchMismatch),
+                }
+            }
+            _ => fail!(PatchMismatch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rancor::Failure;
+
+    use super::{archived_diff, Diff, Patch, Value};
+    use crate::{
+        alloc::{
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
+        api::test::{deserialize, to_archived},
+        Archive, Deserialize, Serialize,
+    };
+
+    #[derive(
+        Archive, Serialize, Deserialize, Diff, Debug, Default, PartialEq,
+    )]
+    #[rkyv(crate, check_bytes)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(
+        Archive, Serialize, Deserialize, Diff, Debug, Default, PartialEq,
+    )]
+    #[rkyv(crate, check_bytes)]
+    struct Shape {
+        name: String,
+        closed: bool,
+        points: Vec<Point>,
+    }
+
+    fn triangle() -> Shape {
+        Shape {
+            name: "triangle".to_string(),
+            closed: true,
+            points: vec![
+                Point { x: 0, y: 0 },
+                Point { x: 4, y: 0 },
+                Point { x: 0, y: 3 },
+            ],
+        }
+    }
+
+    fn diff(a: &Shape, b: &Shape) -> Patch {
+        let mut patch = None;
+        to_archived(a, |a| {
+            to_archived(b, |b| {
+                patch = Some(archived_diff::<Shape>(&a, &b));
+            });
+        });
+        patch.unwrap()
+    }
+
+    fn diff_and_apply(a: &Shape, b: &Shape) -> Patch {
+        let patch = diff(a, b);
+        to_archived(a, |a| {
+            let mut value = deserialize::<Shape>(&*a);
+            patch.apply::<_, Failure>(&mut value).unwrap();
+            assert_eq!(&value, b);
+        });
+        patch
+    }
+
+    #[test]
+    fn diff_identical() {
+        assert!(diff_and_apply(&triangle(), &triangle()).is_empty());
+    }
+
+    #[test]
+    fn diff_fields() {
+        let mut b = triangle();
+        b.closed = false;
+        b.points[1].y = 5;
+
+        let patch = diff_and_apply(&triangle(), &b);
+        let changes = patch
+            .changes()
+            .iter()
+            .map(|c| (c.path().to_vec(), c.value().clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                (vec![1], Value::Bool(false)),
+                (vec![2, 1, 1], Value::I32(5)),
+            ],
+        );
+    }
+
+    #[test]
+    fn diff_vec_lengths() {
+        let mut longer = triangle();
+        longer.name = "quad".to_string();
+        longer.points.push(Point { x: 4, y: 3 });
+        diff_and_apply(&triangle(), &longer);
+
+        let mut shorter = triangle();
+        shorter.points.truncate(1);
+        let patch = diff_and_apply(&triangle(), &shorter);
+        assert_eq!(patch.changes().len(), 1);
+        assert_eq!(patch.changes()[0].value(), &Value::Len(1));
+
+        diff_and_apply(&Shape::default(), &triangle());
+        diff_and_apply(&triangle(), &Shape::default());
+    }
+
+    #[test]
+    fn apply_mismatched_patch() {
+        let mut b = triangle();
+        b.points[2].x = 1;
+        let patch = diff(&triangle(), &b);
+
+        let mut value = Shape::default();
+        patch.apply::<_, Failure>(&mut value).unwrap_err();
+    }
+}
@@ -2,8 +2,11 @@
 
 use core::{
     borrow::Borrow,
-    cmp, fmt, hash,
+    cmp,
+    ffi::c_char,
+    fmt, hash,
     ops::{Deref, Index, RangeFull},
+    str::Utf8Error,
 };
 use std::ffi::CStr;
 
@@ -48,6 +51,47 @@ impl ArchivedCString {
         self.as_c_str().to_bytes_with_nul()
     }
 
+    /// Returns the contents of this CString as a slice of bytes.
+    ///
+    /// This is the same as [`as_bytes`][ArchivedCString::as_bytes()], and
+    /// matches [`CStr::to_bytes`].
+    #[inline]
+    pub fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    /// Returns the contents of this CString as a slice of bytes, including the
+    /// trailing nul terminator.
+    ///
+    /// This is the same as
+    /// [`as_bytes_with_nul`][ArchivedCString::as_bytes_with_nul()], and
+    /// matches [`CStr::to_bytes_with_nul`].
+    #[inline]
+    pub fn to_bytes_with_nul(&self) -> &[u8] {
+        self.as_bytes_with_nul()
+    }
+
+    /// Yields a `&str` slice if the CString contains valid UTF-8.
+    ///
+    /// Returns an error if the bytes are not valid UTF-8. The returned slice
+    /// does not contain the trailing nul terminator.
+    #[inline]
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        self.as_c_str().to_str()
+    }
+
+    /// Returns a pointer to the nul-terminated contents of this CString.
+    ///
+    /// The pointer can be passed directly to C functions which take a
+    /// `*const c_char` without copying, and is valid for as long as the
+    /// archived string is borrowed. Validation checks that the archived bytes
+    /// end with a nul terminator, so a checked `ArchivedCString` is always
+    /// terminated.
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_char {
+        self.as_c_str().as_ptr()
+    }
+
     /// Extracts a `CStr` slice containing the entire string.
     #[inline]
     pub fn as_c_str(&self) -> &CStr {
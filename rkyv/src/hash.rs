@@ -123,3 +123,100 @@ where
     value.hash(&mut state);
     state.finish()
 }
+
+/// Hashes the given value with [`FxHasher64`].
+///
+/// This is the same hash that archived hash maps and sets compute for their
+/// keys by default, and it is the same on every platform. It can be used to
+/// precompute the hashes of keys before they are archived, for example to
+/// build external indexes which are consistent with archived maps.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{hash::hash_value_fx, rancor::Error, Archived};
+///
+/// let value = "hello world".to_string();
+/// let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+/// let archived = rkyv::access::<Archived<String>, Error>(&bytes).unwrap();
+///
+/// assert_eq!(hash_value_fx(&value), hash_value_fx(archived));
+/// ```
+pub fn hash_value_fx<Q>(value: &Q) -> u64
+where
+    Q: Hash + ?Sized,
+{
+    hash_value::<Q, FxHasher64>(value)
+}
+
+/// Feeds an order-independent hash of the given items into `state`.
+///
+/// This is the algorithm that [`ArchivedHashMap`] and [`ArchivedHashSet`] use
+/// to implement `Hash`. Each item is hashed with [`hash_value_fx`], and the
+/// results are combined with XOR so that the order of the items doesn't
+/// matter. Then the number of items is written with `write_usize`, followed by
+/// the combined hash with `write_u64`. Map entries are hashed as `(key, value)`
+/// tuples, and set entries are hashed as their keys.
+///
+/// Native hash maps and sets don't implement `Hash`, but this can be used to
+/// hash them consistently with their archived counterparts.
+///
+/// This algorithm will not change between versions with the same major
+/// version.
+///
+/// [`ArchivedHashMap`]: crate::collections::swiss_table::ArchivedHashMap
+/// [`ArchivedHashSet`]: crate::collections::swiss_table::ArchivedHashSet
+///
+/// # Example
+///
+/// ```
+/// use core::hash::{Hash, Hasher};
+/// use std::collections::{hash_map::DefaultHasher, HashMap};
+///
+/// use rkyv::{hash::hash_unordered, rancor::Error, Archived};
+///
+/// let mut value = HashMap::new();
+/// value.insert("a".to_string(), 1);
+/// value.insert("b".to_string(), 2);
+///
+/// let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+/// let archived =
+///     rkyv::access::<Archived<HashMap<String, i32>>, Error>(&bytes).unwrap();
+///
+/// let mut native_state = DefaultHasher::new();
+/// hash_unordered(value.iter(), &mut native_state);
+/// let mut archived_state = DefaultHasher::new();
+/// archived.hash(&mut archived_state);
+///
+/// assert_eq!(native_state.finish(), archived_state.finish());
+/// ```
+pub fn hash_unordered<I, S>(items: I, state: &mut S)
+where
+    I: IntoIterator,
+    I::Item: Hash,
+    S: Hasher + ?Sized,
+{
+    let mut len = 0;
+    let mut combined = 0;
+    for item in items {
+        len += 1;
+        combined ^= hash_value_fx(&item);
+    }
+    state.write_usize(len);
+    state.write_u64(combined);
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::hash_value_fx;
+    use crate::{alloc::string::String, api::test::to_archived};
+
+    #[test]
+    fn hash_value_fx_matches_archived() {
+        let value = String::from("hello world");
+        to_archived(&value, |archived| {
+            assert_eq!(hash_value_fx(&value), hash_value_fx(&*archived));
+            assert_eq!(hash_value_fx("hello world"), hash_value_fx(&*archived));
+        });
+    }
+}
@@ -3,12 +3,15 @@ use core::cmp;
 use rancor::{Fallible, ResultExt as _, Source};
 
 use crate::{
-    alloc::{alloc::alloc, boxed::Box},
+    alloc::{
+        alloc::{alloc, dealloc, handle_alloc_error},
+        boxed::Box,
+    },
     boxed::{ArchivedBox, BoxResolver},
     niche::option_box::ArchivedOptionBox,
     traits::{ArchivePointee, LayoutRaw},
-    Archive, ArchiveUnsized, Deserialize, DeserializeUnsized, Place, Serialize,
-    SerializeUnsized,
+    Archive, ArchiveUnsized, Deserialize, DeserializeInto, DeserializeUnsized,
+    Place, Serialize, SerializeUnsized,
 };
 
 impl<T: ArchiveUnsized + ?Sized> Archive for Box<T> {
@@ -44,20 +47,48 @@ where
         let metadata = self.get().deserialize_metadata();
         let layout = T::layout_raw(metadata).into_error()?;
         let data_address = if layout.size() > 0 {
-            unsafe { alloc(layout) }
+            let ptr = unsafe { alloc(layout) };
+            if ptr.is_null() {
+                handle_alloc_error(layout);
+            }
+            ptr
         } else {
             crate::polyfill::dangling(&layout).as_ptr()
         };
 
         let out = ptr_meta::from_raw_parts_mut(data_address.cast(), metadata);
 
-        unsafe {
-            self.get().deserialize_unsized(deserializer, out)?;
+        let result =
+            unsafe { self.get().deserialize_unsized(deserializer, out) };
+        if let Err(error) = result {
+            // Any values which were already deserialized are leaked, but the
+            // allocation is freed.
+            if layout.size() > 0 {
+                unsafe { dealloc(data_address, layout) };
+            }
+            return Err(error);
         }
         unsafe { Ok(Box::from_raw(out)) }
     }
 }
 
+impl<T, D> DeserializeInto<Box<T>, D> for ArchivedBox<T::Archived>
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut Box<T>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        // Deserialize into the boxed value so that the box is reused.
+        self.get().deserialize_into(&mut **place, deserializer)
+    }
+}
+
 impl<T, U> PartialEq<Box<U>> for ArchivedBox<T>
 where
     T: ArchivePointee + PartialEq<U> + ?Sized,
@@ -95,7 +126,12 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        alloc::{boxed::Box, string::ToString, vec, vec::Vec},
+        alloc::{
+            boxed::Box,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        },
         api::test::roundtrip,
     };
 
@@ -115,6 +151,23 @@ mod tests {
     fn roundtrip_boxed_slice() {
         roundtrip(&Vec::<i32>::new().into_boxed_slice());
         roundtrip(&vec![1, 2, 3, 4].into_boxed_slice());
+        roundtrip(&Vec::<String>::new().into_boxed_slice());
+        roundtrip(
+            &vec!["hello".to_string(), "world".to_string()].into_boxed_slice(),
+        );
+    }
+
+    #[test]
+    fn boxed_slice_layout() {
+        use core::mem::size_of;
+
+        use crate::{primitive::ArchivedUsize, Archived};
+
+        // Archived boxed slices are just a relative pointer and a length.
+        assert_eq!(
+            size_of::<Archived<Box<[u32]>>>(),
+            2 * size_of::<ArchivedUsize>(),
+        );
     }
 
     #[test]
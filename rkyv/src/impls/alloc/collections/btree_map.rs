@@ -6,7 +6,7 @@ use crate::{
     alloc::collections::BTreeMap,
     collections::btree_map::{ArchivedBTreeMap, BTreeMapResolver},
     ser::{Allocator, Writer},
-    Archive, Deserialize, Place, Serialize,
+    Archive, Deserialize, DeserializeInto, Place, Serialize,
 };
 
 impl<K: Archive + Ord, V: Archive> Archive for BTreeMap<K, V>
@@ -70,6 +70,19 @@ where
     }
 }
 
+// B-trees don't have any capacity to reuse, so the default implementation is
+// used.
+impl<K, V, D> DeserializeInto<BTreeMap<K, V>, D>
+    for ArchivedBTreeMap<K::Archived, V::Archived>
+where
+    K: Archive + Ord,
+    K::Archived: Deserialize<K, D> + Ord,
+    V: Archive,
+    V::Archived: Deserialize<V, D>,
+    D: Fallible + ?Sized,
+{
+}
+
 impl<K, V, AK, AV> PartialEq<BTreeMap<K, V>> for ArchivedBTreeMap<AK, AV>
 where
     AK: PartialEq<K>,
@@ -95,17 +108,25 @@ where
 
 #[cfg(test)]
 mod tests {
-    use core::{ops::ControlFlow, pin::Pin};
+    use core::{
+        cmp::Ordering,
+        ops::{Bound, ControlFlow},
+        pin::Pin,
+    };
+
+    use equivalent::{Comparable, Equivalent};
 
     use crate::{
         alloc::{
             collections::BTreeMap,
+            format,
             string::{String, ToString},
             vec,
             vec::Vec,
         },
         api::test::{roundtrip, to_archived},
-        primitive::ArchivedI32,
+        primitive::{ArchivedI32, ArchivedU32},
+        string::ArchivedString,
         Archive, Deserialize, Serialize,
     };
 
@@ -229,6 +250,37 @@ mod tests {
         });
     }
 
+    #[test]
+    fn btree_map_increment_in_place() {
+        // Enough entries to span multiple levels of the B-tree.
+        const ENTRIES: u32 = 200;
+
+        let mut value = BTreeMap::new();
+        for i in 0..ENTRIES {
+            value.insert(i, 0u32);
+        }
+
+        to_archived(&value, |mut archived| {
+            for _ in 0..3 {
+                for i in (0..ENTRIES).step_by(7) {
+                    let key = ArchivedU32::from_native(i);
+                    let mut counter = archived.as_mut().get_pin(&key).unwrap();
+                    *counter =
+                        ArchivedU32::from_native(counter.to_native() + 1);
+                }
+            }
+            let missing = ArchivedU32::from_native(ENTRIES);
+            assert!(archived.as_mut().get_pin(&missing).is_none());
+
+            assert_eq!(archived.len(), ENTRIES as usize);
+            for i in 0..ENTRIES {
+                let key = ArchivedU32::from_native(i);
+                let expected = if i % 7 == 0 { 3 } else { 0 };
+                assert_eq!(archived.get(&key).unwrap().to_native(), expected);
+            }
+        });
+    }
+
     #[test]
     fn btree_map_iter() {
         let mut value = BTreeMap::<String, i32>::new();
@@ -266,4 +318,164 @@ mod tests {
             assert_eq!(i.next(), None);
         });
     }
+
+    #[test]
+    fn btree_map_first_last_key_value() {
+        to_archived(&BTreeMap::<String, i32>::new(), |archived| {
+            assert!(archived.first_key_value().is_none());
+            assert!(archived.last_key_value().is_none());
+        });
+
+        // These sizes are chosen based on a branching factor of 6 to cover a
+        // single leaf node and trees with two and three levels.
+        const SIZES: &[usize] = &[1, 2, 5, 6, 17, 36, 112, 215];
+        for &size in SIZES {
+            let mut value = BTreeMap::<String, i32>::new();
+            for i in 0..size {
+                value.insert(format!("{:03}", i), i as i32);
+            }
+
+            to_archived(&value, |archived| {
+                let (k, v) = archived.first_key_value().unwrap();
+                assert_eq!((k.as_str(), v.to_native()), ("000", 0));
+                let (k, v) = archived.last_key_value().unwrap();
+                let last = size as i32 - 1;
+                assert_eq!(k.as_str(), format!("{:03}", last));
+                assert_eq!(v.to_native(), last);
+            });
+        }
+    }
+
+    #[test]
+    fn btree_map_range() {
+        // Most of these keys are present in the maps below, but some of them
+        // fall between or outside of the keys in the maps.
+        const PROBES: &[&str] = &[
+            "000", "0005", "003", "017", "030", "0305", "100", "214", "999",
+        ];
+        // These sizes are chosen based on a branching factor of 6 to cover
+        // trees with one, two, and three levels.
+        const SIZES: &[usize] = &[0, 1, 5, 6, 17, 36, 112, 215];
+
+        fn bounds(probe: &str) -> [Bound<&str>; 3] {
+            [
+                Bound::Included(probe),
+                Bound::Excluded(probe),
+                Bound::Unbounded,
+            ]
+        }
+
+        for &size in SIZES {
+            let mut value = BTreeMap::<String, i32>::new();
+            for i in 0..size {
+                value.insert(format!("{:03}", i), i as i32);
+            }
+
+            to_archived(&value, |archived| {
+                for start in PROBES.iter().flat_map(|p| bounds(p)) {
+                    for end in PROBES.iter().flat_map(|p| bounds(p)) {
+                        // `BTreeMap::range` panics on these ranges
+                        let is_invalid = match (start, end) {
+                            (
+                                Bound::Included(s) | Bound::Excluded(s),
+                                Bound::Included(e) | Bound::Excluded(e),
+                            ) if s > e => true,
+                            (Bound::Excluded(s), Bound::Excluded(e)) => s == e,
+                            _ => false,
+                        };
+                        if is_invalid {
+                            continue;
+                        }
+
+                        let expected = value
+                            .range::<str, _>((start, end))
+                            .map(|(k, v)| (k.as_str(), *v))
+                            .collect::<Vec<_>>();
+                        let actual = archived
+                            .range::<str, _>((start, end))
+                            .map(|(k, v)| (k.as_str(), v.to_native()))
+                            .collect::<Vec<_>>();
+                        assert_eq!(actual, expected);
+
+                        let actual_rev = archived
+                            .range::<str, _>((start, end))
+                            .rev()
+                            .map(|(k, v)| (k.as_str(), v.to_native()))
+                            .collect::<Vec<_>>();
+                        let expected_rev =
+                            expected.iter().rev().cloned().collect::<Vec<_>>();
+                        assert_eq!(actual_rev, expected_rev);
+
+                        // Alternate between the front and back of the range
+                        let mut range = archived.range::<str, _>((start, end));
+                        let mut front = Vec::new();
+                        let mut back = Vec::new();
+                        while let Some((k, _)) = range.next() {
+                            front.push(k.as_str());
+                            match range.next_back() {
+                                Some((k, _)) => back.push(k.as_str()),
+                                None => break,
+                            }
+                        }
+                        front.extend(back.into_iter().rev());
+                        assert_eq!(
+                            front,
+                            expected
+                                .iter()
+                                .map(|(k, _)| *k)
+                                .collect::<Vec<_>>()
+                        );
+                    }
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn btree_map_range_comparable() {
+        // Compares equal to every key that starts with the prefix.
+        struct Prefix<'a>(&'a str);
+
+        impl Equivalent<ArchivedString> for Prefix<'_> {
+            fn equivalent(&self, key: &ArchivedString) -> bool {
+                key.as_str().starts_with(self.0)
+            }
+        }
+
+        impl Comparable<ArchivedString> for Prefix<'_> {
+            fn compare(&self, key: &ArchivedString) -> Ordering {
+                if self.equivalent(key) {
+                    Ordering::Equal
+                } else {
+                    self.0.cmp(key.as_str())
+                }
+            }
+        }
+
+        let mut value = BTreeMap::<String, i32>::new();
+        for i in 0..215 {
+            value.insert(format!("{:03}", i), i);
+        }
+
+        to_archived(&value, |archived| {
+            let keys = archived
+                .range(Prefix("01")..=Prefix("01"))
+                .map(|(k, _)| k.as_str())
+                .collect::<Vec<_>>();
+            let expected =
+                (10..20).map(|i| format!("{:03}", i)).collect::<Vec<_>>();
+            assert_eq!(keys, expected);
+
+            let keys = archived
+                .range(Prefix("1")..Prefix("2"))
+                .rev()
+                .map(|(k, _)| k.as_str())
+                .collect::<Vec<_>>();
+            let expected = (100..200)
+                .rev()
+                .map(|i| format!("{:03}", i))
+                .collect::<Vec<_>>();
+            assert_eq!(keys, expected);
+        });
+    }
 }
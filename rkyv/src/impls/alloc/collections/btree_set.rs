@@ -6,7 +6,7 @@ use crate::{
     alloc::collections::BTreeSet,
     collections::btree_set::{ArchivedBTreeSet, BTreeSetResolver},
     ser::{Allocator, Writer},
-    Archive, Deserialize, Place, Serialize,
+    Archive, Deserialize, DeserializeInto, Place, Serialize,
 };
 
 impl<K: Archive + Ord> Archive for BTreeSet<K>
@@ -66,6 +66,16 @@ where
     }
 }
 
+// B-trees don't have any capacity to reuse, so the default implementation is
+// used.
+impl<K, D> DeserializeInto<BTreeSet<K>, D> for ArchivedBTreeSet<K::Archived>
+where
+    K: Archive + Ord,
+    K::Archived: Deserialize<K, D> + Ord,
+    D: Fallible + ?Sized,
+{
+}
+
 impl<K, AK: PartialEq<K>> PartialEq<BTreeSet<K>> for ArchivedBTreeSet<AK> {
     fn eq(&self, other: &BTreeSet<K>) -> bool {
         if self.len() != other.len() {
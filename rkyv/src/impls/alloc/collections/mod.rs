@@ -1,2 +1,3 @@
 mod btree_map;
 mod btree_set;
+mod vec_deque;
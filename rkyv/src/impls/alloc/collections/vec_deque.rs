@@ -1,8 +1,9 @@
-use std::{alloc, cmp, collections::VecDeque};
+use core::cmp;
 
 use rancor::{Fallible, ResultExt, Source};
 
 use crate::{
+    alloc::{alloc::alloc, boxed::Box, collections::VecDeque, vec::Vec},
     ser::{Allocator, Writer},
     traits::LayoutRaw,
     vec::{ArchivedVec, VecResolver},
@@ -55,7 +56,7 @@ where
         let metadata = self.as_slice().deserialize_metadata();
         let layout = <[T] as LayoutRaw>::layout_raw(metadata).into_error()?;
         let data_address = if layout.size() > 0 {
-            unsafe { alloc::alloc(layout) }
+            unsafe { alloc(layout) }
         } else {
             crate::polyfill::dangling(&layout).as_ptr()
         };
@@ -82,13 +83,28 @@ impl<T: PartialOrd> PartialOrd<VecDeque<T>> for ArchivedVec<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::VecDeque;
-
     use crate::{
-        access_unchecked, api::test::deserialize, rancor::Error, to_bytes,
-        vec::ArchivedVec, Archived,
+        access_unchecked,
+        alloc::collections::VecDeque,
+        api::test::{deserialize, roundtrip},
+        rancor::Error,
+        to_bytes,
+        vec::ArchivedVec,
+        Archived,
     };
 
+    #[test]
+    fn roundtrip_empty_vecdeque() {
+        roundtrip(&VecDeque::<i32>::new());
+    }
+
+    #[test]
+    fn roundtrip_single_element_vecdeque() {
+        let mut deque = VecDeque::new();
+        deque.push_back(42);
+        roundtrip(&deque);
+    }
+
     #[test]
     fn vecdeque() {
         for n in 2..10 {
@@ -3,4 +3,4 @@ mod collections;
 mod rc;
 mod string;
 mod vec;
-mod with;
+pub(crate) mod with;
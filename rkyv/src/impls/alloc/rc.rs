@@ -311,7 +311,7 @@ mod tests {
             deserialize_with,
             test::{roundtrip, to_archived},
         },
-        de::Pool,
+        de::{Pool, Unshared},
         to_bytes, Archive, Archived, Deserialize, Serialize,
     };
 
@@ -390,6 +390,68 @@ mod tests {
         });
     }
 
+    #[test]
+    fn deserialize_rc_unshared() {
+        #[derive(Archive, Deserialize, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            a: Rc<u32>,
+            b: Rc<u32>,
+        }
+
+        let shared = Rc::new(10);
+        let value = Test {
+            a: shared.clone(),
+            b: shared,
+        };
+
+        to_archived(&value, |archived| {
+            let mut deserializer = Unshared::new();
+            let deserialized = deserialize_with::<Test, _, Panic>(
+                &*archived,
+                &mut deserializer,
+            )
+            .unwrap();
+
+            assert_eq!(*deserialized.a, 10);
+            assert_eq!(*deserialized.b, 10);
+            assert!(!Rc::ptr_eq(&deserialized.a, &deserialized.b));
+            assert_eq!(Rc::strong_count(&deserialized.a), 2);
+            assert_eq!(Rc::strong_count(&deserialized.b), 2);
+
+            core::mem::drop(deserializer);
+
+            assert_eq!(Rc::strong_count(&deserialized.a), 1);
+            assert_eq!(Rc::strong_count(&deserialized.b), 1);
+        });
+    }
+
+    #[test]
+    fn archived_rc_ptr_eq() {
+        #[derive(Archive, Deserialize, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            a: Rc<u32>,
+            b: Rc<u32>,
+            c: Rc<u32>,
+        }
+
+        let shared = Rc::new(10);
+        let value = Test {
+            a: shared.clone(),
+            b: shared,
+            c: Rc::new(10),
+        };
+
+        to_archived(&value, |archived| {
+            assert!(archived.a.ptr_eq(&archived.a));
+            assert!(archived.a.ptr_eq(&archived.b));
+            assert!(archived.b.ptr_eq(&archived.a));
+            assert!(!archived.a.ptr_eq(&archived.c));
+            assert_eq!(archived.a, archived.c);
+        });
+    }
+
     #[test]
     fn roundtrip_rc_zst() {
         #[derive(Archive, Deserialize, Serialize, Debug, PartialEq)]
@@ -538,4 +600,154 @@ mod tests {
         assert_eq!(Rc::weak_count(&deserialized.a), 1);
         assert_eq!(Weak::weak_count(&deserialized.b), 1);
     }
+
+    #[test]
+    fn deserialize_weak_links() {
+        #[derive(Archive, Serialize, Deserialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Graph {
+            nodes: vec::Vec<Rc<u32>>,
+            edges: vec::Vec<Weak<u32>>,
+            orphan: Weak<u32>,
+            dead: Weak<u32>,
+        }
+
+        let nodes = vec![Rc::new(1), Rc::new(2), Rc::new(3)];
+        let orphan = Rc::new(4);
+        let value = Graph {
+            edges: vec![
+                Rc::downgrade(&nodes[2]),
+                Rc::downgrade(&nodes[0]),
+                Rc::downgrade(&nodes[2]),
+            ],
+            nodes,
+            orphan: Rc::downgrade(&orphan),
+            dead: Rc::downgrade(&Rc::new(5)),
+        };
+
+        let buf = to_bytes::<Panic>(&value).unwrap();
+        let archived = unsafe { access_unchecked::<ArchivedGraph>(&buf) };
+        assert!(archived.dead.upgrade().is_none());
+
+        let mut deserializer = Pool::new();
+        let deserialized =
+            deserialize_with::<Graph, _, Panic>(archived, &mut deserializer)
+                .unwrap();
+        core::mem::drop(deserializer);
+
+        let nodes = &deserialized.nodes;
+        for (edge, target) in deserialized.edges.iter().zip([2, 0, 2]) {
+            let upgraded = edge.upgrade().unwrap();
+            assert!(Rc::ptr_eq(&upgraded, &nodes[target]));
+        }
+        assert_eq!(Rc::strong_count(&nodes[2]), 1);
+        assert_eq!(Rc::weak_count(&nodes[2]), 2);
+
+        // The only strong pointer to the orphan was dropped with the pool
+        assert!(deserialized.orphan.upgrade().is_none());
+        assert!(deserialized.dead.upgrade().is_none());
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn reject_cyclic_shared_ptr() {
+        use rancor::{Error, Fallible, Source};
+
+        use crate::{
+            api::high::{access, from_bytes},
+            de::Pooling,
+            ser::{Sharing, Writer},
+            validation::{ArchiveContext, SharedContext},
+            Place, RelPtr,
+        };
+
+        #[derive(Archive, Serialize, Deserialize, Debug)]
+        #[rkyv(
+            crate,
+            check_bytes(bounds(
+                __C: ArchiveContext + SharedContext,
+                <__C as Fallible>::Error: Source,
+            )),
+            serialize_bounds(__S: Sharing + Writer),
+            deserialize_bounds(
+                __D: Pooling,
+                <__D as Fallible>::Error: Source,
+            ),
+            derive(Debug),
+        )]
+        enum Node {
+            Nil,
+            Cons(#[omit_bounds] Rc<Node>),
+        }
+
+        let value = Node::Cons(Rc::new(Node::Cons(Rc::new(Node::Nil))));
+        let mut bytes = to_bytes::<Error>(&value).unwrap();
+        access::<ArchivedNode, Error>(&bytes).unwrap();
+
+        // Point the inner node at itself instead of at the `Nil` node.
+        let (node_pos, ptr_pos) = {
+            let archived = unsafe { access_unchecked::<ArchivedNode>(&bytes) };
+            let ArchivedNode::Cons(outer) = archived else {
+                unreachable!()
+            };
+            let ArchivedNode::Cons(inner) = &**outer else {
+                unreachable!()
+            };
+            let base = bytes.as_ptr() as usize;
+            (
+                &**outer as *const ArchivedNode as usize - base,
+                inner as *const _ as usize - base,
+            )
+        };
+        unsafe {
+            let out = Place::new_unchecked(
+                ptr_pos,
+                bytes
+                    .as_mut_ptr()
+                    .add(ptr_pos)
+                    .cast::<RelPtr<ArchivedNode>>(),
+            );
+            RelPtr::emplace(node_pos, out);
+        }
+
+        // Following the cycle would recurse until the stack overflows, so it
+        // must be rejected during validation.
+        let error = access::<ArchivedNode, Error>(&bytes).unwrap_err();
+        assert!(error.to_string().contains("pointer to itself"));
+        from_bytes::<Node, Error>(&bytes).unwrap_err();
+    }
+
+    #[test]
+    fn reject_weak_parent_links() {
+        use rancor::Error;
+
+        use crate::ser::{Allocator, Sharing, Writer};
+
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate, serialize_bounds(__S: Allocator + Sharing + Writer))]
+        struct Node {
+            #[omit_bounds]
+            parent: Weak<Node>,
+            #[omit_bounds]
+            children: vec::Vec<Rc<Node>>,
+        }
+
+        let root = Rc::new_cyclic(|root| Node {
+            parent: Weak::new(),
+            children: vec![Rc::new(Node {
+                parent: root.clone(),
+                children: vec::Vec::new(),
+            })],
+        });
+
+        // The child points back to the root while the root is still being
+        // serialized, which would otherwise recurse until the stack overflows.
+        let error = to_bytes::<Error>(&root).unwrap_err();
+        assert!(error.to_string().contains("pointer to itself"));
+
+        // Breaking the cycle makes it serializable again
+        let child = root.children[0].clone();
+        drop(root);
+        to_bytes::<Error>(&child).unwrap();
+    }
 }
@@ -1,12 +1,17 @@
 use core::cmp::Ordering;
 
-use rancor::Fallible;
+use rancor::{Fallible, Source};
 
 use crate::{
-    alloc::string::{String, ToString},
+    alloc::{
+        borrow::Cow,
+        string::{String, ToString},
+        sync::Arc,
+    },
+    de::{Pooling, PoolingExt as _},
     string::{ArchivedString, StringResolver},
-    Archive, Deserialize, DeserializeUnsized, Place, Serialize,
-    SerializeUnsized,
+    Archive, Deserialize, DeserializeInto, DeserializeUnsized, Place,
+    Serialize, SerializeUnsized,
 };
 
 impl Archive for String {
@@ -40,6 +45,43 @@ where
     }
 }
 
+// Strings are shared by the address of their bytes. Inline strings are
+// stored inside of the `ArchivedString`, which may be the same address as a
+// shared pointer to the value containing it, so they are never shared.
+impl<D> Deserialize<Arc<str>, D> for ArchivedString
+where
+    D: Fallible + Pooling + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Arc<str>, D::Error> {
+        if self.is_inline() {
+            return Ok(Arc::from(self.as_str()));
+        }
+
+        let raw_shared_ptr =
+            deserializer.deserialize_shared::<str, Arc<str>>(self.as_str())?;
+        unsafe {
+            Arc::<str>::increment_strong_count(raw_shared_ptr);
+        }
+        unsafe { Ok(Arc::<str>::from_raw(raw_shared_ptr)) }
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeInto<String, D> for ArchivedString
+where
+    str: DeserializeUnsized<str, D>,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut String,
+        _: &mut D,
+    ) -> Result<(), D::Error> {
+        place.clear();
+        place.push_str(self.as_str());
+        Ok(())
+    }
+}
+
 impl PartialEq<String> for ArchivedString {
     #[inline]
     fn eq(&self, other: &String) -> bool {
@@ -68,9 +110,67 @@ impl PartialOrd<ArchivedString> for String {
     }
 }
 
+impl Archive for Cow<'_, str> {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedString::resolve_from_str(self, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Cow<'_, str>
+where
+    str: SerializeUnsized<S>,
+{
+    fn serialize(
+        &self,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self, serializer)
+    }
+}
+
+impl<'a, D: Fallible + ?Sized> Deserialize<Cow<'a, str>, D> for ArchivedString
+where
+    str: DeserializeUnsized<str, D>,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Cow<'a, str>, D::Error> {
+        Ok(Cow::Owned(self.as_str().to_string()))
+    }
+}
+
+impl PartialEq<Cow<'_, str>> for ArchivedString {
+    #[inline]
+    fn eq(&self, other: &Cow<'_, str>) -> bool {
+        PartialEq::eq(self.as_str(), &**other)
+    }
+}
+
+impl PartialEq<ArchivedString> for Cow<'_, str> {
+    #[inline]
+    fn eq(&self, other: &ArchivedString) -> bool {
+        PartialEq::eq(other.as_str(), &**self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{alloc::string::ToString, api::test::roundtrip};
+    use rancor::{Panic, Strategy};
+
+    use crate::{
+        alloc::{
+            borrow::Cow,
+            string::{String, ToString},
+            sync::Arc,
+        },
+        api::test::{roundtrip, to_archived},
+        de::Pool,
+        string::ArchivedString,
+        with::Dedup,
+        Archive, Deserialize, Serialize,
+    };
 
     #[test]
     fn roundtrip_string() {
@@ -78,6 +178,13 @@ mod tests {
         roundtrip(&"hello world".to_string());
     }
 
+    #[test]
+    fn roundtrip_cow_str() {
+        roundtrip(&Cow::Borrowed(""));
+        roundtrip(&Cow::Borrowed("hello world"));
+        roundtrip(&Cow::<str>::Owned("hello world".to_string()));
+    }
+
     #[test]
     fn roundtrip_option_string() {
         roundtrip(&Some("".to_string()));
@@ -92,4 +199,99 @@ mod tests {
         roundtrip(&Err::<(), _>("".to_string()));
         roundtrip(&Err::<(), _>("hello world".to_string()));
     }
+
+    #[test]
+    fn partial_eq_string() {
+        let value = "hello world".to_string();
+        to_archived(&value, |archived| {
+            let archived = &*archived;
+
+            assert_eq!(*archived, value);
+            assert_eq!(value, *archived);
+            assert_eq!(*archived, "hello world");
+            assert_eq!("hello world", *archived);
+            assert_eq!(*archived, *"hello world");
+            assert_eq!(*"hello world", *archived);
+            assert_ne!(*"hello", *archived);
+        });
+    }
+
+    #[test]
+    fn archived_string_bytes_and_split_at() {
+        // A mix of one, two, three, and four byte characters
+        let value = "aé€😀b".to_string();
+        to_archived(&value, |archived| {
+            assert_eq!(archived.as_bytes(), value.as_bytes());
+
+            assert_eq!(archived.split_at(0), Some(("", "aé€😀b")));
+            assert_eq!(archived.split_at(1), Some(("a", "é€😀b")));
+            assert_eq!(archived.split_at(2), None);
+            assert_eq!(archived.split_at(3), Some(("aé", "€😀b")));
+            assert_eq!(archived.split_at(4), None);
+            assert_eq!(archived.split_at(5), None);
+            assert_eq!(archived.split_at(6), Some(("aé€", "😀b")));
+            assert_eq!(archived.split_at(7), None);
+            assert_eq!(archived.split_at(10), Some(("aé€😀", "b")));
+            assert_eq!(archived.split_at(11), Some(("aé€😀b", "")));
+            assert_eq!(archived.split_at(12), None);
+        });
+    }
+
+    #[test]
+    fn deserialize_arc_str() {
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            #[with(Dedup)]
+            a: String,
+            #[with(Dedup)]
+            b: String,
+            c: String,
+            inline: String,
+        }
+
+        fn deserialize_arc(
+            value: &ArchivedString,
+            pool: &mut Pool,
+        ) -> Arc<str> {
+            value.deserialize(Strategy::<_, Panic>::wrap(pool)).unwrap()
+        }
+
+        let long = "a string which is too long to be inlined";
+        let value = Test {
+            a: long.to_string(),
+            b: long.to_string(),
+            c: long.to_string(),
+            inline: "ab".to_string(),
+        };
+
+        to_archived(&value, |archived| {
+            assert!(archived.inline.is_inline());
+
+            let mut pool = Pool::new();
+            let a = deserialize_arc(&archived.a, &mut pool);
+            let b = deserialize_arc(&archived.b, &mut pool);
+            let c = deserialize_arc(&archived.c, &mut pool);
+            let c2 = deserialize_arc(&archived.c, &mut pool);
+            let inline = deserialize_arc(&archived.inline, &mut pool);
+            let inline2 = deserialize_arc(&archived.inline, &mut pool);
+
+            assert_eq!(&*a, long);
+            assert_eq!(&*c, long);
+            assert_eq!(&*inline, "ab");
+
+            // Deduplicated strings share the same archived bytes.
+            assert!(Arc::ptr_eq(&a, &b));
+            assert!(!Arc::ptr_eq(&a, &c));
+            assert!(Arc::ptr_eq(&c, &c2));
+            // Inline strings are never shared.
+            assert!(!Arc::ptr_eq(&inline, &inline2));
+
+            core::mem::drop(pool);
+
+            assert_eq!(Arc::strong_count(&a), 2);
+            assert_eq!(Arc::strong_count(&c), 2);
+            assert_eq!(Arc::strong_count(&inline), 1);
+        });
+    }
 }
@@ -1,13 +1,15 @@
-use core::cmp;
+use core::{cmp, mem::size_of_val};
 
 use rancor::{Fallible, ResultExt as _, Source};
 
 use crate::{
-    alloc::{alloc::alloc, boxed::Box, vec::Vec},
+    alloc::{alloc::alloc, borrow::Cow, boxed::Box, sync::Arc, vec::Vec},
+    de::{Pooling, PoolingExt as _},
     ser::{Allocator, Writer},
     traits::LayoutRaw,
     vec::{ArchivedVec, VecResolver},
-    Archive, Deserialize, DeserializeUnsized, Place, Serialize,
+    Archive, Deserialize, DeserializeInto, DeserializeUnsized, Place,
+    Serialize,
 };
 
 impl<T: Archive> Archive for Vec<T> {
@@ -56,12 +58,73 @@ where
     }
 }
 
+// Slices are shared by the address of their elements. Slices which don't take
+// up any space may have the same address as other values, so they are never
+// shared.
+impl<T, D> Deserialize<Arc<[T]>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + Pooling + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Arc<[T]>, D::Error> {
+        if size_of_val(self.as_slice()) == 0 {
+            return Ok(Arc::from(Deserialize::<Vec<T>, D>::deserialize(
+                self,
+                deserializer,
+            )?));
+        }
+
+        let raw_shared_ptr = deserializer
+            .deserialize_shared::<[T], Arc<[T]>>(self.as_slice())?;
+        unsafe {
+            Arc::<[T]>::increment_strong_count(raw_shared_ptr);
+        }
+        unsafe { Ok(Arc::<[T]>::from_raw(raw_shared_ptr)) }
+    }
+}
+
+impl<T, D> DeserializeInto<Vec<T>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, D>,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut Vec<T>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        place.truncate(self.len());
+        // Deserialize into the existing elements first so that their
+        // allocations can be reused.
+        for (value, out) in self.iter().zip(place.iter_mut()) {
+            value.deserialize_into(out, deserializer)?;
+        }
+        let start = place.len();
+        place.reserve(self.len() - start);
+        for value in self[start..].iter() {
+            place.push(value.deserialize(deserializer)?);
+        }
+        Ok(())
+    }
+}
+
 impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for ArchivedVec<T> {
     fn eq(&self, other: &Vec<U>) -> bool {
         self.as_slice().eq(other.as_slice())
     }
 }
 
+impl<T: PartialEq<U>, U> PartialEq<ArchivedVec<U>> for Vec<T> {
+    fn eq(&self, other: &ArchivedVec<U>) -> bool {
+        self.as_slice().eq(other.as_slice())
+    }
+}
+
 impl<T: PartialOrd<U>, U> PartialOrd<Vec<U>> for ArchivedVec<T> {
     fn partial_cmp(&self, other: &Vec<U>) -> Option<cmp::Ordering> {
         let min_len = self.len().min(other.len());
@@ -75,11 +138,76 @@ impl<T: PartialOrd<U>, U> PartialOrd<Vec<U>> for ArchivedVec<T> {
     }
 }
 
+impl<T: Archive + Clone> Archive for Cow<'_, [T]> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self, resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for Cow<'_, [T]>
+where
+    T: Serialize<S> + Clone,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(
+        &self,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
+impl<'a, T, D> Deserialize<Cow<'a, [T]>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive + Clone,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(
+        &self,
+        deserializer: &mut D,
+    ) -> Result<Cow<'a, [T]>, D::Error> {
+        Ok(Cow::Owned(Deserialize::<Vec<T>, D>::deserialize(
+            self,
+            deserializer,
+        )?))
+    }
+}
+
+impl<T: PartialEq<U>, U: Clone> PartialEq<Cow<'_, [U]>> for ArchivedVec<T> {
+    fn eq(&self, other: &Cow<'_, [U]>) -> bool {
+        self.as_slice().eq(&**other)
+    }
+}
+
+impl<T: PartialEq<U> + Clone, U> PartialEq<ArchivedVec<U>> for Cow<'_, [T]> {
+    fn eq(&self, other: &ArchivedVec<U>) -> bool {
+        (**self).eq(other.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use rancor::{Panic, Strategy};
+
     use crate::{
-        alloc::{vec, vec::Vec},
-        api::test::roundtrip,
+        alloc::{
+            borrow::Cow,
+            string::{String, ToString},
+            sync::Arc,
+            vec,
+            vec::Vec,
+        },
+        api::test::{deserialize_into, roundtrip, to_archived},
+        de::Pool,
+        primitive::{ArchivedF32, ArchivedI32, ArchivedU32, ArchivedU64},
+        vec::ArchivedVec,
+        with::Dedup,
+        Archive, Deserialize, Serialize,
     };
 
     #[test]
@@ -88,6 +216,17 @@ mod tests {
         roundtrip(&vec![1, 2, 3, 4]);
     }
 
+    #[test]
+    fn roundtrip_cow_slice() {
+        roundtrip(&Cow::<[i32]>::Borrowed(&[]));
+        roundtrip(&Cow::<[i32]>::Borrowed(&[1, 2, 3, 4]));
+        roundtrip(&Cow::<[i32]>::Owned(vec![1, 2, 3, 4]));
+        roundtrip(&Cow::<[String]>::Borrowed(&[
+            "hello".to_string(),
+            "world".to_string(),
+        ]));
+    }
+
     #[test]
     fn roundtrip_vec_zst() {
         roundtrip(&Vec::<()>::new());
@@ -108,4 +247,328 @@ mod tests {
         roundtrip(&Err::<(), _>(Vec::<i32>::new()));
         roundtrip(&Err::<(), _>(vec![1, 2, 3, 4]));
     }
+
+    #[test]
+    fn binary_search_vec() {
+        let value = vec![1u64, 3, 5, 7, 9, 300, 70_000];
+        to_archived(&value, |archived| {
+            for (i, x) in value.iter().enumerate() {
+                let x = ArchivedU64::from_native(*x);
+                assert_eq!(archived.binary_search(&x), Ok(i));
+            }
+
+            let search =
+                |x: u64| archived.binary_search(&ArchivedU64::from_native(x));
+            assert_eq!(search(0), Err(0));
+            assert_eq!(search(4), Err(2));
+            assert_eq!(search(256), Err(5));
+            assert_eq!(search(1_000_000), Err(7));
+
+            assert_eq!(
+                archived.binary_search_by(|x| x.to_native().cmp(&9)),
+                Ok(4),
+            );
+            assert_eq!(
+                archived.binary_search_by_key(&10, |x| x.to_native()),
+                Err(5),
+            );
+        });
+    }
+
+    #[test]
+    fn partial_eq_vec() {
+        let value = vec!["foo".to_string(), "bar".to_string()];
+        to_archived(&value, |archived| {
+            let archived = &*archived;
+            let slice = value.as_slice();
+            let cow = Cow::Borrowed(slice);
+
+            assert_eq!(*archived, value);
+            assert_eq!(value, *archived);
+            assert_eq!(*archived, *slice);
+            assert_eq!(*slice, *archived);
+            assert_eq!(*archived, slice);
+            assert_eq!(slice, *archived);
+            assert_eq!(*archived, cow);
+            assert_eq!(cow, *archived);
+
+            let other = vec!["foo".to_string()];
+            assert_ne!(*archived, other);
+            assert_ne!(other, *archived);
+        });
+    }
+
+    #[test]
+    fn chunks_vec() {
+        let value = vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        to_archived(&value, |archived| {
+            let chunks = archived
+                .chunks(3)
+                .map(|c| c.iter().map(|x| x.to_native()).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                chunks,
+                vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0]],
+            );
+            assert_eq!(archived.chunks(7).count(), 1);
+            assert_eq!(archived.chunks(8).count(), 1);
+        });
+    }
+
+    #[test]
+    fn windows_vec() {
+        let value = vec![1.0f32, 2.0, 3.0, 4.0, 5.0];
+        to_archived(&value, |archived| {
+            let sums = archived
+                .windows(2)
+                .map(|w| w.iter().map(|x| x.to_native()).sum::<f32>())
+                .collect::<Vec<_>>();
+            assert_eq!(sums, vec![3.0, 5.0, 7.0, 9.0]);
+            assert_eq!(archived.windows(5).count(), 1);
+            assert_eq!(archived.windows(6).count(), 0);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_vec_zero() {
+        let value = vec![1.0f32, 2.0, 3.0];
+        to_archived(&value, |archived| {
+            let _ = archived.chunks(0);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_vec_zero() {
+        let value = vec![1.0f32, 2.0, 3.0];
+        to_archived(&value, |archived| {
+            let _ = archived.windows(0);
+        });
+    }
+
+    #[test]
+    fn nested_vec_rows() {
+        let value = vec![vec![1u32, 2, 3], vec![], vec![4], vec![], vec![5, 6]];
+        to_archived(&value, |archived| {
+            let row = |i| {
+                archived
+                    .row(i)
+                    .iter()
+                    .map(|x| x.to_native())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(row(0), [1, 2, 3]);
+            assert!(row(1).is_empty());
+            assert_eq!(row(2), [4]);
+            assert!(row(3).is_empty());
+            assert_eq!(row(4), [5, 6]);
+
+            let flat = archived
+                .flat_iter()
+                .map(|x| x.to_native())
+                .collect::<Vec<_>>();
+            assert_eq!(flat, &[1, 2, 3, 4, 5, 6]);
+        });
+
+        let value: Vec<Vec<u32>> = vec![vec![], vec![]];
+        to_archived(&value, |archived| {
+            assert_eq!(archived.flat_iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn sorted_intersection_and_union_vec() {
+        fn check(
+            left: &[u32],
+            right: &[u32],
+            intersection: &[u32],
+            union: &[u32],
+        ) {
+            let value = (left.to_vec(), right.to_vec());
+            to_archived(&value, |archived| {
+                let result = archived
+                    .0
+                    .sorted_intersection(&archived.1)
+                    .map(|x| x.to_native())
+                    .collect::<Vec<_>>();
+                assert_eq!(result, intersection);
+
+                let result = archived
+                    .0
+                    .sorted_union(&archived.1)
+                    .map(|x| x.to_native())
+                    .collect::<Vec<_>>();
+                assert_eq!(result, union);
+            });
+        }
+
+        // Overlapping
+        check(&[1, 3, 5, 7], &[3, 4, 5, 8], &[3, 5], &[1, 3, 4, 5, 7, 8]);
+        check(&[1, 2, 3], &[1, 2, 3], &[1, 2, 3], &[1, 2, 3]);
+        // Disjoint
+        check(&[1, 2], &[5, 6, 7], &[], &[1, 2, 5, 6, 7]);
+        check(&[2, 4, 6], &[1, 3, 5], &[], &[1, 2, 3, 4, 5, 6]);
+        // Empty
+        check(&[], &[1, 2], &[], &[1, 2]);
+        check(&[1, 2], &[], &[], &[1, 2]);
+        check(&[], &[], &[], &[]);
+    }
+
+    #[test]
+    fn as_slice_pin_vec() {
+        let value = vec![1.0f32, 2.0, 3.0, 4.0];
+        to_archived(&value, |mut archived| {
+            for x in archived.as_mut().as_slice_pin().get_mut() {
+                *x = ArchivedF32::from_native(x.to_native() * 2.0);
+            }
+            let scaled =
+                archived.iter().map(|x| x.to_native()).collect::<Vec<_>>();
+            assert_eq!(scaled, vec![2.0, 4.0, 6.0, 8.0]);
+        });
+    }
+
+    #[test]
+    fn iter_pin_vec() {
+        let value = vec![-5i32, 3, 12, 7, 100];
+        to_archived(&value, |mut archived| {
+            assert_eq!(archived.as_mut().iter_pin().len(), 5);
+            for mut x in archived.as_mut().iter_pin() {
+                *x = ArchivedI32::from_native(x.to_native().clamp(0, 10));
+            }
+            let clamped =
+                archived.iter().map(|x| x.to_native()).collect::<Vec<_>>();
+            assert_eq!(clamped, vec![0, 3, 10, 7, 10]);
+
+            let last = archived.as_mut().iter_pin().next_back().unwrap();
+            assert_eq!(*last, 10);
+        });
+    }
+
+    #[test]
+    fn first_and_last_vec() {
+        to_archived(&Vec::<u64>::new(), |mut archived| {
+            assert!(archived.first().is_none());
+            assert!(archived.last().is_none());
+            assert!(archived.split_first().is_none());
+            assert!(archived.split_last().is_none());
+            assert!(archived.as_mut().first_pin().is_none());
+            assert!(archived.as_mut().last_pin().is_none());
+            assert!(archived.as_mut().split_first_pin().is_none());
+            assert!(archived.as_mut().split_last_pin().is_none());
+        });
+
+        to_archived(&vec![7u64], |mut archived| {
+            assert_eq!(*archived.first().unwrap(), 7);
+            assert_eq!(*archived.last().unwrap(), 7);
+            let (first, rest) = archived.split_first().unwrap();
+            assert_eq!(*first, 7);
+            assert!(rest.is_empty());
+            let (last, rest) = archived.split_last().unwrap();
+            assert_eq!(*last, 7);
+            assert!(rest.is_empty());
+
+            let (mut first, rest) =
+                archived.as_mut().split_first_pin().unwrap();
+            *first = ArchivedU64::from_native(8);
+            assert!(rest.is_empty());
+            *archived.as_mut().last_pin().unwrap() =
+                ArchivedU64::from_native(9);
+            assert_eq!(*archived.first().unwrap(), 9);
+        });
+
+        to_archived(&vec![1u64, 2, 3], |mut archived| {
+            let (first, rest) = archived.split_first().unwrap();
+            assert_eq!(*first, 1);
+            assert_eq!(rest, [2, 3].map(ArchivedU64::from_native));
+            let (last, rest) = archived.split_last().unwrap();
+            assert_eq!(*last, 3);
+            assert_eq!(rest, [1, 2].map(ArchivedU64::from_native));
+
+            let (mut last, mut rest) =
+                archived.as_mut().split_last_pin().unwrap();
+            *last = ArchivedU64::from_native(30);
+            rest[0] = ArchivedU64::from_native(10);
+            assert_eq!(
+                archived.iter().map(|x| x.to_native()).collect::<Vec<_>>(),
+                vec![10, 2, 30],
+            );
+        });
+    }
+
+    #[test]
+    fn deserialize_into_vec() {
+        let mut place = Vec::<String>::with_capacity(16);
+        place.push(String::with_capacity(64));
+        let capacity = place.capacity();
+        let first_capacity = place[0].capacity();
+
+        let value = vec!["foo".to_string(), "bar".to_string()];
+        to_archived(&value, |archived| {
+            deserialize_into(&*archived, &mut place);
+        });
+        assert_eq!(place, value);
+        assert_eq!(place.capacity(), capacity);
+        assert_eq!(place[0].capacity(), first_capacity);
+
+        let value = Vec::<String>::new();
+        to_archived(&value, |archived| {
+            deserialize_into(&*archived, &mut place);
+        });
+        assert!(place.is_empty());
+        assert_eq!(place.capacity(), capacity);
+    }
+
+    #[test]
+    fn deserialize_arc_slice() {
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            #[with(Dedup)]
+            a: Vec<u32>,
+            #[with(Dedup)]
+            b: Vec<u32>,
+            c: Vec<u32>,
+            empty: Vec<u32>,
+        }
+
+        fn deserialize_arc(
+            value: &ArchivedVec<ArchivedU32>,
+            pool: &mut Pool,
+        ) -> Arc<[u32]> {
+            value.deserialize(Strategy::<_, Panic>::wrap(pool)).unwrap()
+        }
+
+        let value = Test {
+            a: vec![1, 2, 3],
+            b: vec![1, 2, 3],
+            c: vec![1, 2, 3],
+            empty: Vec::new(),
+        };
+
+        to_archived(&value, |archived| {
+            let mut pool = Pool::new();
+            let a = deserialize_arc(&archived.a, &mut pool);
+            let b = deserialize_arc(&archived.b, &mut pool);
+            let c = deserialize_arc(&archived.c, &mut pool);
+            let c2 = deserialize_arc(&archived.c, &mut pool);
+            let empty = deserialize_arc(&archived.empty, &mut pool);
+
+            assert_eq!(*a, [1, 2, 3]);
+            assert_eq!(*c, [1, 2, 3]);
+            assert!(empty.is_empty());
+
+            // Deduplicated vecs share the same archived elements.
+            assert!(Arc::ptr_eq(&a, &b));
+            assert!(!Arc::ptr_eq(&a, &c));
+            assert!(Arc::ptr_eq(&c, &c2));
+            assert_eq!(Arc::strong_count(&a), 3);
+
+            core::mem::drop(pool);
+
+            assert_eq!(Arc::strong_count(&a), 2);
+            assert_eq!(Arc::strong_count(&c), 2);
+            assert_eq!(Arc::strong_count(&empty), 1);
+        });
+    }
 }
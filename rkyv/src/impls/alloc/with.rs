@@ -1,7 +1,13 @@
-use core::marker::PhantomData;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::ControlFlow,
+    str::FromStr,
+};
 
 use ptr_meta::Pointee;
-use rancor::{Fallible, Source};
+use rancor::{fail, Fallible, Source};
 
 use crate::{
     alloc::{
@@ -9,25 +15,83 @@ use crate::{
         boxed::Box,
         collections::{BTreeMap, BTreeSet},
         rc::Rc,
+        string::{String, ToString},
         sync::Arc,
         vec::Vec,
     },
-    collections::util::{Entry, EntryAdapter},
+    api::high::HighDeserializer,
+    collections::{
+        btree_map::{ArchivedBTreeMap, BTreeMapResolver},
+        util::{Entry, EntryAdapter},
+    },
+    compression::{ArchivedCompressed, Codec, CompressedResolver},
+    de::{Borrowing, BorrowingExt as _},
     niche::option_box::{ArchivedOptionBox, OptionBoxResolver},
-    ser::{Allocator, Writer},
-    string::{ArchivedString, StringResolver},
+    place::Initialized,
+    ser::{Allocator, Writer, WriterExt as _},
+    string::{ArchivedString, DisplayResolver, StringResolver},
     traits::LayoutRaw,
-    vec::{ArchivedVec, VecResolver},
+    util::{Deferred, DeferredResolver},
+    vec::{ArchivedInlineVec, ArchivedVec, InlineVecResolver, VecResolver},
     with::{
-        ArchiveWith, AsOwned, AsVec, DeserializeWith, Map, Niche,
+        ArchiveWith, AsCowStr, AsOwned, AsVec, Compressed, DeserializeWith,
+        DisplayFromStr, InlineVec, Lazy, Map, MapKV, Niche, RawVec,
         SerializeWith, Unshare,
     },
     Archive, ArchiveUnsized, ArchivedMetadata, Deserialize, DeserializeUnsized,
-    Place, Serialize, SerializeUnsized,
+    Place, Portable, Serialize, SerializeUnsized,
 };
 
 // Map
 
+// Wrapper for O so that we have Archive and Serialize implementations and
+// ArchivedVec::serialize_from_* and the map serialization functions are happy
+// about the bound constraints. Wrapped values are hashed and compared by their
+// unwrapped values.
+#[repr(transparent)]
+pub(crate) struct RefWrapper<A, O>(PhantomData<A>, O);
+
+impl<A, O> RefWrapper<A, O> {
+    pub(crate) fn new(value: &O) -> &Self {
+        // SAFETY: `RefWrapper` is `repr(transparent)` over `O`, so it has the
+        // same layout as `O`.
+        unsafe { &*(value as *const O).cast::<Self>() }
+    }
+}
+
+impl<A: ArchiveWith<O>, O> Archive for RefWrapper<A, O> {
+    type Archived = <A as ArchiveWith<O>>::Archived;
+    type Resolver = <A as ArchiveWith<O>>::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        A::resolve_with(&self.1, resolver, out)
+    }
+}
+
+impl<A, O, S> Serialize<S> for RefWrapper<A, O>
+where
+    A: ArchiveWith<O> + SerializeWith<O, S>,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
+        A::serialize_with(&self.1, s)
+    }
+}
+
+impl<A, O: Hash> Hash for RefWrapper<A, O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state)
+    }
+}
+
+impl<A, O: PartialEq> PartialEq for RefWrapper<A, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<A, O: Eq> Eq for RefWrapper<A, O> {}
+
 impl<A, O> ArchiveWith<Vec<O>> for Map<A>
 where
     A: ArchiveWith<O>,
@@ -53,39 +117,9 @@ where
         field: &Vec<O>,
         s: &mut S,
     ) -> Result<Self::Resolver, S::Error> {
-        // Wrapper for O so that we have an Archive and Serialize implementation
-        // and ArchivedVec::serialize_from_* is happy about the bound
-        // constraints
-        struct RefWrapper<'o, A, O>(&'o O, PhantomData<A>);
-
-        impl<A: ArchiveWith<O>, O> Archive for RefWrapper<'_, A, O> {
-            type Archived = <A as ArchiveWith<O>>::Archived;
-            type Resolver = <A as ArchiveWith<O>>::Resolver;
-
-            fn resolve(
-                &self,
-                resolver: Self::Resolver,
-                out: Place<Self::Archived>,
-            ) {
-                A::resolve_with(self.0, resolver, out)
-            }
-        }
+        let iter = field.iter().map(RefWrapper::<A, O>::new);
 
-        impl<A, O, S> Serialize<S> for RefWrapper<'_, A, O>
-        where
-            A: ArchiveWith<O> + SerializeWith<O, S>,
-            S: Fallible + Writer + ?Sized,
-        {
-            fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
-                A::serialize_with(self.0, s)
-            }
-        }
-
-        let iter = field
-            .iter()
-            .map(|value| RefWrapper::<'_, A, O>(value, PhantomData));
-
-        ArchivedVec::serialize_from_iter(iter, s)
+        ArchivedVec::serialize_from_iter::<RefWrapper<A, O>, _, _>(iter, s)
     }
 }
 
@@ -107,6 +141,182 @@ where
     }
 }
 
+impl<A, K, V> ArchiveWith<BTreeMap<K, V>> for Map<A>
+where
+    K: Archive + Ord,
+    K::Archived: Ord,
+    A: ArchiveWith<V>,
+{
+    type Archived =
+        ArchivedBTreeMap<K::Archived, <A as ArchiveWith<V>>::Archived>;
+    type Resolver = BTreeMapResolver;
+
+    fn resolve_with(
+        field: &BTreeMap<K, V>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedBTreeMap::resolve_from_len(field.len(), resolver, out)
+    }
+}
+
+impl<A, K, V, S> SerializeWith<BTreeMap<K, V>, S> for Map<A>
+where
+    K: Serialize<S> + Ord,
+    K::Archived: Ord,
+    A: ArchiveWith<V> + SerializeWith<V, S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &BTreeMap<K, V>,
+        s: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        // The keys are unchanged, so they are still in order.
+        ArchivedBTreeMap::<
+            K::Archived,
+            <A as ArchiveWith<V>>::Archived,
+        >::serialize_from_ordered_iter(
+            field
+                .iter()
+                .map(|(key, value)| (key, RefWrapper::<A, V>::new(value))),
+            s,
+        )
+    }
+}
+
+impl<A, K, V, D>
+    DeserializeWith<
+        ArchivedBTreeMap<K::Archived, <A as ArchiveWith<V>>::Archived>,
+        BTreeMap<K, V>,
+        D,
+    > for Map<A>
+where
+    K: Archive + Ord,
+    K::Archived: Deserialize<K, D> + Ord,
+    A: ArchiveWith<V> + DeserializeWith<<A as ArchiveWith<V>>::Archived, V, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(
+        field: &ArchivedBTreeMap<K::Archived, <A as ArchiveWith<V>>::Archived>,
+        d: &mut D,
+    ) -> Result<BTreeMap<K, V>, D::Error> {
+        let mut result = BTreeMap::new();
+        let r = field.visit(|ak, av| {
+            let k = match ak.deserialize(d) {
+                Ok(k) => k,
+                Err(e) => return ControlFlow::Break(e),
+            };
+            let v = match A::deserialize_with(av, d) {
+                Ok(v) => v,
+                Err(e) => return ControlFlow::Break(e),
+            };
+            result.insert(k, v);
+            ControlFlow::Continue(())
+        });
+        match r {
+            Some(e) => Err(e),
+            None => Ok(result),
+        }
+    }
+}
+
+// MapKV
+
+impl<AK, AV, K, V> ArchiveWith<BTreeMap<K, V>> for MapKV<AK, AV>
+where
+    AK: ArchiveWith<K>,
+    <AK as ArchiveWith<K>>::Archived: Ord,
+    AV: ArchiveWith<V>,
+{
+    type Archived = ArchivedBTreeMap<
+        <AK as ArchiveWith<K>>::Archived,
+        <AV as ArchiveWith<V>>::Archived,
+    >;
+    type Resolver = BTreeMapResolver;
+
+    fn resolve_with(
+        field: &BTreeMap<K, V>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedBTreeMap::resolve_from_len(field.len(), resolver, out)
+    }
+}
+
+impl<AK, AV, K, V, S> SerializeWith<BTreeMap<K, V>, S> for MapKV<AK, AV>
+where
+    AK: ArchiveWith<K> + SerializeWith<K, S>,
+    <AK as ArchiveWith<K>>::Archived: Ord,
+    AV: ArchiveWith<V> + SerializeWith<V, S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &BTreeMap<K, V>,
+        s: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        // The archived keys must be in the same order as the original keys.
+        ArchivedBTreeMap::<
+            <AK as ArchiveWith<K>>::Archived,
+            <AV as ArchiveWith<V>>::Archived,
+        >::serialize_from_ordered_iter(
+            field.iter().map(|(key, value)| {
+                (
+                    RefWrapper::<AK, K>::new(key),
+                    RefWrapper::<AV, V>::new(value),
+                )
+            }),
+            s,
+        )
+    }
+}
+
+impl<AK, AV, K, V, D>
+    DeserializeWith<
+        ArchivedBTreeMap<
+            <AK as ArchiveWith<K>>::Archived,
+            <AV as ArchiveWith<V>>::Archived,
+        >,
+        BTreeMap<K, V>,
+        D,
+    > for MapKV<AK, AV>
+where
+    K: Ord,
+    AK: ArchiveWith<K>
+        + DeserializeWith<<AK as ArchiveWith<K>>::Archived, K, D>,
+    <AK as ArchiveWith<K>>::Archived: Ord,
+    AV: ArchiveWith<V>
+        + DeserializeWith<<AV as ArchiveWith<V>>::Archived, V, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(
+        field: &ArchivedBTreeMap<
+            <AK as ArchiveWith<K>>::Archived,
+            <AV as ArchiveWith<V>>::Archived,
+        >,
+        d: &mut D,
+    ) -> Result<BTreeMap<K, V>, D::Error> {
+        let mut result = BTreeMap::new();
+        let r = field.visit(|ak, av| {
+            let k = match AK::deserialize_with(ak, d) {
+                Ok(k) => k,
+                Err(e) => return ControlFlow::Break(e),
+            };
+            let v = match AV::deserialize_with(av, d) {
+                Ok(v) => v,
+                Err(e) => return ControlFlow::Break(e),
+            };
+            result.insert(k, v);
+            ControlFlow::Continue(())
+        });
+        match r {
+            Some(e) => Err(e),
+            None => Ok(result),
+        }
+    }
+}
+
 // AsOwned
 
 impl<'a, F: Archive + Clone> ArchiveWith<Cow<'a, F>> for AsOwned {
@@ -175,23 +385,34 @@ where
     }
 }
 
-impl<'a, T, D> DeserializeWith<ArchivedVec<T::Archived>, Cow<'a, [T]>, D>
-    for AsOwned
+impl<'a> ArchiveWith<Cow<'a, str>> for AsOwned {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    fn resolve_with(
+        field: &Cow<'a, str>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedString::resolve_from_str(field, resolver, out);
+    }
+}
+
+impl<'a, S> SerializeWith<Cow<'a, str>, S> for AsOwned
 where
-    T: Archive + Clone,
-    T::Archived: Deserialize<T, D>,
-    D: Fallible + ?Sized,
-    D::Error: Source,
+    S: Fallible + Writer + ?Sized,
 {
-    fn deserialize_with(
-        field: &ArchivedVec<T::Archived>,
-        deserializer: &mut D,
-    ) -> Result<Cow<'a, [T]>, D::Error> {
-        Ok(Cow::Owned(field.deserialize(deserializer)?))
+    fn serialize_with(
+        field: &Cow<'a, str>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(field, serializer)
     }
 }
 
-impl<'a> ArchiveWith<Cow<'a, str>> for AsOwned {
+// AsCowStr
+
+impl<'a> ArchiveWith<Cow<'a, str>> for AsCowStr {
     type Archived = ArchivedString;
     type Resolver = StringResolver;
 
@@ -204,7 +425,7 @@ impl<'a> ArchiveWith<Cow<'a, str>> for AsOwned {
     }
 }
 
-impl<'a, S> SerializeWith<Cow<'a, str>, S> for AsOwned
+impl<'a, S> SerializeWith<Cow<'a, str>, S> for AsCowStr
 where
     S: Fallible + Writer + ?Sized,
 {
@@ -216,15 +437,127 @@ where
     }
 }
 
-impl<'a, D> DeserializeWith<ArchivedString, Cow<'a, str>, D> for AsOwned
+impl<'a, D> DeserializeWith<ArchivedString, Cow<'a, str>, D> for AsCowStr
 where
-    D: Fallible + ?Sized,
+    D: Fallible + Borrowing<'a> + ?Sized,
 {
     fn deserialize_with(
         field: &ArchivedString,
         deserializer: &mut D,
     ) -> Result<Cow<'a, str>, D::Error> {
-        Ok(Cow::Owned(field.deserialize(deserializer)?))
+        match deserializer.borrow_str(field.as_str()) {
+            Some(s) => Ok(Cow::Borrowed(s)),
+            None => Ok(Cow::Owned(String::from(field.as_str()))),
+        }
+    }
+}
+
+// DisplayFromStr
+
+#[derive(Debug)]
+struct ParseStringError {
+    message: String,
+}
+
+impl fmt::Display for ParseStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse archived string: {}", self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseStringError {}
+
+impl<T: fmt::Display> ArchiveWith<T> for DisplayFromStr {
+    type Archived = ArchivedString;
+    type Resolver = DisplayResolver;
+
+    fn resolve_with(
+        _: &T,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedString::resolve_from_display(resolver, out);
+    }
+}
+
+impl<T, S> SerializeWith<T, S> for DisplayFromStr
+where
+    T: fmt::Display,
+    S: Fallible + Writer + ?Sized,
+{
+    fn serialize_with(
+        field: &T,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_display(field, serializer)
+    }
+}
+
+impl<T, D> DeserializeWith<ArchivedString, T, D> for DisplayFromStr
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(
+        field: &ArchivedString,
+        _: &mut D,
+    ) -> Result<T, D::Error> {
+        match field.as_str().parse() {
+            Ok(value) => Ok(value),
+            Err(e) => fail!(ParseStringError {
+                message: e.to_string(),
+            }),
+        }
+    }
+}
+
+// Lazy
+
+impl<'a, T: Archive> ArchiveWith<Deferred<'a, T>> for Lazy {
+    type Archived = T::Archived;
+    type Resolver = DeferredResolver<T>;
+
+    fn resolve_with(
+        field: &Deferred<'a, T>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        field.resolve_deferred(resolver, out);
+    }
+}
+
+impl<'a, T, S> SerializeWith<Deferred<'a, T>, S> for Lazy
+where
+    T: Archive + Serialize<S>,
+    T::Archived: Deserialize<T, HighDeserializer<S::Error>>,
+    S: Fallible + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &Deferred<'a, T>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        field.serialize_deferred(serializer)
+    }
+}
+
+impl<'a, T, D> DeserializeWith<T::Archived, Deferred<'a, T>, D> for Lazy
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + Borrowing<'a> + ?Sized,
+{
+    fn deserialize_with(
+        field: &T::Archived,
+        deserializer: &mut D,
+    ) -> Result<Deferred<'a, T>, D::Error> {
+        match deserializer.borrow_archived(field) {
+            Some(archived) => Ok(Deferred::from_archived(archived)),
+            None => Ok(Deferred::new(field.deserialize(deserializer)?)),
+        }
     }
 }
 
@@ -335,6 +668,152 @@ where
     }
 }
 
+// InlineVec
+
+impl<T: Archive, const N: usize> ArchiveWith<Vec<T>> for InlineVec<N> {
+    type Archived = ArchivedInlineVec<T::Archived, N>;
+    type Resolver = InlineVecResolver<T::Resolver, N>;
+
+    fn resolve_with(
+        field: &Vec<T>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedInlineVec::resolve_from_slice(field.as_slice(), resolver, out);
+    }
+}
+
+impl<T, S, const N: usize> SerializeWith<Vec<T>, S> for InlineVec<N>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize_with(
+        field: &Vec<T>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedInlineVec::serialize_from_slice(field.as_slice(), serializer)
+    }
+}
+
+impl<T, D, const N: usize>
+    DeserializeWith<ArchivedInlineVec<T::Archived, N>, Vec<T>, D>
+    for InlineVec<N>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(
+        field: &ArchivedInlineVec<T::Archived, N>,
+        deserializer: &mut D,
+    ) -> Result<Vec<T>, D::Error> {
+        let mut result = Vec::with_capacity(field.len());
+        for value in field.iter() {
+            result.push(value.deserialize(deserializer)?);
+        }
+        Ok(result)
+    }
+}
+
+// RawVec
+
+impl<T: Portable + Copy + Initialized> ArchiveWith<Vec<T>> for RawVec {
+    type Archived = ArchivedVec<T>;
+    type Resolver = VecResolver;
+
+    fn resolve_with(
+        field: &Vec<T>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedVec::resolve_from_len(field.len(), resolver, out);
+    }
+}
+
+impl<T, S> SerializeWith<Vec<T>, S> for RawVec
+where
+    T: Portable + Copy + Initialized,
+    S: Fallible + Writer + ?Sized,
+{
+    fn serialize_with(
+        field: &Vec<T>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        let pos = serializer.align_for::<T>()?;
+        // SAFETY: `T` is `Initialized`, so all of the bytes of the slice are
+        // initialized.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                field.as_ptr().cast::<u8>(),
+                core::mem::size_of_val(field.as_slice()),
+            )
+        };
+        serializer.write(bytes)?;
+        Ok(VecResolver::from_pos(pos))
+    }
+}
+
+impl<T, D> DeserializeWith<ArchivedVec<T>, Vec<T>, D> for RawVec
+where
+    T: Portable + Copy + Initialized,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(
+        field: &ArchivedVec<T>,
+        _: &mut D,
+    ) -> Result<Vec<T>, D::Error> {
+        Ok(field.as_slice().to_vec())
+    }
+}
+
+// Compressed
+
+impl<C: Codec> ArchiveWith<Vec<u8>> for Compressed<C> {
+    type Archived = ArchivedCompressed<C>;
+    type Resolver = CompressedResolver;
+
+    fn resolve_with(
+        field: &Vec<u8>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedCompressed::resolve_from_len(field.len(), resolver, out);
+    }
+}
+
+impl<C, S> SerializeWith<Vec<u8>, S> for Compressed<C>
+where
+    C: Codec,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize_with(
+        field: &Vec<u8>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedCompressed::<C>::serialize_from_slice(
+            field.as_slice(),
+            serializer,
+        )
+    }
+}
+
+impl<C, D> DeserializeWith<ArchivedCompressed<C>, Vec<u8>, D> for Compressed<C>
+where
+    C: Codec,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(
+        field: &ArchivedCompressed<C>,
+        _: &mut D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let mut result = Vec::with_capacity(field.len());
+        field.decompress_to(&mut result)?;
+        Ok(result)
+    }
+}
+
 // Niche
 
 impl<T> ArchiveWith<Option<Box<T>>> for Niche
@@ -464,15 +943,29 @@ where
 mod tests {
     use core::mem::size_of;
 
+    use rancor::Panic;
+
     use crate::{
+        access_unchecked,
         alloc::{
             borrow::Cow,
             boxed::Box,
             collections::{BTreeMap, BTreeSet},
             string::{String, ToString},
+            vec::Vec,
+        },
+        api::{
+            deserialize_with,
+            test::{
+                deserialize, roundtrip, roundtrip_with, to_archived, to_bytes,
+            },
+        },
+        de::{Borrower, Pool},
+        util::Deferred,
+        with::{
+            AsBox, AsCowStr, AsOwned, AsVec, Dedup, InlineVec, Lazy, Map,
+            MapKV, Niche, RawVec,
         },
-        api::test::{roundtrip, to_archived},
-        with::{AsOwned, AsVec, Niche},
         Archive, Deserialize, Serialize,
     };
 
@@ -534,6 +1027,223 @@ mod tests {
         });
     }
 
+    #[test]
+    fn with_as_cow_str() {
+        #[derive(Archive, Serialize, Deserialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Test<'a> {
+            #[with(AsCowStr)]
+            a: Cow<'a, str>,
+        }
+
+        let value = Test {
+            a: Cow::Borrowed("hello world"),
+        };
+        to_bytes(&value, |bytes| {
+            let archived = unsafe { access_unchecked::<ArchivedTest>(bytes) };
+            assert_eq!(archived.a, "hello world");
+
+            let mut deserializer = Borrower::new(bytes, Pool::new());
+            let deserialized = deserialize_with::<Test<'_>, _, Panic>(
+                archived,
+                &mut deserializer,
+            )
+            .unwrap();
+            match deserialized.a {
+                Cow::Borrowed(a) => assert_eq!(a, "hello world"),
+                Cow::Owned(_) => panic!("expected a borrowed string"),
+            }
+
+            let deserialized = deserialize_with::<Test<'_>, _, Panic>(
+                archived,
+                &mut Pool::new(),
+            )
+            .unwrap();
+            match deserialized.a {
+                Cow::Borrowed(_) => panic!("expected an owned string"),
+                Cow::Owned(a) => assert_eq!(a, "hello world"),
+            }
+        });
+    }
+
+    #[test]
+    fn with_display_from_str() {
+        use core::{fmt, str::FromStr};
+
+        use rancor::{Failure, Strategy};
+
+        use crate::{
+            string::ArchivedString,
+            with::{DeserializeWith, DisplayFromStr},
+        };
+
+        #[derive(Debug, PartialEq)]
+        struct Semver {
+            major: u32,
+            minor: u32,
+            patch: u32,
+        }
+
+        impl fmt::Display for Semver {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+            }
+        }
+
+        impl FromStr for Semver {
+            type Err = &'static str;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut parts = s.split('.').map(u32::from_str);
+                let mut next = || match parts.next() {
+                    Some(Ok(part)) => Ok(part),
+                    _ => Err("invalid version"),
+                };
+                let version = Semver {
+                    major: next()?,
+                    minor: next()?,
+                    patch: next()?,
+                };
+                match parts.next() {
+                    None => Ok(version),
+                    Some(_) => Err("invalid version"),
+                }
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Package {
+            name: String,
+            #[with(DisplayFromStr)]
+            version: Semver,
+        }
+
+        let value = Package {
+            name: "rkyv".to_string(),
+            version: Semver {
+                major: 0,
+                minor: 8,
+                patch: 10,
+            },
+        };
+        roundtrip_with(&value, |a, b| {
+            assert_eq!(b.name, a.name);
+            assert_eq!(b.version, "0.8.10");
+        });
+
+        to_archived(&"0.eight.10".to_string(), |archived| {
+            let result = <DisplayFromStr as DeserializeWith<
+                ArchivedString,
+                Semver,
+                Strategy<(), Failure>,
+            >>::deserialize_with(
+                &*archived, Strategy::wrap(&mut ())
+            );
+            assert!(result.is_err());
+        });
+    }
+
+    #[test]
+    fn with_display_from_str_formats_once() {
+        use core::{cell::Cell, fmt};
+
+        use crate::with::DisplayFromStr;
+
+        // Formats as a longer string every time it's displayed.
+        struct Growing(Cell<usize>);
+
+        impl fmt::Display for Growing {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let len = self.0.get();
+                self.0.set(len + 10);
+                write!(f, "{}", "x".repeat(len))
+            }
+        }
+
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate)]
+        struct Example {
+            #[with(DisplayFromStr)]
+            value: Growing,
+        }
+
+        for len in [4, 20] {
+            let value = Example {
+                value: Growing(Cell::new(len)),
+            };
+            to_bytes(&value, |bytes| {
+                let archived =
+                    unsafe { access_unchecked::<ArchivedExample>(bytes) };
+                assert_eq!(archived.value, "x".repeat(len));
+            });
+        }
+    }
+
+    #[test]
+    fn with_lazy() {
+        #[derive(Archive, Serialize, Deserialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Test<'a> {
+            #[with(Lazy)]
+            a: Deferred<'a, Vec<String>>,
+        }
+
+        let value = Test {
+            a: Deferred::new(vec!["hello".to_string(), "world".to_string()]),
+        };
+        to_bytes(&value, |bytes| {
+            let archived = unsafe { access_unchecked::<ArchivedTest>(bytes) };
+            assert_eq!(archived.a, ["hello", "world"]);
+
+            let mut deserializer = Borrower::new(bytes, Pool::new());
+            let deserialized = deserialize_with::<Test<'_>, _, Panic>(
+                archived,
+                &mut deserializer,
+            )
+            .unwrap();
+            assert!(!deserialized.a.is_deserialized());
+            assert!(deserialized.a.archived().is_some());
+            assert_eq!(
+                deserialized.a.get::<Panic>().unwrap(),
+                &["hello", "world"],
+            );
+            assert!(deserialized.a.is_deserialized());
+
+            // Serializing an undeserialized value deserializes a copy of it.
+            let deserialized = deserialize_with::<Test<'_>, _, Panic>(
+                archived,
+                &mut deserializer,
+            )
+            .unwrap();
+            let reserialized =
+                crate::api::high::to_bytes::<Panic>(&deserialized).unwrap();
+            assert!(!deserialized.a.is_deserialized());
+            assert_eq!(
+                deserialized
+                    .a
+                    .get_with::<_, Panic>(&mut deserializer)
+                    .unwrap(),
+                &["hello", "world"],
+            );
+            let archived =
+                unsafe { access_unchecked::<ArchivedTest>(&reserialized) };
+            assert_eq!(archived.a, ["hello", "world"]);
+
+            let deserialized = deserialize_with::<Test<'_>, _, Panic>(
+                archived,
+                &mut Pool::new(),
+            )
+            .unwrap();
+            assert!(deserialized.a.is_deserialized());
+            assert!(deserialized.a.archived().is_none());
+            assert_eq!(
+                deserialized.a.into_inner::<Panic>().unwrap(),
+                ["hello", "world"],
+            );
+        });
+    }
+
     #[test]
     fn with_as_vec() {
         #[derive(Archive, Serialize, Deserialize)]
@@ -588,6 +1298,130 @@ mod tests {
         });
     }
 
+    #[test]
+    fn roundtrip_nested_map() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(Map<Map<AsBox>>)]
+            vec: Vec<Vec<u32>>,
+            #[with(Map<Map<AsBox>>)]
+            map: BTreeMap<String, Vec<u32>>,
+        }
+
+        let mut map = BTreeMap::new();
+        map.insert("foo".to_string(), vec![1, 2, 3]);
+        map.insert("bar".to_string(), Vec::new());
+
+        let value = Test {
+            vec: vec![vec![4, 5], Vec::new(), vec![6]],
+            map,
+        };
+        roundtrip_with(&value, |a, b| {
+            assert_eq!(a.vec.len(), b.vec.len());
+            for (a, b) in a.vec.iter().zip(b.vec.iter()) {
+                assert!(b.iter().map(|x| x.get()).eq(a.iter()));
+            }
+            assert_eq!(a.map.len(), b.map.len());
+            for (key, value) in a.map.iter() {
+                let archived = b.map.get(key.as_str()).unwrap();
+                assert!(archived.iter().map(|x| x.get()).eq(value.iter()));
+            }
+        });
+    }
+
+    #[test]
+    fn roundtrip_map_kv_btree_map() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(MapKV<AsBox, Niche>)]
+            map: BTreeMap<String, Option<Box<str>>>,
+        }
+
+        let mut map = BTreeMap::new();
+        map.insert("foo".to_string(), Some(Box::from("hello")));
+        map.insert("bar".to_string(), None);
+        map.insert("baz".to_string(), Some(Box::from("world")));
+
+        roundtrip_with(&Test { map }, |a, b| {
+            assert_eq!(a.map.len(), b.map.len());
+            let mut expected = a.map.iter();
+            b.map.visit(|key, value| {
+                let (a_key, a_value) = expected.next().unwrap();
+                assert_eq!(key.as_str(), a_key);
+                assert_eq!(value.as_deref(), a_value.as_deref());
+                core::ops::ControlFlow::<()>::Continue(())
+            });
+            assert!(expected.next().is_none());
+        });
+    }
+
+    #[test]
+    fn roundtrip_inline_vec() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(InlineVec<2>)]
+            value: Vec<String>,
+        }
+
+        for len in 0..5 {
+            let value = Test {
+                value: (0..len).map(|i| i.to_string()).collect(),
+            };
+            roundtrip_with(&value, |a, b| {
+                assert_eq!(b.value.len(), len);
+                assert_eq!(b.value.is_inline(), len <= 2);
+                assert!(a.value.iter().eq(b.value.iter()));
+            });
+        }
+    }
+
+    #[test]
+    fn roundtrip_raw_vec() {
+        use crate::{place::Initialized, Archived, Portable};
+
+        #[derive(Clone, Copy, Debug, PartialEq, Portable)]
+        #[cfg_attr(feature = "bytecheck", derive(bytecheck::CheckBytes))]
+        #[rkyv(crate)]
+        #[repr(C)]
+        struct Pod {
+            a: Archived<u32>,
+            b: Archived<u16>,
+            c: Archived<u16>,
+        }
+
+        // SAFETY: `Pod` is made of archived integers and has no padding.
+        unsafe impl Initialized for Pod {}
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(RawVec)]
+            pods: Vec<Pod>,
+            value: u8,
+        }
+
+        for len in [0u32, 1, 100] {
+            let value = Test {
+                pods: (0..len)
+                    .map(|i| Pod {
+                        a: Archived::<u32>::from_native(i),
+                        b: Archived::<u16>::from_native(i as u16 * 2),
+                        c: Archived::<u16>::from_native(u16::MAX - i as u16),
+                    })
+                    .collect(),
+                value: 42,
+            };
+            roundtrip_with(&value, |a, b| {
+                assert_eq!(b.pods.len(), a.pods.len());
+                assert_eq!(b.pods.as_slice(), a.pods.as_slice());
+                assert_eq!(b.value, 42);
+            });
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn with_niche_box() {
@@ -620,4 +1454,37 @@ mod tests {
         });
         assert!(size_of::<ArchivedTest>() < size_of::<ArchivedTestNoNiching>());
     }
+
+    #[test]
+    fn with_dedup() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            #[with(Dedup)]
+            a: String,
+            #[with(Dedup)]
+            b: String,
+            #[with(Dedup)]
+            c: Vec<u32>,
+            #[with(Dedup)]
+            d: Vec<u32>,
+        }
+
+        let value = Test {
+            a: "a string which is stored out of line".to_string(),
+            b: "a string which is stored out of line".to_string(),
+            c: vec![1, 2, 3],
+            d: vec![1, 2, 4],
+        };
+        to_archived(&value, |archived| {
+            assert_eq!(archived.a.as_str(), value.a);
+            assert!(archived.a.ptr_eq(&archived.b));
+            assert_eq!(archived.c.as_slice(), &[1, 2, 3]);
+            assert_eq!(archived.d.as_slice(), &[1, 2, 4]);
+            assert!(!archived.c.ptr_eq(&archived.d));
+
+            let deserialized = deserialize::<Test>(&*archived);
+            assert_eq!(deserialized, value);
+        });
+    }
 }
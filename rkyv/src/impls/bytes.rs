@@ -7,6 +7,43 @@ use crate::{
     Archive, Archived, Deserialize, Place, Serialize,
 };
 
+impl ArchivedVec<u8> {
+    /// Returns a `Bytes` which refers to the archived bytes without copying
+    /// them.
+    ///
+    /// `owner` must be the buffer backing this archive. The returned `Bytes`
+    /// shares ownership of `owner`, so it remains valid after the archive is
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the archived bytes are not located within `owner`. Use
+    /// [`try_as_bytes_ref`](Self::try_as_bytes_ref) to check this instead.
+    pub fn as_bytes_ref(&self, owner: Bytes) -> Bytes {
+        owner.slice_ref(self.as_slice())
+    }
+
+    /// Returns a `Bytes` which refers to the archived bytes without copying
+    /// them, or `None` if the archived bytes are not located within `owner`.
+    ///
+    /// See [`as_bytes_ref`](Self::as_bytes_ref) for more details.
+    pub fn try_as_bytes_ref(&self, owner: Bytes) -> Option<Bytes> {
+        if self.is_empty() {
+            return Some(Bytes::new());
+        }
+
+        let owner_start = owner.as_ptr() as usize;
+        let owner_end = owner_start + owner.len();
+        let start = self.as_ptr() as usize;
+        let end = start + self.len();
+        if start >= owner_start && end <= owner_end {
+            Some(owner.slice(start - owner_start..end - owner_start))
+        } else {
+            None
+        }
+    }
+}
+
 impl Archive for Bytes {
     type Archived = ArchivedVec<u8>;
     type Resolver = VecResolver;
@@ -43,14 +80,46 @@ where
     }
 }
 
+// SAFETY: The bytes of a `Bytes` are never moved or modified while any
+// `Bytes` refers to them.
+#[cfg(feature = "alloc")]
+unsafe impl crate::api::high::StableBytes for Bytes {}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
+    use rancor::Panic;
 
-    use crate::{alloc::vec, api::test::roundtrip};
+    use crate::{
+        access_unchecked, alloc::vec, api::test::roundtrip, util::AlignedVec,
+        vec::ArchivedVec,
+    };
 
     #[test]
     fn roundtrip_bytes() {
         roundtrip(&Bytes::from(vec![10, 20, 40, 80]));
     }
+
+    #[test]
+    fn archived_vec_as_bytes_ref() {
+        let value = Bytes::from(vec![10, 20, 40, 80]);
+        let buf = crate::api::high::to_bytes::<Panic>(&value).unwrap();
+        // Copy the archive into a `Bytes` at an offset which keeps it aligned.
+        let mut storage = vec![0u8; buf.len() + AlignedVec::<16>::ALIGNMENT];
+        let offset = storage.as_ptr().align_offset(AlignedVec::<16>::ALIGNMENT);
+        storage[offset..offset + buf.len()].copy_from_slice(&buf);
+        let owner = Bytes::from(storage).slice(offset..offset + buf.len());
+        assert_eq!(owner.as_ptr().align_offset(AlignedVec::<16>::ALIGNMENT), 0);
+
+        let archived = unsafe { access_unchecked::<ArchivedVec<u8>>(&owner) };
+        let bytes = archived.as_bytes_ref(owner.clone());
+        assert_eq!(bytes, value);
+        assert_eq!(bytes.as_ptr(), archived.as_ptr());
+
+        let bytes = archived.try_as_bytes_ref(owner.clone()).unwrap();
+        assert_eq!(bytes, value);
+        assert!(archived
+            .try_as_bytes_ref(Bytes::from_static(&[0; 4]))
+            .is_none());
+    }
 }
@@ -0,0 +1,148 @@
+use core::{fmt, time::Duration};
+
+use chrono::TimeDelta;
+use rancor::{Fallible, OptionExt as _, Source};
+
+use crate::{
+    time::ArchivedDuration,
+    with::{ArchiveWith, AsChronoDuration, DeserializeWith, SerializeWith},
+    Archive, Place,
+};
+
+#[derive(Debug)]
+struct NegativeDuration;
+
+impl fmt::Display for NegativeDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "negative durations cannot be archived as `ArchivedDuration`"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NegativeDuration {}
+
+#[derive(Debug)]
+struct DurationOutOfRange;
+
+impl fmt::Display for DurationOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the archived duration is out of range for `chrono::Duration`"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationOutOfRange {}
+
+impl ArchiveWith<TimeDelta> for AsChronoDuration {
+    type Archived = ArchivedDuration;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve_with(
+        field: &TimeDelta,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        // We already checked that the duration is non-negative during
+        // serialize_with
+        let duration = field.to_std().unwrap();
+        Archive::resolve(&duration, resolver, out);
+    }
+}
+
+impl<S> SerializeWith<TimeDelta, S> for AsChronoDuration
+where
+    S: Fallible + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &TimeDelta,
+        _: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        field.to_std().ok().into_trace(NegativeDuration)?;
+        Ok(())
+    }
+}
+
+impl<D> DeserializeWith<ArchivedDuration, TimeDelta, D> for AsChronoDuration
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(
+        field: &ArchivedDuration,
+        _: &mut D,
+    ) -> Result<TimeDelta, D::Error> {
+        TimeDelta::from_std(Duration::from(*field))
+            .ok()
+            .into_trace(DurationOutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use chrono::TimeDelta;
+    use rancor::{Failure, Strategy};
+
+    use crate::{
+        api::test::{roundtrip_with, to_archived},
+        with::{AsChronoDuration, DeserializeWith},
+        Archive, Deserialize, Serialize,
+    };
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    #[rkyv(crate, check_bytes, derive(Debug))]
+    struct Test {
+        #[with(AsChronoDuration)]
+        value: TimeDelta,
+    }
+
+    #[test]
+    fn roundtrip_chrono_duration() {
+        roundtrip_with(
+            &Test {
+                value: TimeDelta::new(1234, 567_890).unwrap(),
+            },
+            |a, b| {
+                assert_eq!(b.value.as_secs(), 1234);
+                assert_eq!(b.value.subsec_nanos(), 567_890);
+                assert_eq!(a.value.to_std().unwrap(), Duration::from(b.value));
+            },
+        );
+        roundtrip_with(
+            &Test {
+                value: TimeDelta::zero(),
+            },
+            |_, b| assert_eq!(Duration::from(b.value), Duration::ZERO),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn serialize_negative_chrono_duration() {
+        let value = Test {
+            value: TimeDelta::seconds(-1),
+        };
+        assert!(crate::to_bytes::<Failure>(&value).is_err());
+    }
+
+    #[test]
+    fn deserialize_out_of_range_chrono_duration() {
+        to_archived(&Duration::new(u64::MAX, 0), |archived| {
+            let result: Result<TimeDelta, Failure> =
+                AsChronoDuration::deserialize_with(
+                    &*archived,
+                    Strategy::wrap(&mut ()),
+                );
+            assert!(result.is_err());
+        });
+    }
+}
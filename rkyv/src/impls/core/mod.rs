@@ -13,8 +13,8 @@ use crate::{
     ser::{Allocator, Writer, WriterExt as _},
     traits::{ArchivePointee, CopyOptimization, LayoutRaw},
     tuple::*,
-    Archive, ArchiveUnsized, ArchivedMetadata, Deserialize, DeserializeUnsized,
-    Place, Portable, Serialize, SerializeUnsized,
+    Archive, ArchiveUnsized, ArchivedMetadata, Deserialize, DeserializeInto,
+    DeserializeUnsized, Place, Portable, Serialize, SerializeUnsized,
 };
 
 mod net;
@@ -164,6 +164,28 @@ macro_rules! impl_tuple {
                 ))
             }
         }
+
+        impl<$($type,)* D> DeserializeInto<($($type,)*), D>
+            for $name<$($type::Archived,)*>
+        where
+            D: Fallible + ?Sized,
+            $($type: Archive,)*
+            $($type::Archived: DeserializeInto<$type, D>,)*
+        {
+            fn deserialize_into(
+                &self,
+                place: &mut ($($type,)*),
+                deserializer: &mut D,
+            ) -> Result<(), D::Error> {
+                $(
+                    self.$index.deserialize_into(
+                        &mut place.$index,
+                        deserializer,
+                    )?;
+                )*
+                Ok(())
+            }
+        }
     };
 }
 
@@ -193,6 +215,18 @@ impl_tuple!(
     ArchivedTuple13, T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9,
     T10 10, T11 11, T12 12
 );
+impl_tuple!(
+    ArchivedTuple14, T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9,
+    T10 10, T11 11, T12 12, T13 13
+);
+impl_tuple!(
+    ArchivedTuple15, T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9,
+    T10 10, T11 11, T12 12, T13 13, T14 14
+);
+impl_tuple!(
+    ArchivedTuple16, T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9,
+    T10 10, T11 11, T12 12, T13 13, T14 14, T15 15
+);
 
 impl<T: Archive, const N: usize> Archive for [T; N] {
     const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe {
@@ -248,6 +282,24 @@ where
     }
 }
 
+impl<T, D, const N: usize> DeserializeInto<[T; N], D> for [T::Archived; N]
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut [T; N],
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        for (value, out) in self.iter().zip(place.iter_mut()) {
+            value.deserialize_into(out, deserializer)?;
+        }
+        Ok(())
+    }
+}
+
 impl<T: Archive> ArchiveUnsized for [T] {
     type Archived = [T::Archived];
 
@@ -451,13 +503,57 @@ where
 
 #[cfg(test)]
 mod tests {
-    use core::mem::ManuallyDrop;
+    use core::mem::{size_of, ManuallyDrop};
 
     use crate::{
-        api::test::{roundtrip, roundtrip_with},
+        api::test::{deserialize, roundtrip, roundtrip_with, to_archived},
+        traits::ArchivedLayout,
         tuple::ArchivedTuple3,
+        Archive, Archived, Deserialize, Serialize,
     };
 
+    #[test]
+    fn archived_size_and_align() {
+        use core::mem::align_of;
+
+        macro_rules! assert_archived_layout {
+            ($($ty:ty),* $(,)?) => {
+                $(
+                    assert_eq!(
+                        <$ty as ArchivedLayout>::ARCHIVED_SIZE,
+                        size_of::<Archived<$ty>>(),
+                    );
+                    assert_eq!(
+                        <$ty as ArchivedLayout>::ARCHIVED_ALIGN,
+                        align_of::<Archived<$ty>>(),
+                    );
+                )*
+            };
+        }
+
+        #[derive(Archive)]
+        #[rkyv(crate)]
+        struct Record {
+            _id: u64,
+            _flag: bool,
+            _values: [u16; 3],
+        }
+
+        assert_archived_layout!(
+            (),
+            u8,
+            u32,
+            char,
+            (u8, u64),
+            [u16; 3],
+            Option<u32>,
+            Record,
+        );
+
+        const RECORD_SIZE: usize = <Record as ArchivedLayout>::ARCHIVED_SIZE;
+        assert_eq!(RECORD_SIZE, size_of::<ArchivedRecord>());
+    }
+
     #[test]
     fn roundtrip_tuple() {
         roundtrip_with(
@@ -470,6 +566,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn roundtrip_tuple_16() {
+        // Tuples this large don't implement `Debug` or `PartialEq`, so the
+        // elements are compared individually.
+        macro_rules! assert_elements_eq {
+            ($a:expr, $b:expr) => {
+                assert_elements_eq!(
+                    $a, $b, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+                );
+            };
+            ($a:expr, $b:expr, $($index:tt),*) => {
+                $(assert_eq!($a.$index, $b.$index);)*
+            };
+        }
+
+        type Tuple = (
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            f32,
+            f64,
+            bool,
+            (),
+            [u8; 2],
+            u16,
+        );
+
+        let value: Tuple = (
+            1,
+            2,
+            3,
+            4,
+            5,
+            -6,
+            -7,
+            -8,
+            -9,
+            -10,
+            11.0,
+            12.0,
+            true,
+            (),
+            [14, 14],
+            16,
+        );
+        to_archived(&value, |archived| {
+            assert_elements_eq!(archived, value);
+            let deserialized = deserialize::<Tuple>(&*archived);
+            assert_elements_eq!(deserialized, value);
+        });
+    }
+
     #[test]
     fn roundtrip_array() {
         roundtrip(&[1, 2, 3, 4, 5, 6]);
@@ -477,6 +632,27 @@ mod tests {
         roundtrip(&[(), (), (), ()]);
     }
 
+    #[test]
+    fn roundtrip_large_arrays() {
+        assert_eq!(size_of::<Archived<[u32; 0]>>(), 0);
+        roundtrip(&[0u32; 0]);
+        roundtrip(&core::array::from_fn::<u8, 64, _>(|i| i as u8));
+        roundtrip(&core::array::from_fn::<u8, 256, _>(|i| i as u8));
+        roundtrip(&core::array::from_fn::<u32, 1024, _>(|i| i as u32));
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        struct Keys {
+            public: [u8; 64],
+            digest: [u8; 256],
+        }
+
+        roundtrip(&Keys {
+            public: [0xaa; 64],
+            digest: core::array::from_fn(|i| i as u8),
+        });
+    }
+
     #[test]
     fn roundtrip_manually_drop() {
         roundtrip(&ManuallyDrop::new(123i8));
@@ -67,6 +67,13 @@ impl PartialOrd<ArchivedIpv4Addr> for Ipv4Addr {
     }
 }
 
+impl From<&ArchivedIpv4Addr> for Ipv4Addr {
+    #[inline]
+    fn from(value: &ArchivedIpv4Addr) -> Self {
+        value.as_ipv4()
+    }
+}
+
 // Ipv6Addr
 
 impl Archive for Ipv6Addr {
@@ -119,6 +126,13 @@ impl PartialOrd<ArchivedIpv6Addr> for Ipv6Addr {
     }
 }
 
+impl From<&ArchivedIpv6Addr> for Ipv6Addr {
+    #[inline]
+    fn from(value: &ArchivedIpv6Addr) -> Self {
+        value.as_ipv6()
+    }
+}
+
 // IpAddr
 
 #[allow(dead_code)]
@@ -230,6 +244,13 @@ impl PartialOrd<ArchivedIpAddr> for IpAddr {
     }
 }
 
+impl From<&ArchivedIpAddr> for IpAddr {
+    #[inline]
+    fn from(value: &ArchivedIpAddr) -> Self {
+        value.as_ipaddr()
+    }
+}
+
 // SocketAddrV4
 
 impl Archive for SocketAddrV4 {
@@ -292,6 +313,13 @@ impl PartialOrd<ArchivedSocketAddrV4> for SocketAddrV4 {
     }
 }
 
+impl From<&ArchivedSocketAddrV4> for SocketAddrV4 {
+    #[inline]
+    fn from(value: &ArchivedSocketAddrV4) -> Self {
+        value.as_socket_addr_v4()
+    }
+}
+
 // SocketAddrV6
 
 impl Archive for SocketAddrV6 {
@@ -358,6 +386,13 @@ impl PartialOrd<ArchivedSocketAddrV6> for SocketAddrV6 {
     }
 }
 
+impl From<&ArchivedSocketAddrV6> for SocketAddrV6 {
+    #[inline]
+    fn from(value: &ArchivedSocketAddrV6) -> Self {
+        value.as_socket_addr_v6()
+    }
+}
+
 // SocketAddr
 
 #[allow(dead_code)]
@@ -463,13 +498,20 @@ impl PartialOrd<ArchivedSocketAddr> for SocketAddr {
     }
 }
 
+impl From<&ArchivedSocketAddr> for SocketAddr {
+    #[inline]
+    fn from(value: &ArchivedSocketAddr) -> Self {
+        value.as_socket_addr()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::net::{
         IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
     };
 
-    use crate::api::test::roundtrip;
+    use crate::api::test::{roundtrip, to_archived};
 
     #[test]
     fn roundtrip_ipv4_addr() {
@@ -515,4 +557,44 @@ mod tests {
             0,
         )));
     }
+
+    #[test]
+    fn convert_archived_addrs() {
+        let v4 = Ipv4Addr::new(127, 0, 0, 1);
+        let v6 = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+
+        to_archived(&v4, |archived| {
+            assert_eq!(Ipv4Addr::from(&*archived), v4);
+        });
+        to_archived(&v6, |archived| {
+            assert_eq!(Ipv6Addr::from(&*archived), v6);
+        });
+        to_archived(&IpAddr::V4(v4), |archived| {
+            assert_eq!(IpAddr::from(&*archived), IpAddr::V4(v4));
+            assert!(archived.is_loopback());
+            assert!(!archived.is_multicast());
+            assert!(!archived.is_unspecified());
+        });
+        to_archived(&IpAddr::V6(v6), |archived| {
+            assert_eq!(IpAddr::from(&*archived), IpAddr::V6(v6));
+            assert!(!archived.is_loopback());
+            assert!(archived.is_multicast());
+            assert!(!archived.is_unspecified());
+        });
+        to_archived(&IpAddr::V6(Ipv6Addr::UNSPECIFIED), |archived| {
+            assert!(archived.is_unspecified());
+        });
+
+        let socket_v4 = SocketAddrV4::new(v4, 5358);
+        let socket_v6 = SocketAddrV6::new(v6, 2384, 1, 2);
+        to_archived(&socket_v4, |archived| {
+            assert_eq!(SocketAddrV4::from(&*archived), socket_v4);
+        });
+        to_archived(&socket_v6, |archived| {
+            assert_eq!(SocketAddrV6::from(&*archived), socket_v6);
+        });
+        to_archived(&SocketAddr::V6(socket_v6), |archived| {
+            assert_eq!(SocketAddr::from(&*archived), SocketAddr::V6(socket_v6));
+        });
+    }
 }
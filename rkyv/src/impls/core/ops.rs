@@ -1,8 +1,8 @@
 use core::{
     hint::unreachable_unchecked,
     ops::{
-        Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
-        RangeToInclusive,
+        Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive,
+        RangeTo, RangeToInclusive,
     },
 };
 
@@ -102,9 +102,11 @@ impl<T: Archive> Archive for RangeInclusive<T> {
     type Resolver = Range<T::Resolver>;
 
     fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
-        munge!(let ArchivedRangeInclusive { start, end } = out);
+        munge!(let ArchivedRangeInclusive { start, end, exhausted } = out);
         self.start().resolve(resolver.start, start);
         self.end().resolve(resolver.end, end);
+        // Exhausted ranges report an excluded end bound.
+        exhausted.write(matches!(self.end_bound(), Bound::Excluded(_)));
     }
 }
 
@@ -120,21 +122,37 @@ impl<T: Serialize<S>, S: Fallible + ?Sized> Serialize<S> for RangeInclusive<T> {
     }
 }
 
+/// A `RangeInclusive` can only be exhausted by iterating over it, so only
+/// ranges of types which can be iterated over (e.g. integers and `char`s) can
+/// be deserialized with their exhausted flag.
 impl<T, D> Deserialize<RangeInclusive<T>, D>
     for ArchivedRangeInclusive<T::Archived>
 where
     T: Archive,
     T::Archived: Deserialize<T, D>,
+    RangeInclusive<T>: Iterator,
     D: Fallible + ?Sized,
 {
     fn deserialize(
         &self,
         deserializer: &mut D,
     ) -> Result<RangeInclusive<T>, D::Error> {
-        Ok(RangeInclusive::new(
-            self.start.deserialize(deserializer)?,
-            self.end.deserialize(deserializer)?,
-        ))
+        if self.exhausted {
+            // Iterating over a range leaves its start and end equal once it is
+            // exhausted, so this recreates it by exhausting a range of one
+            // item.
+            let mut result = RangeInclusive::new(
+                self.end.deserialize(deserializer)?,
+                self.end.deserialize(deserializer)?,
+            );
+            result.next();
+            Ok(result)
+        } else {
+            Ok(RangeInclusive::new(
+                self.start.deserialize(deserializer)?,
+                self.end.deserialize(deserializer)?,
+            ))
+        }
     }
 }
 
@@ -143,7 +161,10 @@ where
     U: PartialEq<T>,
 {
     fn eq(&self, other: &RangeInclusive<T>) -> bool {
-        self.start.eq(other.start()) && self.end.eq(other.end())
+        self.start.eq(other.start())
+            && self.end.eq(other.end())
+            && self.is_exhausted()
+                == matches!(other.end_bound(), Bound::Excluded(_))
     }
 }
 
@@ -420,9 +441,12 @@ where
 
 #[cfg(test)]
 mod tests {
-    use core::ops::Bound;
+    use core::ops::{Bound, RangeInclusive};
 
-    use crate::api::test::roundtrip;
+    use crate::{
+        api::test::{deserialize, roundtrip, roundtrip_with, to_archived},
+        primitive::ArchivedU64,
+    };
 
     #[test]
     fn roundtrip_ranges() {
@@ -434,6 +458,57 @@ mod tests {
         roundtrip(&(..=100u8));
     }
 
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn roundtrip_u64_ranges() {
+        let n = ArchivedU64::from_native;
+
+        roundtrip_with(&(10u64..20u64), |_, archived| {
+            assert!(archived.contains(&n(10)));
+            assert!(archived.contains(&n(19)));
+            assert!(!archived.contains(&n(20)));
+            assert!(!archived.is_empty());
+        });
+        roundtrip_with(&(10u64..=20u64), |_, archived| {
+            assert!(archived.contains(&n(10)));
+            assert!(archived.contains(&n(20)));
+            assert!(!archived.contains(&n(21)));
+            assert!(!archived.is_empty());
+        });
+        roundtrip_with(&(20u64..10u64), |_, archived| {
+            assert!(archived.is_empty());
+        });
+        roundtrip_with(&(20u64..=10u64), |_, archived| {
+            assert!(archived.is_empty());
+        });
+    }
+
+    #[test]
+    fn archive_exhausted_range_inclusive() {
+        let mut range = 1u64..=1u64;
+        assert_eq!(range.next(), Some(1));
+        assert!(range.is_empty());
+
+        roundtrip_with(&range, |original, archived| {
+            assert_eq!(archived.start, 1u64);
+            assert_eq!(archived.end, 1u64);
+            assert!(archived.is_exhausted());
+            assert!(archived.is_empty());
+            assert!(!archived.contains(&ArchivedU64::from_native(1)));
+            assert_eq!(*archived, *original);
+            assert_ne!(*archived, 1u64..=1u64);
+
+            let deserialized = deserialize::<RangeInclusive<u64>>(archived);
+            assert!(deserialized.is_empty());
+            assert_eq!(deserialized, *original);
+        });
+
+        to_archived(&(1u64..=1u64), |archived| {
+            assert!(!archived.is_exhausted());
+            assert!(!archived.is_empty());
+        });
+    }
+
     #[test]
     fn roundtrip_bound() {
         roundtrip(&Bound::Included(100u8));
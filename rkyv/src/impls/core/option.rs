@@ -4,8 +4,8 @@ use munge::munge;
 use rancor::Fallible;
 
 use crate::{
-    option::ArchivedOption, place::Initialized, Archive, Deserialize, Place,
-    Serialize,
+    option::ArchivedOption, place::Initialized, Archive, Deserialize,
+    DeserializeInto, Place, Serialize,
 };
 
 #[allow(dead_code)]
@@ -86,6 +86,29 @@ where
     }
 }
 
+impl<T, D> DeserializeInto<Option<T>, D> for ArchivedOption<T::Archived>
+where
+    T: Archive,
+    T::Archived: DeserializeInto<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut Option<T>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        match (self, place) {
+            (ArchivedOption::Some(value), Some(place)) => {
+                value.deserialize_into(place, deserializer)
+            }
+            (value, place) => {
+                *place = value.deserialize(deserializer)?;
+                Ok(())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::api::test::roundtrip;
@@ -3,7 +3,7 @@ use core::{
     num::{
         NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8,
         NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64,
-        NonZeroU8, NonZeroUsize,
+        NonZeroU8, NonZeroUsize, Saturating, Wrapping,
     },
 };
 
@@ -20,7 +20,7 @@ use crate::{
         ArchivedU128, ArchivedU16, ArchivedU32, ArchivedU64, ArchivedUsize,
     },
     traits::CopyOptimization,
-    Archive, Deserialize, Place, Portable, Serialize,
+    Archive, Deserialize, DeserializeInto, Place, Portable, Serialize,
 };
 
 macro_rules! unsafe_impl_initialized_and_portable {
@@ -157,6 +157,8 @@ macro_rules! impl_archive_self_primitive {
                 Ok(*self)
             }
         }
+
+        impl<D: Fallible + ?Sized> DeserializeInto<$type, D> for $type {}
     };
 }
 
@@ -213,6 +215,8 @@ macro_rules! impl_multibyte_primitive {
                 Ok(self.to_native())
             }
         }
+
+        impl<D: Fallible + ?Sized> DeserializeInto<$type, D> for $archived {}
     };
 }
 
@@ -354,6 +358,8 @@ impl<D: Fallible + ?Sized> Deserialize<usize, D> for ArchivedUsize {
     }
 }
 
+impl<D: Fallible + ?Sized> DeserializeInto<usize, D> for ArchivedUsize {}
+
 // isize
 
 impl Archive for isize {
@@ -385,6 +391,8 @@ impl<D: Fallible + ?Sized> Deserialize<isize, D> for ArchivedIsize {
     }
 }
 
+impl<D: Fallible + ?Sized> DeserializeInto<isize, D> for ArchivedIsize {}
+
 // NonZeroUsize
 
 impl Archive for NonZeroUsize {
@@ -457,6 +465,93 @@ where
     }
 }
 
+// Wrapping and Saturating
+
+// `Wrapping<T>` and `Saturating<T>` are archived as the archived `T` they wrap.
+// They're `#[repr(transparent)]`, so they can be copy optimized whenever `T`
+// can.
+
+macro_rules! impl_archive_transparent_wrapper {
+    ($wrapper:ident: $($archived:ident: $type:ty),* $(,)?) => {
+        impl<T: Archive> Archive for $wrapper<T> {
+            const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe {
+                CopyOptimization::enable_if(T::COPY_OPTIMIZATION.is_enabled())
+            };
+
+            type Archived = T::Archived;
+            type Resolver = T::Resolver;
+
+            #[inline]
+            fn resolve(
+                &self,
+                resolver: Self::Resolver,
+                out: Place<Self::Archived>,
+            ) {
+                self.0.resolve(resolver, out);
+            }
+        }
+
+        impl<T, S> Serialize<S> for $wrapper<T>
+        where
+            T: Serialize<S>,
+            S: Fallible + ?Sized,
+        {
+            fn serialize(
+                &self,
+                serializer: &mut S,
+            ) -> Result<Self::Resolver, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        $(
+            impl<D: Fallible + ?Sized> Deserialize<$wrapper<$type>, D>
+                for $archived
+            {
+                fn deserialize(
+                    &self,
+                    deserializer: &mut D,
+                ) -> Result<$wrapper<$type>, D::Error> {
+                    Deserialize::<$type, D>::deserialize(self, deserializer)
+                        .map($wrapper)
+                }
+            }
+        )*
+    };
+}
+
+impl_archive_transparent_wrapper! {
+    Wrapping:
+    i8: i8,
+    ArchivedI16: i16,
+    ArchivedI32: i32,
+    ArchivedI64: i64,
+    ArchivedI128: i128,
+    ArchivedIsize: isize,
+    u8: u8,
+    ArchivedU16: u16,
+    ArchivedU32: u32,
+    ArchivedU64: u64,
+    ArchivedU128: u128,
+    ArchivedUsize: usize,
+}
+
+impl_archive_transparent_wrapper! {
+    Saturating:
+    i8: i8,
+    ArchivedI16: i16,
+    ArchivedI32: i32,
+    ArchivedI64: i64,
+    ArchivedI128: i128,
+    ArchivedIsize: isize,
+    u8: u8,
+    ArchivedU16: u16,
+    ArchivedU32: u32,
+    ArchivedU64: u64,
+    ArchivedU128: u128,
+    ArchivedUsize: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use core::{
@@ -464,7 +559,7 @@ mod tests {
         num::{
             NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8,
             NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64,
-            NonZeroU8, NonZeroUsize,
+            NonZeroU8, NonZeroUsize, Saturating, Wrapping,
         },
     };
 
@@ -514,12 +609,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn roundtrip_wrapping_and_saturating() {
+        roundtrip_with(&Wrapping(200u8), |a, b| assert_eq!(a.0, *b));
+        roundtrip_with(&Wrapping(-1234567890i32), |a, b| {
+            assert_eq!(a.0, b.to_native())
+        });
+        roundtrip_with(&Wrapping(u64::MAX), |a, b| {
+            assert_eq!(a.0, b.to_native())
+        });
+
+        roundtrip_with(&Saturating(-100i8), |a, b| assert_eq!(a.0, *b));
+        roundtrip_with(&Saturating(i32::MIN), |a, b| {
+            assert_eq!(a.0, b.to_native())
+        });
+        roundtrip_with(&Saturating(12345678901234567890u64), |a, b| {
+            assert_eq!(a.0, b.to_native())
+        });
+    }
+
     #[test]
     fn roundtrip_phantoms() {
         roundtrip(&PhantomData::<&'static u8>);
         roundtrip(&PhantomPinned);
     }
 
+    #[test]
+    fn roundtrip_phantom_marker() {
+        use crate::{Archive, Deserialize, Serialize};
+
+        // Deliberately does not implement `Archive`.
+        #[derive(Debug, PartialEq)]
+        struct Marker;
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        struct Marked {
+            value: u32,
+            marker: PhantomData<Marker>,
+        }
+
+        roundtrip(&Marked {
+            value: 42,
+            marker: PhantomData,
+        });
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        struct Tagged<M> {
+            value: u32,
+            marker: PhantomData<M>,
+        }
+
+        roundtrip(&Tagged::<Marker> {
+            value: 42,
+            marker: PhantomData,
+        });
+    }
+
     #[test]
     fn roundtrip_sizes() {
         roundtrip_with(&12345isize, |a, b| {
@@ -100,11 +100,37 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::api::test::roundtrip;
+    use crate::{api::test::roundtrip, result::ArchivedResult};
 
     #[test]
     fn roundtrip_result() {
         roundtrip(&Result::<i32, u32>::Ok(12345i32));
         roundtrip(&Result::<i32, u32>::Err(12345u32));
     }
+
+    #[test]
+    fn archived_result_combinators() {
+        let ok = ArchivedResult::<i32, u32>::Ok(10);
+        assert!(ok.is_ok());
+        assert_eq!(ok.as_ref().ok(), Some(&10));
+        assert_eq!(ArchivedResult::<i32, u32>::Ok(10).ok(), Some(10));
+        assert_eq!(ArchivedResult::<i32, u32>::Ok(10).err(), None);
+        assert_eq!(ArchivedResult::<i32, u32>::Ok(10).map(|x| x * 2), Ok(20));
+        assert_eq!(
+            ArchivedResult::<i32, u32>::Ok(10).map_err(|e| e + 1),
+            Ok(10),
+        );
+        assert_eq!(ArchivedResult::<i32, u32>::Ok(10).unwrap_or(0), 10);
+
+        let err = ArchivedResult::<i32, u32>::Err(20);
+        assert!(err.is_err());
+        assert_eq!(ArchivedResult::<i32, u32>::Err(20).ok(), None);
+        assert_eq!(ArchivedResult::<i32, u32>::Err(20).err(), Some(20));
+        assert_eq!(ArchivedResult::<i32, u32>::Err(20).map(|x| x * 2), Err(20));
+        assert_eq!(
+            ArchivedResult::<i32, u32>::Err(20).map_err(|e| e + 1),
+            Err(21),
+        );
+        assert_eq!(ArchivedResult::<i32, u32>::Err(20).unwrap_or(0), 0);
+    }
 }
@@ -166,4 +166,65 @@ mod tests {
         };
         roundtrip(&value);
     }
+
+    #[cfg(target_has_atomic = "64")]
+    #[test]
+    fn with_atomic_load_widths() {
+        use core::sync::atomic::{
+            AtomicBool, AtomicI16, AtomicI8, AtomicU64, AtomicUsize, Ordering,
+        };
+
+        use crate::{
+            api::test::{deserialize, to_archived},
+            with::{Acquire, AtomicLoad, Relaxed, SeqCst},
+            Archive, Deserialize, Serialize,
+        };
+
+        #[derive(Archive, Deserialize, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        struct Counters {
+            #[with(AtomicLoad<Relaxed>)]
+            flag: AtomicBool,
+            #[with(AtomicLoad<Relaxed>)]
+            small: AtomicI8,
+            #[with(AtomicLoad<Acquire>)]
+            medium: AtomicI16,
+            #[with(AtomicLoad<SeqCst>)]
+            large: AtomicU64,
+            #[with(AtomicLoad<Relaxed>)]
+            size: AtomicUsize,
+        }
+
+        let value = Counters {
+            flag: AtomicBool::new(false),
+            small: AtomicI8::new(0),
+            medium: AtomicI16::new(0),
+            large: AtomicU64::new(0),
+            size: AtomicUsize::new(0),
+        };
+        // Values are loaded when serializing, not when the value is created.
+        value.flag.store(true, Ordering::Relaxed);
+        value.small.store(-8, Ordering::Relaxed);
+        value.medium.store(-1600, Ordering::Relaxed);
+        value.large.store(u64::MAX - 64, Ordering::Relaxed);
+        value.size.fetch_add(1234, Ordering::Relaxed);
+
+        to_archived(&value, |archived| {
+            assert!(archived.flag);
+            assert_eq!(archived.small, -8);
+            assert_eq!(archived.medium, -1600);
+            assert_eq!(archived.large, u64::MAX - 64);
+            assert_eq!(archived.size, 1234);
+
+            let deserialized = deserialize::<Counters>(&*archived);
+            assert!(deserialized.flag.load(Ordering::Relaxed));
+            assert_eq!(deserialized.small.load(Ordering::Relaxed), -8);
+            assert_eq!(deserialized.medium.load(Ordering::Relaxed), -1600);
+            assert_eq!(
+                deserialized.large.load(Ordering::Relaxed),
+                u64::MAX - 64
+            );
+            assert_eq!(deserialized.size.load(Ordering::Relaxed), 1234);
+        });
+    }
 }
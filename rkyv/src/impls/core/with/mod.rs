@@ -2,6 +2,7 @@ mod atomic;
 
 use core::{
     cell::{Cell, UnsafeCell},
+    hash::{Hash, Hasher},
     hint::unreachable_unchecked,
     num::{
         NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8,
@@ -11,26 +12,34 @@ use core::{
 };
 
 use munge::munge;
-use rancor::Fallible;
+use rancor::{Fallible, Source};
 
 use crate::{
     boxed::{ArchivedBox, BoxResolver},
-    niche::option_nonzero::{
-        ArchivedOptionNonZeroI128, ArchivedOptionNonZeroI16,
-        ArchivedOptionNonZeroI32, ArchivedOptionNonZeroI64,
-        ArchivedOptionNonZeroI8, ArchivedOptionNonZeroIsize,
-        ArchivedOptionNonZeroU128, ArchivedOptionNonZeroU16,
-        ArchivedOptionNonZeroU32, ArchivedOptionNonZeroU64,
-        ArchivedOptionNonZeroU8, ArchivedOptionNonZeroUsize,
+    niche::{
+        niched_option::NichedOption,
+        niching::{CheckNiche, Niching},
+        option_nonzero::{
+            ArchivedOptionNonZeroI128, ArchivedOptionNonZeroI16,
+            ArchivedOptionNonZeroI32, ArchivedOptionNonZeroI64,
+            ArchivedOptionNonZeroI8, ArchivedOptionNonZeroIsize,
+            ArchivedOptionNonZeroU128, ArchivedOptionNonZeroU16,
+            ArchivedOptionNonZeroU32, ArchivedOptionNonZeroU64,
+            ArchivedOptionNonZeroU8, ArchivedOptionNonZeroUsize,
+        },
     },
     option::ArchivedOption,
     place::Initialized,
     primitive::{FixedNonZeroIsize, FixedNonZeroUsize},
+    rc::{ArchivedRc, DedupFlavor, RcResolver},
+    ser::{Deduplication, Writer},
     with::{
-        ArchiveWith, AsBox, DeserializeWith, Inline, InlineAsBox, Map, Niche,
-        SerializeWith, Skip, Unsafe,
+        ArchiveWith, AsBox, AsBytes, Dedup, DeserializeWith, HashKeyWith,
+        Inline, InlineAsBox, Map, Niche, NicheInto, SerializeWith, Skip,
+        Unsafe,
     },
-    Archive, ArchiveUnsized, Deserialize, Place, Serialize, SerializeUnsized,
+    Archive, ArchiveUnsized, Deserialize, Place, Portable, Serialize,
+    SerializeUnsized,
 };
 
 // InlineAsBox
@@ -61,6 +70,13 @@ where
     }
 }
 
+// Archived boxes hash the same way as their contents
+impl<F: ArchiveUnsized + Hash + ?Sized> HashKeyWith<&F> for InlineAsBox {
+    fn hash_key_with<H: Hasher>(field: &&F, state: &mut H) {
+        field.hash(state);
+    }
+}
+
 // AsBox
 
 impl<F: ArchiveUnsized + ?Sized> ArchiveWith<F> for AsBox {
@@ -89,6 +105,12 @@ where
     }
 }
 
+impl<F: ArchiveUnsized + Hash + ?Sized> HashKeyWith<F> for AsBox {
+    fn hash_key_with<H: Hasher>(field: &F, state: &mut H) {
+        field.hash(state);
+    }
+}
+
 impl<F, D> DeserializeWith<ArchivedBox<F::Archived>, F, D> for AsBox
 where
     F: Archive,
@@ -103,6 +125,50 @@ where
     }
 }
 
+// Dedup
+
+impl<F: Archive> ArchiveWith<F> for Dedup {
+    type Archived = ArchivedRc<F::Archived, DedupFlavor>;
+    type Resolver = RcResolver;
+
+    fn resolve_with(
+        field: &F,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedRc::resolve_from_ref(field, resolver, out);
+    }
+}
+
+impl<F, S> SerializeWith<F, S> for Dedup
+where
+    F: Serialize<S> + Hash + Eq + Clone + Send + Sync + 'static,
+    S: Fallible + Writer + Deduplication + ?Sized,
+{
+    fn serialize_with(
+        field: &F,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedRc::<F::Archived, DedupFlavor>::serialize_deduplicated(
+            field, serializer,
+        )
+    }
+}
+
+impl<F, D> DeserializeWith<ArchivedRc<F::Archived, DedupFlavor>, F, D> for Dedup
+where
+    F: Archive,
+    F::Archived: Deserialize<F, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(
+        field: &ArchivedRc<F::Archived, DedupFlavor>,
+        deserializer: &mut D,
+    ) -> Result<F, D::Error> {
+        field.get().deserialize(deserializer)
+    }
+}
+
 // Map
 
 // Copy-paste from Option's impls for the most part
@@ -338,6 +404,62 @@ where
     }
 }
 
+// NicheInto
+
+impl<T, N> ArchiveWith<Option<T>> for NicheInto<N>
+where
+    T: Archive,
+    N: Niching<T::Archived>,
+{
+    type Archived = NichedOption<T::Archived, N>;
+    type Resolver = Option<T::Resolver>;
+
+    fn resolve_with(
+        field: &Option<T>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        NichedOption::resolve_from_option(field.as_ref(), resolver, out);
+    }
+}
+
+impl<T, N, S> SerializeWith<Option<T>, S> for NicheInto<N>
+where
+    T: Serialize<S>,
+    N: CheckNiche<T>,
+    S: Fallible + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &Option<T>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        NichedOption::<T::Archived, N>::serialize_from_option(
+            field.as_ref(),
+            serializer,
+        )
+    }
+}
+
+impl<T, N, D> DeserializeWith<NichedOption<T::Archived, N>, Option<T>, D>
+    for NicheInto<N>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    N: Niching<T::Archived>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(
+        field: &NichedOption<T::Archived, N>,
+        deserializer: &mut D,
+    ) -> Result<Option<T>, D::Error> {
+        field
+            .as_ref()
+            .map(|value| value.deserialize(deserializer))
+            .transpose()
+    }
+}
+
 // Inline
 
 impl<F: Archive> ArchiveWith<&F> for Inline {
@@ -362,6 +484,12 @@ impl<F: Serialize<S>, S: Fallible + ?Sized> SerializeWith<&F, S> for Inline {
     }
 }
 
+impl<F: Archive + Hash> HashKeyWith<&F> for Inline {
+    fn hash_key_with<H: Hasher>(field: &&F, state: &mut H) {
+        field.hash(state);
+    }
+}
+
 // Unsafe
 
 impl<F: Archive> ArchiveWith<UnsafeCell<F>> for Unsafe {
@@ -467,15 +595,46 @@ impl<F: Default, D: Fallible + ?Sized> DeserializeWith<(), F, D> for Skip {
     }
 }
 
+// AsBytes
+
+impl<F: Portable + Copy + Initialized> ArchiveWith<F> for AsBytes {
+    type Archived = F;
+    type Resolver = ();
+
+    fn resolve_with(field: &F, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(*field);
+    }
+}
+
+impl<F, S> SerializeWith<F, S> for AsBytes
+where
+    F: Portable + Copy + Initialized,
+    S: Fallible + ?Sized,
+{
+    fn serialize_with(_: &F, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<F, D> DeserializeWith<F, F, D> for AsBytes
+where
+    F: Portable + Copy + Initialized,
+    D: Fallible + ?Sized,
+{
+    fn deserialize_with(field: &F, _: &mut D) -> Result<F, D::Error> {
+        Ok(*field)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        api::test::{roundtrip, roundtrip_with, to_archived},
+        api::test::{deserialize, roundtrip, roundtrip_with, to_archived},
         rancor::Fallible,
         ser::Writer,
         with::{
-            ArchiveWith, AsBox, DeserializeWith, Inline, InlineAsBox, Niche,
-            SerializeWith, Unsafe,
+            ArchiveWith, AsBox, AsBytes, DeserializeWith, Inline, InlineAsBox,
+            Niche, NicheInto, SerializeWith, Skip, Unsafe,
         },
         Archive, Archived, Deserialize, Place, Serialize,
     };
@@ -636,6 +795,42 @@ mod tests {
         });
     }
 
+    #[test]
+    fn with_boxed_inline_enum() {
+        #[derive(Archive, Serialize, Deserialize)]
+        #[rkyv(crate, check_bytes)]
+        enum Test<'a> {
+            A {
+                #[with(InlineAsBox)]
+                value: &'a str,
+                other: i32,
+            },
+            B(#[with(InlineAsBox)] &'a str),
+        }
+
+        let value = Test::A {
+            value: "hello world",
+            other: 42,
+        };
+        to_archived(&value, |archived| {
+            if let ArchivedTest::A { value, other } = &*archived {
+                assert_eq!(value.as_ref(), "hello world");
+                assert_eq!(*other, 42);
+            } else {
+                panic!("expected variant A");
+            }
+        });
+
+        let value = Test::B("hello world");
+        to_archived(&value, |archived| {
+            if let ArchivedTest::B(value) = &*archived {
+                assert_eq!(value.as_ref(), "hello world");
+            } else {
+                panic!("expected variant B");
+            }
+        });
+    }
+
     #[test]
     fn with_niche_nonzero() {
         use core::{
@@ -719,6 +914,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_niche_into() {
+        use core::{
+            mem::size_of,
+            num::{NonZeroIsize, NonZeroU32},
+        };
+
+        use crate::niche::niching::{Bool, Zero};
+
+        #[derive(Archive, Debug, Deserialize, Serialize, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(NicheInto<Zero>)]
+            a: Option<NonZeroU32>,
+            #[with(NicheInto<Zero>)]
+            b: Option<NonZeroIsize>,
+            #[with(NicheInto<Bool>)]
+            c: Option<bool>,
+        }
+
+        assert_eq!(
+            size_of::<Archived<Test>>(),
+            size_of::<Archived<(NonZeroU32, NonZeroIsize, bool)>>(),
+        );
+
+        for value in [
+            Test {
+                a: NonZeroU32::new(10),
+                b: NonZeroIsize::new(-10),
+                c: Some(false),
+            },
+            Test {
+                a: None,
+                b: None,
+                c: None,
+            },
+        ] {
+            roundtrip_with(&value, |a, b| {
+                assert_eq!(b.a.as_ref().map(|x| x.get()), a.a.map(|x| x.get()));
+                assert_eq!(
+                    b.b.as_ref().map(|x| x.get() as isize),
+                    a.b.map(|x| x.get()),
+                );
+                assert_eq!(b.c, a.c);
+            });
+        }
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn with_niche_into_validation() {
+        use rancor::Failure;
+
+        use crate::{
+            api::low::access,
+            niche::{niched_option::NichedOption, niching::Bool},
+        };
+
+        type Niched = NichedOption<bool, Bool>;
+
+        assert_eq!(access::<Niched, Failure>(&[0]).unwrap(), &Some(false));
+        assert_eq!(access::<Niched, Failure>(&[1]).unwrap(), &Some(true));
+        assert!(access::<Niched, Failure>(&[2]).unwrap().is_none());
+        access::<Niched, Failure>(&[3]).unwrap_err();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn with_niche_into_valid_sentinel() {
+        use rancor::Failure;
+
+        use crate::niche::niching::{CheckNiche, Niching};
+
+        // A niching strategy whose sentinel is a valid `u8`.
+        struct Max;
+
+        unsafe impl Niching<u8> for Max {
+            unsafe fn is_niched(niched: *const u8) -> bool {
+                unsafe { *niched == u8::MAX }
+            }
+
+            fn resolve_niched(out: Place<u8>) {
+                out.write(u8::MAX);
+            }
+        }
+
+        impl CheckNiche<u8> for Max {
+            fn would_niche(value: &u8) -> bool {
+                *value == u8::MAX
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(NicheInto<Max>)]
+            value: Option<u8>,
+        }
+
+        crate::api::high::to_bytes::<Failure>(&Test {
+            value: Some(u8::MAX),
+        })
+        .unwrap_err();
+
+        for value in [Test { value: Some(1) }, Test { value: None }] {
+            roundtrip_with(&value, |a, b| assert_eq!(b.value, a.value));
+        }
+    }
+
     #[test]
     fn with_unsafe() {
         use core::cell::Cell;
@@ -741,4 +1045,85 @@ mod tests {
         };
         roundtrip(&value);
     }
+
+    #[test]
+    fn with_as_bytes() {
+        use crate::{place::Initialized, Portable};
+
+        #[derive(Clone, Copy, Debug, PartialEq, Portable)]
+        #[cfg_attr(feature = "bytecheck", derive(bytecheck::CheckBytes))]
+        #[rkyv(crate)]
+        #[repr(C)]
+        struct Pod {
+            a: Archived<u32>,
+            b: Archived<u16>,
+            c: Archived<u16>,
+        }
+
+        // SAFETY: `Pod` is made of archived integers and has no padding.
+        unsafe impl Initialized for Pod {}
+
+        #[derive(Archive, Debug, Deserialize, Serialize, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(AsBytes)]
+            pod: Pod,
+            value: u32,
+        }
+
+        impl PartialEq<Test> for ArchivedTest {
+            fn eq(&self, other: &Test) -> bool {
+                self.pod == other.pod && self.value == other.value
+            }
+        }
+
+        let value = Test {
+            pod: Pod {
+                a: Archived::<u32>::from_native(1),
+                b: Archived::<u16>::from_native(2),
+                c: Archived::<u16>::from_native(3),
+            },
+            value: 4,
+        };
+        roundtrip(&value);
+    }
+
+    #[test]
+    fn with_skip() {
+        #[derive(Archive, Debug, Deserialize, Serialize, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            a: u32,
+            #[with(Skip)]
+            cache: Option<u32>,
+            b: u32,
+        }
+
+        assert_eq!(
+            ::core::mem::size_of::<ArchivedTest>(),
+            2 * ::core::mem::size_of::<Archived<u32>>(),
+        );
+
+        let value = Test {
+            a: 1,
+            cache: Some(2),
+            b: 3,
+        };
+        to_archived(&value, |archived| {
+            assert_eq!(archived.a, 1);
+            // The skipped field is still present as a placeholder
+            let () = archived.cache;
+            assert_eq!(archived.b, 3);
+
+            let deserialized = deserialize::<Test>(&*archived);
+            assert_eq!(
+                deserialized,
+                Test {
+                    a: 1,
+                    cache: None,
+                    b: 3,
+                }
+            );
+        });
+    }
 }
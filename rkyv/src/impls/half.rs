@@ -0,0 +1,74 @@
+use half::{bf16, f16};
+use rancor::Fallible;
+
+use crate::{
+    primitive::{ArchivedBf16, ArchivedF16},
+    Archive, Deserialize, DeserializeInto, Place, Serialize,
+};
+
+macro_rules! impl_half_float {
+    ($archived:ident : $type:ty) => {
+        impl Archive for $type {
+            type Archived = $archived;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+                out.write(<$archived>::from_native(*self));
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $type {
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$type, D> for $archived {
+            fn deserialize(&self, _: &mut D) -> Result<$type, D::Error> {
+                Ok(self.to_native())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> DeserializeInto<$type, D> for $archived {}
+    };
+}
+
+impl_half_float!(ArchivedF16: f16);
+impl_half_float!(ArchivedBf16: bf16);
+
+#[cfg(test)]
+mod tests {
+    use half::{bf16, f16};
+
+    use crate::api::test::{deserialize, roundtrip, to_archived};
+
+    #[test]
+    fn roundtrip_half_floats() {
+        roundtrip(&f16::from_f32(1.5));
+        roundtrip(&f16::MIN_POSITIVE_SUBNORMAL);
+        roundtrip(&bf16::from_f32(-3.25));
+        roundtrip(&bf16::MAX);
+    }
+
+    #[test]
+    fn roundtrip_half_float_bits() {
+        // Infinities, signed zeros, and NaNs with different payloads
+        let f16_bits = [0x7c00, 0xfc00, 0x0000, 0x8000, 0x7e00, 0x7c01, 0xfe55];
+        for bits in f16_bits {
+            to_archived(&f16::from_bits(bits), |archived| {
+                assert_eq!(archived.to_bits(), bits);
+                assert_eq!(deserialize::<f16>(&*archived).to_bits(), bits);
+            });
+        }
+
+        let bf16_bits =
+            [0x7f80, 0xff80, 0x0000, 0x8000, 0x7fc0, 0x7f81, 0xffc5];
+        for bits in bf16_bits {
+            to_archived(&bf16::from_bits(bits), |archived| {
+                assert_eq!(archived.to_bits(), bits);
+                assert_eq!(deserialize::<bf16>(&*archived).to_bits(), bits);
+            });
+        }
+    }
+}
@@ -71,7 +71,9 @@ mod tests {
     use indexmap::IndexSet;
 
     use crate::{
-        alloc::string::String, api::test::roundtrip_with, hash::FxHasher64,
+        alloc::string::String,
+        api::test::{deserialize, roundtrip_with, to_archived},
+        hash::FxHasher64,
     };
 
     #[test]
@@ -91,4 +93,29 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn index_set_order() {
+        let mut value =
+            IndexSet::with_hasher(BuildHasherDefault::<FxHasher64>::default());
+        for tag in ["zeta", "alpha", "mu", "beta", "omega", "delta"] {
+            value.insert(String::from(tag));
+        }
+        value.swap_remove("mu");
+
+        roundtrip_with(&value, |a, b| {
+            assert!(b.iter().eq(a.iter()));
+            for (i, k) in a.iter().enumerate() {
+                assert_eq!(b.get_index_of(k.as_str()), Some(i));
+            }
+        });
+
+        // `IndexSet` equality ignores order, so check it explicitly.
+        to_archived(&value, |archived| {
+            let deserialized = deserialize::<
+                IndexSet<String, BuildHasherDefault<FxHasher64>>,
+            >(&*archived);
+            assert!(deserialized.iter().eq(value.iter()));
+        });
+    }
 }
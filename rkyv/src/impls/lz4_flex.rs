@@ -0,0 +1,85 @@
+use core::fmt;
+
+use lz4_flex::block;
+use rancor::{fail, Source};
+
+use crate::{
+    alloc::vec::Vec,
+    compression::{Codec, Lz4},
+};
+
+#[derive(Debug)]
+struct DecompressError;
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to decompress LZ4 block")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecompressError {}
+
+impl Codec for Lz4 {
+    const TAG: u8 = 1;
+
+    fn max_compressed_len(len: usize) -> usize {
+        block::get_maximum_output_size(len)
+    }
+
+    fn max_decompressed_len(compressed_len: usize) -> usize {
+        // Every byte of an LZ4 block decompresses to at most 255 bytes.
+        compressed_len.saturating_mul(255)
+    }
+
+    fn compress(input: &[u8], output: &mut Vec<u8>) {
+        output.extend_from_slice(&block::compress(input));
+    }
+
+    fn decompress<E: Source>(
+        input: &[u8],
+        len: usize,
+        output: &mut Vec<u8>,
+    ) -> Result<(), E> {
+        let start = output.len();
+        output.resize(start + len, 0);
+        match block::decompress_into(input, &mut output[start..]) {
+            Ok(written) => {
+                output.truncate(start + written);
+                Ok(())
+            }
+            Err(_) => fail!(DecompressError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        alloc::vec::Vec, api::test::roundtrip_with, compression::Lz4,
+        with::Compressed, Archive, Deserialize, Serialize,
+    };
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    #[rkyv(crate, check_bytes, derive(Debug))]
+    struct Test {
+        #[with(Compressed<Lz4>)]
+        payload: Vec<u8>,
+    }
+
+    #[test]
+    fn roundtrip_compressed_lz4() {
+        roundtrip_with(
+            &Test {
+                payload: Vec::new(),
+            },
+            |_, b| assert!(b.payload.is_empty()),
+        );
+
+        let payload = (0..4096).map(|i| (i % 16) as u8).collect::<Vec<_>>();
+        roundtrip_with(&Test { payload }, |a, b| {
+            assert_eq!(b.payload.len(), a.payload.len());
+            assert!(b.payload.compressed_bytes().len() < a.payload.len());
+        });
+    }
+}
@@ -17,12 +17,20 @@ mod std;
 mod arrayvec;
 #[cfg(feature = "bytes")]
 mod bytes;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "half")]
+mod half;
 #[cfg(feature = "hashbrown")]
 mod hashbrown;
 #[cfg(feature = "indexmap")]
 mod indexmap;
+#[cfg(feature = "lz4_flex")]
+mod lz4_flex;
 #[cfg(feature = "smallvec")]
 mod smallvec;
+#[cfg(feature = "smallvec2")]
+mod smallvec2;
 #[cfg(feature = "smol_str")]
 mod smolstr;
 #[cfg(feature = "thin-vec")]
@@ -41,7 +49,7 @@ mod core_tests {
     use rancor::{Fallible, Source};
 
     use crate::{
-        api::test::{roundtrip, to_archived},
+        api::test::{deserialize, roundtrip, to_archived, to_bytes},
         option::ArchivedOption,
         primitive::{ArchivedI32, ArchivedU32},
         Archive, Deserialize, Place, Portable, Serialize,
@@ -178,6 +186,72 @@ mod core_tests {
         roundtrip(&[Test::A, Test::B(42), Test::C { inner: 42 }]);
     }
 
+    #[test]
+    fn archived_enum_variant_info() {
+        use crate::traits::ArchivedEnum as _;
+
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        enum Shape {
+            Empty,
+            Circle(u32),
+            Rect { width: u32, height: u32 },
+        }
+
+        let rect = Shape::Rect {
+            width: 4,
+            height: 5,
+        };
+        for (value, name, discriminant) in [
+            (Shape::Empty, "Empty", 0),
+            (Shape::Circle(3), "Circle", 1),
+            (rect, "Rect", 2),
+        ] {
+            to_archived(&value, |archived| {
+                assert_eq!(archived.variant_name(), name);
+                assert_eq!(archived.discriminant(), discriminant);
+            });
+        }
+
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        enum Level {
+            Low = 10,
+            Medium,
+            High = 100,
+        }
+
+        for (value, name, discriminant) in [
+            (Level::Low, "Low", 10),
+            (Level::Medium, "Medium", 11),
+            (Level::High, "High", 100),
+        ] {
+            to_archived(&value, |archived| {
+                assert_eq!(archived.variant_name(), name);
+                assert_eq!(archived.discriminant(), discriminant);
+            });
+        }
+
+        #[derive(Archive, Serialize)]
+        #[rkyv(crate, check_bytes)]
+        #[repr(i8)]
+        enum Signed {
+            Negative = -2,
+            Zero = 0,
+        }
+
+        // Negative discriminants are sign-extended.
+        for (value, name, discriminant) in [
+            (Signed::Negative, "Negative", -2),
+            (Signed::Zero, "Zero", 0),
+        ] {
+            to_archived(&value, |archived| {
+                assert_eq!(archived.variant_name(), name);
+                assert_eq!(archived.discriminant(), discriminant);
+            });
+        }
+    }
+
     #[test]
     fn roundtrip_generic_enum() {
         use core::fmt;
@@ -393,6 +467,7 @@ mod core_tests {
             crate,
             archived = ATest,
             resolver = RTest,
+            resolver_builder,
             check_bytes,
             compare(PartialEq),
             derive(Debug),
@@ -410,10 +485,10 @@ mod core_tests {
             Option<u32>: Serialize<S>,
         {
             fn serialize(&self, serializer: &mut S) -> Result<RTest, S::Error> {
-                Ok(RTest {
-                    a: self.a.serialize(serializer)?,
-                    b: self.b.serialize(serializer)?,
-                })
+                Ok(RTest::builder()
+                    .a(self.a.serialize(serializer)?)
+                    .b(self.b.serialize(serializer)?)
+                    .build())
             }
         }
 
@@ -440,6 +515,123 @@ mod core_tests {
         roundtrip(&value);
     }
 
+    #[test]
+    fn derive_remote() {
+        // Stands in for a crate which doesn't derive `Archive`.
+        mod foreign {
+            #[derive(Debug, PartialEq)]
+            pub struct Point {
+                pub x: i32,
+                pub y: i32,
+            }
+
+            #[derive(Debug, PartialEq)]
+            pub struct Label {
+                id: u32,
+                visible: bool,
+            }
+
+            impl Label {
+                pub fn new(id: u32, visible: bool) -> Self {
+                    Self { id, visible }
+                }
+
+                pub fn id(&self) -> u32 {
+                    self.id
+                }
+
+                pub fn visible(&self) -> &bool {
+                    &self.visible
+                }
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize)]
+        #[rkyv(crate, remote = foreign::Point, check_bytes)]
+        struct PointDef {
+            x: i32,
+            y: i32,
+        }
+
+        impl From<PointDef> for foreign::Point {
+            fn from(value: PointDef) -> Self {
+                Self {
+                    x: value.x,
+                    y: value.y,
+                }
+            }
+        }
+
+        // Private fields are read with getters, which may return the field by
+        // value or by reference.
+        #[derive(Archive, Serialize, Deserialize)]
+        #[rkyv(crate, remote = foreign::Label, check_bytes)]
+        struct LabelDef {
+            #[rkyv(getter = foreign::Label::id)]
+            id: u32,
+            #[rkyv(getter = foreign::Label::visible)]
+            visible: bool,
+        }
+
+        impl From<LabelDef> for foreign::Label {
+            fn from(value: LabelDef) -> Self {
+                Self::new(value.id, value.visible)
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes)]
+        struct Shape {
+            #[with(PointDef)]
+            origin: foreign::Point,
+            #[with(LabelDef)]
+            label: foreign::Label,
+        }
+
+        let value = Shape {
+            origin: foreign::Point { x: 1, y: -2 },
+            label: foreign::Label::new(7, true),
+        };
+        to_archived(&value, |archived| {
+            assert_eq!(archived.origin.x, 1);
+            assert_eq!(archived.origin.y, -2);
+            assert_eq!(archived.label.id, 7);
+            assert!(archived.label.visible);
+            assert_eq!(deserialize::<Shape>(&*archived), value);
+        });
+    }
+
+    #[test]
+    fn resolver_builder_generic() {
+        #[derive(Archive)]
+        #[rkyv(crate, resolver_builder, check_bytes)]
+        struct Test<T> {
+            a: T,
+            b: [T; 2],
+        }
+
+        impl<T, S> Serialize<S> for Test<T>
+        where
+            T: Serialize<S>,
+            S: Fallible + ?Sized,
+        {
+            fn serialize(
+                &self,
+                serializer: &mut S,
+            ) -> Result<TestResolver<T>, S::Error> {
+                // Setters can be called in any order.
+                let b = self.b.serialize(serializer)?;
+                let a = self.a.serialize(serializer)?;
+                Ok(TestResolver::builder().b(b).a(a).build())
+            }
+        }
+
+        to_archived(&Test { a: 1u32, b: [2, 3] }, |archived| {
+            assert_eq!(archived.a, 1);
+            assert_eq!(archived.b, [2, 3]);
+        });
+    }
+
     #[test]
     fn compare() {
         #[derive(Archive, Serialize, Deserialize)]
@@ -647,6 +839,393 @@ mod core_tests {
         }
     }
 
+    #[test]
+    fn schema_hash() {
+        mod a {
+            use crate::Archive;
+
+            #[derive(Archive)]
+            #[rkyv(crate)]
+            pub struct Test {
+                pub a: u32,
+                pub b: i64,
+            }
+        }
+
+        mod b {
+            use crate::Archive;
+
+            #[derive(Archive)]
+            #[rkyv(crate)]
+            pub struct Test {
+                pub a: u32,
+                pub b: i64,
+            }
+        }
+
+        mod c {
+            use crate::Archive;
+
+            #[derive(Archive)]
+            #[rkyv(crate)]
+            pub struct Test {
+                pub a: u32,
+                pub b: u64,
+            }
+        }
+
+        mod d {
+            use crate::Archive;
+
+            #[derive(Archive)]
+            #[rkyv(crate)]
+            pub struct Test {
+                pub b: i64,
+                pub a: u32,
+            }
+        }
+
+        // Attributes which don't change the layout don't change the hash.
+        mod e {
+            use crate::Archive;
+
+            #[derive(Archive)]
+            #[rkyv(crate, derive(Debug), attr(allow(dead_code)))]
+            pub struct Test {
+                pub a: u32,
+                pub b: i64,
+            }
+        }
+
+        mod f {
+            use crate::Archive;
+
+            #[derive(Archive)]
+            #[rkyv(crate, attr(repr(align(16))))]
+            pub struct Test {
+                pub a: u32,
+                pub b: i64,
+            }
+        }
+
+        use crate::traits::SchemaHash;
+
+        assert_eq!(a::Test::SCHEMA_HASH, b::Test::SCHEMA_HASH);
+        assert_ne!(a::Test::SCHEMA_HASH, c::Test::SCHEMA_HASH);
+        assert_ne!(a::Test::SCHEMA_HASH, d::Test::SCHEMA_HASH);
+        assert_eq!(a::Test::SCHEMA_HASH, e::Test::SCHEMA_HASH);
+        assert_ne!(a::Test::SCHEMA_HASH, f::Test::SCHEMA_HASH);
+    }
+
+    #[test]
+    fn skip_fields() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        struct Test {
+            a: i32,
+            #[rkyv(skip)]
+            cache: Option<u64>,
+            b: i32,
+        }
+
+        assert_eq!(
+            ::core::mem::size_of::<ArchivedTest>(),
+            2 * ::core::mem::size_of::<ArchivedI32>(),
+        );
+
+        roundtrip(&Test {
+            a: 1,
+            cache: None,
+            b: 2,
+        });
+
+        let value = Test {
+            a: 1,
+            cache: Some(42),
+            b: 2,
+        };
+        to_archived(&value, |archived| {
+            assert_eq!(*archived, value);
+            let deserialized = deserialize::<Test>(&*archived);
+            assert_eq!(deserialized.cache, None);
+        });
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct TestTuple(i32, #[rkyv(skip, default = 7)] i32, i32);
+
+        to_archived(&TestTuple(1, 2, 3), |archived| {
+            assert_eq!(archived.0, 1);
+            assert_eq!(archived.1, 3);
+            assert_eq!(
+                deserialize::<TestTuple>(&*archived),
+                TestTuple(1, 7, 3)
+            );
+        });
+    }
+
+    #[test]
+    fn pack_bools() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(
+            crate,
+            pack_bools,
+            check_bytes,
+            compare(PartialEq),
+            derive(Debug)
+        )]
+        struct Flags {
+            f0: bool,
+            f1: bool,
+            f2: bool,
+            f3: bool,
+            f4: bool,
+            f5: bool,
+            f6: bool,
+            f7: bool,
+            f8: bool,
+            f9: bool,
+            f10: bool,
+            f11: bool,
+            f12: bool,
+            f13: bool,
+            f14: bool,
+            f15: bool,
+        }
+
+        assert_eq!(::core::mem::size_of::<ArchivedFlags>(), 2);
+
+        for bits in [0u16, 0xffff, 0b1010_0101_1100_0011, 1 << 8, 1 << 15] {
+            let bit = |i: u16| bits & (1 << i) != 0;
+            let value = Flags {
+                f0: bit(0),
+                f1: bit(1),
+                f2: bit(2),
+                f3: bit(3),
+                f4: bit(4),
+                f5: bit(5),
+                f6: bit(6),
+                f7: bit(7),
+                f8: bit(8),
+                f9: bit(9),
+                f10: bit(10),
+                f11: bit(11),
+                f12: bit(12),
+                f13: bit(13),
+                f14: bit(14),
+                f15: bit(15),
+            };
+            to_archived(&value, |archived| {
+                assert_eq!(archived.f0(), bit(0));
+                assert_eq!(archived.f7(), bit(7));
+                assert_eq!(archived.f8(), bit(8));
+                assert_eq!(archived.f15(), bit(15));
+            });
+            roundtrip(&value);
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(
+            crate,
+            pack_bools,
+            check_bytes,
+            compare(PartialEq),
+            derive(Debug)
+        )]
+        struct Mixed {
+            enabled: bool,
+            id: u32,
+            #[rkyv(skip)]
+            dirty: bool,
+            visible: bool,
+        }
+
+        let value = Mixed {
+            enabled: false,
+            id: 42,
+            dirty: true,
+            visible: true,
+        };
+        to_archived(&value, |archived| {
+            assert!(!archived.enabled());
+            assert_eq!(archived.id, 42);
+            assert!(archived.visible());
+            assert!(!deserialize::<Mixed>(&*archived).dirty);
+        });
+    }
+
+    #[test]
+    fn explicit_discriminants() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        #[repr(i8)]
+        enum Test {
+            A = -10,
+            B = 10,
+            C = 20,
+        }
+
+        assert_eq!(::core::mem::size_of::<ArchivedTest>(), 1);
+
+        for (value, tag) in [(Test::A, -10i8), (Test::B, 10), (Test::C, 20)] {
+            to_bytes(&value, |bytes| {
+                assert_eq!(bytes[bytes.len() - 1] as i8, tag);
+            });
+            roundtrip(&value);
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        #[repr(u8)]
+        enum TestFields {
+            A(u8) = 10,
+            B = 200,
+        }
+
+        to_bytes(&TestFields::A(42), |bytes| {
+            let root =
+                bytes.len() - ::core::mem::size_of::<ArchivedTestFields>();
+            assert_eq!(bytes[root], 10);
+            assert_eq!(bytes[root + 1], 42);
+        });
+        to_bytes(&TestFields::B, |bytes| {
+            let root =
+                bytes.len() - ::core::mem::size_of::<ArchivedTestFields>();
+            assert_eq!(bytes[root], 200);
+        });
+        roundtrip(&TestFields::A(42));
+        roundtrip(&TestFields::B);
+    }
+
+    #[cfg(not(feature = "unaligned"))]
+    #[test]
+    fn explicit_repr_tags() {
+        fn tag_bytes<const N: usize>(bytes: &[u8]) -> [u8; N] {
+            let root = bytes.len() - N;
+            bytes[root..].try_into().unwrap()
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq), derive(Debug))]
+        #[repr(u16)]
+        enum Unsigned {
+            A = 1000,
+            B,
+        }
+
+        assert_eq!(::core::mem::size_of::<ArchivedUnsigned>(), 2);
+
+        for (value, tag) in [(Unsigned::A, 1000u16), (Unsigned::B, 1001)] {
+            to_bytes(&value, |bytes| {
+                let expected = if cfg!(feature = "big_endian") {
+                    tag.to_be_bytes()
+                } else {
+                    tag.to_le_bytes()
+                };
+                assert_eq!(tag_bytes::<2>(bytes), expected);
+            });
+            roundtrip(&value);
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, compare(PartialEq, PartialOrd))]
+        #[rkyv(derive(Debug))]
+        #[repr(i32)]
+        enum Signed {
+            A = -100_000,
+            B = -1,
+            C = 7,
+        }
+
+        assert_eq!(::core::mem::size_of::<ArchivedSigned>(), 4);
+
+        for (value, tag) in
+            [(Signed::A, -100_000i32), (Signed::B, -1), (Signed::C, 7)]
+        {
+            to_bytes(&value, |bytes| {
+                let expected = if cfg!(feature = "big_endian") {
+                    tag.to_be_bytes()
+                } else {
+                    tag.to_le_bytes()
+                };
+                assert_eq!(tag_bytes::<4>(bytes), expected);
+            });
+            roundtrip(&value);
+        }
+
+        to_archived(&Signed::B, |archived| {
+            assert!(Signed::A < *archived);
+            assert!(Signed::C > *archived);
+        });
+    }
+
+    #[cfg(not(feature = "unaligned"))]
+    #[test]
+    fn wide_enum_tags() {
+        macro_rules! define_wide {
+            ($($variant:ident)*) => {
+                #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+                #[rkyv(crate, check_bytes, compare(PartialEq, PartialOrd))]
+                #[rkyv(derive(Debug))]
+                enum Wide {
+                    $($variant,)*
+                    Last(u32),
+                }
+            };
+        }
+
+        define_wide! {
+            V0 V1 V2 V3 V4 V5 V6 V7 V8 V9 V10 V11 V12 V13 V14 V15 V16 V17 V18
+            V19 V20 V21 V22 V23 V24 V25 V26 V27 V28 V29 V30 V31 V32 V33 V34 V35
+            V36 V37 V38 V39 V40 V41 V42 V43 V44 V45 V46 V47 V48 V49 V50 V51 V52
+            V53 V54 V55 V56 V57 V58 V59 V60 V61 V62 V63 V64 V65 V66 V67 V68 V69
+            V70 V71 V72 V73 V74 V75 V76 V77 V78 V79 V80 V81 V82 V83 V84 V85 V86
+            V87 V88 V89 V90 V91 V92 V93 V94 V95 V96 V97 V98 V99 V100 V101 V102
+            V103 V104 V105 V106 V107 V108 V109 V110 V111 V112 V113 V114 V115
+            V116 V117 V118 V119 V120 V121 V122 V123 V124 V125 V126 V127 V128
+            V129 V130 V131 V132 V133 V134 V135 V136 V137 V138 V139 V140 V141
+            V142 V143 V144 V145 V146 V147 V148 V149 V150 V151 V152 V153 V154
+            V155 V156 V157 V158 V159 V160 V161 V162 V163 V164 V165 V166 V167
+            V168 V169 V170 V171 V172 V173 V174 V175 V176 V177 V178 V179 V180
+            V181 V182 V183 V184 V185 V186 V187 V188 V189 V190 V191 V192 V193
+            V194 V195 V196 V197 V198 V199 V200 V201 V202 V203 V204 V205 V206
+            V207 V208 V209 V210 V211 V212 V213 V214 V215 V216 V217 V218 V219
+            V220 V221 V222 V223 V224 V225 V226 V227 V228 V229 V230 V231 V232
+            V233 V234 V235 V236 V237 V238 V239 V240 V241 V242 V243 V244 V245
+            V246 V247 V248 V249 V250 V251 V252 V253 V254 V255 V256 V257 V258
+            V259 V260 V261 V262 V263 V264 V265 V266 V267 V268 V269 V270 V271
+            V272 V273 V274 V275 V276 V277 V278 V279 V280 V281 V282 V283 V284
+            V285 V286 V287 V288 V289 V290 V291 V292 V293 V294 V295 V296 V297
+            V298 V299
+        }
+
+        assert_eq!(
+            ::core::mem::size_of::<ArchivedWide>(),
+            2 * ::core::mem::size_of::<ArchivedU32>(),
+        );
+
+        for (value, tag) in
+            [(Wide::V0, 0u16), (Wide::V255, 255), (Wide::V299, 299)]
+        {
+            to_bytes(&value, |bytes| {
+                let root = bytes.len() - ::core::mem::size_of::<ArchivedWide>();
+                let expected = if cfg!(feature = "big_endian") {
+                    tag.to_be_bytes()
+                } else {
+                    tag.to_le_bytes()
+                };
+                assert_eq!(bytes[root..root + 2], expected);
+            });
+            roundtrip(&value);
+        }
+        roundtrip(&Wide::Last(42));
+
+        to_archived(&Wide::V256, |archived| {
+            assert!(Wide::V255 < *archived);
+            assert!(Wide::Last(0) > *archived);
+        });
+    }
+
     #[test]
     fn pass_thru_derive_with_option() {
         #[derive(
@@ -687,10 +1266,10 @@ mod alloc_tests {
             vec,
             vec::Vec,
         },
-        api::test::{roundtrip, to_archived},
+        api::test::{deserialize_into, roundtrip, to_archived},
         primitive::ArchivedI32,
         ser::Writer,
-        Archive, Deserialize, Serialize,
+        Archive, Deserialize, DeserializeInto, Serialize,
     };
 
     #[test]
@@ -801,4 +1380,136 @@ mod alloc_tests {
             }),
         });
     }
+
+    #[test]
+    fn recursive_bound_shorthand() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(
+            crate,
+            check_bytes(bounds(__C: crate::validation::ArchiveContext)),
+            // Equivalent to the separate bounds of `recursive_self_types`
+            bound(
+                T::Archived: core::fmt::Debug,
+                __S: Writer,
+                __D::Error: Source,
+            ),
+            compare(PartialEq),
+            derive(Debug),
+        )]
+        pub enum LinkedList<T: Archive> {
+            Empty,
+            Node {
+                val: T,
+                #[omit_bounds]
+                next: Box<Self>,
+            },
+        }
+
+        roundtrip(&LinkedList::Node {
+            val: 42i32,
+            next: Box::new(LinkedList::Node {
+                val: 100i32,
+                next: Box::new(LinkedList::Empty),
+            }),
+        });
+    }
+
+    #[test]
+    fn derive_deserialize_into() {
+        #[derive(
+            Archive, Serialize, Deserialize, DeserializeInto, Debug, PartialEq,
+        )]
+        #[rkyv(crate, check_bytes)]
+        enum Kind {
+            A,
+            B(String),
+        }
+
+        #[derive(
+            Archive, Serialize, Deserialize, DeserializeInto, Debug, PartialEq,
+        )]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            id: u32,
+            name: String,
+            values: Vec<Vec<i32>>,
+            kind: Kind,
+            #[with(crate::with::AsBox)]
+            boxed: i32,
+        }
+
+        let mut place = Test {
+            id: 0,
+            name: String::with_capacity(64),
+            values: vec![Vec::with_capacity(64)],
+            kind: Kind::A,
+            boxed: 0,
+        };
+        let name_capacity = place.name.capacity();
+        let values_capacity = place.values[0].capacity();
+
+        let value = Test {
+            id: 42,
+            name: "hello world".to_string(),
+            values: vec![vec![1, 2, 3], vec![4, 5]],
+            kind: Kind::B("foo".to_string()),
+            boxed: 100,
+        };
+        to_archived(&value, |archived| {
+            deserialize_into(&*archived, &mut place);
+        });
+        assert_eq!(place, value);
+        assert_eq!(place.name.capacity(), name_capacity);
+        assert_eq!(place.values[0].capacity(), values_capacity);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[allow(clippy::box_collection)]
+    fn derive_deserialize_into_containers() {
+        use std::collections::HashMap;
+
+        use crate::alloc::collections::BTreeMap;
+
+        #[derive(
+            Archive, Serialize, Deserialize, DeserializeInto, Debug, PartialEq,
+        )]
+        #[rkyv(crate, check_bytes)]
+        struct Test {
+            boxed: Box<String>,
+            hash_map: HashMap<String, u32>,
+            btree_map: BTreeMap<u32, String>,
+            tuple: (u8, String, Vec<i32>),
+        }
+
+        let mut place = Test {
+            boxed: Box::new(String::with_capacity(64)),
+            hash_map: HashMap::with_capacity(64),
+            btree_map: BTreeMap::new(),
+            tuple: (0, String::new(), Vec::with_capacity(64)),
+        };
+        let boxed_ptr = &*place.boxed as *const String;
+        let boxed_capacity = place.boxed.capacity();
+        let hash_map_capacity = place.hash_map.capacity();
+        let tuple_capacity = place.tuple.2.capacity();
+
+        let value = Test {
+            boxed: Box::new("hello world".to_string()),
+            hash_map: [("a".to_string(), 1), ("b".to_string(), 2)]
+                .into_iter()
+                .collect(),
+            btree_map: [(1, "one".to_string()), (2, "two".to_string())]
+                .into_iter()
+                .collect(),
+            tuple: (42, "foo".to_string(), vec![1, 2, 3]),
+        };
+        to_archived(&value, |archived| {
+            deserialize_into(&*archived, &mut place);
+        });
+        assert_eq!(place, value);
+        assert_eq!(&*place.boxed as *const String, boxed_ptr);
+        assert_eq!(place.boxed.capacity(), boxed_capacity);
+        assert_eq!(place.hash_map.capacity(), hash_map_capacity);
+        assert_eq!(place.tuple.2.capacity(), tuple_capacity);
+    }
 }
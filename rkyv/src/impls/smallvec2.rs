@@ -0,0 +1,69 @@
+use rancor::Fallible;
+use smallvec2::SmallVec;
+
+use crate::{
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Archived, Deserialize, Place, Serialize,
+};
+
+impl<T: Archive, const N: usize> Archive for SmallVec<T, N> {
+    type Archived = ArchivedVec<Archived<T>>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self.as_slice(), resolver, out);
+    }
+}
+
+impl<T, S, const N: usize> Serialize<S> for SmallVec<T, N>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(
+        &self,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<T, D, const N: usize> Deserialize<SmallVec<T, N>, D>
+    for ArchivedVec<Archived<T>>
+where
+    T: Archive,
+    Archived<T>: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(
+        &self,
+        deserializer: &mut D,
+    ) -> Result<SmallVec<T, N>, D::Error> {
+        let mut result = SmallVec::new();
+        for item in self.as_slice() {
+            result.push(item.deserialize(deserializer)?);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smallvec2::SmallVec;
+
+    use crate::api::test::roundtrip_with;
+
+    #[test]
+    fn roundtrip_small_vec() {
+        let value: SmallVec<i32, 4> = SmallVec::from([10, 20, 40, 80]);
+        roundtrip_with(&value, |a, b| assert_eq!(**a, **b));
+    }
+
+    #[test]
+    fn roundtrip_spilled_small_vec() {
+        let value: SmallVec<i32, 2> = SmallVec::from([10, 20, 40, 80]);
+        assert!(value.spilled());
+        roundtrip_with(&value, |a, b| assert_eq!(**a, **b));
+    }
+}
@@ -9,7 +9,7 @@ use rancor::{Fallible, Source};
 use crate::{
     collections::swiss_table::map::{ArchivedHashMap, HashMapResolver},
     ser::{Allocator, Writer},
-    Archive, Deserialize, Place, Serialize,
+    Archive, Deserialize, DeserializeInto, Place, Serialize,
 };
 
 impl<K, V: Archive, S> Archive for HashMap<K, V, S>
@@ -71,6 +71,34 @@ where
     }
 }
 
+impl<K, V, D, S> DeserializeInto<HashMap<K, V, S>, D>
+    for ArchivedHashMap<K::Archived, V::Archived>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D> + Hash + Eq,
+    V: Archive,
+    V::Archived: Deserialize<V, D>,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut HashMap<K, V, S>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        // Clearing the map keeps its capacity and hasher.
+        place.clear();
+        place.reserve(self.len());
+        for (k, v) in self.iter() {
+            place.insert(
+                k.deserialize(deserializer)?,
+                v.deserialize(deserializer)?,
+            );
+        }
+        Ok(())
+    }
+}
+
 impl<K, V, AK, AV, S> PartialEq<HashMap<K, V, S>> for ArchivedHashMap<AK, AV>
 where
     K: Hash + Eq + Borrow<AK>,
@@ -89,11 +117,12 @@ where
     }
 }
 
-impl<K, V, AK, AV> PartialEq<ArchivedHashMap<AK, AV>> for HashMap<K, V>
+impl<K, V, AK, AV, S> PartialEq<ArchivedHashMap<AK, AV>> for HashMap<K, V, S>
 where
     K: Hash + Eq + Borrow<AK>,
     AK: Hash + Eq,
     AV: PartialEq<V>,
+    S: BuildHasher,
 {
     fn eq(&self, other: &ArchivedHashMap<AK, AV>) -> bool {
         other.eq(self)
@@ -102,13 +131,18 @@ where
 
 #[cfg(test)]
 mod tests {
-    use core::{fmt::Debug, hash::BuildHasher};
-    use std::collections::HashMap;
+    use core::{
+        fmt::Debug,
+        hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
+    };
+    use std::collections::{hash_map::DefaultHasher, HashMap};
 
     use ahash::RandomState;
 
     use crate::{
-        api::test::{roundtrip, roundtrip_with, to_archived},
+        api::test::{deserialize, roundtrip, roundtrip_with, to_archived},
+        hash::hash_unordered,
+        primitive::ArchivedI32,
         Archive, Archived, Deserialize, Serialize,
     };
 
@@ -195,6 +229,59 @@ mod tests {
         roundtrip_with(&hash_map, assert_equal);
     }
 
+    #[test]
+    fn deserialize_with_different_hasher() {
+        type CustomHasher = BuildHasherDefault<DefaultHasher>;
+
+        let mut hash_map = HashMap::new();
+        hash_map.insert("hello".to_string(), 1);
+        hash_map.insert("world".to_string(), 2);
+
+        to_archived(&hash_map, |archived| {
+            let deserialized: HashMap<String, i32, CustomHasher> =
+                deserialize(&*archived);
+            assert_eq!(deserialized.len(), 2);
+            assert_eq!(deserialized["hello"], 1);
+            assert_eq!(deserialized["world"], 2);
+
+            let deserialized: HashMap<String, i32, RandomState> =
+                deserialize(&*archived);
+            assert_eq!(deserialized["hello"], 1);
+        });
+    }
+
+    #[test]
+    fn hash_matches_native() {
+        fn hash_native(value: &HashMap<String, i32>) -> u64 {
+            let mut state = DefaultHasher::new();
+            hash_unordered(value.iter(), &mut state);
+            state.finish()
+        }
+
+        let mut hash_map = HashMap::new();
+        for i in 0..20 {
+            hash_map.insert(i.to_string(), i);
+        }
+        let mut reversed = HashMap::new();
+        for i in (0..20).rev() {
+            reversed.insert(i.to_string(), i);
+        }
+        assert_eq!(hash_native(&hash_map), hash_native(&reversed));
+
+        to_archived(&hash_map, |archived| {
+            let mut state = DefaultHasher::new();
+            archived.hash(&mut state);
+            assert_eq!(state.finish(), hash_native(&hash_map));
+        });
+
+        hash_map.insert("0".to_string(), 100);
+        to_archived(&hash_map, |archived| {
+            let mut state = DefaultHasher::new();
+            archived.hash(&mut state);
+            assert_ne!(state.finish(), hash_native(&reversed));
+        });
+    }
+
     #[test]
     fn get_with() {
         #[derive(Archive, Serialize, Deserialize, Eq, Hash, PartialEq)]
@@ -221,4 +308,109 @@ mod tests {
             assert_eq!(get_with.as_str(), "value");
         });
     }
+
+    #[test]
+    fn contains_key() {
+        let mut hash_map = HashMap::new();
+        for i in 0..100 {
+            hash_map.insert(i.to_string(), i);
+        }
+
+        to_archived(&hash_map, |archived| {
+            for i in 0..100 {
+                assert!(archived.contains_key(i.to_string().as_str()));
+            }
+            for i in 100..200 {
+                assert!(!archived.contains_key(i.to_string().as_str()));
+            }
+
+            assert!(archived.contains_key_with("42", |q, k| q == k.as_str()));
+            assert!(!archived.contains_key_with("42", |_, _| false));
+        });
+
+        to_archived(&HashMap::<String, i32>::new(), |archived| {
+            assert!(!archived.contains_key("0"));
+        });
+    }
+
+    #[test]
+    fn get_missing_keys() {
+        // Capacities that aren't a power of two must still probe every bucket
+        // before giving up on a missing key.
+        for len in 1..=100 {
+            let mut hash_map = HashMap::new();
+            for i in 0..len {
+                hash_map.insert(format!("dir/file{i}"), i);
+            }
+
+            to_archived(&hash_map, |archived| {
+                for i in 0..len {
+                    let key = format!("dir/file{i}");
+                    assert_eq!(*archived.get(key.as_str()).unwrap(), i);
+                }
+                for i in 0..100 {
+                    let key = format!("missing/{i}");
+                    assert!(archived.get(key.as_str()).is_none());
+                    assert!(!archived.contains_key(key.as_str()));
+                    assert!(archived
+                        .get_with(key.as_str(), |q, k| q == k.as_str())
+                        .is_none());
+                    assert!(!archived
+                        .contains_key_with(key.as_str(), |q, k| q
+                            == k.as_str()));
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn get_key_value() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert("hello".to_string(), 1);
+        hash_map.insert("world".to_string(), 2);
+
+        to_archived(&hash_map, |archived| {
+            let (key, value) = archived.get_key_value("hello").unwrap();
+            assert_eq!(key, "hello");
+            assert_eq!(*value, 1);
+
+            let (key, value) = archived.get_key_value("world").unwrap();
+            assert_eq!(key, "world");
+            assert_eq!(*value, 2);
+
+            assert!(archived.get_key_value("foo").is_none());
+        });
+    }
+
+    #[test]
+    fn keys_values_and_values_pin() {
+        let mut hash_map = HashMap::new();
+        hash_map.insert("a".to_string(), 1i32);
+        hash_map.insert("b".to_string(), 2);
+        hash_map.insert("c".to_string(), 3);
+
+        to_archived(&hash_map, |mut archived| {
+            assert_eq!(archived.keys().len(), 3);
+            let mut keys =
+                archived.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+            keys.sort();
+            assert_eq!(keys, ["a", "b", "c"]);
+
+            assert_eq!(archived.values().len(), 3);
+            let mut values =
+                archived.values().map(|v| v.to_native()).collect::<Vec<_>>();
+            values.sort();
+            assert_eq!(values, [1, 2, 3]);
+
+            let values_pin = archived.as_mut().values_pin();
+            assert_eq!(values_pin.len(), 3);
+            for mut value in values_pin {
+                *value = ArchivedI32::from_native(value.to_native() * 10);
+            }
+
+            for (key, value) in archived.iter() {
+                assert_eq!(value.to_native(), hash_map[key.as_str()] * 10);
+            }
+        });
+    }
 }
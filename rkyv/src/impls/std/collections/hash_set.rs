@@ -9,7 +9,7 @@ use rancor::{Fallible, Source};
 use crate::{
     collections::swiss_table::set::{ArchivedHashSet, HashSetResolver},
     ser::{Allocator, Writer},
-    Archive, Deserialize, Place, Serialize,
+    Archive, Deserialize, DeserializeInto, Place, Serialize,
 };
 
 impl<K, S> Archive for HashSet<K, S>
@@ -68,6 +68,28 @@ where
     }
 }
 
+impl<K, D, S> DeserializeInto<HashSet<K, S>, D> for ArchivedHashSet<K::Archived>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D> + Hash + Eq,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_into(
+        &self,
+        place: &mut HashSet<K, S>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        // Clearing the set keeps its capacity and hasher.
+        place.clear();
+        place.reserve(self.len());
+        for k in self.iter() {
+            place.insert(k.deserialize(deserializer)?);
+        }
+        Ok(())
+    }
+}
+
 impl<K: Hash + Eq + Borrow<AK>, AK: Hash + Eq, S: BuildHasher>
     PartialEq<HashSet<K, S>> for ArchivedHashSet<AK>
 {
@@ -90,9 +112,13 @@ impl<K: Hash + Eq + Borrow<AK>, AK: Hash + Eq, S: BuildHasher>
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use core::hash::{Hash, Hasher};
+    use std::collections::{hash_map::DefaultHasher, HashSet};
 
-    use crate::api::test::{roundtrip, roundtrip_with};
+    use crate::{
+        api::test::{roundtrip, roundtrip_with, to_archived},
+        hash::hash_unordered,
+    };
 
     #[test]
     fn roundtrip_hash_set() {
@@ -116,6 +142,19 @@ mod tests {
         });
     }
 
+    #[test]
+    fn hash_matches_native() {
+        let hash_set = (0..20).map(|i| i.to_string()).collect::<HashSet<_>>();
+        let mut native_state = DefaultHasher::new();
+        hash_unordered(hash_set.iter(), &mut native_state);
+
+        to_archived(&hash_set, |archived| {
+            let mut state = DefaultHasher::new();
+            archived.hash(&mut state);
+            assert_eq!(state.finish(), native_state.finish());
+        });
+    }
+
     #[test]
     fn roundtrip_hash_set_zst() {
         let mut value = HashSet::new();
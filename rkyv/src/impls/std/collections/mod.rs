@@ -1,3 +1,2 @@
 mod hash_map;
 mod hash_set;
-mod vec_deque;
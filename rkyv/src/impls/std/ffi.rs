@@ -151,9 +151,9 @@ impl PartialEq<ArchivedCString> for CString {
 
 #[cfg(test)]
 mod tests {
-    use std::ffi::CString;
+    use std::ffi::{CStr, CString};
 
-    use crate::api::test::roundtrip;
+    use crate::api::test::{roundtrip, to_archived};
 
     #[test]
     fn roundtrip_c_string() {
@@ -162,4 +162,46 @@ mod tests {
         };
         roundtrip(&value);
     }
+
+    #[test]
+    fn archived_c_string_accessors() {
+        let value = CString::new("hello world").unwrap();
+        to_archived(&value, |archived| {
+            assert_eq!(archived.to_bytes(), b"hello world");
+            assert_eq!(archived.to_bytes_with_nul(), b"hello world\0");
+            assert_eq!(archived.to_str(), Ok("hello world"));
+
+            let c_str = unsafe { CStr::from_ptr(archived.as_ptr()) };
+            assert_eq!(c_str, value.as_c_str());
+        });
+
+        let value = CString::new(b"\xff\xfe".to_vec()).unwrap();
+        to_archived(&value, |archived| {
+            archived.to_str().unwrap_err();
+        });
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn unterminated_c_string() {
+        use rancor::Failure;
+
+        use crate::{
+            api::{high::access, test::to_bytes},
+            ffi::ArchivedCString,
+        };
+
+        let value = CString::new("hello world").unwrap();
+        to_bytes(&value, |bytes| {
+            assert!(access::<ArchivedCString, Failure>(bytes).is_ok());
+
+            // Overwrite the nul terminator
+            let pos = bytes
+                .windows(12)
+                .position(|window| window == b"hello world\0")
+                .unwrap();
+            bytes[pos + 11] = b'!';
+            assert!(access::<ArchivedCString, Failure>(bytes).is_err());
+        });
+    }
 }
@@ -1,4 +1,6 @@
 mod collections;
 mod ffi;
 mod net;
+mod path;
+mod time;
 mod with;
@@ -0,0 +1,242 @@
+#[cfg(not(unix))]
+use std::fmt;
+#[cfg(windows)]
+use std::vec::Vec;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+#[cfg(not(unix))]
+use rancor::fail;
+use rancor::{Fallible, Source};
+
+use crate::{
+    ser::Writer,
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Serialize,
+};
+
+// Paths are archived as the bytes of their OS string. On Unix, these are the
+// raw bytes of the path. On Windows, paths are sequences of possibly
+// ill-formed UTF-16 and are archived as WTF-8. On all other platforms, paths
+// must be valid UTF-8.
+
+#[cfg(not(unix))]
+#[derive(Debug)]
+struct InvalidPathEncoding;
+
+#[cfg(not(unix))]
+impl fmt::Display for InvalidPathEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "archived path bytes are not valid on this platform")
+    }
+}
+
+#[cfg(not(unix))]
+impl std::error::Error for InvalidPathEncoding {}
+
+#[cfg(unix)]
+fn encode_path<E>(path: &Path) -> Result<Cow<'_, [u8]>, E> {
+    use std::os::unix::ffi::OsStrExt;
+
+    Ok(Cow::Borrowed(path.as_os_str().as_bytes()))
+}
+
+#[cfg(unix)]
+fn encoded_len(path: &Path) -> usize {
+    use std::os::unix::ffi::OsStrExt;
+
+    path.as_os_str().as_bytes().len()
+}
+
+#[cfg(unix)]
+fn decode_path<E>(bytes: &[u8]) -> Result<PathBuf, E> {
+    use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+    Ok(PathBuf::from(OsString::from_vec(bytes.to_vec())))
+}
+
+#[cfg(windows)]
+fn encode_path<E>(path: &Path) -> Result<Cow<'_, [u8]>, E> {
+    use std::os::windows::ffi::OsStrExt;
+
+    let mut bytes = Vec::with_capacity(encoded_len(path));
+    for c in char::decode_utf16(path.as_os_str().encode_wide()) {
+        match c {
+            Ok(c) => {
+                bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes())
+            }
+            // Unpaired surrogates are encoded as if they were code points.
+            Err(e) => {
+                let s = e.unpaired_surrogate();
+                bytes.extend_from_slice(&[
+                    0xe0 | (s >> 12) as u8,
+                    0x80 | ((s >> 6) & 0x3f) as u8,
+                    0x80 | (s & 0x3f) as u8,
+                ]);
+            }
+        }
+    }
+    Ok(Cow::Owned(bytes))
+}
+
+#[cfg(windows)]
+fn encoded_len(path: &Path) -> usize {
+    use std::os::windows::ffi::OsStrExt;
+
+    char::decode_utf16(path.as_os_str().encode_wide())
+        .map(|c| c.map_or(3, char::len_utf8))
+        .sum()
+}
+
+#[cfg(windows)]
+fn decode_path<E: Source>(mut bytes: &[u8]) -> Result<PathBuf, E> {
+    use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+
+    let mut wide = Vec::with_capacity(bytes.len());
+    loop {
+        match core::str::from_utf8(bytes) {
+            Ok(s) => {
+                wide.extend(s.encode_utf16());
+                return Ok(PathBuf::from(OsString::from_wide(&wide)));
+            }
+            Err(e) => {
+                let (valid, invalid) = bytes.split_at(e.valid_up_to());
+                // SAFETY: `valid_up_to` returns the length of the prefix of
+                // `bytes` which is valid UTF-8.
+                let valid = unsafe { core::str::from_utf8_unchecked(valid) };
+                wide.extend(valid.encode_utf16());
+
+                // The only sequences which are valid WTF-8 but not valid UTF-8
+                // are encoded surrogates.
+                match *invalid {
+                    [0xed, b1 @ 0xa0..=0xbf, b2 @ 0x80..=0xbf, ..] => {
+                        wide.push(
+                            0xd000
+                                | ((b1 as u16 & 0x3f) << 6)
+                                | (b2 as u16 & 0x3f),
+                        );
+                        bytes = &invalid[3..];
+                    }
+                    _ => fail!(InvalidPathEncoding),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+#[derive(Debug)]
+struct NonUtf8Path;
+
+#[cfg(not(any(unix, windows)))]
+impl fmt::Display for NonUtf8Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "path is not valid UTF-8")
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+impl std::error::Error for NonUtf8Path {}
+
+#[cfg(not(any(unix, windows)))]
+fn encode_path<E: Source>(path: &Path) -> Result<Cow<'_, [u8]>, E> {
+    match path.to_str() {
+        Some(s) => Ok(Cow::Borrowed(s.as_bytes())),
+        None => fail!(NonUtf8Path),
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn encoded_len(path: &Path) -> usize {
+    // It's safe to unwrap here because if the path wasn't valid UTF-8 it would
+    // have failed to serialize
+    path.to_str().unwrap().len()
+}
+
+#[cfg(not(any(unix, windows)))]
+fn decode_path<E: Source>(bytes: &[u8]) -> Result<PathBuf, E> {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => Ok(PathBuf::from(s)),
+        Err(_) => fail!(InvalidPathEncoding),
+    }
+}
+
+// PathBuf
+
+impl Archive for PathBuf {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(encoded_len(self), resolver, out);
+    }
+}
+
+impl<S> Serialize<S> for PathBuf
+where
+    S: Fallible + Writer + ?Sized,
+    S::Error: Source,
+{
+    fn serialize(
+        &self,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        let bytes = encode_path::<S::Error>(self)?;
+        let pos = serializer.pos();
+        serializer.write(&bytes)?;
+        Ok(VecResolver::from_pos(pos))
+    }
+}
+
+impl<D> Deserialize<PathBuf, D> for ArchivedVec<u8>
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<PathBuf, D::Error> {
+        decode_path(self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::api::test::roundtrip_with;
+
+    #[test]
+    fn roundtrip_path_buf() {
+        roundtrip_with(&PathBuf::from("/usr/lib/rkyv"), |a, b| {
+            assert_eq!(b.as_slice(), a.to_str().unwrap().as_bytes());
+        });
+        roundtrip_with(&PathBuf::new(), |_, b| assert!(b.is_empty()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn roundtrip_non_utf8_path() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let bytes = b"/tmp/\xff\xfe.bin";
+        let value = PathBuf::from(OsStr::from_bytes(bytes));
+        assert!(value.to_str().is_none());
+
+        roundtrip_with(&value, |_, b| assert_eq!(b.as_slice(), bytes));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn roundtrip_unpaired_surrogate_path() {
+        use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+
+        let value = PathBuf::from(OsString::from_wide(&[0x61, 0xd800, 0x62]));
+        assert!(value.to_str().is_none());
+
+        roundtrip_with(&value, |_, b| {
+            assert_eq!(b.as_slice(), &[0x61, 0xed, 0xa0, 0x80, 0x62]);
+        });
+    }
+}
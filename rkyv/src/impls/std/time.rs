@@ -0,0 +1,130 @@
+use core::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rancor::{Fallible, OptionExt as _, Source};
+
+use crate::{time::ArchivedSystemTime, Archive, Deserialize, Place, Serialize};
+
+#[derive(Debug)]
+struct SystemTimeOverflow;
+
+impl fmt::Display for SystemTimeOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "archived system time cannot be represented on this platform"
+        )
+    }
+}
+
+impl std::error::Error for SystemTimeOverflow {}
+
+impl Archive for SystemTime {
+    type Archived = ArchivedSystemTime;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        let (duration, before_epoch) = match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (duration, false),
+            Err(error) => (error.duration(), true),
+        };
+        unsafe {
+            ArchivedSystemTime::emplace(
+                duration.as_secs(),
+                duration.subsec_nanos(),
+                before_epoch,
+                out.ptr(),
+            );
+        }
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for SystemTime {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D> Deserialize<SystemTime, D> for ArchivedSystemTime
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<SystemTime, D::Error> {
+        let distance = (*self.distance_from_epoch()).into();
+        if self.is_before_epoch() {
+            UNIX_EPOCH.checked_sub(distance)
+        } else {
+            UNIX_EPOCH.checked_add(distance)
+        }
+        .into_trace(SystemTimeOverflow)
+    }
+}
+
+impl PartialEq<SystemTime> for ArchivedSystemTime {
+    #[inline]
+    fn eq(&self, other: &SystemTime) -> bool {
+        match other.duration_since(UNIX_EPOCH) {
+            Ok(duration) => {
+                !self.is_before_epoch()
+                    && *self.distance_from_epoch() == duration
+            }
+            Err(error) => {
+                self.is_before_epoch()
+                    && *self.distance_from_epoch() == error.duration()
+            }
+        }
+    }
+}
+
+impl PartialEq<ArchivedSystemTime> for SystemTime {
+    #[inline]
+    fn eq(&self, other: &ArchivedSystemTime) -> bool {
+        other.eq(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::api::test::roundtrip;
+
+    #[test]
+    fn roundtrip_system_time() {
+        roundtrip(&UNIX_EPOCH);
+        roundtrip(&(UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789)));
+        roundtrip(&SystemTime::now());
+    }
+
+    #[test]
+    fn roundtrip_system_time_before_epoch() {
+        roundtrip(&(UNIX_EPOCH - Duration::new(1, 0)));
+        roundtrip(&(UNIX_EPOCH - Duration::new(0, 1)));
+        // 1901-12-13
+        roundtrip(&(UNIX_EPOCH - Duration::new(2_147_483_648, 500_000_000)));
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn invalid_system_time() {
+        use core::mem::size_of;
+
+        use rancor::Failure;
+
+        use crate::{
+            api::{high::access, test::to_bytes},
+            time::{ArchivedDuration, ArchivedSystemTime},
+        };
+
+        to_bytes(&UNIX_EPOCH, |bytes| {
+            assert!(access::<ArchivedSystemTime, Failure>(bytes).is_ok());
+
+            // Mark the UNIX epoch as being before the epoch
+            let root = bytes.len() - size_of::<ArchivedSystemTime>();
+            bytes[root + size_of::<ArchivedDuration>()] = 1;
+            assert!(access::<ArchivedSystemTime, Failure>(bytes).is_err());
+        });
+    }
+}
@@ -1,9 +1,9 @@
-use core::fmt;
+use core::{fmt, marker::PhantomData};
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
     ffi::{CStr, OsString},
-    hash::Hash,
+    hash::{BuildHasher, Hash, Hasher},
     path::{Path, PathBuf},
     str::FromStr,
     sync::{Mutex, RwLock},
@@ -13,15 +13,24 @@ use std::{
 use rancor::{Fallible, OptionExt, ResultExt, Source};
 
 use crate::{
-    collections::util::{Entry, EntryAdapter},
+    collections::{
+        swiss_table::{
+            map::{ArchivedHashMap, HashMapResolver},
+            set::{ArchivedHashSet, HashSetResolver},
+            ArchivedIndexMap, IndexMapResolver,
+        },
+        util::{Entry, EntryAdapter},
+    },
     ffi::{ArchivedCString, CStringResolver},
+    impls::alloc::with::RefWrapper,
     ser::{Allocator, Writer},
     string::{ArchivedString, StringResolver},
     time::ArchivedDuration,
     vec::{ArchivedVec, VecResolver},
     with::{
         ArchiveWith, AsOwned, AsString, AsUnixTime, AsVec, DeserializeWith,
-        Lock, SerializeWith, Unsafe,
+        HashKeyWith, HashWith, Lock, Map, MapKV, OrderedMap, SerializeWith,
+        Unsafe,
     },
     Archive, Deserialize, Place, Serialize, SerializeUnsized,
 };
@@ -89,6 +98,16 @@ where
     }
 }
 
+impl HashKeyWith<OsString> for AsString {
+    fn hash_key_with<H: Hasher>(field: &OsString, state: &mut H) {
+        // An OsString which isn't valid UTF-8 fails to serialize, so it
+        // doesn't matter how it's hashed
+        if let Some(s) = field.to_str() {
+            s.hash(state);
+        }
+    }
+}
+
 impl ArchiveWith<PathBuf> for AsString {
     type Archived = ArchivedString;
     type Resolver = StringResolver;
@@ -138,6 +157,16 @@ where
     }
 }
 
+impl HashKeyWith<PathBuf> for AsString {
+    fn hash_key_with<H: Hasher>(field: &PathBuf, state: &mut H) {
+        // A PathBuf which isn't valid UTF-8 fails to serialize, so it doesn't
+        // matter how it's hashed
+        if let Some(s) = field.to_str() {
+            s.hash(state);
+        }
+    }
+}
+
 // Lock
 
 #[derive(Debug)]
@@ -369,6 +398,420 @@ where
     }
 }
 
+// Map
+
+impl<A, K, V, S> ArchiveWith<HashMap<K, V, S>> for Map<A>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Hash + Eq,
+    A: ArchiveWith<V>,
+{
+    type Archived =
+        ArchivedHashMap<K::Archived, <A as ArchiveWith<V>>::Archived>;
+    type Resolver = HashMapResolver;
+
+    fn resolve_with(
+        field: &HashMap<K, V, S>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedHashMap::resolve_from_len(field.len(), (7, 8), resolver, out);
+    }
+}
+
+impl<A, K, V, S, RS> SerializeWith<HashMap<K, V, RS>, S> for Map<A>
+where
+    K: Serialize<S> + Hash + Eq,
+    K::Archived: Hash + Eq,
+    A: ArchiveWith<V> + SerializeWith<V, S>,
+    S: Fallible + Writer + Allocator + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &HashMap<K, V, RS>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        // The keys are unchanged, so they still hash to the same values.
+        ArchivedHashMap::<
+            K::Archived,
+            <A as ArchiveWith<V>>::Archived,
+        >::serialize_from_iter(
+            field
+                .iter()
+                .map(|(key, value)| (key, RefWrapper::<A, V>::new(value))),
+            (7, 8),
+            serializer,
+        )
+    }
+}
+
+impl<A, K, V, D, S>
+    DeserializeWith<
+        ArchivedHashMap<K::Archived, <A as ArchiveWith<V>>::Archived>,
+        HashMap<K, V, S>,
+        D,
+    > for Map<A>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D> + Hash + Eq,
+    A: ArchiveWith<V> + DeserializeWith<<A as ArchiveWith<V>>::Archived, V, D>,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_with(
+        field: &ArchivedHashMap<K::Archived, <A as ArchiveWith<V>>::Archived>,
+        deserializer: &mut D,
+    ) -> Result<HashMap<K, V, S>, D::Error> {
+        let mut result =
+            HashMap::with_capacity_and_hasher(field.len(), S::default());
+        for (k, v) in field.iter() {
+            result.insert(
+                k.deserialize(deserializer)?,
+                A::deserialize_with(v, deserializer)?,
+            );
+        }
+        Ok(result)
+    }
+}
+
+// MapKV
+
+// Wrapper for map keys which hashes them the same way as their archived form.
+#[repr(transparent)]
+struct KeyWrapper<A, O>(PhantomData<A>, O);
+
+impl<A, O> KeyWrapper<A, O> {
+    fn new(value: &O) -> &Self {
+        // SAFETY: `KeyWrapper` is `repr(transparent)` over `O`, so it has the
+        // same layout as `O`.
+        unsafe { &*(value as *const O).cast::<Self>() }
+    }
+}
+
+impl<A: ArchiveWith<O>, O> Archive for KeyWrapper<A, O> {
+    type Archived = <A as ArchiveWith<O>>::Archived;
+    type Resolver = <A as ArchiveWith<O>>::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        A::resolve_with(&self.1, resolver, out)
+    }
+}
+
+impl<A, O, S> Serialize<S> for KeyWrapper<A, O>
+where
+    A: ArchiveWith<O> + SerializeWith<O, S>,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
+        A::serialize_with(&self.1, s)
+    }
+}
+
+impl<A: HashKeyWith<O>, O> Hash for KeyWrapper<A, O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        A::hash_key_with(&self.1, state)
+    }
+}
+
+impl<A, O: PartialEq> PartialEq for KeyWrapper<A, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<A, O: Eq> Eq for KeyWrapper<A, O> {}
+
+impl<AK, AV, K, V, S> ArchiveWith<HashMap<K, V, S>> for MapKV<AK, AV>
+where
+    AK: ArchiveWith<K>,
+    <AK as ArchiveWith<K>>::Archived: Hash + Eq,
+    AV: ArchiveWith<V>,
+{
+    type Archived = ArchivedHashMap<
+        <AK as ArchiveWith<K>>::Archived,
+        <AV as ArchiveWith<V>>::Archived,
+    >;
+    type Resolver = HashMapResolver;
+
+    fn resolve_with(
+        field: &HashMap<K, V, S>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedHashMap::resolve_from_len(field.len(), (7, 8), resolver, out);
+    }
+}
+
+impl<AK, AV, K, V, S, RS> SerializeWith<HashMap<K, V, RS>, S> for MapKV<AK, AV>
+where
+    K: Eq,
+    AK: HashKeyWith<K> + SerializeWith<K, S>,
+    <AK as ArchiveWith<K>>::Archived: Hash + Eq,
+    AV: ArchiveWith<V> + SerializeWith<V, S>,
+    S: Fallible + Writer + Allocator + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &HashMap<K, V, RS>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedHashMap::<
+            <AK as ArchiveWith<K>>::Archived,
+            <AV as ArchiveWith<V>>::Archived,
+        >::serialize_from_iter(
+            field.iter().map(|(key, value)| {
+                (
+                    KeyWrapper::<AK, K>::new(key),
+                    RefWrapper::<AV, V>::new(value),
+                )
+            }),
+            (7, 8),
+            serializer,
+        )
+    }
+}
+
+impl<AK, AV, K, V, D, S>
+    DeserializeWith<
+        ArchivedHashMap<
+            <AK as ArchiveWith<K>>::Archived,
+            <AV as ArchiveWith<V>>::Archived,
+        >,
+        HashMap<K, V, S>,
+        D,
+    > for MapKV<AK, AV>
+where
+    K: Hash + Eq,
+    AK: ArchiveWith<K>
+        + DeserializeWith<<AK as ArchiveWith<K>>::Archived, K, D>,
+    <AK as ArchiveWith<K>>::Archived: Hash + Eq,
+    AV: ArchiveWith<V>
+        + DeserializeWith<<AV as ArchiveWith<V>>::Archived, V, D>,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_with(
+        field: &ArchivedHashMap<
+            <AK as ArchiveWith<K>>::Archived,
+            <AV as ArchiveWith<V>>::Archived,
+        >,
+        deserializer: &mut D,
+    ) -> Result<HashMap<K, V, S>, D::Error> {
+        let mut result =
+            HashMap::with_capacity_and_hasher(field.len(), S::default());
+        for (k, v) in field.iter() {
+            result.insert(
+                AK::deserialize_with(k, deserializer)?,
+                AV::deserialize_with(v, deserializer)?,
+            );
+        }
+        Ok(result)
+    }
+}
+
+// HashWith
+
+impl<K, V, S, H> ArchiveWith<HashMap<K, V, S>> for HashWith<H>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Hash + Eq,
+    V: Archive,
+    H: Hasher + Default,
+{
+    type Archived = ArchivedHashMap<K::Archived, V::Archived, H>;
+    type Resolver = HashMapResolver;
+
+    fn resolve_with(
+        field: &HashMap<K, V, S>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedHashMap::resolve_from_len(field.len(), (7, 8), resolver, out);
+    }
+}
+
+impl<K, V, S, H, RS> SerializeWith<HashMap<K, V, RS>, S> for HashWith<H>
+where
+    K: Serialize<S> + Hash + Eq,
+    K::Archived: Hash + Eq,
+    V: Serialize<S>,
+    S: Fallible + Writer + Allocator + ?Sized,
+    S::Error: Source,
+    H: Hasher + Default,
+{
+    fn serialize_with(
+        field: &HashMap<K, V, RS>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedHashMap::<K::Archived, V::Archived, H>::serialize_from_iter(
+            field.iter(),
+            (7, 8),
+            serializer,
+        )
+    }
+}
+
+impl<K, V, D, S, H>
+    DeserializeWith<
+        ArchivedHashMap<K::Archived, V::Archived, H>,
+        HashMap<K, V, S>,
+        D,
+    > for HashWith<H>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D> + Hash + Eq,
+    V: Archive,
+    V::Archived: Deserialize<V, D>,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_with(
+        field: &ArchivedHashMap<K::Archived, V::Archived, H>,
+        deserializer: &mut D,
+    ) -> Result<HashMap<K, V, S>, D::Error> {
+        let mut result =
+            HashMap::with_capacity_and_hasher(field.len(), S::default());
+        for (k, v) in field.iter() {
+            result.insert(
+                k.deserialize(deserializer)?,
+                v.deserialize(deserializer)?,
+            );
+        }
+        Ok(result)
+    }
+}
+
+impl<K, S, H> ArchiveWith<HashSet<K, S>> for HashWith<H>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Hash + Eq,
+    H: Hasher + Default,
+{
+    type Archived = ArchivedHashSet<K::Archived, H>;
+    type Resolver = HashSetResolver;
+
+    fn resolve_with(
+        field: &HashSet<K, S>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedHashSet::resolve_from_len(field.len(), (7, 8), resolver, out);
+    }
+}
+
+impl<K, S, H, RS> SerializeWith<HashSet<K, RS>, S> for HashWith<H>
+where
+    K: Serialize<S> + Hash + Eq,
+    K::Archived: Hash + Eq,
+    S: Fallible + Writer + Allocator + ?Sized,
+    S::Error: Source,
+    H: Hasher + Default,
+{
+    fn serialize_with(
+        field: &HashSet<K, RS>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedHashSet::<K::Archived, H>::serialize_from_iter(
+            field.iter(),
+            (7, 8),
+            serializer,
+        )
+    }
+}
+
+impl<K, D, S, H>
+    DeserializeWith<ArchivedHashSet<K::Archived, H>, HashSet<K, S>, D>
+    for HashWith<H>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D> + Hash + Eq,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_with(
+        field: &ArchivedHashSet<K::Archived, H>,
+        deserializer: &mut D,
+    ) -> Result<HashSet<K, S>, D::Error> {
+        let mut result =
+            HashSet::with_capacity_and_hasher(field.len(), S::default());
+        for k in field.iter() {
+            result.insert(k.deserialize(deserializer)?);
+        }
+        Ok(result)
+    }
+}
+
+// OrderedMap
+
+impl<K, V, S> ArchiveWith<HashMap<K, V, S>> for OrderedMap
+where
+    K: Archive,
+    V: Archive,
+{
+    type Archived = ArchivedIndexMap<K::Archived, V::Archived>;
+    type Resolver = IndexMapResolver;
+
+    fn resolve_with(
+        field: &HashMap<K, V, S>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedIndexMap::resolve_from_len(field.len(), (7, 8), resolver, out);
+    }
+}
+
+impl<K, V, S, RS> SerializeWith<HashMap<K, V, RS>, S> for OrderedMap
+where
+    K: Serialize<S> + Hash + Eq + Ord,
+    V: Serialize<S>,
+    S: Fallible + Writer + Allocator + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &HashMap<K, V, RS>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        let mut entries = field.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|&(key, _)| key);
+
+        ArchivedIndexMap::<K::Archived, V::Archived>::serialize_from_iter(
+            entries.iter().map(|&(k, v)| (k, v)),
+            (7, 8),
+            serializer,
+        )
+    }
+}
+
+impl<K, V, D, S>
+    DeserializeWith<
+        ArchivedIndexMap<K::Archived, V::Archived>,
+        HashMap<K, V, S>,
+        D,
+    > for OrderedMap
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D>,
+    V: Archive,
+    V::Archived: Deserialize<V, D>,
+    D: Fallible + ?Sized,
+    S: Default + BuildHasher,
+{
+    fn deserialize_with(
+        field: &ArchivedIndexMap<K::Archived, V::Archived>,
+        deserializer: &mut D,
+    ) -> Result<HashMap<K, V, S>, D::Error> {
+        let mut result =
+            HashMap::with_capacity_and_hasher(field.len(), S::default());
+        for (k, v) in field.iter() {
+            result.insert(
+                k.deserialize(deserializer)?,
+                v.deserialize(deserializer)?,
+            );
+        }
+        Ok(result)
+    }
+}
+
 // UnixTimestamp
 
 impl ArchiveWith<SystemTime> for AsUnixTime {
@@ -457,17 +900,99 @@ where
 #[cfg(test)]
 mod tests {
     use std::{
+        collections::{HashMap, HashSet},
         ffi::OsString,
+        hash::Hasher,
         path::PathBuf,
         sync::{Mutex, RwLock},
     };
 
+    use rancor::Panic;
+
     use crate::{
-        api::test::roundtrip_with,
-        with::{AsString, Lock, Unsafe},
+        api::{high::to_bytes, test::roundtrip_with},
+        hash::FxHasher64,
+        with::{
+            AsBox, AsString, HashWith, Lock, Map, MapKV, Niche, OrderedMap,
+            Unsafe,
+        },
         Archive, Deserialize, Serialize,
     };
 
+    #[test]
+    fn roundtrip_map_hash_map() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(Map<AsBox>)]
+            value: HashMap<String, u32>,
+        }
+
+        let mut value = HashMap::new();
+        value.insert("foo".to_string(), 10);
+        value.insert("bar".to_string(), 20);
+        value.insert("baz".to_string(), 30);
+
+        roundtrip_with(&Test { value }, |a, b| {
+            assert_eq!(a.value.len(), b.value.len());
+            for (key, value) in a.value.iter() {
+                assert_eq!(b.value.get(key.as_str()).unwrap().get(), value);
+            }
+        });
+    }
+
+    #[test]
+    fn roundtrip_map_kv_hash_map() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(MapKV<AsBox, Niche>)]
+            value: HashMap<String, Option<Box<str>>>,
+        }
+
+        let mut value = HashMap::new();
+        value.insert("foo".to_string(), Some("hello".into()));
+        value.insert("bar".to_string(), None);
+        value.insert("baz".to_string(), Some("world".into()));
+
+        roundtrip_with(&Test { value }, |a, b| {
+            assert_eq!(a.value.len(), b.value.len());
+            for (key, value) in a.value.iter() {
+                let archived = b
+                    .value
+                    .get_with(key.as_str(), |q, k| q == k.as_str())
+                    .unwrap();
+                assert_eq!(archived.as_deref(), value.as_deref());
+            }
+        });
+    }
+
+    #[test]
+    fn roundtrip_map_kv_hash_map_as_string() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(MapKV<AsString, Niche>)]
+            value: HashMap<PathBuf, Option<Box<str>>>,
+        }
+
+        let mut value = HashMap::new();
+        value.insert(PathBuf::from("foo"), Some("hello".into()));
+        value.insert(PathBuf::from("bar/baz"), None);
+        value.insert(PathBuf::from("/qux"), Some("world".into()));
+
+        roundtrip_with(&Test { value }, |a, b| {
+            assert_eq!(a.value.len(), b.value.len());
+            for (key, value) in a.value.iter() {
+                let archived = b.value.get(key.to_str().unwrap()).unwrap();
+                assert_eq!(archived.as_deref(), value.as_deref());
+            }
+            for (key, value) in b.value.iter() {
+                assert!(core::ptr::eq(b.value.get(key).unwrap(), value));
+            }
+        });
+    }
+
     #[test]
     fn roundtrip_mutex() {
         #[derive(Archive, Serialize, Deserialize, Debug)]
@@ -561,4 +1086,118 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn roundtrip_hash_with() {
+        struct SeededHasher(FxHasher64);
+
+        impl Default for SeededHasher {
+            fn default() -> Self {
+                let mut inner = FxHasher64::default();
+                inner.write_u64(0xdead_beef);
+                Self(inner)
+            }
+        }
+
+        impl Hasher for SeededHasher {
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.write(bytes);
+            }
+
+            fn finish(&self) -> u64 {
+                self.0.finish()
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(HashWith<SeededHasher>)]
+            map: HashMap<String, u32>,
+            #[with(HashWith<SeededHasher>)]
+            set: HashSet<String>,
+        }
+
+        let mut map = HashMap::new();
+        let mut set = HashSet::new();
+        for i in 0..32 {
+            map.insert(format!("key {i}"), i);
+            set.insert(format!("value {i}"));
+        }
+
+        roundtrip_with(&Test { map, set }, |a, b| {
+            assert_eq!(a.map.len(), b.map.len());
+            for (key, value) in a.map.iter() {
+                assert_eq!(
+                    b.map.get(key.as_str()).unwrap().to_native(),
+                    *value
+                );
+            }
+            assert!(b.map.get("missing").is_none());
+
+            assert_eq!(a.set.len(), b.set.len());
+            for key in a.set.iter() {
+                assert!(b.set.contains(key.as_str()));
+            }
+            assert!(!b.set.contains("missing"));
+        });
+    }
+
+    #[test]
+    fn roundtrip_ordered_map() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(OrderedMap)]
+            map: HashMap<String, u32>,
+        }
+
+        let mut map = HashMap::new();
+        for i in 0..12 {
+            map.insert(format!("key {i}"), i);
+        }
+
+        roundtrip_with(&Test { map }, |a, b| {
+            assert_eq!(a.map.len(), b.map.len());
+            let mut entries = a.map.iter().collect::<Vec<_>>();
+            entries.sort();
+            for ((ak, av), (bk, bv)) in entries.into_iter().zip(b.map.iter()) {
+                assert_eq!(ak, bk);
+                assert_eq!(*av, bv.to_native());
+            }
+            for (key, value) in a.map.iter() {
+                assert_eq!(
+                    b.map.get(key.as_str()).unwrap().to_native(),
+                    *value
+                );
+            }
+            assert!(b.map.get("missing").is_none());
+        });
+    }
+
+    #[test]
+    fn ordered_map_is_deterministic() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[rkyv(crate, check_bytes, derive(Debug))]
+        struct Test {
+            #[with(OrderedMap)]
+            map: HashMap<String, u32>,
+        }
+
+        let mut a = HashMap::new();
+        for i in 0..12 {
+            a.insert(format!("key {i}"), i);
+        }
+        let mut b = HashMap::with_capacity(64);
+        for i in (0..12).rev() {
+            b.insert(format!("key {i}"), i);
+        }
+
+        let a = Test { map: a };
+        let b = Test { map: b };
+        assert_eq!(
+            to_bytes::<Panic>(&a).unwrap().as_slice(),
+            to_bytes::<Panic>(&b).unwrap().as_slice(),
+        );
+    }
 }
@@ -117,9 +117,11 @@
 //!
 //! - [`arrayvec`](https://docs.rs/arrayvec)
 //! - [`bytes`](https://docs.rs/bytes)
+//! - [`half`](https://docs.rs/half)
 //! - [`hashbrown`](https://docs.rs/hashbrown)
 //! - [`indexmap`](https://docs.rs/indexmap)
-//! - [`smallvec`](https://docs.rs/smallvec)
+//! - [`smallvec`](https://docs.rs/smallvec) (1.x, or 2.x with the `smallvec2`
+//!   feature)
 //! - [`smol_str`](https://docs.rs/smol_str)
 //! - [`tinyvec`](https://docs.rs/tinyvec)
 //! - [`triomphe`](https://docs.rs/triomphe)
@@ -159,11 +161,14 @@ use std as alloc;
 // Re-exports
 #[cfg(feature = "bytecheck")]
 pub use ::bytecheck;
+pub use ::equivalent;
 pub use ::munge;
 pub use ::ptr_meta;
 pub use ::rancor;
 pub use ::rend;
-pub use ::rkyv_derive::{Archive, Deserialize, Portable, Serialize};
+pub use ::rkyv_derive::{
+    Archive, Deserialize, DeserializeInto, Portable, Serialize,
+};
 
 // Modules
 
@@ -173,7 +178,11 @@ mod _macros;
 pub mod api;
 pub mod boxed;
 pub mod collections;
+#[cfg(feature = "alloc")]
+pub mod compression;
 pub mod de;
+#[cfg(feature = "alloc")]
+pub mod diff;
 mod fmt;
 // This is pretty unfortunate. CStr doesn't rely on the rest of std, but it's
 // not in core. If CStr ever gets moved into `core` then this module will no
@@ -219,8 +228,8 @@ pub use crate::{
     api::{access_unchecked, access_unchecked_mut},
     place::Place,
     traits::{
-        Archive, ArchiveUnsized, Deserialize, DeserializeUnsized, Portable,
-        Serialize, SerializeUnsized,
+        Archive, ArchiveUnsized, Deserialize, DeserializeInto,
+        DeserializeUnsized, Portable, Serialize, SerializeUnsized,
     },
 };
 
@@ -0,0 +1,211 @@
+//! A niched archived `Option<T>` that uses a niching strategy.
+
+use core::{fmt, marker::PhantomData, mem::MaybeUninit, pin::Pin};
+
+use rancor::{fail, Fallible, Source};
+
+use crate::{
+    niche::niching::{CheckNiche, Niching},
+    Archive, Place, Portable, Serialize,
+};
+
+/// A niched archived `Option<T>`.
+///
+/// It uses the same space as a `T` by storing the `None` variant with the
+/// niching strategy `N`.
+#[repr(transparent)]
+pub struct NichedOption<T, N: ?Sized> {
+    repr: MaybeUninit<T>,
+    _niching: PhantomData<N>,
+}
+
+// SAFETY: `NichedOption<T, N>` is `#[repr(transparent)]` over a `T`, and so is
+// `Portable` when `T` is `Portable`.
+unsafe impl<T: Portable, N: ?Sized> Portable for NichedOption<T, N> {}
+
+#[cfg(feature = "bytecheck")]
+const _: () = {
+    use crate::bytecheck::CheckBytes;
+
+    // SAFETY: `check_bytes` only returns `Ok` if the value is niched or is a
+    // valid `T`.
+    unsafe impl<T, N, C> CheckBytes<C> for NichedOption<T, N>
+    where
+        T: CheckBytes<C>,
+        N: Niching<T> + ?Sized,
+        C: Fallible + ?Sized,
+    {
+        unsafe fn check_bytes(
+            value: *const Self,
+            context: &mut C,
+        ) -> Result<(), C::Error> {
+            let ptr = value.cast::<T>();
+            // SAFETY: The caller has guaranteed that `value` is aligned and
+            // points to enough initialized bytes for a `T`.
+            if unsafe { N::is_niched(ptr) } {
+                // This is a `None` and doesn't need to be checked further
+                Ok(())
+            } else {
+                // SAFETY: The caller has guaranteed that `value` is aligned
+                // and points to enough initialized bytes for a `T`.
+                unsafe { T::check_bytes(ptr, context) }
+            }
+        }
+    }
+};
+
+impl<T, N: Niching<T> + ?Sized> NichedOption<T, N> {
+    /// Returns `true` if the option is a `None` value.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        // SAFETY: `self.repr` is aligned and all of its bytes are initialized.
+        unsafe { N::is_niched(self.repr.as_ptr()) }
+    }
+
+    /// Returns `true` if the option is a `Some` value.
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+
+    /// Converts to an `Option<&T>`.
+    #[inline]
+    pub fn as_ref(&self) -> Option<&T> {
+        if self.is_none() {
+            None
+        } else {
+            // SAFETY: Values which are not niched are always valid `T`s.
+            unsafe { Some(self.repr.assume_init_ref()) }
+        }
+    }
+
+    /// Converts from `Pin<&mut NichedOption<T, N>>` to `Option<Pin<&mut T>>`.
+    #[inline]
+    pub fn as_pin(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        // SAFETY: The value is never moved out of the option.
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+        if this.is_none() {
+            None
+        } else {
+            // SAFETY: Values which are not niched are always valid `T`s, and
+            // the pinned value is not moved.
+            unsafe { Some(Pin::new_unchecked(this.repr.assume_init_mut())) }
+        }
+    }
+
+    /// Resolves a `NichedOption<U::Archived, N>` from an `Option<&U>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` is a `Some` whose archived value is niched. This can
+    /// only happen if the value was not serialized with
+    /// [`serialize_from_option`](Self::serialize_from_option), or if the
+    /// [`CheckNiche`] implementation of `N` is incorrect.
+    pub fn resolve_from_option<U>(
+        field: Option<&U>,
+        resolver: Option<U::Resolver>,
+        out: Place<Self>,
+    ) where
+        U: Archive<Archived = T>,
+    {
+        // SAFETY: `NichedOption<T, N>` is `#[repr(transparent)]` over a `T`.
+        let out = unsafe { out.cast_unchecked::<T>() };
+        match (field, resolver) {
+            (Some(value), Some(resolver)) => {
+                value.resolve(resolver, out);
+                // SAFETY: `out` is aligned and all of its bytes are
+                // initialized.
+                if unsafe { N::is_niched(out.ptr()) } {
+                    panic!(
+                        "the archived value of a `Some` was the same as the \
+                         niched value"
+                    );
+                }
+            }
+            (None, None) => N::resolve_niched(out),
+            _ => panic!("the option and its resolver did not match"),
+        }
+    }
+
+    /// Serializes a `NichedOption<U::Archived, N>` from an `Option<&U>`.
+    ///
+    /// Returns an error if `field` is a `Some` whose value would be archived
+    /// as the niched representation.
+    pub fn serialize_from_option<U, S>(
+        field: Option<&U>,
+        serializer: &mut S,
+    ) -> Result<Option<U::Resolver>, S::Error>
+    where
+        U: Serialize<S, Archived = T>,
+        N: CheckNiche<U>,
+        S: Fallible + ?Sized,
+        S::Error: Source,
+    {
+        #[derive(Debug)]
+        struct NichedSome;
+
+        impl fmt::Display for NichedSome {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    f,
+                    "the archived value of a `Some` would be the same as the \
+                     niched value"
+                )
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for NichedSome {}
+
+        match field {
+            Some(value) => {
+                if N::would_niche(value) {
+                    fail!(NichedSome);
+                }
+                value.serialize(serializer).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T, N> fmt::Debug for NichedOption<T, N>
+where
+    T: fmt::Debug,
+    N: Niching<T> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl<T, N> Eq for NichedOption<T, N>
+where
+    T: Eq,
+    N: Niching<T> + ?Sized,
+{
+}
+
+impl<T, N> PartialEq for NichedOption<T, N>
+where
+    T: PartialEq,
+    N: Niching<T> + ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T, U, N> PartialEq<Option<U>> for NichedOption<T, N>
+where
+    T: PartialEq<U>,
+    N: Niching<T> + ?Sized,
+{
+    fn eq(&self, other: &Option<U>) -> bool {
+        match (self.as_ref(), other) {
+            (Some(self_value), Some(other_value)) => self_value == other_value,
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
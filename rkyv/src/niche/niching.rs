@@ -0,0 +1,203 @@
+//! Niching strategies which describe how to niche archived types.
+
+use core::mem::size_of;
+
+use crate::{
+    primitive::{
+        ArchivedNonZeroI128, ArchivedNonZeroI16, ArchivedNonZeroI32,
+        ArchivedNonZeroI64, ArchivedNonZeroU128, ArchivedNonZeroU16,
+        ArchivedNonZeroU32, ArchivedNonZeroU64,
+    },
+    Archive, Place,
+};
+
+/// A niching strategy which describes how to store a `None` in the bytes of an
+/// archived `T`.
+///
+/// Niching strategies are used with [`NicheInto`](crate::with::NicheInto) to
+/// archive an `Option<T>` in the same space as a `T`. A `None` is stored as a
+/// sentinel bit pattern which is never used by a valid `T`, such as zero for
+/// `NonZero` integers.
+///
+/// # Safety
+///
+/// - `is_niched` must return `true` for any value written by `resolve_niched`.
+/// - `is_niched` must only read the bytes of the value and must not assume that
+///   they are a valid `T`.
+///
+/// The sentinel written by `resolve_niched` should not be a valid `T`. If it
+/// is, implement [`CheckNiche`] so that serializing a `Some` which would
+/// archive to the sentinel fails with an error.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     niche::niching::{CheckNiche, Niching},
+///     with::NicheInto,
+///     Archive, Archived, Place, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize)]
+/// #[rkyv(check_bytes)]
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// // Archived `Level`s are stored as a `u8` tag of 0 or 1, so any other tag
+/// // can be used to store a `None`.
+/// struct LevelNiche;
+///
+/// unsafe impl Niching<ArchivedLevel> for LevelNiche {
+///     unsafe fn is_niched(niched: *const ArchivedLevel) -> bool {
+///         unsafe { *niched.cast::<u8>() == u8::MAX }
+///     }
+///
+///     fn resolve_niched(out: Place<ArchivedLevel>) {
+///         unsafe { out.cast_unchecked::<u8>() }.write(u8::MAX);
+///     }
+/// }
+///
+/// // No `Level` is archived with a tag of `u8::MAX`.
+/// impl CheckNiche<Level> for LevelNiche {
+///     fn would_niche(_: &Level) -> bool {
+///         false
+///     }
+/// }
+///
+/// #[derive(Archive, Serialize)]
+/// struct Example {
+///     #[with(NicheInto<LevelNiche>)]
+///     level: Option<Level>,
+/// }
+///
+/// assert_eq!(core::mem::size_of::<Archived<Example>>(), 1);
+/// ```
+pub unsafe trait Niching<T> {
+    /// Returns whether the given archived value is niched.
+    ///
+    /// # Safety
+    ///
+    /// `niched` must be properly aligned and point to `size_of::<T>()`
+    /// initialized bytes.
+    unsafe fn is_niched(niched: *const T) -> bool;
+
+    /// Writes the niched representation of a `T` to the given place.
+    fn resolve_niched(out: Place<T>);
+}
+
+/// A niching strategy which can tell whether a value would be archived as the
+/// niched representation.
+///
+/// [`NicheInto`](crate::with::NicheInto) checks each `Some` value while
+/// serializing, and fails instead of archiving a `Some` that would be read
+/// back as a `None`.
+pub trait CheckNiche<U: Archive + ?Sized>: Niching<U::Archived> {
+    /// Returns whether archiving `value` would produce the niched
+    /// representation.
+    fn would_niche(value: &U) -> bool;
+}
+
+/// A niching strategy which stores `None` as all zero bytes.
+///
+/// This is supported for archived `NonZero` integers.
+#[derive(Debug)]
+pub struct Zero;
+
+macro_rules! impl_zero_niching {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            // SAFETY: `is_niched` returns `true` for the zero bytes written by
+            // `resolve_niched`, and only reads the bytes of the value.
+            unsafe impl Niching<$ty> for Zero {
+                #[inline]
+                unsafe fn is_niched(niched: *const $ty) -> bool {
+                    // SAFETY: The caller has guaranteed that `niched` points
+                    // to `size_of::<$ty>()` initialized bytes.
+                    let bytes = unsafe {
+                        core::slice::from_raw_parts(
+                            niched.cast::<u8>(),
+                            size_of::<$ty>(),
+                        )
+                    };
+                    bytes.iter().all(|&b| b == 0)
+                }
+
+                #[inline]
+                fn resolve_niched(out: Place<$ty>) {
+                    // SAFETY: Zero bytes are initialized, and `out` points to
+                    // `size_of::<$ty>()` bytes.
+                    unsafe {
+                        out.ptr().cast::<u8>().write_bytes(0, size_of::<$ty>());
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_niching! {
+    core::num::NonZeroI8,
+    ArchivedNonZeroI16,
+    ArchivedNonZeroI32,
+    ArchivedNonZeroI64,
+    ArchivedNonZeroI128,
+    core::num::NonZeroU8,
+    ArchivedNonZeroU16,
+    ArchivedNonZeroU32,
+    ArchivedNonZeroU64,
+    ArchivedNonZeroU128,
+}
+
+/// A niching strategy which stores `None` as a `bool` with the value 2.
+#[derive(Debug)]
+pub struct Bool;
+
+// SAFETY: `is_niched` returns `true` for the byte written by `resolve_niched`,
+// and only reads the byte of the value.
+unsafe impl Niching<bool> for Bool {
+    #[inline]
+    unsafe fn is_niched(niched: *const bool) -> bool {
+        // SAFETY: The caller has guaranteed that `niched` points to an
+        // initialized byte.
+        unsafe { *niched.cast::<u8>() == 2 }
+    }
+
+    #[inline]
+    fn resolve_niched(out: Place<bool>) {
+        // SAFETY: `u8` and `bool` have the same size and alignment.
+        unsafe { out.cast_unchecked::<u8>() }.write(2);
+    }
+}
+
+macro_rules! impl_never_niched {
+    ($niching:ty => $($ty:ty),* $(,)?) => {
+        $(
+            impl CheckNiche<$ty> for $niching {
+                #[inline]
+                fn would_niche(_: &$ty) -> bool {
+                    false
+                }
+            }
+        )*
+    };
+}
+
+impl_never_niched! {
+    Zero =>
+    core::num::NonZeroI8,
+    core::num::NonZeroI16,
+    core::num::NonZeroI32,
+    core::num::NonZeroI64,
+    core::num::NonZeroI128,
+    core::num::NonZeroIsize,
+    core::num::NonZeroU8,
+    core::num::NonZeroU16,
+    core::num::NonZeroU32,
+    core::num::NonZeroU64,
+    core::num::NonZeroU128,
+    core::num::NonZeroUsize,
+}
+
+impl_never_niched!(Bool => bool);
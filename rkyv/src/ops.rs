@@ -75,6 +75,11 @@ impl<T> RangeBounds<T> for ArchivedRange<T> {
 }
 
 /// An archived [`RangeInclusive`](::core::ops::RangeInclusive).
+///
+/// Like `RangeInclusive`, this records whether the range was exhausted by
+/// iteration. An exhausted range is empty and doesn't contain its end bound.
+/// Deserializing an exhausted range produces an exhausted `RangeInclusive`,
+/// which requires that the range can be iterated over.
 #[derive(Clone, Default, PartialEq, Eq, Hash, Portable)]
 #[cfg_attr(feature = "bytecheck", derive(bytecheck::CheckBytes))]
 #[repr(C)]
@@ -84,6 +89,16 @@ pub struct ArchivedRangeInclusive<T> {
     pub start: T,
     /// The upper bound of the range (inclusive).
     pub end: T,
+    /// Whether the range was exhausted by iteration.
+    pub exhausted: bool,
+}
+
+impl<T> ArchivedRangeInclusive<T> {
+    /// Returns `true` if the range was exhausted by iteration before it was
+    /// archived.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
 }
 
 impl<T: fmt::Debug> fmt::Debug for ArchivedRangeInclusive<T> {
@@ -91,6 +106,9 @@ impl<T: fmt::Debug> fmt::Debug for ArchivedRangeInclusive<T> {
         self.start.fmt(fmt)?;
         write!(fmt, "..=")?;
         self.end.fmt(fmt)?;
+        if self.exhausted {
+            write!(fmt, " (exhausted)")?;
+        }
         Ok(())
     }
 }
@@ -107,6 +125,9 @@ impl<T: PartialOrd<T>> ArchivedRangeInclusive<T> {
 
     /// Returns `true` if the range contains no items.
     pub fn is_empty(&self) -> bool {
+        if self.is_exhausted() {
+            return true;
+        }
         match self.start.partial_cmp(&self.end) {
             None | Some(cmp::Ordering::Greater) => true,
             Some(cmp::Ordering::Less) | Some(cmp::Ordering::Equal) => false,
@@ -120,7 +141,11 @@ impl<T> RangeBounds<T> for ArchivedRangeInclusive<T> {
     }
 
     fn end_bound(&self) -> Bound<&T> {
-        Bound::Included(&self.end)
+        if self.is_exhausted() {
+            Bound::Excluded(&self.end)
+        } else {
+            Bound::Included(&self.end)
+        }
     }
 }
 
@@ -55,6 +55,16 @@ impl<T> ArchivedOption<T> {
             ArchivedOption::Some(value) => value,
         }
     }
+    /// Maps an `ArchivedOption<T>` to an `Option<U>` by applying a function to
+    /// a contained value.
+    ///
+    /// To map a borrowed value, use [`as_ref`](Self::as_ref) first.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Option<U> {
+        match self {
+            ArchivedOption::None => None,
+            ArchivedOption::Some(value) => Some(f(value)),
+        }
+    }
     /// Returns `true` if the option is a `None` value.
     pub fn is_none(&self) -> bool {
         match self {
@@ -376,6 +386,16 @@ mod tests {
         assert_eq!(Some(Ordering::Greater), a.partial_cmp(&b));
     }
 
+    #[test]
+    fn map() {
+        let x: ArchivedOption<u8> = ArchivedOption::Some(1);
+        assert_eq!(x.map(|x| x + 1), Some(2));
+        assert_eq!(x.as_ref().map(|x| *x * 3), Some(3));
+
+        let x: ArchivedOption<u8> = ArchivedOption::None;
+        assert_eq!(x.map(|x| x + 1), None);
+    }
+
     #[test]
     fn into_iter() {
         let x: ArchivedOption<u8> = ArchivedOption::Some(1);
@@ -3,8 +3,12 @@
 use core::{mem::size_of, ptr::NonNull};
 
 use munge::{Borrow, Destructure, Restructure};
+use rancor::Source;
 
-use crate::traits::LayoutRaw;
+use crate::{
+    rel_ptr::{Offset, RelPtr},
+    traits::{ArchivePointee, LayoutRaw},
+};
 
 /// A place to write a `T` paired with its position in the output buffer.
 pub struct Place<T: ?Sized> {
@@ -156,6 +160,163 @@ impl<T, const N: usize> Place<[T; N]> {
     }
 }
 
+impl<T: ArchivePointee + ?Sized, O: Offset> Place<RelPtr<T, O>> {
+    /// Attempts to write a relative pointer to an unsized value to this place.
+    ///
+    /// See [`emplace_unsized`](Place::emplace_unsized) for more details.
+    pub fn try_emplace_unsized<E: Source>(
+        self,
+        pos: usize,
+        metadata: T::ArchivedMetadata,
+    ) -> Result<(), E> {
+        RelPtr::try_emplace_unsized(pos, metadata, self)
+    }
+
+    /// Writes a relative pointer to an unsized value to this place.
+    ///
+    /// `pos` is the position of the value, which is returned from
+    /// [`serialize_unsized`](crate::SerializeUnsized::serialize_unsized).
+    /// `metadata` is the archived pointer metadata of the value, which is
+    /// returned from
+    /// [`archived_metadata`](crate::ArchiveUnsized::archived_metadata).
+    ///
+    /// # Panics
+    ///
+    /// - If the offset between this place and `pos` does not fit in an `isize`
+    /// - If the offset between this place and `pos` exceeds the offset storage
+    ///
+    /// # Example
+    ///
+    /// This example implements [`ArchiveUnsized`](crate::ArchiveUnsized) for a
+    /// custom dynamically-sized type, and then archives it behind a custom
+    /// container which writes its relative pointer with `emplace_unsized`.
+    ///
+    /// ```
+    /// use ptr_meta::Pointee;
+    /// use rkyv::{
+    ///     access_unchecked,
+    ///     munge::munge,
+    ///     primitive::ArchivedU32,
+    ///     rancor::{Error, Fallible},
+    ///     ser::{Writer, WriterExt as _},
+    ///     to_bytes,
+    ///     traits::ArchivePointee,
+    ///     Archive, ArchiveUnsized, ArchivedMetadata, Place, Portable, RelPtr,
+    ///     Serialize, SerializeUnsized,
+    /// };
+    ///
+    /// // A packet is a tag followed by a payload of any length.
+    /// #[repr(C)]
+    /// struct Packet<P: ?Sized = [u8]> {
+    ///     tag: u32,
+    ///     payload: P,
+    /// }
+    ///
+    /// // The metadata of a pointer to a packet is the length of its payload.
+    /// unsafe impl Pointee for Packet {
+    ///     type Metadata = usize;
+    /// }
+    ///
+    /// #[derive(Portable)]
+    /// #[repr(C)]
+    /// struct ArchivedPacket {
+    ///     tag: ArchivedU32,
+    ///     payload: [u8],
+    /// }
+    ///
+    /// unsafe impl Pointee for ArchivedPacket {
+    ///     type Metadata = usize;
+    /// }
+    ///
+    /// // Relative pointers to archived packets store the length of the payload
+    /// // in the same format as relative pointers to byte slices.
+    /// impl ArchivePointee for ArchivedPacket {
+    ///     type ArchivedMetadata = <[u8] as ArchivePointee>::ArchivedMetadata;
+    ///
+    ///     fn pointer_metadata(metadata: &Self::ArchivedMetadata) -> usize {
+    ///         <[u8]>::pointer_metadata(metadata)
+    ///     }
+    /// }
+    ///
+    /// impl ArchiveUnsized for Packet {
+    ///     type Archived = ArchivedPacket;
+    ///
+    ///     fn archived_metadata(&self) -> ArchivedMetadata<Self> {
+    ///         self.payload.archived_metadata()
+    ///     }
+    /// }
+    ///
+    /// impl<S: Fallible + Writer + ?Sized> SerializeUnsized<S> for Packet {
+    ///     fn serialize_unsized(
+    ///         &self,
+    ///         serializer: &mut S,
+    ///     ) -> Result<usize, S::Error> {
+    ///         // Packets don't have any dependencies to serialize first, so the
+    ///         // archived packet can be written right away. Its position is
+    ///         // returned so that it can be pointed to.
+    ///         let pos = serializer.align_for::<ArchivedU32>()?;
+    ///         unsafe {
+    ///             serializer.resolve_aligned(&self.tag, ())?;
+    ///         }
+    ///         serializer.write(&self.payload)?;
+    ///         Ok(pos)
+    ///     }
+    /// }
+    ///
+    /// // A custom container which owns a possibly-unsized value.
+    /// struct MyBox<T: ?Sized>(Box<T>);
+    ///
+    /// #[derive(Portable)]
+    /// #[repr(transparent)]
+    /// struct ArchivedMyBox<T: ArchivePointee + ?Sized> {
+    ///     ptr: RelPtr<T>,
+    /// }
+    ///
+    /// impl<T: ArchiveUnsized + ?Sized> Archive for MyBox<T> {
+    ///     type Archived = ArchivedMyBox<T::Archived>;
+    ///     // The resolver for an unsized value is its position.
+    ///     type Resolver = usize;
+    ///
+    ///     fn resolve(&self, pos: usize, out: Place<Self::Archived>) {
+    ///         munge!(let ArchivedMyBox { ptr } = out);
+    ///         ptr.emplace_unsized(pos, T::archived_metadata(&self.0));
+    ///     }
+    /// }
+    ///
+    /// impl<T, S> Serialize<S> for MyBox<T>
+    /// where
+    ///     T: SerializeUnsized<S> + ?Sized,
+    ///     S: Fallible + ?Sized,
+    /// {
+    ///     fn serialize(&self, serializer: &mut S) -> Result<usize, S::Error> {
+    ///         T::serialize_unsized(&self.0, serializer)
+    ///     }
+    /// }
+    ///
+    /// // Make a `Box<Packet>` by "unsizing" a `Box<Packet<[u8; 3]>>`.
+    /// let sized = Box::into_raw(Box::new(Packet {
+    ///     tag: 7,
+    ///     payload: [1u8, 2, 3],
+    /// }));
+    /// let packet = unsafe {
+    ///     Box::from_raw(ptr_meta::from_raw_parts_mut::<Packet>(
+    ///         sized.cast::<()>(),
+    ///         3,
+    ///     ))
+    /// };
+    ///
+    /// let bytes = to_bytes::<Error>(&MyBox(packet)).unwrap();
+    /// let archived =
+    ///     unsafe { access_unchecked::<ArchivedMyBox<ArchivedPacket>>(&bytes) };
+    /// let packet = unsafe { &*archived.ptr.as_ptr() };
+    /// assert_eq!(packet.tag, 7);
+    /// assert_eq!(&packet.payload, &[1, 2, 3]);
+    /// ```
+    pub fn emplace_unsized(self, pos: usize, metadata: T::ArchivedMetadata) {
+        RelPtr::emplace_unsized(pos, metadata, self)
+    }
+}
+
 unsafe impl<T: ?Sized> Destructure for Place<T> {
     type Underlying = T;
     type Destructuring = Borrow;
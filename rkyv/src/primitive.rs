@@ -111,6 +111,53 @@ pub type FixedUsize = match_pointer_width!(u16, u32, u64);
 pub type ArchivedUsize =
     match_pointer_width!(ArchivedU16, ArchivedU32, ArchivedU64);
 
+macro_rules! define_archived_tag_fns {
+    ($($name:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Returns the native `",
+                stringify!($ty),
+                "` whose bytes are the archived bytes of `tag`.",
+            )]
+            ///
+            /// The derive macros use this to choose the discriminants of
+            /// archived enums with tags wider than a byte.
+            #[doc(hidden)]
+            pub const fn $name(tag: $ty) -> $ty {
+                // Archived enums with wide tags would not be unaligned.
+                #[cfg(feature = "unaligned")]
+                {
+                    let _ = tag;
+                    panic!(
+                        "enums with tags wider than a byte cannot be archived \
+                         with the `unaligned` feature"
+                    );
+                }
+                #[cfg(all(not(feature = "unaligned"), feature = "big_endian"))]
+                {
+                    tag.to_be()
+                }
+                #[cfg(all(
+                    not(feature = "unaligned"),
+                    not(feature = "big_endian"),
+                ))]
+                {
+                    tag.to_le()
+                }
+            }
+        )*
+    };
+}
+
+define_archived_tag_fns! {
+    archived_tag_i16: i16,
+    archived_tag_i32: i32,
+    archived_tag_i64: i64,
+    archived_tag_u16: u16,
+    archived_tag_u32: u32,
+    archived_tag_u64: u64,
+}
+
 define_multibyte_primitives! {
     ArchivedNonZeroI16:
         NonZeroI16,
@@ -208,3 +255,247 @@ pub type ArchivedNonZeroUsize = match_pointer_width!(
     ArchivedNonZeroU32,
     ArchivedNonZeroU64
 );
+
+/// An archived primitive which can be converted to its native type.
+///
+/// This is implemented for all archived integers, floats, and `char`s, and can
+/// be used to write code which is generic over archived primitives regardless
+/// of their endianness.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::primitive::{ArchivedU32, AsNative};
+///
+/// fn sum<T>(values: &[T]) -> T::Native
+/// where
+///     T: AsNative,
+///     T::Native: core::iter::Sum,
+/// {
+///     values.iter().map(T::to_native).sum()
+/// }
+///
+/// let values = [1, 2, 3].map(ArchivedU32::from_native);
+/// assert_eq!(sum(&values), 6);
+/// ```
+pub trait AsNative {
+    /// The native type of the primitive.
+    type Native;
+
+    /// Returns the native value of the archived primitive.
+    fn to_native(&self) -> Self::Native;
+}
+
+macro_rules! impl_as_native {
+    ($($archived:ty: $native:ty),* $(,)?) => {
+        $(
+            impl AsNative for $archived {
+                type Native = $native;
+
+                #[inline]
+                fn to_native(&self) -> Self::Native {
+                    <$archived>::to_native(*self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_as_native_identity {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl AsNative for $ty {
+                type Native = $ty;
+
+                #[inline]
+                fn to_native(&self) -> Self::Native {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_as_native_identity! {
+    i8,
+    u8,
+    ::core::num::NonZeroI8,
+    ::core::num::NonZeroU8,
+}
+
+impl_as_native! {
+    ArchivedI16: i16,
+    ArchivedI32: i32,
+    ArchivedI64: i64,
+    ArchivedI128: i128,
+    ArchivedU16: u16,
+    ArchivedU32: u32,
+    ArchivedU64: u64,
+    ArchivedU128: u128,
+    ArchivedF32: f32,
+    ArchivedF64: f64,
+    ArchivedChar: char,
+    ArchivedNonZeroI16: ::core::num::NonZeroI16,
+    ArchivedNonZeroI32: ::core::num::NonZeroI32,
+    ArchivedNonZeroI64: ::core::num::NonZeroI64,
+    ArchivedNonZeroI128: ::core::num::NonZeroI128,
+    ArchivedNonZeroU16: ::core::num::NonZeroU16,
+    ArchivedNonZeroU32: ::core::num::NonZeroU32,
+    ArchivedNonZeroU64: ::core::num::NonZeroU64,
+    ArchivedNonZeroU128: ::core::num::NonZeroU128,
+}
+
+#[cfg(feature = "half")]
+impl_as_native! {
+    ArchivedF16: ::half::f16,
+    ArchivedBf16: ::half::bf16,
+}
+
+#[cfg(feature = "half")]
+macro_rules! define_archived_half_floats {
+    ($($archived:ident: $name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "The archived version of [`half::",
+                stringify!($name),
+                "`].",
+            )]
+            ///
+            /// The raw bits of the float are stored as an [`ArchivedU16`], so
+            /// every bit pattern (including each distinct NaN) is preserved
+            /// exactly.
+            #[derive(Clone, Copy, crate::Portable)]
+            #[rkyv(crate)]
+            #[cfg_attr(feature = "bytecheck", derive(bytecheck::CheckBytes))]
+            #[repr(transparent)]
+            pub struct $archived(ArchivedU16);
+
+            impl $archived {
+                #[doc = concat!(
+                    "Returns an archived `",
+                    stringify!($name),
+                    "` with the same value as the given native `",
+                    stringify!($name),
+                    "`.",
+                )]
+                #[inline]
+                pub fn from_native(value: ::half::$name) -> Self {
+                    Self::from_bits(value.to_bits())
+                }
+
+                #[doc = concat!(
+                    "Returns the native `",
+                    stringify!($name),
+                    "` value of this archived `",
+                    stringify!($name),
+                    "`.",
+                )]
+                #[inline]
+                pub fn to_native(self) -> ::half::$name {
+                    ::half::$name::from_bits(self.to_bits())
+                }
+
+                /// Returns an archived float with the given raw bits.
+                #[inline]
+                pub fn from_bits(bits: u16) -> Self {
+                    Self(ArchivedU16::from_native(bits))
+                }
+
+                /// Returns the raw bits of this archived float.
+                #[inline]
+                pub fn to_bits(self) -> u16 {
+                    self.0.to_native()
+                }
+            }
+
+            impl ::core::fmt::Debug for $archived {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(&self.to_native(), f)
+                }
+            }
+
+            impl ::core::fmt::Display for $archived {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.to_native(), f)
+                }
+            }
+
+            impl PartialEq for $archived {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.to_native() == other.to_native()
+                }
+            }
+
+            impl PartialEq<::half::$name> for $archived {
+                #[inline]
+                fn eq(&self, other: &::half::$name) -> bool {
+                    self.to_native() == *other
+                }
+            }
+
+            impl PartialOrd for $archived {
+                #[inline]
+                fn partial_cmp(
+                    &self,
+                    other: &Self,
+                ) -> Option<::core::cmp::Ordering> {
+                    self.to_native().partial_cmp(&other.to_native())
+                }
+            }
+
+            // SAFETY: The archived float is a transparent wrapper around an
+            // `ArchivedU16`, which is fully initialized.
+            unsafe impl crate::place::Initialized for $archived {}
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+define_archived_half_floats! {
+    ArchivedF16: f16,
+    ArchivedBf16: bf16,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU16;
+
+    use super::{
+        ArchivedChar, ArchivedF64, ArchivedI64, ArchivedNonZeroU16,
+        ArchivedU32, AsNative,
+    };
+
+    fn sum<T>(values: &[T]) -> T::Native
+    where
+        T: AsNative,
+        T::Native: core::iter::Sum,
+    {
+        values.iter().map(T::to_native).sum()
+    }
+
+    fn native<T: AsNative>(value: &T) -> T::Native {
+        value.to_native()
+    }
+
+    #[test]
+    fn as_native_generic() {
+        assert_eq!(sum(&[1u8, 2, 3]), 6);
+        assert_eq!(sum(&[1, 2, 3].map(ArchivedU32::from_native)), 6);
+        assert_eq!(sum(&[-1, 2, -3].map(ArchivedI64::from_native)), -2);
+        assert_eq!(sum(&[0.5, 1.5].map(ArchivedF64::from_native)), 2.0);
+
+        assert_eq!(native(&ArchivedChar::from_native('x')), 'x');
+        let non_zero = NonZeroU16::new(7).unwrap();
+        assert_eq!(
+            native(&ArchivedNonZeroU16::from_native(non_zero)),
+            non_zero,
+        );
+    }
+}
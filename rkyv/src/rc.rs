@@ -9,7 +9,7 @@ use rancor::Fallible;
 
 use crate::{
     place::Initialized,
-    ser::{Sharing, SharingExt, Writer, WriterExt as _},
+    ser::{Deduplication, Sharing, SharingExt, Writer, WriterExt as _},
     traits::ArchivePointee,
     ArchiveUnsized, Place, Portable, RelPtr, SerializeUnsized,
 };
@@ -20,6 +20,9 @@ pub struct RcFlavor;
 /// The flavor type for [`Arc`](std::sync::Arc).
 pub struct ArcFlavor;
 
+/// The flavor type for values deduplicated with [`Dedup`](crate::with::Dedup).
+pub struct DedupFlavor;
+
 /// An archived `Rc`.
 ///
 /// This is a thin wrapper around a [`RelPtr`] to the archived type paired with
@@ -45,6 +48,17 @@ impl<T: ArchivePointee + ?Sized, F> ArchivedRc<T, F> {
         unsafe { &*self.ptr.as_ptr() }
     }
 
+    /// Returns whether this `ArchivedRc` and `other` point to the same value.
+    ///
+    /// This compares the positions of the pointed-to values in the archive, so
+    /// it's only meaningful for `ArchivedRc`s in the same archive buffer.
+    /// Values which were shared before serialization are also shared in the
+    /// archive.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.ptr.as_ptr_wrapping().cast::<u8>()
+            == other.ptr.as_ptr_wrapping().cast::<u8>()
+    }
+
     /// Gets the pinned mutable value of this `ArchivedRc`.
     ///
     /// # Safety
@@ -86,6 +100,37 @@ impl<T: ArchivePointee + ?Sized, F> ArchivedRc<T, F> {
 
         Ok(RcResolver { pos })
     }
+
+    /// Serializes an archived `Rc` from a given value, sharing the
+    /// serialization of an equal value if one was already serialized.
+    pub fn serialize_deduplicated<U, S>(
+        value: &U,
+        serializer: &mut S,
+    ) -> Result<RcResolver, S::Error>
+    where
+        U: SerializeUnsized<S>
+            + hash::Hash
+            + Eq
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S: Fallible + Writer + Deduplication + ?Sized,
+    {
+        if let Some(pos) = serializer.get_dedup_pos(value) {
+            return Ok(RcResolver { pos });
+        }
+
+        let pos = value.serialize_unsized(serializer)?;
+        // As with shared pointers, the positions of deduplicated values must
+        // be unique.
+        if serializer.pos() == pos {
+            serializer.pad(1)?;
+        }
+        serializer.add_dedup_pos(value, pos)?;
+
+        Ok(RcResolver { pos })
+    }
 }
 
 impl<T: ArchivePointee + ?Sized, F> AsRef<T> for ArchivedRc<T, F> {
@@ -172,7 +217,20 @@ pub struct RcResolver {
 
 /// An archived `rc::Weak`.
 ///
-/// This is essentially just an optional [`ArchivedRc`].
+/// This is essentially just an optional [`ArchivedRc`]. Weak pointers which
+/// could not be upgraded when they were serialized are archived as `None`.
+///
+/// When deserialized with a [`Pooling`](crate::de::Pooling) deserializer, a
+/// weak pointer is downgraded from the same shared pointer as the strong
+/// pointers to its value. It can be upgraded as long as one of those strong
+/// pointers is alive, and can't be upgraded if none of them were archived.
+///
+/// Shared values are always serialized before the values which point to them,
+/// so weak pointers can't be used to archive cycles. For example, a tree with
+/// strong pointers from parents to children can't also have weak pointers from
+/// children back to their parents. Serializing a cycle with a sharing strategy
+/// that detects cycles, like [`Share`](crate::ser::sharing::Share), returns an
+/// error.
 #[derive(Portable)]
 #[rkyv(crate)]
 #[repr(u8)]
@@ -313,12 +371,13 @@ mod verify {
             let ptr = self.ptr.as_ptr_wrapping();
             let type_id = TypeId::of::<ArchivedRc<T, F>>();
 
-            let is_new = context
-                .register_shared_ptr(ptr as *const u8 as usize, type_id)?;
+            let address = ptr as *const u8 as usize;
+            let is_new = context.start_shared_ptr(address, type_id)?;
             if is_new {
                 context.in_subtree(ptr, |context| unsafe {
                     T::check_bytes(ptr, context)
-                })?
+                })?;
+                context.finish_shared_ptr(address, type_id)?;
             }
 
             Ok(())
@@ -285,6 +285,26 @@ impl<O: Offset> RawRelPtr<O> {
         unsafe { Self::is_invalid_raw(this.cast_mut()) }
     }
 
+    /// Gets whether the offset of the relative pointer is 0.
+    ///
+    /// A relative pointer with an offset of 0 points to itself. This is never
+    /// written by rkyv, but may be found in zeroed or corrupted buffers. Note
+    /// that this is different from [`is_invalid`](Self::is_invalid).
+    pub fn is_null(&self) -> bool {
+        self.offset() == 0
+    }
+
+    /// Calculates the position of the target of this relative pointer, given
+    /// `base_pos`, the position of this relative pointer in its buffer.
+    ///
+    /// This is an advanced API intended for tools which inspect archived
+    /// buffers. It only performs arithmetic on positions, so the returned
+    /// position is not guaranteed to be in bounds of the buffer. The addition
+    /// wraps on overflow.
+    pub fn target_pos(&self, base_pos: usize) -> usize {
+        base_pos.wrapping_add_signed(self.offset())
+    }
+
     /// Calculates the memory address being pointed to by this relative pointer.
     ///
     /// # Safety
@@ -570,11 +590,26 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
         self.raw_ptr.offset()
     }
 
-    /// Gets whether the offset of the relative pointer is 0.
+    /// Calculates the position of the target of this relative pointer, given
+    /// `base_pos`, the position of this relative pointer in its buffer.
+    ///
+    /// See [`RawRelPtr::target_pos`] for more details.
+    pub fn target_pos(&self, base_pos: usize) -> usize {
+        self.raw_ptr.target_pos(base_pos)
+    }
+
+    /// Gets whether the offset of the relative pointer is invalid.
     pub fn is_invalid(&self) -> bool {
         self.raw_ptr.is_invalid()
     }
 
+    /// Gets whether the offset of the relative pointer is 0.
+    ///
+    /// See [`RawRelPtr::is_null`] for more details.
+    pub fn is_null(&self) -> bool {
+        self.raw_ptr.is_null()
+    }
+
     /// Gets the metadata of the relative pointer.
     pub fn metadata(&self) -> &T::ArchivedMetadata {
         &self.metadata
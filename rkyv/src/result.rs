@@ -30,6 +30,33 @@ impl<T, E> ArchivedResult<T, E> {
             ArchivedResult::Err(_) => None,
         }
     }
+    /// Converts from `ArchivedResult<T, E>` to `Option<E>`.
+    pub fn err(self) -> Option<E> {
+        match self {
+            ArchivedResult::Ok(_) => None,
+            ArchivedResult::Err(err) => Some(err),
+        }
+    }
+    /// Maps an `ArchivedResult<T, E>` to a `Result<U, E>` by applying a
+    /// function to a contained [`Ok`](ArchivedResult::Ok) value.
+    ///
+    /// To map a borrowed value, use [`as_ref`](Self::as_ref) first.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Result<U, E> {
+        match self {
+            ArchivedResult::Ok(value) => Ok(f(value)),
+            ArchivedResult::Err(err) => Err(err),
+        }
+    }
+    /// Maps an `ArchivedResult<T, E>` to a `Result<T, F>` by applying a
+    /// function to a contained [`Err`](ArchivedResult::Err) value.
+    ///
+    /// To map a borrowed value, use [`as_ref`](Self::as_ref) first.
+    pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> Result<T, F> {
+        match self {
+            ArchivedResult::Ok(value) => Ok(value),
+            ArchivedResult::Err(err) => Err(op(err)),
+        }
+    }
     /// Returns the contained [`Ok`](ArchivedResult::Ok) value, consuming the
     /// `self` value.
     pub fn unwrap(self) -> T {
@@ -40,6 +67,14 @@ impl<T, E> ArchivedResult<T, E> {
             }
         }
     }
+    /// Returns the contained [`Ok`](ArchivedResult::Ok) value or the provided
+    /// default.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            ArchivedResult::Ok(value) => value,
+            ArchivedResult::Err(_) => default,
+        }
+    }
     /// Returns the contained `Ok` value or computes it from a closure.
     pub fn unwrap_or_else<F>(self, op: F) -> T
     where
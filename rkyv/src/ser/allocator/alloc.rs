@@ -1,28 +1,60 @@
 use core::{
     alloc::Layout,
+    fmt,
     marker::PhantomData,
     mem::{align_of, size_of, ManuallyDrop},
     ptr::{slice_from_raw_parts_mut, NonNull},
 };
 
+use rancor::{fail, Source};
+
 use crate::{
     alloc::alloc::{alloc, dealloc, handle_alloc_error},
     ser::Allocator,
 };
 
+#[derive(Debug)]
+struct BlockAllocError {
+    layout: Layout,
+}
+
+impl fmt::Display for BlockAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to allocate a new arena block for request of size {} and \
+             align {}",
+            self.layout.size(),
+            self.layout.align()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockAllocError {}
+
 struct Block {
     next_ptr: NonNull<Block>,
     next_size: usize,
 }
 
 impl Block {
+    fn layout(size: usize) -> Option<Layout> {
+        Layout::from_size_align(size, align_of::<Self>()).ok()
+    }
+
     fn alloc(size: usize) -> NonNull<Self> {
-        debug_assert!(size >= size_of::<Self>());
-        let layout = Layout::from_size_align(size, align_of::<Self>()).unwrap();
-        let ptr = unsafe { alloc(layout).cast::<Self>() };
-        let Some(ptr) = NonNull::new(ptr) else {
+        let layout = Self::layout(size).unwrap();
+        let Some(ptr) = Self::try_alloc(layout) else {
             handle_alloc_error(layout)
         };
+        ptr
+    }
+
+    /// Returns `None` if the allocation fails.
+    fn try_alloc(layout: Layout) -> Option<NonNull<Self>> {
+        debug_assert!(layout.size() >= size_of::<Self>());
+        let ptr = NonNull::new(unsafe { alloc(layout).cast::<Self>() })?;
 
         unsafe {
             ptr.as_ptr().write(Self {
@@ -31,7 +63,7 @@ impl Block {
             });
         }
 
-        ptr
+        Some(ptr)
     }
 
     unsafe fn dealloc(ptr: NonNull<Self>, size: usize) {
@@ -197,6 +229,11 @@ impl Default for Arena {
 }
 
 /// A handle which can allocate within an arena.
+///
+/// When the arena runs out of space, the handle allocates a new block. If that
+/// allocation fails, the handle returns an error instead of aborting. This is
+/// why it only implements [`Allocator<E>`] for error types which implement
+/// [`Source`].
 pub struct ArenaHandle<'a> {
     tail_ptr: NonNull<Block>,
     tail_size: usize,
@@ -204,7 +241,10 @@ pub struct ArenaHandle<'a> {
     _phantom: PhantomData<&'a mut Arena>,
 }
 
-unsafe impl<E> Allocator<E> for ArenaHandle<'_> {
+// `E: Source` is required to return a `BlockAllocError` when a new block can't
+// be allocated. Every error type accepted by the high-level API implements
+// `Source`, so this doesn't restrict any of its functions.
+unsafe impl<E: Source> Allocator<E> for ArenaHandle<'_> {
     unsafe fn push_alloc(
         &mut self,
         layout: Layout,
@@ -214,13 +254,20 @@ unsafe impl<E> Allocator<E> for ArenaHandle<'_> {
         if pad + layout.size() <= self.tail_size - self.used {
             self.used += pad;
         } else {
-            // Allocation request is too large, allocate a new block
-            let size = usize::max(
-                2 * self.tail_size,
-                (size_of::<Block>() + layout.size() + layout.align())
-                    .next_power_of_two(),
-            );
-            let next = Block::alloc(size);
+            // Allocation request is too large, allocate a new block. This
+            // returns an error instead of aborting so that serializing with an
+            // arena stays fallible when memory runs out.
+            let block_layout = size_of::<Block>()
+                .checked_add(layout.size())
+                .and_then(|size| size.checked_add(layout.align()))
+                .and_then(usize::checked_next_power_of_two)
+                .map(|size| usize::max(self.tail_size.saturating_mul(2), size))
+                .and_then(Block::layout);
+            let Some((next, size)) = block_layout.and_then(|block_layout| {
+                Some((Block::try_alloc(block_layout)?, block_layout.size()))
+            }) else {
+                fail!(BlockAllocError { layout });
+            };
             unsafe {
                 Block::push_next(self.tail_ptr, next);
             }
@@ -264,7 +311,7 @@ unsafe impl<E> Allocator<E> for ArenaHandle<'_> {
 mod tests {
     use core::alloc::Layout;
 
-    use rancor::{Panic, ResultExt};
+    use rancor::{Failure, Panic, ResultExt};
 
     use crate::{
         alloc::{string::ToString, vec},
@@ -314,4 +361,24 @@ mod tests {
                 .always_ok();
         }
     }
+
+    #[test]
+    fn oversized_block_fails() {
+        let mut arena = Arena::new();
+        let mut handle = arena.acquire();
+
+        // The new block for this request would be larger than `isize::MAX`.
+        let layout =
+            Layout::from_size_align(isize::MAX as usize - 64, 1).unwrap();
+
+        unsafe {
+            Allocator::<Failure>::push_alloc(&mut handle, layout).unwrap_err();
+        }
+
+        // The arena is still usable afterward.
+        let layout = Layout::from_size_align(16, 1).unwrap();
+        unsafe {
+            Allocator::<Failure>::push_alloc(&mut handle, layout).unwrap();
+        }
+    }
 }
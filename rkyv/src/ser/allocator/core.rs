@@ -98,3 +98,111 @@ where
         Ok(())
     }
 }
+
+#[derive(Debug)]
+struct AllocationLimitExceeded {
+    layout: Layout,
+    used: usize,
+    capacity: usize,
+}
+
+impl fmt::Display for AllocationLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocation request of size {} and align {} would exceed the \
+             allocation limit of {} bytes ({} bytes already used)",
+            self.layout.size(),
+            self.layout.align(),
+            self.capacity,
+            self.used,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocationLimitExceeded {}
+
+/// An allocator that draws from a fixed-size buffer provided by the caller.
+///
+/// Allocation requests that don't fit in the remaining space of the buffer
+/// return an error instead of falling back to the global allocator. This
+/// places a hard cap on the scratch space used during serialization. The space
+/// used includes any padding needed to align allocations, so it can be
+/// somewhat larger than the total size of the live allocations.
+///
+/// # Example
+///
+/// ```
+/// use core::mem::MaybeUninit;
+///
+/// use rkyv::{
+///     api::high::to_bytes_with_alloc, rancor::Error,
+///     ser::allocator::BoundedAllocator,
+/// };
+///
+/// let value = vec![vec![1, 2], vec![3, 4]];
+///
+/// let mut scratch = [MaybeUninit::<u8>::uninit(); 1024];
+/// let alloc = BoundedAllocator::new(&mut scratch);
+/// assert!(to_bytes_with_alloc::<_, Error>(&value, alloc).is_ok());
+///
+/// let alloc = BoundedAllocator::new(&mut []);
+/// assert!(to_bytes_with_alloc::<_, Error>(&value, alloc).is_err());
+/// ```
+#[derive(Debug)]
+pub struct BoundedAllocator<'a> {
+    inner: SubAllocator<'a>,
+}
+
+impl<'a> BoundedAllocator<'a> {
+    /// Creates a new bounded allocator which allocates from the given buffer.
+    pub fn new(bytes: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            inner: SubAllocator::new(bytes),
+        }
+    }
+
+    /// Returns the size of the buffer in bytes.
+    pub fn capacity(&self) -> usize {
+        self.inner.size
+    }
+
+    /// Returns the number of bytes of the buffer which are currently in use,
+    /// including alignment padding.
+    pub fn used(&self) -> usize {
+        self.inner.used
+    }
+}
+
+unsafe impl<E: Source> Allocator<E> for BoundedAllocator<'_> {
+    unsafe fn push_alloc(
+        &mut self,
+        layout: Layout,
+    ) -> Result<NonNull<[u8]>, E> {
+        let pos = self.inner.bytes.as_ptr() as usize + self.inner.used;
+        let pad = 0usize.wrapping_sub(pos) % layout.align();
+        if pad + layout.size() > self.inner.size - self.inner.used {
+            fail!(AllocationLimitExceeded {
+                layout,
+                used: self.inner.used,
+                capacity: self.inner.size,
+            });
+        }
+
+        // SAFETY: The safety requirements for `push_alloc` are the same as the
+        // requirements for `inner.push_alloc`. We just checked that the
+        // request fits, so it won't fail.
+        unsafe { self.inner.push_alloc(layout) }
+    }
+
+    unsafe fn pop_alloc(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+    ) -> Result<(), E> {
+        // SAFETY: The safety requirements for `pop_alloc` are the same as the
+        // requirements for `inner.pop_alloc`.
+        unsafe { self.inner.pop_alloc(ptr, layout) }
+    }
+}
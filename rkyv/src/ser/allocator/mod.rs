@@ -174,9 +174,9 @@ impl<T> From<T> for AllocationTracker<T> {
 
 #[cfg(test)]
 mod tests {
-    use core::mem::MaybeUninit;
+    use core::{alloc::Layout, mem::MaybeUninit};
 
-    use rancor::{Panic, Strategy};
+    use rancor::{Failure, Panic, Strategy};
 
     use crate::{
         api::serialize_with,
@@ -184,7 +184,7 @@ mod tests {
             allocator::{AllocationStats, AllocationTracker, SubAllocator},
             sharing::Unshare,
             writer::Buffer,
-            Serializer,
+            Allocator, Serializer,
         },
         util::Align,
         Serialize,
@@ -244,4 +244,59 @@ mod tests {
         assert_eq!(stats.max_allocations, 1);
         assert_ne!(stats.min_arena_capacity(), 0);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bounded() {
+        use rancor::Failure;
+
+        use crate::{
+            alloc::vec, api::high::to_bytes_with_alloc,
+            ser::allocator::BoundedAllocator,
+        };
+
+        let value = vec![vec![1, 2], vec![3, 4]];
+        let stats = track_serialize(&value);
+        let capacity =
+            stats.min_arena_capacity() + stats.min_arena_capacity_max_error();
+        let mut scratch = [MaybeUninit::<u8>::uninit(); 256];
+
+        let alloc = BoundedAllocator::new(&mut scratch[..capacity]);
+        to_bytes_with_alloc::<_, Failure>(&value, alloc).unwrap();
+
+        let alloc = BoundedAllocator::new(
+            &mut scratch[..stats.max_bytes_allocated - 1],
+        );
+        to_bytes_with_alloc::<_, Failure>(&value, alloc).unwrap_err();
+    }
+
+    #[test]
+    fn bounded_counts_padding() {
+        use crate::ser::allocator::BoundedAllocator;
+
+        let mut scratch = Align([MaybeUninit::<u8>::uninit(); 16]);
+        let mut alloc = BoundedAllocator::new(&mut *scratch);
+        let byte = Layout::new::<u8>();
+        let word = Layout::new::<u64>();
+
+        unsafe {
+            let first =
+                Allocator::<Failure>::push_alloc(&mut alloc, byte).unwrap();
+            assert_eq!(alloc.used(), 1);
+            // The second allocation is padded to the alignment of `u64`.
+            let second =
+                Allocator::<Failure>::push_alloc(&mut alloc, word).unwrap();
+            assert_eq!(alloc.used(), 16);
+            Allocator::<Failure>::push_alloc(&mut alloc, byte).unwrap_err();
+
+            Allocator::<Failure>::pop_alloc(&mut alloc, second.cast(), word)
+                .unwrap();
+            // The padding before the second allocation is released along with
+            // the first allocation.
+            assert_eq!(alloc.used(), 8);
+            Allocator::<Failure>::pop_alloc(&mut alloc, first.cast(), byte)
+                .unwrap();
+            assert_eq!(alloc.used(), 0);
+        }
+    }
 }
@@ -0,0 +1,222 @@
+//! Building archives incrementally by appending values.
+
+use core::{fmt, marker::PhantomData};
+
+use rancor::{Fallible, Strategy};
+
+use crate::{
+    api::serialize_with,
+    boxed::{ArchivedBox, BoxResolver},
+    ser::{Positional, Writer},
+    Archive, Place, Serialize,
+};
+
+/// The position of a value of type `T` which has already been serialized.
+///
+/// A `Pos` archives as an [`ArchivedBox`] pointing to the serialized value, so
+/// collections of positions can be serialized to build an index of
+/// previously-serialized values. For example, a `Vec<Pos<T>>` archives as an
+/// `ArchivedVec<ArchivedBox<T::Archived>>`.
+///
+/// See [`AppendWriter`] for an example.
+pub struct Pos<T> {
+    pos: usize,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Pos<T> {
+    /// Creates a new `Pos` from the position of a serialized `T`.
+    ///
+    /// # Safety
+    ///
+    /// An archived `T` must be located at `pos` in the output of the
+    /// serializer that `Pos` will be serialized with.
+    pub unsafe fn new_unchecked(pos: usize) -> Self {
+        Self {
+            pos,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the position of the serialized value.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<T> Clone for Pos<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Pos<T> {}
+
+impl<T> fmt::Debug for Pos<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pos").field(&self.pos).finish()
+    }
+}
+
+impl<T: Archive> Archive for Pos<T> {
+    type Archived = ArchivedBox<T::Archived>;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedBox::resolve_from_raw_parts(
+            BoxResolver::from_pos(self.pos),
+            (),
+            out,
+        );
+    }
+}
+
+impl<T: Archive, S: Fallible + ?Sized> Serialize<S> for Pos<T> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+/// A serializer adapter for building an archive one value at a time.
+///
+/// Each appended value is serialized immediately and a [`Pos`] to it is
+/// returned. Because serializers only ever write to the end of their output,
+/// the position of a serialized value never changes as more values are
+/// appended. Archived relative pointers are relative to their own position in
+/// the output, so they remain valid even if the underlying buffer is
+/// reallocated while it grows.
+///
+/// To finish the archive, append a root value last. The root will usually be
+/// an index of the positions of the other values, like a `Vec<Pos<T>>`.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     access,
+///     boxed::ArchivedBox,
+///     rancor::Error,
+///     ser::{allocator::Arena, sharing::Share, AppendWriter, Serializer},
+///     util::AlignedVec,
+///     vec::ArchivedVec,
+///     Archive, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize)]
+/// #[rkyv(check_bytes)]
+/// struct Record {
+///     id: u32,
+///     message: String,
+/// }
+///
+/// let mut arena = Arena::new();
+/// let mut writer = AppendWriter::new(Serializer::new(
+///     AlignedVec::<16>::new(),
+///     arena.acquire(),
+///     Share::new(),
+/// ));
+///
+/// // Append records over time, keeping track of where each one was written.
+/// let mut index = Vec::new();
+/// for id in 0..4 {
+///     let record = Record {
+///         id,
+///         message: format!("record {id}"),
+///     };
+///     index.push(writer.append::<_, Error>(&record).unwrap());
+/// }
+///
+/// // Finish the archive by appending the index as the root.
+/// writer.append::<_, Error>(&index).unwrap();
+/// let bytes = writer.into_inner().into_writer();
+///
+/// let archived =
+///     access::<ArchivedVec<ArchivedBox<ArchivedRecord>>, Error>(&bytes)
+///         .unwrap();
+/// assert_eq!(archived.len(), 4);
+/// assert_eq!(archived[2].id, 2);
+/// assert_eq!(archived[2].message, "record 2");
+/// ```
+#[derive(Debug, Default)]
+pub struct AppendWriter<S> {
+    serializer: S,
+}
+
+impl<S> AppendWriter<S> {
+    /// Creates a new `AppendWriter` which appends values to the given
+    /// serializer.
+    pub fn new(serializer: S) -> Self {
+        Self { serializer }
+    }
+
+    /// Serializes the given value and returns its position.
+    pub fn append<T, E>(&mut self, value: &T) -> Result<Pos<T>, E>
+    where
+        T: Serialize<Strategy<S, E>>,
+        S: Writer<E>,
+    {
+        let pos = serialize_with(value, &mut self.serializer)?;
+        // SAFETY: `serialize_with` returned the position of the archived value.
+        Ok(unsafe { Pos::new_unchecked(pos) })
+    }
+
+    /// Returns the current position of the serializer.
+    pub fn pos(&self) -> usize
+    where
+        S: Positional,
+    {
+        self.serializer.pos()
+    }
+
+    /// Consumes the `AppendWriter` and returns the underlying serializer.
+    pub fn into_inner(self) -> S {
+        self.serializer
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use rancor::Panic;
+
+    use crate::{
+        access_unchecked,
+        alloc::{string::String, vec::Vec},
+        boxed::ArchivedBox,
+        ser::{allocator::Arena, sharing::Share, AppendWriter, Serializer},
+        string::ArchivedString,
+        util::AlignedVec,
+        vec::ArchivedVec,
+    };
+
+    #[test]
+    fn append_then_index() {
+        let mut arena = Arena::new();
+        let mut writer = AppendWriter::new(Serializer::new(
+            AlignedVec::<16>::new(),
+            arena.acquire(),
+            Share::new(),
+        ));
+
+        let mut index = Vec::new();
+        let mut last_pos = 0;
+        for i in 0..100 {
+            let value = String::from("a fairly long string ") + &"x".repeat(i);
+            let pos = writer.append::<_, Panic>(&value).unwrap();
+            assert!(pos.pos() >= last_pos);
+            last_pos = pos.pos();
+            index.push(pos);
+        }
+        writer.append::<_, Panic>(&index).unwrap();
+        let bytes = writer.into_inner().into_writer();
+
+        type ArchivedIndex = ArchivedVec<ArchivedBox<ArchivedString>>;
+        let archived = unsafe { access_unchecked::<ArchivedIndex>(&bytes) };
+        assert_eq!(archived.len(), 100);
+        for (i, value) in archived.iter().enumerate() {
+            assert_eq!(
+                value.get().as_str(),
+                String::from("a fairly long string ") + &"x".repeat(i),
+            );
+        }
+    }
+}
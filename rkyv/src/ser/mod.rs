@@ -1,15 +1,19 @@
 //! Serialization traits and adapters.
 
 pub mod allocator;
+pub mod append;
 pub mod sharing;
+pub mod stats;
 pub mod writer;
 
-use ::core::{alloc::Layout, ptr::NonNull};
+use ::core::{alloc::Layout, hash::Hash, ptr::NonNull};
 
 #[doc(inline)]
 pub use self::{
-    allocator::Allocator,
-    sharing::{Sharing, SharingExt},
+    allocator::{Allocator, BoundedAllocator},
+    append::{AppendWriter, Pos},
+    sharing::{Deduplication, Sharing, SharingExt},
+    stats::{SerializerStats, StatsSerializer},
     writer::{Positional, Writer, WriterExt},
 };
 
@@ -88,4 +92,27 @@ impl<W, A, S: Sharing<E>, E> Sharing<E> for Serializer<W, A, S> {
     fn add_shared_ptr(&mut self, address: usize, pos: usize) -> Result<(), E> {
         self.sharing.add_shared_ptr(address, pos)
     }
+
+    fn start_shared_ptr(&mut self, address: usize) -> Result<(), E> {
+        self.sharing.start_shared_ptr(address)
+    }
+}
+
+impl<W, A, S, E> Deduplication<E> for Serializer<W, A, S>
+where
+    S: Deduplication<E>,
+{
+    fn get_dedup_pos<T>(&self, value: &T) -> Option<usize>
+    where
+        T: Hash + Eq + 'static,
+    {
+        self.sharing.get_dedup_pos(value)
+    }
+
+    fn add_dedup_pos<T>(&mut self, value: &T, pos: usize) -> Result<(), E>
+    where
+        T: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        self.sharing.add_dedup_pos(value, pos)
+    }
 }
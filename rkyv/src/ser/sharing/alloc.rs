@@ -1,9 +1,21 @@
-use core::{fmt, hash::BuildHasherDefault, mem::size_of};
+use core::{
+    any::{Any, TypeId},
+    fmt,
+    hash::{BuildHasherDefault, Hash},
+    mem::size_of,
+};
 
-use hashbrown::hash_map::{Entry, HashMap};
+use hashbrown::{
+    hash_map::{Entry, HashMap},
+    HashSet,
+};
 use rancor::{fail, Source};
 
-use crate::{hash::FxHasher64, ser::Sharing};
+use crate::{
+    alloc::{boxed::Box, vec::Vec},
+    hash::{hash_value_fx, FxHasher64},
+    ser::{sharing::Deduplication, Sharing},
+};
 
 #[derive(Debug)]
 struct DuplicateSharedPointer {
@@ -24,14 +36,42 @@ impl fmt::Display for DuplicateSharedPointer {
 #[cfg(feature = "std")]
 impl std::error::Error for DuplicateSharedPointer {}
 
+#[derive(Debug)]
+struct CyclicSharedPointer {
+    address: usize,
+}
+
+impl fmt::Display for CyclicSharedPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the shared value at {:#.*x} contains a pointer to itself",
+            size_of::<usize>() * 2,
+            self.address
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CyclicSharedPointer {}
+
 /// A shared pointer strategy that shares serializations of the same shared
 /// pointer.
+///
+/// It also shares serializations of equal values. To compare values, it keeps
+/// a clone of each deduplicated value.
 #[derive(Debug, Default)]
 pub struct Share {
     shared_address_to_pos:
         HashMap<usize, usize, BuildHasherDefault<FxHasher64>>,
+    pending_addresses: HashSet<usize, BuildHasherDefault<FxHasher64>>,
+    dedup_values:
+        HashMap<(TypeId, u64), DedupBucket, BuildHasherDefault<FxHasher64>>,
 }
 
+// The deduplicated values with the same type and hash, and their positions.
+type DedupBucket = Vec<(Box<dyn Any + Send + Sync>, usize)>;
+
 impl Share {
     /// Creates a new shared pointer unifier.
     #[inline]
@@ -47,6 +87,8 @@ impl Share {
                 capacity,
                 Default::default(),
             ),
+            pending_addresses: HashSet::default(),
+            dedup_values: HashMap::default(),
         }
     }
 }
@@ -57,6 +99,7 @@ impl<E: Source> Sharing<E> for Share {
     }
 
     fn add_shared_ptr(&mut self, address: usize, pos: usize) -> Result<(), E> {
+        self.pending_addresses.remove(&address);
         match self.shared_address_to_pos.entry(address) {
             Entry::Occupied(_) => {
                 fail!(DuplicateSharedPointer { address });
@@ -67,4 +110,38 @@ impl<E: Source> Sharing<E> for Share {
             }
         }
     }
+
+    fn start_shared_ptr(&mut self, address: usize) -> Result<(), E> {
+        if !self.pending_addresses.insert(address) {
+            fail!(CyclicSharedPointer { address });
+        }
+        Ok(())
+    }
+}
+
+impl<E> Deduplication<E> for Share {
+    fn get_dedup_pos<T>(&self, value: &T) -> Option<usize>
+    where
+        T: Hash + Eq + 'static,
+    {
+        let key = (TypeId::of::<T>(), hash_value_fx(value));
+        self.dedup_values
+            .get(&key)?
+            .iter()
+            .find_map(|(existing, pos)| {
+                (existing.downcast_ref::<T>() == Some(value)).then_some(*pos)
+            })
+    }
+
+    fn add_dedup_pos<T>(&mut self, value: &T, pos: usize) -> Result<(), E>
+    where
+        T: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        let key = (TypeId::of::<T>(), hash_value_fx(value));
+        self.dedup_values
+            .entry(key)
+            .or_default()
+            .push((Box::new(value.clone()), pos));
+        Ok(())
+    }
 }
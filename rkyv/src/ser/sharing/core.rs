@@ -1,7 +1,11 @@
-use crate::ser::Sharing;
+use core::hash::Hash;
+
+use crate::ser::{sharing::Deduplication, Sharing};
 
 /// A shared pointer strategy that duplicates serializations of the same shared
 /// pointer.
+///
+/// It also duplicates serializations of equal values.
 #[derive(Debug, Default)]
 pub struct Unshare;
 
@@ -14,3 +18,19 @@ impl<E> Sharing<E> for Unshare {
         Ok(())
     }
 }
+
+impl<E> Deduplication<E> for Unshare {
+    fn get_dedup_pos<T>(&self, _: &T) -> Option<usize>
+    where
+        T: Hash + Eq + 'static,
+    {
+        None
+    }
+
+    fn add_dedup_pos<T>(&mut self, _: &T, _: usize) -> Result<(), E>
+    where
+        T: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        Ok(())
+    }
+}
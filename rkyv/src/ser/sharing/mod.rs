@@ -4,6 +4,7 @@
 mod alloc;
 mod core;
 
+use ::core::hash::Hash;
 use rancor::{Fallible, Strategy};
 
 #[cfg(feature = "alloc")]
@@ -22,6 +23,16 @@ pub trait Sharing<E = <Self as Fallible>::Error> {
 
     /// Adds the serialized position of a shared pointer.
     fn add_shared_ptr(&mut self, address: usize, pos: usize) -> Result<(), E>;
+
+    /// Marks a shared pointer which has not been added yet as being
+    /// serialized.
+    ///
+    /// Returns an error if the shared pointer is already being serialized,
+    /// which means that the shared pointers form a cycle. The default
+    /// implementation does not detect cycles.
+    fn start_shared_ptr(&mut self, _address: usize) -> Result<(), E> {
+        Ok(())
+    }
 }
 
 impl<T, E> Sharing<E> for Strategy<T, E>
@@ -35,6 +46,10 @@ where
     fn add_shared_ptr(&mut self, address: usize, pos: usize) -> Result<(), E> {
         T::add_shared_ptr(self, address, pos)
     }
+
+    fn start_shared_ptr(&mut self, address: usize) -> Result<(), E> {
+        T::start_shared_ptr(self, address)
+    }
 }
 
 /// Helper methods for [`Sharing`].
@@ -58,6 +73,9 @@ pub trait SharingExt<E>: Sharing<E> {
     /// Archives the given shared value and returns its position. If the value
     /// has already been added then it returns the position of the
     /// previously added value.
+    ///
+    /// Returns an error if serializing the value would serialize the value
+    /// itself again, which would otherwise recurse forever.
     fn serialize_shared<T: SerializeUnsized<Self> + ?Sized>(
         &mut self,
         value: &T,
@@ -68,6 +86,7 @@ pub trait SharingExt<E>: Sharing<E> {
         if let Some(pos) = self.get_shared(value) {
             Ok(pos)
         } else {
+            self.start_shared_ptr(value as *const T as *const () as usize)?;
             let pos = value.serialize_unsized(self)?;
             self.add_shared(value, pos)?;
             Ok(pos)
@@ -76,3 +95,42 @@ pub trait SharingExt<E>: Sharing<E> {
 }
 
 impl<S, E> SharingExt<E> for S where S: Sharing<E> + ?Sized {}
+
+/// A value deduplication strategy.
+///
+/// Unlike [`Sharing`], which shares serializations of the same shared pointer,
+/// deduplication shares serializations of equal values. This trait is required
+/// to serialize fields with [`Dedup`](crate::with::Dedup).
+pub trait Deduplication<E = <Self as Fallible>::Error> {
+    /// Gets the position of a previously-added value which is equal to the
+    /// given value.
+    ///
+    /// Returns `None` if no equal value has been added.
+    fn get_dedup_pos<T>(&self, value: &T) -> Option<usize>
+    where
+        T: Hash + Eq + 'static;
+
+    /// Adds the serialized position of a value.
+    fn add_dedup_pos<T>(&mut self, value: &T, pos: usize) -> Result<(), E>
+    where
+        T: Hash + Eq + Clone + Send + Sync + 'static;
+}
+
+impl<T, E> Deduplication<E> for Strategy<T, E>
+where
+    T: Deduplication<E> + ?Sized,
+{
+    fn get_dedup_pos<U>(&self, value: &U) -> Option<usize>
+    where
+        U: Hash + Eq + 'static,
+    {
+        T::get_dedup_pos(self, value)
+    }
+
+    fn add_dedup_pos<U>(&mut self, value: &U, pos: usize) -> Result<(), E>
+    where
+        U: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        T::add_dedup_pos(self, value, pos)
+    }
+}
@@ -0,0 +1,242 @@
+//! Recording statistics about serialization.
+
+use core::{alloc::Layout, cell::Cell, hash::Hash, ptr::NonNull};
+
+use crate::ser::{Allocator, Deduplication, Positional, Sharing, Writer};
+
+/// Statistics recorded by a [`StatsSerializer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SerializerStats {
+    /// The total number of bytes written, including padding.
+    pub bytes_written: usize,
+    /// The largest number of bytes of scratch space which were allocated at
+    /// the same time.
+    pub peak_scratch: usize,
+    /// The number of shared pointers which reused the serialization of a
+    /// previous shared pointer to the same value.
+    pub shared_ptrs_deduplicated: usize,
+    /// The number of values which reused the serialization of a previous equal
+    /// value.
+    pub values_deduplicated: usize,
+}
+
+/// A serializer adapter which records statistics about serialization.
+///
+/// `StatsSerializer` forwards everything to the serializer it wraps, so the
+/// serialized bytes are the same as they would be without it. The recorded
+/// [`SerializerStats`] can be used to choose initial capacities for writers and
+/// arenas.
+///
+/// Scratch space is measured as the total size of the live allocations
+/// requested from the allocator. Allocators may reserve more memory than
+/// requested, so this is a lower bound on the memory they use.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use rkyv::{
+///     api::serialize_with,
+///     rancor::Error,
+///     ser::{allocator::Arena, sharing::Share, Serializer, StatsSerializer},
+///     util::AlignedVec,
+/// };
+///
+/// let shared = Rc::new("a shared string".to_string());
+/// let value = vec![shared.clone(), shared.clone(), shared];
+///
+/// let mut arena = Arena::new();
+/// let mut serializer = StatsSerializer::new(Serializer::new(
+///     AlignedVec::<16>::new(),
+///     arena.acquire(),
+///     Share::new(),
+/// ));
+/// serialize_with::<_, Error>(&value, &mut serializer).unwrap();
+///
+/// let stats = serializer.stats();
+/// let bytes = serializer.into_inner().into_writer();
+/// assert_eq!(stats.bytes_written, bytes.len());
+/// assert_eq!(stats.shared_ptrs_deduplicated, 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct StatsSerializer<S> {
+    serializer: S,
+    bytes_written: usize,
+    scratch: usize,
+    peak_scratch: usize,
+    shared_ptrs_deduplicated: Cell<usize>,
+    values_deduplicated: Cell<usize>,
+}
+
+impl<S> StatsSerializer<S> {
+    /// Creates a new `StatsSerializer` which records statistics about the
+    /// given serializer.
+    pub fn new(serializer: S) -> Self {
+        Self {
+            serializer,
+            bytes_written: 0,
+            scratch: 0,
+            peak_scratch: 0,
+            shared_ptrs_deduplicated: Cell::new(0),
+            values_deduplicated: Cell::new(0),
+        }
+    }
+
+    /// Returns the statistics recorded so far.
+    pub fn stats(&self) -> SerializerStats {
+        SerializerStats {
+            bytes_written: self.bytes_written,
+            peak_scratch: self.peak_scratch,
+            shared_ptrs_deduplicated: self.shared_ptrs_deduplicated.get(),
+            values_deduplicated: self.values_deduplicated.get(),
+        }
+    }
+
+    /// Consumes the `StatsSerializer` and returns the underlying serializer.
+    pub fn into_inner(self) -> S {
+        self.serializer
+    }
+}
+
+impl<S: Positional> Positional for StatsSerializer<S> {
+    fn pos(&self) -> usize {
+        self.serializer.pos()
+    }
+}
+
+impl<S: Writer<E>, E> Writer<E> for StatsSerializer<S> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), E> {
+        self.serializer.write(bytes)?;
+        self.bytes_written += bytes.len();
+        Ok(())
+    }
+}
+
+// SAFETY: `StatsSerializer` forwards all allocations to the underlying
+// allocator.
+unsafe impl<S: Allocator<E>, E> Allocator<E> for StatsSerializer<S> {
+    unsafe fn push_alloc(
+        &mut self,
+        layout: Layout,
+    ) -> Result<NonNull<[u8]>, E> {
+        // SAFETY: The safety requirements for `S::push_alloc()` are the same as
+        // the safety requirements for `push_alloc()`.
+        let result = unsafe { self.serializer.push_alloc(layout)? };
+        self.scratch += layout.size();
+        self.peak_scratch = self.peak_scratch.max(self.scratch);
+        Ok(result)
+    }
+
+    unsafe fn pop_alloc(
+        &mut self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+    ) -> Result<(), E> {
+        // SAFETY: The safety requirements for `S::pop_alloc()` are the same as
+        // the safety requirements for `pop_alloc()`.
+        unsafe { self.serializer.pop_alloc(ptr, layout)? };
+        self.scratch -= layout.size();
+        Ok(())
+    }
+}
+
+impl<S: Sharing<E>, E> Sharing<E> for StatsSerializer<S> {
+    fn get_shared_ptr(&self, address: usize) -> Option<usize> {
+        let result = self.serializer.get_shared_ptr(address);
+        if result.is_some() {
+            self.shared_ptrs_deduplicated
+                .set(self.shared_ptrs_deduplicated.get() + 1);
+        }
+        result
+    }
+
+    fn add_shared_ptr(&mut self, address: usize, pos: usize) -> Result<(), E> {
+        self.serializer.add_shared_ptr(address, pos)
+    }
+
+    fn start_shared_ptr(&mut self, address: usize) -> Result<(), E> {
+        self.serializer.start_shared_ptr(address)
+    }
+}
+
+impl<S: Deduplication<E>, E> Deduplication<E> for StatsSerializer<S> {
+    fn get_dedup_pos<T>(&self, value: &T) -> Option<usize>
+    where
+        T: Hash + Eq + 'static,
+    {
+        let result = self.serializer.get_dedup_pos(value);
+        if result.is_some() {
+            self.values_deduplicated
+                .set(self.values_deduplicated.get() + 1);
+        }
+        result
+    }
+
+    fn add_dedup_pos<T>(&mut self, value: &T, pos: usize) -> Result<(), E>
+    where
+        T: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        self.serializer.add_dedup_pos(value, pos)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use rancor::Panic;
+
+    use crate::{
+        alloc::{rc::Rc, string::String, vec, vec::Vec},
+        api::{high::to_bytes, serialize_with},
+        ser::{
+            allocator::Arena, sharing::Share, Serializer, SerializerStats,
+            StatsSerializer,
+        },
+        util::AlignedVec,
+    };
+
+    #[test]
+    fn stats_shared_rcs() {
+        let first = Rc::new(String::from("the first shared string"));
+        let second = Rc::new(String::from("the second shared string"));
+        let value =
+            vec![first.clone(), second.clone(), first.clone(), first, second];
+
+        let mut arena = Arena::new();
+        let mut serializer = StatsSerializer::new(Serializer::new(
+            AlignedVec::<16>::new(),
+            arena.acquire(),
+            Share::new(),
+        ));
+        serialize_with::<_, Panic>(&value, &mut serializer).unwrap();
+        let stats = serializer.stats();
+        let bytes = serializer.into_inner().into_writer();
+
+        // The output is the same as it would be without recording stats.
+        let expected = to_bytes::<Panic>(&value).unwrap();
+        assert_eq!(bytes.as_slice(), expected.as_slice());
+
+        assert_eq!(stats.bytes_written, bytes.len());
+        assert_eq!(stats.shared_ptrs_deduplicated, 3);
+        assert_eq!(stats.values_deduplicated, 0);
+        // The resolvers for the elements of the vec are held in scratch space.
+        assert!(stats.peak_scratch > 0);
+    }
+
+    #[test]
+    fn stats_empty() {
+        let mut arena = Arena::new();
+        let serializer = StatsSerializer::new(Serializer::new(
+            AlignedVec::<16>::new(),
+            arena.acquire(),
+            Share::new(),
+        ));
+        assert_eq!(serializer.stats(), SerializerStats::default());
+
+        let mut serializer = serializer;
+        serialize_with::<_, Panic>(&Vec::<u32>::new(), &mut serializer)
+            .unwrap();
+        assert_eq!(serializer.stats().shared_ptrs_deduplicated, 0);
+        assert_eq!(serializer.stats().peak_scratch, 0);
+    }
+}
@@ -1,3 +1,7 @@
+use core::alloc::GlobalAlloc;
+
+use rancor::{ResultExt as _, Source};
+
 use crate::{
     alloc::vec::Vec,
     ser::{Positional, Writer},
@@ -18,16 +22,75 @@ impl<E> Writer<E> for Vec<u8> {
     }
 }
 
-impl<const A: usize> Positional for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> Positional
+    for AlignedVec<ALIGNMENT, A>
+{
     #[inline]
     fn pos(&self) -> usize {
         self.len()
     }
 }
 
-impl<E, const A: usize> Writer<E> for AlignedVec<A> {
+impl<E, const ALIGNMENT: usize, A: GlobalAlloc> Writer<E>
+    for AlignedVec<ALIGNMENT, A>
+{
     fn write(&mut self, bytes: &[u8]) -> Result<(), E> {
         self.extend_from_slice(bytes);
         Ok(())
     }
 }
+
+/// Wraps an [`AlignedVec`] and equips it with a [`Writer`] which returns an
+/// error instead of panicking or aborting when growing the vector fails.
+///
+/// `AlignedVec` is also a `Writer`, but it panics if its capacity overflows and
+/// aborts if allocating fails. `TryAlignedVec` grows the vector with
+/// [`try_extend_from_slice`](AlignedVec::try_extend_from_slice) instead and
+/// returns any allocation failures as errors from `write`.
+///
+/// # Examples
+/// ```
+/// use rkyv::{
+///     api::high::to_bytes_in, rancor::Error, ser::writer::TryAlignedVec,
+/// };
+///
+/// let writer =
+///     to_bytes_in::<_, Error>(&42u32, TryAlignedVec::<16>::new()).unwrap();
+/// let bytes = writer.into_inner();
+/// assert_eq!(bytes.len(), 4);
+/// ```
+#[derive(Debug, Default)]
+pub struct TryAlignedVec<const A: usize = 16> {
+    inner: AlignedVec<A>,
+}
+
+impl<const A: usize> TryAlignedVec<A> {
+    /// Creates a new, empty `TryAlignedVec`.
+    pub fn new() -> Self {
+        Self::from_inner(AlignedVec::new())
+    }
+
+    /// Creates a new `TryAlignedVec` which appends bytes to the given
+    /// `AlignedVec`.
+    pub fn from_inner(inner: AlignedVec<A>) -> Self {
+        Self { inner }
+    }
+
+    /// Consumes the `TryAlignedVec` and returns the underlying `AlignedVec`.
+    pub fn into_inner(self) -> AlignedVec<A> {
+        self.inner
+    }
+}
+
+impl<const A: usize> Positional for TryAlignedVec<A> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<E: Source, const A: usize> Writer<E> for TryAlignedVec<A> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), E> {
+        self.inner.try_extend_from_slice(bytes).into_error()
+    }
+}
@@ -39,7 +39,9 @@ const _: () = {
 /// Wraps a byte buffer and equips it with [`Writer`].
 ///
 /// Common uses include archiving in `#![no_std]` environments and archiving
-/// small objects without allocating.
+/// small objects without allocating. Writing past the end of the buffer returns
+/// an error instead of allocating, so `Buffer` can also be used in builds
+/// without an allocator.
 ///
 /// # Examples
 ///
@@ -73,6 +75,55 @@ const _: () = {
 ///     panic!("archived event was of the wrong type");
 /// }
 /// ```
+///
+/// Without an allocator, scratch space can be provided with a
+/// [`SubAllocator`](crate::ser::allocator::SubAllocator):
+///
+/// ```
+/// use core::mem::MaybeUninit;
+///
+/// use rkyv::{
+///     access_unchecked,
+///     api::low::to_bytes_in_with_alloc,
+///     rancor::Failure,
+///     ser::{allocator::SubAllocator, writer::Buffer},
+///     util::Align,
+///     Archive, Archived, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize)]
+/// struct Reading {
+///     sensor: u8,
+///     value: i32,
+/// }
+///
+/// let reading = Reading {
+///     sensor: 3,
+///     value: -12,
+/// };
+///
+/// let mut output = Align([0u8; 64]);
+/// let mut scratch = [MaybeUninit::uninit(); 64];
+/// let bytes = to_bytes_in_with_alloc::<_, _, Failure>(
+///     &reading,
+///     Buffer::from(&mut *output),
+///     SubAllocator::new(&mut scratch),
+/// )
+/// .unwrap();
+///
+/// let archived = unsafe { access_unchecked::<Archived<Reading>>(&bytes) };
+/// assert_eq!(archived.sensor, 3);
+/// assert_eq!(archived.value, -12);
+///
+/// // Serializing into a buffer which is too small fails.
+/// let mut output = Align([0u8; 4]);
+/// let result = to_bytes_in_with_alloc::<_, _, Failure>(
+///     &reading,
+///     Buffer::from(&mut *output),
+///     SubAllocator::new(&mut scratch),
+/// );
+/// assert!(result.is_err());
+/// ```
 #[derive(Debug)]
 pub struct Buffer<'a> {
     ptr: NonNull<u8>,
@@ -174,11 +225,14 @@ impl<E: Source> Writer<E> for Buffer<'_> {
 mod tests {
     use core::mem::MaybeUninit;
 
-    use rancor::Panic;
+    use rancor::{Failure, Panic};
 
     use crate::{
-        api::serialize_with,
-        ser::{writer::Buffer, Serializer},
+        access_unchecked,
+        api::{low::to_bytes_in_with_alloc, serialize_with},
+        ser::{allocator::SubAllocator, writer::Buffer, Serializer},
+        util::Align,
+        Archived,
     };
 
     #[test]
@@ -206,4 +260,63 @@ mod tests {
             .iter()
             .all(|&b| b == 0));
     }
+
+    #[test]
+    fn align_writer() {
+        use rancor::Strategy;
+
+        use crate::ser::{Positional as _, Writer as _, WriterExt as _};
+
+        let mut bytes = [MaybeUninit::<u8>::new(0xcc); 256];
+        let mut buffer = Buffer::from(&mut bytes);
+        let writer = Strategy::<_, Panic>::wrap(&mut buffer);
+
+        writer.write(&[1]).unwrap();
+        assert_eq!(writer.align_for::<u32>().unwrap(), 4);
+        assert_eq!(writer.align_for::<u32>().unwrap(), 4);
+        assert_eq!(writer.align(128).unwrap(), 128);
+        assert_eq!(writer.pos(), 128);
+
+        assert_eq!(buffer[0], 1);
+        assert!(buffer[1..128].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn buffer_no_alloc() {
+        let mut output = Align([0u8; 64]);
+        let mut scratch = [MaybeUninit::uninit(); 64];
+        let bytes = to_bytes_in_with_alloc::<_, _, Panic>(
+            &[1u32, 2, 3, 4],
+            Buffer::from(&mut *output),
+            SubAllocator::new(&mut scratch),
+        )
+        .unwrap();
+        assert_eq!(bytes.len(), 16);
+
+        let archived =
+            unsafe { access_unchecked::<Archived<[u32; 4]>>(&bytes) };
+        assert_eq!(archived, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn buffer_no_alloc_overflow() {
+        let mut output = Align([0u8; 8]);
+        let mut scratch = [MaybeUninit::uninit(); 64];
+        to_bytes_in_with_alloc::<_, _, Failure>(
+            &[1u32, 2, 3, 4],
+            Buffer::from(&mut *output),
+            SubAllocator::new(&mut scratch),
+        )
+        .unwrap_err();
+
+        // Writes that exactly fill the buffer succeed.
+        let mut output = Align([0u8; 16]);
+        let bytes = to_bytes_in_with_alloc::<_, _, Failure>(
+            &[1u32, 2, 3, 4],
+            Buffer::from(&mut *output),
+            SubAllocator::new(&mut scratch),
+        )
+        .unwrap();
+        assert_eq!(bytes.len(), 16);
+    }
 }
@@ -6,15 +6,22 @@ mod core;
 #[cfg(feature = "std")]
 mod std;
 
-use ::core::mem;
+use ::core::{mem, ops::Range};
 use rancor::{Fallible, Strategy};
 
+#[cfg(feature = "alloc")]
+pub use self::alloc::*;
 pub use self::core::*;
 #[cfg(feature = "std")]
 pub use self::std::*;
-use crate::{Archive, ArchiveUnsized, Place, RelPtr};
+use crate::{Archive, ArchiveUnsized, Place, RelPtr, Serialize};
 
 /// A writer that knows its current position.
+///
+/// `Positional` is a supertrait of [`Writer`], so any serializer which can
+/// write can also report its position. Together with
+/// [`serialize_and_track`](WriterExt::serialize_and_track), this can be used to
+/// record where values were written in an archive.
 pub trait Positional {
     /// Returns the current position of the writer.
     fn pos(&self) -> usize;
@@ -29,6 +36,15 @@ where
     }
 }
 
+impl<T> Positional for &mut T
+where
+    T: Positional + ?Sized,
+{
+    fn pos(&self) -> usize {
+        T::pos(self)
+    }
+}
+
 /// A type that writes bytes to some output.
 ///
 /// A type that is [`Write`](::std::io::Write) can be wrapped in an [`IoWriter`]
@@ -52,18 +68,67 @@ where
     }
 }
 
+impl<T, E> Writer<E> for &mut T
+where
+    T: Writer<E> + ?Sized,
+{
+    fn write(&mut self, bytes: &[u8]) -> Result<(), E> {
+        T::write(self, bytes)
+    }
+}
+
 /// Helper methods for [`Writer`].
+///
+/// These are useful when writing custom data alongside archived values, for
+/// example to align the writer for a value before writing its raw bytes.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     rancor::{Error, Strategy},
+///     ser::{Positional, Writer, WriterExt as _},
+///     util::AlignedVec,
+/// };
+///
+/// let mut bytes = AlignedVec::<16>::new();
+/// let writer = Strategy::<_, Error>::wrap(&mut bytes);
+/// writer.write(&[1, 2, 3]).unwrap();
+///
+/// let pos = writer.align_for::<u64>().unwrap();
+/// assert_eq!(pos, 8);
+/// writer.write(&42u64.to_le_bytes()).unwrap();
+///
+/// assert_eq!(writer.align(16).unwrap(), 16);
+/// assert_eq!(writer.pos(), 16);
+///
+/// assert_eq!(&bytes[3..8], &[0; 5]);
+/// ```
 pub trait WriterExt<E>: Writer<E> {
     /// Advances the given number of bytes as padding.
+    ///
+    /// The padding bytes are written as zeroes.
     fn pad(&mut self, padding: usize) -> Result<(), E> {
         const MAX_ZEROES: usize = 32;
         const ZEROES: [u8; MAX_ZEROES] = [0; MAX_ZEROES];
-        debug_assert!(padding < MAX_ZEROES);
 
-        self.write(&ZEROES[0..padding])
+        let mut remaining = padding;
+        while remaining > 0 {
+            let len = remaining.min(MAX_ZEROES);
+            self.write(&ZEROES[0..len])?;
+            remaining -= len;
+        }
+        Ok(())
     }
 
     /// Aligns the position of the serializer to the given alignment.
+    ///
+    /// Returns the new position of the serializer, which is the smallest
+    /// multiple of `align` that is greater than or equal to the previous
+    /// position. Nothing is written if the serializer is already aligned. The
+    /// next value written will start at the returned position.
+    ///
+    /// `align` must be a power of two.
     fn align(&mut self, align: usize) -> Result<usize, E> {
         let mask = align - 1;
         debug_assert_eq!(align & mask, 0);
@@ -74,10 +139,56 @@ pub trait WriterExt<E>: Writer<E> {
 
     /// Aligns the position of the serializer to be suitable to write the given
     /// type.
+    ///
+    /// Returns the new position of the serializer. See
+    /// [`align`](WriterExt::align) for more details.
     fn align_for<T>(&mut self) -> Result<usize, E> {
         self.align(mem::align_of::<T>())
     }
 
+    /// Serializes the given value and returns its resolver along with the
+    /// range of bytes written while serializing it.
+    ///
+    /// Serializing a value only writes its dependencies, like the contents of
+    /// a string or vec. The archived value itself is written later when it is
+    /// resolved, for example by
+    /// [`resolve_aligned`](WriterExt::resolve_aligned).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rkyv::{
+    ///     rancor::{Error, Strategy},
+    ///     ser::WriterExt as _,
+    ///     string::ArchivedString,
+    ///     util::AlignedVec,
+    /// };
+    ///
+    /// let mut bytes = AlignedVec::<16>::new();
+    /// let writer = Strategy::<_, Error>::wrap(&mut bytes);
+    ///
+    /// let value = "a string which is too long to be inlined".to_string();
+    /// let (resolver, range) = writer.serialize_and_track(&value).unwrap();
+    /// assert_eq!(range, 0..value.len());
+    ///
+    /// writer.align_for::<ArchivedString>().unwrap();
+    /// let pos = unsafe { writer.resolve_aligned(&value, resolver).unwrap() };
+    /// assert!(pos >= range.end);
+    ///
+    /// assert_eq!(&bytes[range], value.as_bytes());
+    /// ```
+    fn serialize_and_track<T: Serialize<Self> + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(T::Resolver, Range<usize>), E>
+    where
+        Self: Fallible<Error = E>,
+    {
+        let start = self.pos();
+        let resolver = value.serialize(self)?;
+        Ok((resolver, start..self.pos()))
+    }
+
     /// Resolves the given value with its resolver and writes the archived type.
     ///
     /// Returns the position of the written archived type.
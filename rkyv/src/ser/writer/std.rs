@@ -7,6 +7,14 @@ use crate::ser::{Positional, Writer};
 /// Wraps a type that implements [`io::Write`](std::io::Write) and equips it
 /// with [`Writer`].
 ///
+/// Serializers only ever append bytes to their writer: each object is written
+/// after all of the objects that it points to, and previously-written bytes are
+/// never revisited. Because of this, `IoWriter` does not need to buffer or
+/// back-patch any data and passes bytes straight through to the inner writer.
+/// The root object is always written last. If the inner writer is unbuffered
+/// (e.g. a `File` or `TcpStream`), consider wrapping it in a
+/// [`BufWriter`](std::io::BufWriter) to reduce the number of small writes.
+///
 /// # Examples
 /// ```
 /// # use rkyv::ser::{Writer, Positional, writer::IoWriter};
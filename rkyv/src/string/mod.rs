@@ -14,7 +14,7 @@ use core::{
 };
 
 use munge::munge;
-use rancor::Fallible;
+use rancor::{Fallible, Panic, ResultExt as _};
 use repr::{ArchivedStringRepr, INLINE_CAPACITY};
 
 use crate::{Place, Portable, SerializeUnsized};
@@ -43,6 +43,34 @@ impl ArchivedString {
         self.repr.as_str()
     }
 
+    /// Returns the bytes of the `ArchivedString`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Returns whether the string is stored inside the `ArchivedString`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn is_inline(&self) -> bool {
+        self.repr.is_inline()
+    }
+
+    /// Divides the `ArchivedString` into two string slices at the given byte
+    /// index.
+    ///
+    /// Returns `None` if `mid` is out of bounds or is not on a `char`
+    /// boundary.
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> Option<(&str, &str)> {
+        let s = self.as_str();
+        if s.is_char_boundary(mid) {
+            Some(s.split_at(mid))
+        } else {
+            None
+        }
+    }
+
     /// Extracts a pinned mutable string slice containing the entire
     /// `ArchivedString`.
     #[inline]
@@ -73,6 +101,63 @@ impl ArchivedString {
         }
     }
 
+    /// Resolves an archived string from a resolver returned by
+    /// [`serialize_from_display`](ArchivedString::serialize_from_display).
+    pub fn resolve_from_display(resolver: DisplayResolver, out: Place<Self>) {
+        munge!(let ArchivedString { repr } = out);
+        if resolver.len <= repr::INLINE_CAPACITY {
+            // SAFETY: The inline bytes of the resolver were copied from a
+            // formatted string of length `resolver.len`, so they are valid
+            // UTF-8.
+            let value = unsafe {
+                str::from_utf8_unchecked(&resolver.inline[..resolver.len])
+            };
+            unsafe {
+                ArchivedStringRepr::emplace_inline(value, repr.ptr());
+            }
+        } else {
+            unsafe {
+                ArchivedStringRepr::try_emplace_out_of_line_len::<Panic>(
+                    resolver.len,
+                    resolver.pos,
+                    repr,
+                )
+                .always_ok();
+            }
+        }
+    }
+
+    /// Serializes an archived string by formatting the given value.
+    ///
+    /// The value is only formatted once, so the archived string is always
+    /// consistent even if the value formats differently each time.
+    #[cfg(feature = "alloc")]
+    pub fn serialize_from_display<T, S>(
+        value: &T,
+        serializer: &mut S,
+    ) -> Result<DisplayResolver, S::Error>
+    where
+        T: fmt::Display + ?Sized,
+        S: Fallible + ?Sized,
+        str: SerializeUnsized<S>,
+    {
+        use crate::alloc::string::ToString;
+
+        let formatted = value.to_string();
+        let mut inline = [0; INLINE_CAPACITY];
+        let pos = if formatted.len() <= INLINE_CAPACITY {
+            inline[..formatted.len()].copy_from_slice(formatted.as_bytes());
+            0
+        } else {
+            formatted.as_str().serialize_unsized(serializer)?
+        };
+        Ok(DisplayResolver {
+            len: formatted.len(),
+            pos,
+            inline,
+        })
+    }
+
     /// Serializes an archived string from a given `str`.
     pub fn serialize_from_str<S: Fallible + ?Sized>(
         value: &str,
@@ -238,6 +323,14 @@ pub struct StringResolver {
     pos: usize,
 }
 
+/// The resolver for a string formatted by
+/// [`serialize_from_display`](ArchivedString::serialize_from_display).
+pub struct DisplayResolver {
+    len: usize,
+    pos: usize,
+    inline: [u8; INLINE_CAPACITY],
+}
+
 #[cfg(feature = "bytecheck")]
 mod verify {
     use bytecheck::{
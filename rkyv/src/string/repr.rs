@@ -189,6 +189,22 @@ impl ArchivedStringRepr {
         value: &str,
         target: usize,
         out: Place<Self>,
+    ) -> Result<(), E> {
+        // SAFETY: The caller has guaranteed that the length of `value` is
+        // greater than `INLINE_CAPACITY`.
+        unsafe { Self::try_emplace_out_of_line_len(value.len(), target, out) }
+    }
+
+    /// Emplaces a new out-of-line representation for a `str` of the given
+    /// length.
+    ///
+    /// # Safety
+    ///
+    /// `value_len` must be greater than [`INLINE_CAPACITY`].
+    pub unsafe fn try_emplace_out_of_line_len<E: Source>(
+        value_len: usize,
+        target: usize,
+        out: Place<Self>,
     ) -> Result<(), E> {
         munge! {
             let ArchivedStringRepr {
@@ -196,7 +212,7 @@ impl ArchivedStringRepr {
             } = out;
         }
         len.write(ArchivedUsize::from_native(
-            value.len().try_into().into_error()?,
+            value_len.try_into().into_error()?,
         ));
 
         let off = crate::rel_ptr::signed_offset(out.pos(), target)?;
@@ -21,6 +21,70 @@ pub struct ArchivedDuration {
     nanos: ArchivedU32,
 }
 
+/// An archived [`SystemTime`](::std::time::SystemTime).
+///
+/// Archived system times are stored as an [`ArchivedDuration`] since the UNIX
+/// epoch along with whether the time occurs before the epoch. The UNIX epoch
+/// itself is always stored as being after the epoch, so each time has exactly
+/// one archived representation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Portable)]
+#[rkyv(crate)]
+#[repr(C)]
+#[cfg_attr(
+    feature = "bytecheck",
+    derive(bytecheck::CheckBytes),
+    check_bytes(verify)
+)]
+pub struct ArchivedSystemTime {
+    since_epoch: ArchivedDuration,
+    before_epoch: bool,
+}
+
+impl ArchivedSystemTime {
+    /// Returns the distance between this time and the UNIX epoch.
+    ///
+    /// Use [`is_before_epoch`](Self::is_before_epoch) to determine which side
+    /// of the epoch the time is on.
+    #[inline]
+    pub const fn distance_from_epoch(&self) -> &ArchivedDuration {
+        &self.since_epoch
+    }
+
+    /// Returns whether this time occurs before the UNIX epoch.
+    #[inline]
+    pub const fn is_before_epoch(&self) -> bool {
+        self.before_epoch
+    }
+
+    /// Constructs an archived system time at the given position.
+    ///
+    /// `before_epoch` is ignored if `secs` and `nanos` are both zero. This
+    /// function is guaranteed not to write any uninitialized bytes to `out`.
+    ///
+    /// # Safety
+    ///
+    /// `out` must point to memory suitable for holding an
+    /// `ArchivedSystemTime`.
+    #[inline]
+    pub unsafe fn emplace(
+        secs: u64,
+        nanos: u32,
+        before_epoch: bool,
+        out: *mut ArchivedSystemTime,
+    ) {
+        use core::ptr::addr_of_mut;
+
+        let out_since_epoch = unsafe { addr_of_mut!((*out).since_epoch) };
+        unsafe {
+            ArchivedDuration::emplace(secs, nanos, out_since_epoch);
+        }
+        let out_before_epoch = unsafe { addr_of_mut!((*out).before_epoch) };
+        unsafe {
+            out_before_epoch.write(before_epoch && (secs != 0 || nanos != 0));
+        }
+    }
+}
+
 const NANOS_PER_SEC: u32 = 1_000_000_000;
 const NANOS_PER_MILLI: u32 = 1_000_000;
 const NANOS_PER_MICRO: u32 = 1_000;
@@ -151,7 +215,7 @@ mod verify {
     };
     use rancor::fail;
 
-    use super::ArchivedDuration;
+    use super::{ArchivedDuration, ArchivedSystemTime};
 
     /// An error resulting from an invalid duration.
     ///
@@ -188,4 +252,33 @@ mod verify {
             }
         }
     }
+
+    /// An error resulting from an invalid system time.
+    ///
+    /// The UNIX epoch must not be stored as being before the epoch.
+    #[derive(Debug)]
+    pub struct SystemTimeError;
+
+    impl fmt::Display for SystemTimeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "the UNIX epoch is marked as being before the epoch")
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for SystemTimeError {}
+
+    unsafe impl<C> Verify<C> for ArchivedSystemTime
+    where
+        C: Fallible + ?Sized,
+        C::Error: Source,
+    {
+        fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+            if self.before_epoch && self.since_epoch.as_nanos() == 0 {
+                fail!(SystemTimeError);
+            } else {
+                Ok(())
+            }
+        }
+    }
 }
@@ -237,6 +237,80 @@ pub trait Archive {
     fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>);
 }
 
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T: super::Archive> Sealed for T {}
+}
+
+/// The layout of the archived representation of a type.
+///
+/// This is implemented for every type that implements [`Archive`], and can't be
+/// implemented manually.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{traits::ArchivedLayout, Archive};
+///
+/// #[derive(Archive)]
+/// struct Record {
+///     id: u64,
+///     values: [u16; 3],
+/// }
+///
+/// // Enough space for 16 archived records
+/// const BUFFER_SIZE: usize = 16 * Record::ARCHIVED_SIZE;
+/// assert_eq!(BUFFER_SIZE, 16 * core::mem::size_of::<ArchivedRecord>());
+/// ```
+pub trait ArchivedLayout: Archive + sealed::Sealed {
+    /// The size of the archived representation of this type in bytes.
+    ///
+    /// This is always `size_of::<Self::Archived>()`.
+    const ARCHIVED_SIZE: usize;
+
+    /// The alignment of the archived representation of this type in bytes.
+    ///
+    /// This is always `align_of::<Self::Archived>()`.
+    const ARCHIVED_ALIGN: usize;
+}
+
+impl<T: Archive> ArchivedLayout for T {
+    const ARCHIVED_SIZE: usize = core::mem::size_of::<T::Archived>();
+    const ARCHIVED_ALIGN: usize = core::mem::align_of::<T::Archived>();
+}
+
+/// A type with a hash of its schema.
+///
+/// This is implemented by the `Archive` derive macro. The hash is computed from
+/// the definition of the type, and changes whenever the names, types, or
+/// wrappers of its fields change. It can be stored alongside archives to detect
+/// when they were written with a different definition of the type.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{traits::SchemaHash, Archive};
+///
+/// #[derive(Archive)]
+/// struct Record {
+///     id: u64,
+///     values: [u16; 3],
+/// }
+///
+/// #[derive(Archive)]
+/// struct RecordV2 {
+///     id: u64,
+///     values: [u32; 3],
+/// }
+///
+/// assert_ne!(Record::SCHEMA_HASH, RecordV2::SCHEMA_HASH);
+/// ```
+pub trait SchemaHash {
+    /// A hash of the schema of this type.
+    const SCHEMA_HASH: u64;
+}
+
 /// Converts a type to its archived form.
 ///
 /// Objects perform any supportive serialization during
@@ -268,6 +342,108 @@ pub trait Deserialize<T, D: Fallible + ?Sized> {
     fn deserialize(&self, deserializer: &mut D) -> Result<T, D::Error>;
 }
 
+/// Deserializes an archived value into an existing value.
+///
+/// Deserializing into an existing value allows its allocations to be reused.
+/// For example, deserializing an `ArchivedVec` into an existing `Vec` reuses
+/// the capacity of the `Vec` instead of allocating a new one. This is useful
+/// for loops which repeatedly deserialize large values.
+///
+/// The default implementation deserializes a new value and assigns it to
+/// `place`, which doesn't reuse any allocations.
+///
+/// # Partial failure
+///
+/// If `deserialize_into` returns an error, `place` is left in a valid but
+/// unspecified state. Some of its fields may have been overwritten with
+/// deserialized values, and containers may have been cleared or partially
+/// filled.
+///
+/// This can be derived with [`DeserializeInto`](macro@crate::DeserializeInto).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     access, rancor::Error, to_bytes, Archive, Deserialize, DeserializeInto,
+///     Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize, DeserializeInto)]
+/// #[rkyv(check_bytes)]
+/// struct Frame {
+///     id: u32,
+///     samples: Vec<f32>,
+/// }
+///
+/// let mut frame = Frame {
+///     id: 0,
+///     samples: Vec::with_capacity(1024),
+/// };
+/// let capacity = frame.samples.capacity();
+///
+/// for id in 0..4 {
+///     let bytes = to_bytes::<Error>(&Frame {
+///         id,
+///         samples: vec![id as f32; 256],
+///     })
+///     .unwrap();
+///     let archived = access::<ArchivedFrame, Error>(&bytes).unwrap();
+///     rkyv::api::high::deserialize_into::<Frame, Error>(archived, &mut frame)
+///         .unwrap();
+///
+///     assert_eq!(frame.id, id);
+///     assert_eq!(frame.samples.len(), 256);
+///     assert_eq!(frame.samples.capacity(), capacity);
+/// }
+/// ```
+pub trait DeserializeInto<T, D: Fallible + ?Sized>: Deserialize<T, D> {
+    /// Deserializes using the given deserializer, writing the result into
+    /// `place`.
+    fn deserialize_into(
+        &self,
+        place: &mut T,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        *place = self.deserialize(deserializer)?;
+        Ok(())
+    }
+}
+
+/// Information about the active variant of an archived enum.
+///
+/// This is implemented for the archived types of enums that derive
+/// [`Archive`], unless the archived type is set with `as = ...`.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{rancor::Error, traits::ArchivedEnum, Archive, Serialize};
+///
+/// #[derive(Archive, Serialize)]
+/// #[repr(i8)]
+/// enum Level {
+///     Low = -1,
+///     High = 1,
+/// }
+///
+/// let bytes = rkyv::to_bytes::<Error>(&Level::Low).unwrap();
+/// let archived = unsafe { rkyv::access_unchecked::<ArchivedLevel>(&bytes) };
+/// assert_eq!(archived.variant_name(), "Low");
+/// assert_eq!(archived.discriminant(), -1);
+/// ```
+pub trait ArchivedEnum {
+    /// Returns the name of the active variant.
+    fn variant_name(&self) -> &'static str;
+
+    /// Returns the discriminant of the active variant in the unarchived type.
+    ///
+    /// The discriminant is returned as an `i128` so that it keeps its value for
+    /// every integer `repr`: negative discriminants stay negative, and large
+    /// `u64` discriminants don't wrap.
+    fn discriminant(&self) -> i128;
+}
+
 /// A counterpart of [`Archive`] that's suitable for unsized types.
 ///
 /// Unlike `Archive`, types that implement `ArchiveUnsized` must be serialized
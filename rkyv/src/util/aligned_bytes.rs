@@ -0,0 +1,270 @@
+use core::{
+    array::TryFromSliceError,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// A marker type for an alignment of `ALIGN` bytes.
+///
+/// `Alignment<ALIGN>` implements [`SupportedAlignment`] for every power of two
+/// from 1 to 4096.
+#[derive(Debug)]
+pub struct Alignment<const ALIGN: usize>;
+
+/// An alignment which can be used with [`AlignedBytes`].
+///
+/// This trait is sealed and cannot be implemented outside of rkyv.
+pub trait SupportedAlignment: sealed::Sealed {
+    #[doc(hidden)]
+    type Archetype: Copy;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+#[doc(hidden)]
+pub mod archetypes {
+    macro_rules! define_archetypes {
+        ($($align:literal => $name:ident),* $(,)?) => {
+            $(
+                #[derive(Clone, Copy)]
+                #[repr(align($align))]
+                pub struct $name;
+
+                impl super::sealed::Sealed for super::Alignment<$align> {}
+
+                impl super::SupportedAlignment for super::Alignment<$align> {
+                    type Archetype = $name;
+                }
+            )*
+        };
+    }
+
+    define_archetypes! {
+        1 => Align1,
+        2 => Align2,
+        4 => Align4,
+        8 => Align8,
+        16 => Align16,
+        32 => Align32,
+        64 => Align64,
+        128 => Align128,
+        256 => Align256,
+        512 => Align512,
+        1024 => Align1024,
+        2048 => Align2048,
+        4096 => Align4096,
+    }
+}
+
+/// A fixed-size array of `N` bytes which is aligned to `ALIGN` bytes.
+///
+/// `AlignedBytes` derefs to `[u8]`, and can be used anywhere an aligned buffer
+/// of a known size is needed without allocating. Because it can be constructed
+/// in a `const` context, it's especially useful for test fixtures which need a
+/// particular alignment. `ALIGN` must be a power of two from 1 to 4096.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{access, rancor::Error, util::AlignedBytes, Archived};
+///
+/// // The archived bytes of `u32::MAX`.
+/// static BYTES: AlignedBytes<4, 4> = AlignedBytes::new([0xff; 4]);
+///
+/// let archived = access::<Archived<u32>, Error>(&*BYTES).unwrap();
+/// assert_eq!(*archived, u32::MAX);
+///
+/// let bytes = AlignedBytes::<4, 16>::try_from(BYTES.as_slice()).unwrap();
+/// assert_eq!(bytes.as_ptr() as usize % 16, 0);
+/// assert_eq!(&*bytes, &*BYTES);
+/// ```
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct AlignedBytes<const N: usize, const ALIGN: usize>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    _align: [<Alignment<ALIGN> as SupportedAlignment>::Archetype; 0],
+    bytes: [u8; N],
+}
+
+impl<const N: usize, const ALIGN: usize> AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    /// Returns new aligned bytes with the given contents.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self { _align: [], bytes }
+    }
+
+    /// Returns new aligned bytes filled with zeroes.
+    pub const fn zeroed() -> Self {
+        Self::new([0; N])
+    }
+
+    /// Returns the bytes as a slice.
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    /// Consumes the aligned bytes and returns the inner array.
+    pub const fn into_inner(self) -> [u8; N] {
+        self.bytes
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> Default for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> fmt::Debug for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> Deref for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> DerefMut for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> AsRef<[u8]> for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> AsMut<[u8]> for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> From<[u8; N]>
+    for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn from(bytes: [u8; N]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> TryFrom<&[u8]>
+    for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    type Error = TryFromSliceError;
+
+    /// Copies the given bytes into new aligned bytes.
+    ///
+    /// Returns an error if the length of `bytes` is not `N`.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::new(bytes.try_into()?))
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> PartialEq for AlignedBytes<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> Eq for AlignedBytes<N, ALIGN> where
+    Alignment<ALIGN>: SupportedAlignment
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use super::AlignedBytes;
+
+    #[test]
+    fn aligned_bytes_layout() {
+        assert_eq!(align_of::<AlignedBytes<3, 1>>(), 1);
+        assert_eq!(size_of::<AlignedBytes<3, 1>>(), 3);
+        assert_eq!(align_of::<AlignedBytes<3, 64>>(), 64);
+        assert_eq!(size_of::<AlignedBytes<3, 64>>(), 64);
+        assert_eq!(align_of::<AlignedBytes<0, 4096>>(), 4096);
+        assert_eq!(size_of::<AlignedBytes<0, 4096>>(), 0);
+
+        let bytes = [AlignedBytes::<5, 32>::new([1, 2, 3, 4, 5]); 3];
+        for b in bytes.iter() {
+            assert_eq!(b.as_ptr() as usize % 32, 0);
+            assert_eq!(&**b, &[1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn aligned_bytes_conversions() {
+        const ZEROES: AlignedBytes<4, 8> = AlignedBytes::zeroed();
+        assert_eq!(ZEROES.as_slice(), &[0; 4]);
+        assert_eq!(ZEROES, AlignedBytes::default());
+
+        let mut bytes = AlignedBytes::<4, 8>::from([1, 2, 3, 4]);
+        bytes[0] = 5;
+        assert_eq!(bytes.into_inner(), [5, 2, 3, 4]);
+
+        let slice: &[u8] = &[1, 2, 3];
+        AlignedBytes::<4, 8>::try_from(slice).unwrap_err();
+        let bytes = AlignedBytes::<3, 8>::try_from(slice).unwrap();
+        assert_eq!(bytes.as_ref(), slice);
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn aligned_bytes_access() {
+        use rancor::Failure;
+
+        use crate::{access, Archived};
+
+        // The bytes are a palindrome so the value doesn't depend on endianness.
+        static BYTES: AlignedBytes<8, 8> =
+            AlignedBytes::new([1, 2, 3, 4, 4, 3, 2, 1]);
+
+        let archived = access::<Archived<u64>, Failure>(&BYTES).unwrap();
+        assert_eq!(*archived, 0x0102_0304_0403_0201);
+    }
+}
@@ -1,9 +1,9 @@
 use core::{
-    alloc::Layout,
+    alloc::{GlobalAlloc, Layout},
     borrow::{Borrow, BorrowMut},
     fmt,
     ops::{Deref, DerefMut, Index, IndexMut},
-    ptr::NonNull,
+    ptr::{self, NonNull},
     slice,
 };
 
@@ -21,47 +21,113 @@ use crate::{
     Place,
 };
 
-/// A vector of bytes that aligns its memory to 16 bytes.
+/// The error type for `try_reserve` methods on [`AlignedVec`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryReserveError {
+    layout: Option<Layout>,
+}
+
+impl TryReserveError {
+    fn capacity_overflow() -> Self {
+        Self { layout: None }
+    }
+
+    fn alloc_error(layout: Layout) -> Self {
+        Self {
+            layout: Some(layout),
+        }
+    }
+
+    fn handle(self) -> ! {
+        match self.layout {
+            None => panic!("cannot reserve a larger AlignedVec"),
+            Some(layout) => handle_alloc_error(layout),
+        }
+    }
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.layout {
+            None => write!(f, "cannot reserve a larger AlignedVec"),
+            Some(layout) => write!(
+                f,
+                "memory allocation of {} bytes with alignment {} failed",
+                layout.size(),
+                layout.align(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {}
+
+/// The global memory allocator.
 ///
-/// The alignment also applies to `ArchivedAlignedVec`, which is useful for
-/// aligning opaque bytes inside of an archived data type.
+/// This is the default allocator for [`AlignedVec`], and forwards all calls to
+/// the registered global allocator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+// SAFETY: `Global` forwards all calls to the registered global allocator.
+unsafe impl GlobalAlloc for Global {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: The caller has upheld the safety requirements of `alloc`.
+        unsafe { alloc(layout) }
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller has upheld the safety requirements of `dealloc`.
+        unsafe { dealloc(ptr, layout) }
+    }
+
+    #[inline]
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        // SAFETY: The caller has upheld the safety requirements of `realloc`.
+        unsafe { realloc(ptr, layout, new_size) }
+    }
+}
+
 /// A vector of bytes that aligns its memory to the specified alignment.
 ///
 /// The alignment also applies to `ArchivedAlignedVec`, which is useful for
 /// aligning opaque bytes inside of an archived data type.
 ///
+/// Memory is allocated with `A`, which defaults to the [`Global`] allocator.
+/// Any [`GlobalAlloc`] can be used instead with the `_in` constructors like
+/// [`new_in`](AlignedVec::new_in).
+///
 /// ```
 /// # use rkyv::util::AlignedVec;
 /// let bytes = AlignedVec::<4096>::with_capacity(1);
 /// assert_eq!(bytes.as_ptr() as usize % 4096, 0);
 /// ```
-pub struct AlignedVec<const ALIGNMENT: usize = 16> {
+pub struct AlignedVec<const ALIGNMENT: usize = 16, A: GlobalAlloc = Global> {
     ptr: NonNull<u8>,
     cap: usize,
     len: usize,
+    alloc: A,
 }
 
-impl<const A: usize> Drop for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> Drop for AlignedVec<ALIGNMENT, A> {
     fn drop(&mut self) {
         if self.cap != 0 {
             unsafe {
-                dealloc(self.ptr.as_ptr(), self.layout());
+                self.alloc.dealloc(self.ptr.as_ptr(), self.layout());
             }
         }
     }
 }
 
 impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
-    /// The alignment of the vector
-    pub const ALIGNMENT: usize = ALIGNMENT;
-
-    /// Maximum capacity of the vector.
-    ///
-    /// Dictated by the requirements of [`Layout`]. "`size`, when rounded up to
-    /// the nearest multiple of `align`, must not overflow `isize` (i.e. the
-    /// rounded value must be less than or equal to `isize::MAX`)".
-    pub const MAX_CAPACITY: usize = isize::MAX as usize - (Self::ALIGNMENT - 1);
-
     /// Constructs a new, empty `AlignedVec`.
     ///
     /// The vector will not allocate until elements are pushed into it.
@@ -72,7 +138,7 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
     /// let mut vec = AlignedVec::<16>::new();
     /// ```
     pub fn new() -> Self {
-        Self::with_capacity(0)
+        Self::new_in(Global)
     }
 
     /// Constructs a new, empty `AlignedVec` with the specified capacity.
@@ -102,6 +168,111 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
     /// assert!(vec.capacity() >= 11);
     /// ```
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Constructs a new, empty `AlignedVec` with the specified capacity,
+    /// returning an error if the allocation fails.
+    ///
+    /// See [`with_capacity`](AlignedVec::with_capacity) for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rkyv::util::AlignedVec;
+    /// let vec = AlignedVec::<16>::try_with_capacity(10).unwrap();
+    /// assert_eq!(vec.capacity(), 10);
+    ///
+    /// assert!(AlignedVec::<16>::try_with_capacity(usize::MAX).is_err());
+    /// ```
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+
+    /// Constructs a new `AlignedVec` with the contents of the given
+    /// `Vec<u8>`.
+    ///
+    /// This method always allocates and copies the bytes, so the returned
+    /// vector is aligned even if `vec` was not. The allocation of a `Vec<u8>`
+    /// can't be reused even if it happens to be aligned, because it must be
+    /// deallocated with the same alignment it was allocated with.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rkyv::util::AlignedVec;
+    /// let vec = vec![1, 2, 3, 4, 5];
+    /// // Slicing off the first byte makes the bytes very likely misaligned
+    /// let misaligned = vec[1..].to_vec();
+    ///
+    /// for vec in [vec, misaligned] {
+    ///     let expected = vec.clone();
+    ///     let aligned = AlignedVec::<16>::from_vec(vec);
+    ///     assert_eq!(aligned.as_ptr() as usize % 16, 0);
+    ///     assert_eq!(aligned.as_slice(), expected.as_slice());
+    /// }
+    /// ```
+    pub fn from_vec(vec: Vec<u8>) -> Self {
+        let mut result = Self::with_capacity(vec.len());
+        result.extend_from_slice(&vec);
+        result
+    }
+
+    /// Constructs a new `AlignedVec` with the contents of the given
+    /// `Box<[u8]>`.
+    ///
+    /// Like [`from_vec`](AlignedVec::from_vec), this always allocates and
+    /// copies the bytes so that the returned vector is aligned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rkyv::{
+    /// #     access, rancor::Error, to_bytes, util::AlignedVec, Archived,
+    /// # };
+    /// let value = vec![1u32, 2, 3];
+    /// let boxed = to_bytes::<Error>(&value).unwrap().into_boxed_slice();
+    ///
+    /// let aligned = AlignedVec::<16>::from_boxed_slice(boxed);
+    /// let archived = access::<Archived<Vec<u32>>, Error>(&aligned).unwrap();
+    /// assert_eq!(archived, &value);
+    /// ```
+    pub fn from_boxed_slice(boxed: Box<[u8]>) -> Self {
+        let mut result = Self::with_capacity(boxed.len());
+        result.extend_from_slice(&boxed);
+        result
+    }
+}
+
+impl<const ALIGNMENT: usize, A: GlobalAlloc> AlignedVec<ALIGNMENT, A> {
+    /// The alignment of the vector
+    pub const ALIGNMENT: usize = ALIGNMENT;
+
+    /// Maximum capacity of the vector.
+    ///
+    /// Dictated by the requirements of [`Layout`]. "`size`, when rounded up to
+    /// the nearest multiple of `align`, must not overflow `isize` (i.e. the
+    /// rounded value must be less than or equal to `isize::MAX`)".
+    pub const MAX_CAPACITY: usize = isize::MAX as usize - (Self::ALIGNMENT - 1);
+
+    /// Constructs a new, empty `AlignedVec` which allocates with the given
+    /// allocator.
+    ///
+    /// The vector will not allocate until elements are pushed into it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rkyv::util::{AlignedVec, Global};
+    /// let mut vec = AlignedVec::<16, _>::new_in(Global);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn new_in(alloc: A) -> Self {
+        Self::with_capacity_in(0, alloc)
+    }
+
+    /// Constructs a new, empty `AlignedVec` with the specified capacity which
+    /// allocates with the given allocator.
+    ///
+    /// See [`with_capacity`](AlignedVec::with_capacity) for more details.
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         assert!(ALIGNMENT > 0, "ALIGNMENT must be 1 or more");
         assert!(
             ALIGNMENT.is_power_of_two(),
@@ -116,9 +287,10 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
 
         if capacity == 0 {
             Self {
-                ptr: NonNull::dangling(),
+                ptr: Self::dangling(),
                 cap: 0,
                 len: 0,
+                alloc,
             }
         } else {
             assert!(
@@ -131,7 +303,7 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
                     capacity,
                     Self::ALIGNMENT,
                 );
-                let ptr = alloc(layout);
+                let ptr = alloc.alloc(layout);
                 if ptr.is_null() {
                     handle_alloc_error(layout);
                 }
@@ -142,14 +314,52 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
                 ptr,
                 cap: capacity,
                 len: 0,
+                alloc,
+            }
+        }
+    }
+
+    /// Constructs a new, empty `AlignedVec` with the specified capacity which
+    /// allocates with the given allocator, returning an error if the
+    /// allocation fails.
+    ///
+    /// See [`with_capacity`](AlignedVec::with_capacity) for more details.
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        alloc: A,
+    ) -> Result<Self, TryReserveError> {
+        let mut result = Self::new_in(alloc);
+        if capacity > 0 {
+            if capacity > Self::MAX_CAPACITY {
+                return Err(TryReserveError::capacity_overflow());
+            }
+            // SAFETY: We just checked that `capacity` is less than or equal to
+            // `MAX_CAPACITY`, and the length of the new vector is zero.
+            unsafe {
+                result.try_change_capacity(capacity)?;
             }
         }
+        Ok(result)
+    }
+
+    /// Returns a reference to the underlying allocator.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
     }
 
     fn layout(&self) -> Layout {
         unsafe { Layout::from_size_align_unchecked(self.cap, Self::ALIGNMENT) }
     }
 
+    // Empty vectors still return a pointer which is aligned to `ALIGNMENT`, so
+    // that zero-sized archived values can be accessed from them.
+    fn dangling() -> NonNull<u8> {
+        // SAFETY: `ALIGNMENT` is a power of two, so it is not zero.
+        unsafe {
+            NonNull::new_unchecked(ptr::without_provenance_mut(Self::ALIGNMENT))
+        }
+    }
+
     /// Clears the vector, removing all values.
     ///
     /// Note that this method has no effect on the allocated capacity of the
@@ -187,10 +397,34 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
     ///   [`MAX_CAPACITY`](AlignedVec::MAX_CAPACITY)
     /// - `new_cap` must be greater than or equal to [`len()`](AlignedVec::len)
     pub unsafe fn change_capacity(&mut self, new_cap: usize) {
+        // SAFETY: The caller has guaranteed that `new_cap` is valid.
+        if let Err(error) = unsafe { self.try_change_capacity(new_cap) } {
+            error.handle();
+        }
+    }
+
+    /// Changes the capacity of the vector, returning an error if the
+    /// allocation fails.
+    ///
+    /// # Safety
+    ///
+    /// `new_cap` must meet the requirements of
+    /// [`change_capacity`](AlignedVec::change_capacity).
+    unsafe fn try_change_capacity(
+        &mut self,
+        new_cap: usize,
+    ) -> Result<(), TryReserveError> {
         debug_assert!(new_cap <= Self::MAX_CAPACITY);
         debug_assert!(new_cap >= self.len);
 
         if new_cap > 0 {
+            // SAFETY:
+            // - `ALIGNMENT` is always guaranteed to be a nonzero power of two.
+            // - The caller has guaranteed that `new_cap` doesn't overflow
+            //   `isize` when rounded up to the nearest power of two.
+            let new_layout = unsafe {
+                Layout::from_size_align_unchecked(new_cap, Self::ALIGNMENT)
+            };
             let new_ptr = if self.cap > 0 {
                 // SAFETY:
                 // - `self.ptr` is currently allocated because `self.cap` is
@@ -198,54 +432,34 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
                 // - `self.layout()` always matches the layout used to allocate
                 //   the current block of memory.
                 // - We checked that `new_cap` is greater than zero.
-                let new_ptr = unsafe {
-                    realloc(self.ptr.as_ptr(), self.layout(), new_cap)
-                };
-                if new_ptr.is_null() {
-                    // SAFETY:
-                    // - `ALIGNMENT` is always guaranteed to be a nonzero power
-                    //   of two.
-                    // - We checked that `new_cap` doesn't overflow `isize` when
-                    //   rounded up to the nearest power of two.
-                    let layout = unsafe {
-                        Layout::from_size_align_unchecked(
-                            new_cap,
-                            Self::ALIGNMENT,
-                        )
-                    };
-                    handle_alloc_error(layout);
+                unsafe {
+                    self.alloc.realloc(
+                        self.ptr.as_ptr(),
+                        self.layout(),
+                        new_cap,
+                    )
                 }
-                new_ptr
             } else {
-                // SAFETY:
-                // - `ALIGNMENT` is always guaranteed to be a nonzero power of
-                //   two.
-                // - We checked that `new_cap` doesn't overflow `isize` when
-                //   rounded up to the nearest power of two.
-                let layout = unsafe {
-                    Layout::from_size_align_unchecked(new_cap, Self::ALIGNMENT)
-                };
                 // SAFETY: We checked that `new_cap` has non-zero size.
-                let new_ptr = unsafe { alloc(layout) };
-                if new_ptr.is_null() {
-                    handle_alloc_error(layout);
-                }
-                new_ptr
+                unsafe { self.alloc.alloc(new_layout) }
             };
-            // SAFETY: We checked that `new_ptr` is non-null in each of the
-            // branches.
-            self.ptr = unsafe { NonNull::new_unchecked(new_ptr) };
+            // If allocation fails, the old block of memory (if any) is left
+            // untouched.
+            self.ptr = NonNull::new(new_ptr)
+                .ok_or_else(|| TryReserveError::alloc_error(new_layout))?;
             self.cap = new_cap;
         } else if self.cap > 0 {
             // SAFETY: Because the capacity is nonzero, `self.ptr` points to a
             // currently-allocated memory block. All memory blocks are allocated
             // with a layout of `self.layout()`.
             unsafe {
-                dealloc(self.ptr.as_ptr(), self.layout());
+                self.alloc.dealloc(self.ptr.as_ptr(), self.layout());
             }
-            self.ptr = NonNull::dangling();
+            self.ptr = Self::dangling();
             self.cap = 0;
         }
+
+        Ok(())
     }
 
     /// Shrinks the capacity of the vector as much as possible.
@@ -426,6 +640,43 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
         unsafe { self.grow_capacity_to(new_cap) };
     }
 
+    /// Tries to reserve capacity for at least `additional` more bytes to be
+    /// inserted into the given `AlignedVec`, returning an error instead of
+    /// panicking or aborting if the capacity overflows or the allocation fails.
+    ///
+    /// The growth strategy is the same as [`reserve`](AlignedVec::reserve). If
+    /// an error is returned, the vector is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rkyv::util::AlignedVec;
+    ///
+    /// let mut vec = AlignedVec::<16>::new();
+    /// vec.push(1);
+    /// vec.try_reserve(10).unwrap();
+    /// assert!(vec.capacity() >= 11);
+    ///
+    /// assert!(vec.try_reserve(usize::MAX).is_err());
+    /// assert_eq!(vec.as_slice(), &[1]);
+    /// ```
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), TryReserveError> {
+        let remaining = self.cap.wrapping_sub(self.len);
+        if additional > remaining {
+            let new_cap = self
+                .len
+                .checked_add(additional)
+                .ok_or_else(TryReserveError::capacity_overflow)?;
+            // SAFETY: `new_cap` is greater than the current capacity because
+            // `additional` is greater than the remaining capacity.
+            unsafe { self.try_grow_capacity_to(new_cap) }
+        } else {
+            Ok(())
+        }
+    }
+
     /// Grows total capacity of vector to `new_cap` or more.
     ///
     /// Capacity after this call will be `new_cap` rounded up to next power of
@@ -463,16 +714,33 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
     /// assert_eq!(vec.capacity(), 64);
     /// ```
     pub unsafe fn grow_capacity_to(&mut self, new_cap: usize) {
+        // SAFETY: The caller has guaranteed that `new_cap` is greater than the
+        // current capacity.
+        if let Err(error) = unsafe { self.try_grow_capacity_to(new_cap) } {
+            error.handle();
+        }
+    }
+
+    /// Grows total capacity of vector to `new_cap` or more, returning an error
+    /// if the capacity overflows or the allocation fails.
+    ///
+    /// # Safety
+    ///
+    /// `new_cap` must be greater than current
+    /// [`capacity()`](AlignedVec::capacity)
+    unsafe fn try_grow_capacity_to(
+        &mut self,
+        new_cap: usize,
+    ) -> Result<(), TryReserveError> {
         debug_assert!(new_cap > self.cap);
 
         let new_cap = if new_cap > (isize::MAX as usize + 1) >> 1 {
             // Rounding up to next power of 2 would result in `isize::MAX + 1`
             // or higher, which exceeds max capacity. So cap at max
             // instead.
-            assert!(
-                new_cap <= Self::MAX_CAPACITY,
-                "cannot reserve a larger AlignedVec"
-            );
+            if new_cap > Self::MAX_CAPACITY {
+                return Err(TryReserveError::capacity_overflow());
+            }
             Self::MAX_CAPACITY
         } else {
             // Cannot overflow due to check above
@@ -480,9 +748,7 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
         };
         // SAFETY: We just checked that `new_cap` is greater than or equal to
         // `len` and less than or equal to `MAX_CAPACITY`.
-        unsafe {
-            self.change_capacity(new_cap);
-        }
+        unsafe { self.try_change_capacity(new_cap) }
     }
 
     /// Resizes the Vec in-place so that len is equal to new_len.
@@ -584,6 +850,42 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
         }
     }
 
+    /// Copies and appends all bytes in a slice to the `AlignedVec`, returning
+    /// an error instead of panicking or aborting if the capacity overflows or
+    /// the allocation fails.
+    ///
+    /// If an error is returned, the vector is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rkyv::util::AlignedVec;
+    ///
+    /// let mut vec = AlignedVec::<16>::new();
+    /// vec.push(1);
+    /// vec.try_extend_from_slice(&[2, 3, 4]).unwrap();
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn try_extend_from_slice(
+        &mut self,
+        other: &[u8],
+    ) -> Result<(), TryReserveError> {
+        if !other.is_empty() {
+            self.try_reserve(other.len())?;
+            // SAFETY: We just reserved space for at least `other.len()` more
+            // bytes after the initialized portion of the vector, and `other`
+            // can't overlap the vector because it's borrowed separately.
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    other.as_ptr(),
+                    self.as_mut_ptr().add(self.len()),
+                    other.len(),
+                );
+            }
+            self.len += other.len();
+        }
+        Ok(())
+    }
+
     /// Removes the last element from a vector and returns it, or `None` if it
     /// is empty.
     ///
@@ -724,6 +1026,12 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
     /// This method reallocates and copies the underlying bytes. Any excess
     /// capacity is dropped.
     ///
+    /// Archives in the returned slice may fail to be accessed because they are
+    /// not guaranteed to be aligned. To keep an aligned buffer without any
+    /// excess capacity, use [`shrink_to_fit`](AlignedVec::shrink_to_fit)
+    /// instead. Use [`from_boxed_slice`](AlignedVec::from_boxed_slice) to
+    /// convert the boxed slice back into an aligned vector.
+    ///
     /// # Examples
     /// ```
     /// # use rkyv::util::AlignedVec;
@@ -772,7 +1080,7 @@ impl<const ALIGNMENT: usize> AlignedVec<ALIGNMENT> {
 const _: () = {
     use std::io;
 
-    impl<const A: usize> AlignedVec<A> {
+    impl<const ALIGNMENT: usize, A: GlobalAlloc> AlignedVec<ALIGNMENT, A> {
         /// Reads all bytes until EOF from `r` and appends them to this
         /// `AlignedVec`.
         ///
@@ -879,7 +1187,9 @@ const _: () = {
         }
     }
 
-    impl<const A: usize> io::Write for AlignedVec<A> {
+    impl<const ALIGNMENT: usize, A: GlobalAlloc> io::Write
+        for AlignedVec<ALIGNMENT, A>
+    {
         fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
             self.extend_from_slice(buf);
             Ok(buf.len())
@@ -908,40 +1218,53 @@ const _: () = {
     }
 };
 
-impl<const A: usize> From<AlignedVec<A>> for Vec<u8> {
-    fn from(aligned: AlignedVec<A>) -> Self {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> From<AlignedVec<ALIGNMENT, A>>
+    for Vec<u8>
+{
+    fn from(aligned: AlignedVec<ALIGNMENT, A>) -> Self {
         aligned.to_vec()
     }
 }
 
-impl<const A: usize> AsMut<[u8]> for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> AsMut<[u8]>
+    for AlignedVec<ALIGNMENT, A>
+{
     fn as_mut(&mut self) -> &mut [u8] {
         self.as_mut_slice()
     }
 }
 
-impl<const A: usize> AsRef<[u8]> for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> AsRef<[u8]>
+    for AlignedVec<ALIGNMENT, A>
+{
     fn as_ref(&self) -> &[u8] {
         self.as_slice()
     }
 }
 
-impl<const A: usize> Borrow<[u8]> for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> Borrow<[u8]>
+    for AlignedVec<ALIGNMENT, A>
+{
     fn borrow(&self) -> &[u8] {
         self.as_slice()
     }
 }
 
-impl<const A: usize> BorrowMut<[u8]> for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> BorrowMut<[u8]>
+    for AlignedVec<ALIGNMENT, A>
+{
     fn borrow_mut(&mut self) -> &mut [u8] {
         self.as_mut_slice()
     }
 }
 
-impl<const A: usize> Clone for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc + Clone> Clone
+    for AlignedVec<ALIGNMENT, A>
+{
     fn clone(&self) -> Self {
         unsafe {
-            let mut result = Self::with_capacity(self.len);
+            let mut result =
+                Self::with_capacity_in(self.len, self.alloc.clone());
             result.len = self.len;
             core::ptr::copy_nonoverlapping(
                 self.as_ptr(),
@@ -953,19 +1276,25 @@ impl<const A: usize> Clone for AlignedVec<A> {
     }
 }
 
-impl<const A: usize> fmt::Debug for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> fmt::Debug
+    for AlignedVec<ALIGNMENT, A>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_slice().fmt(f)
     }
 }
 
-impl<const A: usize> Default for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc + Default> Default
+    for AlignedVec<ALIGNMENT, A>
+{
     fn default() -> Self {
-        Self::new()
+        Self::new_in(A::default())
     }
 }
 
-impl<const A: usize> Deref for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> Deref
+    for AlignedVec<ALIGNMENT, A>
+{
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
@@ -973,13 +1302,17 @@ impl<const A: usize> Deref for AlignedVec<A> {
     }
 }
 
-impl<const A: usize> DerefMut for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc> DerefMut
+    for AlignedVec<ALIGNMENT, A>
+{
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_slice()
     }
 }
 
-impl<const A: usize, I: slice::SliceIndex<[u8]>> Index<I> for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc, I: slice::SliceIndex<[u8]>>
+    Index<I> for AlignedVec<ALIGNMENT, A>
+{
     type Output = <I as slice::SliceIndex<[u8]>>::Output;
 
     fn index(&self, index: I) -> &Self::Output {
@@ -987,26 +1320,39 @@ impl<const A: usize, I: slice::SliceIndex<[u8]>> Index<I> for AlignedVec<A> {
     }
 }
 
-impl<const A: usize, I: slice::SliceIndex<[u8]>> IndexMut<I> for AlignedVec<A> {
+impl<const ALIGNMENT: usize, A: GlobalAlloc, I: slice::SliceIndex<[u8]>>
+    IndexMut<I> for AlignedVec<ALIGNMENT, A>
+{
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         &mut self.as_mut_slice()[index]
     }
 }
 
-// SAFETY: AlignedVec is safe to send to another thread
-unsafe impl<const A: usize> Send for AlignedVec<A> {}
+// SAFETY: AlignedVec is safe to send to another thread if its allocator is
+unsafe impl<const ALIGNMENT: usize, A: GlobalAlloc + Send> Send
+    for AlignedVec<ALIGNMENT, A>
+{
+}
 
-// SAFETY: AlignedVec is safe to share between threads
-unsafe impl<const A: usize> Sync for AlignedVec<A> {}
+// SAFETY: AlignedVec is safe to share between threads if its allocator is
+unsafe impl<const ALIGNMENT: usize, A: GlobalAlloc + Sync> Sync
+    for AlignedVec<ALIGNMENT, A>
+{
+}
 
-impl<const A: usize> Unpin for AlignedVec<A> {}
+impl<const ALIGNMENT: usize, A: GlobalAlloc> Unpin
+    for AlignedVec<ALIGNMENT, A>
+{
+}
 
-impl<const A: usize> ArchiveWith<AlignedVec<A>> for AsVec {
+impl<const ALIGNMENT: usize, A: GlobalAlloc>
+    ArchiveWith<AlignedVec<ALIGNMENT, A>> for AsVec
+{
     type Archived = ArchivedVec<u8>;
     type Resolver = VecResolver;
 
     fn resolve_with(
-        field: &AlignedVec<A>,
+        field: &AlignedVec<ALIGNMENT, A>,
         resolver: Self::Resolver,
         out: Place<Self::Archived>,
     ) {
@@ -1014,12 +1360,13 @@ impl<const A: usize> ArchiveWith<AlignedVec<A>> for AsVec {
     }
 }
 
-impl<S, const A: usize> SerializeWith<AlignedVec<A>, S> for AsVec
+impl<S, const ALIGNMENT: usize, A: GlobalAlloc>
+    SerializeWith<AlignedVec<ALIGNMENT, A>, S> for AsVec
 where
     S: Allocator + Fallible + Writer + ?Sized,
 {
     fn serialize_with(
-        field: &AlignedVec<A>,
+        field: &AlignedVec<ALIGNMENT, A>,
         serializer: &mut S,
     ) -> Result<Self::Resolver, S::Error> {
         ArchivedVec::serialize_from_slice(field.as_slice(), serializer)
@@ -1040,3 +1387,66 @@ where
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        alloc::{GlobalAlloc, Layout},
+        cell::Cell,
+    };
+
+    use super::{AlignedVec, Global};
+
+    #[derive(Default)]
+    struct CountingAlloc {
+        allocs: Cell<usize>,
+        reallocs: Cell<usize>,
+        deallocs: Cell<usize>,
+    }
+
+    // SAFETY: All calls are forwarded to the global allocator.
+    unsafe impl GlobalAlloc for &CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            self.allocs.set(self.allocs.get() + 1);
+            unsafe { Global.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            self.deallocs.set(self.deallocs.get() + 1);
+            unsafe { Global.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(
+            &self,
+            ptr: *mut u8,
+            layout: Layout,
+            new_size: usize,
+        ) -> *mut u8 {
+            self.reallocs.set(self.reallocs.get() + 1);
+            unsafe { Global.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    #[test]
+    fn custom_allocator() {
+        let counter = CountingAlloc::default();
+
+        let mut vec = AlignedVec::<64, _>::new_in(&counter);
+        assert_eq!(counter.allocs.get(), 0);
+
+        vec.extend_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(vec.as_ptr() as usize % 64, 0);
+        assert_eq!(counter.allocs.get(), 1);
+
+        vec.reserve_exact(1024);
+        assert_eq!(counter.reallocs.get(), 1);
+
+        let cloned = vec.clone();
+        assert_eq!(cloned.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(counter.allocs.get(), 2);
+
+        drop(cloned);
+        drop(vec);
+        assert_eq!(counter.deallocs.get(), 2);
+    }
+}
@@ -0,0 +1,219 @@
+use core::{cell::OnceCell, fmt};
+
+use rancor::{Fallible, Source, Strategy};
+
+use crate::{
+    api::{deserialize_with, high::HighDeserializer},
+    de::Pool,
+    Archive, Deserialize, Place, Serialize,
+};
+
+/// A value which may be deserialized from an archive the first time it is
+/// accessed.
+///
+/// A `Deferred` either holds a value, or a reference to an archived value which
+/// is deserialized on the first call to [`get`](Deferred::get). Deferred values
+/// are produced by deserializing fields with [`Lazy`](crate::with::Lazy).
+pub struct Deferred<'a, T: Archive> {
+    // This is only `None` if `value` is initialized.
+    archived: Option<&'a T::Archived>,
+    value: OnceCell<T>,
+}
+
+impl<'a, T: Archive> Deferred<'a, T> {
+    /// Returns a new `Deferred` holding the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            archived: None,
+            value: OnceCell::from(value),
+        }
+    }
+
+    /// Returns a new `Deferred` which deserializes the given archived value
+    /// when it is first accessed.
+    pub fn from_archived(archived: &'a T::Archived) -> Self {
+        Self {
+            archived: Some(archived),
+            value: OnceCell::new(),
+        }
+    }
+
+    /// Returns the archived value that this `Deferred` was created from, if
+    /// any.
+    pub fn archived(&self) -> Option<&'a T::Archived> {
+        self.archived
+    }
+
+    /// Returns the value if it has already been deserialized.
+    pub fn get_deserialized(&self) -> Option<&T> {
+        self.value.get()
+    }
+
+    /// Returns whether the value has been deserialized.
+    pub fn is_deserialized(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    fn undeserialized(archived: Option<&'a T::Archived>) -> &'a T::Archived {
+        // `archived` is only `None` if the value has already been
+        // deserialized.
+        archived.expect("deferred value has no archived value")
+    }
+
+    /// Returns the value, deserializing it with a high-level deserializer if
+    /// this is the first time it has been accessed.
+    ///
+    /// If deserialization fails, the error is returned and the value remains
+    /// undeserialized. Later calls will attempt to deserialize it again.
+    pub fn get<E>(&self) -> Result<&T, E>
+    where
+        T::Archived: Deserialize<T, HighDeserializer<E>>,
+        E: Source,
+    {
+        self.get_with(&mut Pool::new())
+    }
+
+    /// Returns the value, deserializing it with the given deserializer if this
+    /// is the first time it has been accessed.
+    ///
+    /// See [`get`](Deferred::get) for more details.
+    pub fn get_with<D, E>(&self, deserializer: &mut D) -> Result<&T, E>
+    where
+        T::Archived: Deserialize<T, Strategy<D, E>>,
+    {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+
+        let archived = Self::undeserialized(self.archived);
+        let value = deserialize_with::<T, D, E>(archived, deserializer)?;
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Returns the value mutably, deserializing it with a high-level
+    /// deserializer if this is the first time it has been accessed.
+    ///
+    /// See [`get`](Deferred::get) for more details.
+    pub fn get_mut<E>(&mut self) -> Result<&mut T, E>
+    where
+        T::Archived: Deserialize<T, HighDeserializer<E>>,
+        E: Source,
+    {
+        self.get_mut_with(&mut Pool::new())
+    }
+
+    /// Returns the value mutably, deserializing it with the given deserializer
+    /// if this is the first time it has been accessed.
+    ///
+    /// See [`get`](Deferred::get) for more details.
+    pub fn get_mut_with<D, E>(
+        &mut self,
+        deserializer: &mut D,
+    ) -> Result<&mut T, E>
+    where
+        T::Archived: Deserialize<T, Strategy<D, E>>,
+    {
+        if self.value.get().is_none() {
+            let archived = Self::undeserialized(self.archived);
+            let value = deserialize_with::<T, D, E>(archived, deserializer)?;
+            let _ = self.value.set(value);
+        }
+        Ok(self.value.get_mut().unwrap())
+    }
+
+    /// Consumes the `Deferred` and returns the value, deserializing it with a
+    /// high-level deserializer if it has not been accessed yet.
+    pub fn into_inner<E>(self) -> Result<T, E>
+    where
+        T::Archived: Deserialize<T, HighDeserializer<E>>,
+        E: Source,
+    {
+        self.into_inner_with(&mut Pool::new())
+    }
+
+    /// Consumes the `Deferred` and returns the value, deserializing it with the
+    /// given deserializer if it has not been accessed yet.
+    pub fn into_inner_with<D, E>(self, deserializer: &mut D) -> Result<T, E>
+    where
+        T::Archived: Deserialize<T, Strategy<D, E>>,
+    {
+        match self.value.into_inner() {
+            Some(value) => Ok(value),
+            None => deserialize_with::<T, D, E>(
+                Self::undeserialized(self.archived),
+                deserializer,
+            ),
+        }
+    }
+
+    /// Serializes the value of this `Deferred`.
+    ///
+    /// If the value has not been deserialized yet, it is deserialized with a
+    /// high-level deserializer and kept in the returned resolver. The
+    /// `Deferred` itself is left undeserialized. Errors from deserializing the
+    /// value are returned.
+    pub fn serialize_deferred<S>(
+        &self,
+        serializer: &mut S,
+    ) -> Result<DeferredResolver<T>, S::Error>
+    where
+        T: Serialize<S>,
+        T::Archived: Deserialize<T, HighDeserializer<S::Error>>,
+        S: Fallible + ?Sized,
+        S::Error: Source,
+    {
+        match self.value.get() {
+            Some(value) => Ok(DeferredResolver {
+                resolver: value.serialize(serializer)?,
+                value: None,
+            }),
+            None => {
+                let value = deserialize_with::<T, _, S::Error>(
+                    Self::undeserialized(self.archived),
+                    &mut Pool::new(),
+                )?;
+                Ok(DeferredResolver {
+                    resolver: value.serialize(serializer)?,
+                    value: Some(value),
+                })
+            }
+        }
+    }
+
+    /// Resolves the value of this `Deferred` from the output of
+    /// [`serialize_deferred`](Deferred::serialize_deferred).
+    pub fn resolve_deferred(
+        &self,
+        resolver: DeferredResolver<T>,
+        out: Place<T::Archived>,
+    ) {
+        let DeferredResolver { resolver, value } = resolver;
+        // `value` is only `None` if this `Deferred` was already deserialized
+        // when it was serialized. A `Deferred` can't become undeserialized
+        // through a shared reference, so it still holds its value.
+        if let Some(value) = value.as_ref().or_else(|| self.value.get()) {
+            value.resolve(resolver, out);
+        }
+    }
+}
+
+/// The resolver for a [`Deferred`].
+pub struct DeferredResolver<T: Archive> {
+    resolver: T::Resolver,
+    value: Option<T>,
+}
+
+impl<T: Archive> From<T> for Deferred<'_, T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Archive + fmt::Debug> fmt::Debug for Deferred<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.get() {
+            Some(value) => f.debug_tuple("Deferred").field(value).finish(),
+            None => f.write_str("Deferred(<not deserialized>)"),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 mod aligned_vec;
 mod arena;
+mod deferred;
 
-pub use self::{aligned_vec::*, arena::*};
+pub use self::{aligned_vec::*, arena::*, deferred::*};
@@ -1,5 +1,6 @@
 //! Utilities for common operations.
 
+mod aligned_bytes;
 #[cfg(feature = "alloc")]
 mod alloc;
 mod inline_vec;
@@ -11,7 +12,11 @@ use core::ops::{Deref, DerefMut};
 #[cfg(feature = "alloc")]
 pub use self::alloc::*;
 #[doc(inline)]
-pub use self::{inline_vec::InlineVec, ser_vec::SerVec};
+pub use self::{
+    aligned_bytes::{AlignedBytes, Alignment, SupportedAlignment},
+    inline_vec::InlineVec,
+    ser_vec::SerVec,
+};
 
 /// A wrapper which aligns its inner value to 16 bytes.
 #[derive(Clone, Copy, Debug)]
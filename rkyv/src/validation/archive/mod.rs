@@ -2,10 +2,13 @@
 
 mod validator;
 
-use core::{alloc::Layout, ops::Range};
+use core::{alloc::Layout, fmt, ops::Range};
 
-use bytecheck::rancor::{Fallible, Source, Strategy};
-use rancor::ResultExt as _;
+use bytecheck::{
+    rancor::{Fallible, Source, Strategy},
+    CheckBytes,
+};
+use rancor::{fail, ResultExt as _};
 
 pub use self::validator::*;
 use crate::traits::LayoutRaw;
@@ -16,6 +19,10 @@ use crate::traits::LayoutRaw;
 ///
 /// `check_subtree_ptr` must only return true if `ptr` is located entirely
 /// within the subtree range and is safe to dereference.
+///
+/// If `recover_element` returns `Ok`, the subtree range must be restored so
+/// that `check_subtree_ptr` still only returns true for pointers which are
+/// safe to dereference.
 pub unsafe trait ArchiveContext<E = <Self as Fallible>::Error> {
     /// Checks that the given data address and layout is located completely
     /// within the subtree range.
@@ -53,6 +60,38 @@ pub unsafe trait ArchiveContext<E = <Self as Fallible>::Error> {
         &mut self,
         range: Range<usize>,
     ) -> Result<(), E>;
+
+    /// Returns the position of the given pointer relative to the start of the
+    /// archive, if it is known.
+    ///
+    /// This is used to report the position of errors. Positions are tracked
+    /// per subtree: an error is traced with the position of the subtree that
+    /// was being checked when it occurred, not the position of the field
+    /// within that subtree which was invalid. When subtrees are nested, the
+    /// first traced position is the innermost one. The default implementation
+    /// returns `None`.
+    fn archive_pos(&self, ptr: *const u8) -> Option<usize> {
+        let _ = ptr;
+        None
+    }
+
+    /// Handles an error that occurred while checking an element of a slice
+    /// with [`check_elements`](ArchiveContextExt::check_elements).
+    ///
+    /// `slice` points to the first element of the slice, and `element` points
+    /// to the element which failed to validate. If this returns `Ok`, the rest
+    /// of the elements are still checked to find more errors. The slice is
+    /// still invalid, so `check_elements` returns an error after checking the
+    /// rest of the elements. The default implementation returns the error.
+    fn recover_element(
+        &mut self,
+        slice: *const u8,
+        element: *const u8,
+        error: E,
+    ) -> Result<(), E> {
+        let _ = (slice, element);
+        Err(error)
+    }
 }
 
 unsafe impl<T, E> ArchiveContext<E> for Strategy<T, E>
@@ -85,13 +124,66 @@ where
         // has the same safety requirements.
         unsafe { T::pop_subtree_range(self, range) }
     }
+
+    fn archive_pos(&self, ptr: *const u8) -> Option<usize> {
+        T::archive_pos(self, ptr)
+    }
+
+    fn recover_element(
+        &mut self,
+        slice: *const u8,
+        element: *const u8,
+        error: E,
+    ) -> Result<(), E> {
+        T::recover_element(self, slice, element, error)
+    }
+}
+
+#[derive(Debug)]
+struct SubtreePosition {
+    pos: usize,
+}
+
+impl fmt::Display for SubtreePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "while checking the value at offset {:#x}", self.pos)
+    }
+}
+
+#[derive(Debug)]
+struct ElementIndex {
+    index: usize,
+}
+
+impl fmt::Display for ElementIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "while checking index '{}' of slice", self.index)
+    }
+}
+
+#[derive(Debug)]
+struct InvalidElements {
+    count: usize,
 }
 
+impl fmt::Display for InvalidElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} elements of the slice were invalid", self.count)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidElements {}
+
 /// Helper methods for [`ArchiveContext`].
 pub trait ArchiveContextExt<E>: ArchiveContext<E> {
     /// Checks that the given pointer and layout are within the current subtree
     /// range of the context, then pushes a new subtree range onto the validator
     /// for it and calls the given function.
+    ///
+    /// If the context knows the position of `ptr` in the archive, any error
+    /// returned from `f` is traced with that position. See
+    /// [`archive_pos`](ArchiveContext::archive_pos) for more details.
     fn in_subtree_raw<R>(
         &mut self,
         ptr: *const u8,
@@ -107,6 +199,22 @@ pub trait ArchiveContextExt<E>: ArchiveContext<E> {
         ptr: *const T,
         f: impl FnOnce(&mut Self) -> Result<R, E>,
     ) -> Result<R, E>;
+
+    /// Checks each element of the slice the given pointer points to.
+    ///
+    /// This is equivalent to checking the slice with `CheckBytes`, except that
+    /// errors are passed to
+    /// [`recover_element`](ArchiveContext::recover_element). Contexts that
+    /// recover from an error continue checking the rest of the elements, and
+    /// then an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be properly aligned and point to enough bytes for the slice.
+    unsafe fn check_elements<T>(&mut self, ptr: *const [T]) -> Result<(), E>
+    where
+        T: CheckBytes<Self>,
+        Self: Fallible<Error = E>;
 }
 
 impl<C: ArchiveContext<E> + ?Sized, E: Source> ArchiveContextExt<E> for C {
@@ -124,7 +232,10 @@ impl<C: ArchiveContext<E> + ?Sized, E: Source> ArchiveContextExt<E> for C {
         let range =
             unsafe { self.push_subtree_range(ptr, ptr.add(layout.size()))? };
 
-        let result = f(self)?;
+        let result = match self.archive_pos(ptr) {
+            Some(pos) => f(self).trace(SubtreePosition { pos })?,
+            None => f(self)?,
+        };
 
         // SAFETY: `range` was returned from `push_subtree_range`.
         unsafe {
@@ -145,4 +256,30 @@ impl<C: ArchiveContext<E> + ?Sized, E: Source> ArchiveContextExt<E> for C {
 
         self.in_subtree_raw(root, layout, f)
     }
+
+    unsafe fn check_elements<T>(&mut self, ptr: *const [T]) -> Result<(), E>
+    where
+        T: CheckBytes<Self>,
+        Self: Fallible<Error = E>,
+    {
+        let base = ptr.cast::<T>();
+        let len = ptr_meta::metadata(ptr);
+        let mut invalid = 0;
+        for index in 0..len {
+            // SAFETY: The caller has guaranteed that `ptr` points to enough
+            // bytes for the slice and is properly aligned, so we can create
+            // pointers to each element and check them.
+            let element = unsafe { base.add(index) };
+            let result = unsafe { T::check_bytes(element, self) };
+            if let Err(error) = result.trace(ElementIndex { index }) {
+                self.recover_element(base.cast(), element.cast(), error)?;
+                invalid += 1;
+            }
+        }
+
+        if invalid > 0 {
+            fail!(InvalidElements { count: invalid });
+        }
+        Ok(())
+    }
 }
@@ -6,29 +6,56 @@ use rancor::{fail, OptionExt, Source};
 
 use crate::{fmt::Pointer, validation::ArchiveContext};
 
+/// An error which indicates that a value in the archive was not properly
+/// aligned.
+///
+/// This is returned when accessing an archive from a buffer which isn't
+/// aligned for the root object, most commonly a `Vec<u8>` instead of an
+/// [`AlignedVec`](crate::util::AlignedVec).
 #[derive(Debug)]
-struct UnalignedPointer {
-    address: usize,
-    align: usize,
+pub struct Unaligned {
+    required: usize,
+    actual_addr: usize,
+    pos: usize,
 }
 
-impl fmt::Display for UnalignedPointer {
+impl Unaligned {
+    /// Returns the alignment which the value required.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the address of the unaligned value.
+    pub fn actual_addr(&self) -> usize {
+        self.actual_addr
+    }
+
+    /// Returns the position of the unaligned value in the archive.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+}
+
+impl fmt::Display for Unaligned {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "unaligned pointer: ptr {} unaligned for alignment {}",
-            Pointer(self.address),
-            self.align,
+            "unaligned pointer: ptr {} at offset {:#x} unaligned for \
+             alignment {}",
+            Pointer(self.actual_addr),
+            self.pos,
+            self.required,
         )
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for UnalignedPointer {}
+impl std::error::Error for Unaligned {}
 
 #[derive(Debug)]
 struct InvalidSubtreePointer {
     address: usize,
+    pos: isize,
     size: usize,
     subtree_range: Range<usize>,
 }
@@ -37,8 +64,10 @@ impl fmt::Display for InvalidSubtreePointer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "subtree pointer overran range: ptr {} size {} in range {}..{}",
+            "subtree pointer overran range: ptr {} at offset {} size {} in \
+             range {}..{}",
             Pointer(self.address),
+            SignedHex(self.pos),
             self.size,
             Pointer(self.subtree_range.start),
             Pointer(self.subtree_range.end),
@@ -49,6 +78,18 @@ impl fmt::Display for InvalidSubtreePointer {
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidSubtreePointer {}
 
+struct SignedHex(isize);
+
+impl fmt::Display for SignedHex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-{:#x}", self.0.unsigned_abs())
+        } else {
+            write!(f, "{:#x}", self.0)
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ExceededMaximumSubtreeDepth;
 
@@ -89,22 +130,36 @@ impl fmt::Display for RangePoppedOutOfOrder {
 impl std::error::Error for RangePoppedOutOfOrder {}
 
 /// A validator that can verify archives with nonlocal memory.
+///
+/// Validating a subtree (like the contents of a `Box`) recurses, so deeply
+/// nested archives could overflow the stack. To prevent this, the validator has
+/// a maximum subtree depth and returns an error if it is reached. The root of
+/// the archive has a depth of one, and each nested subtree adds one to the
+/// depth. By default, the maximum depth is
+/// [`DEFAULT_MAX_DEPTH`](ArchiveValidator::DEFAULT_MAX_DEPTH).
 #[derive(Debug)]
 pub struct ArchiveValidator<'a> {
+    base: usize,
     subtree_range: Range<usize>,
     max_subtree_depth: Option<NonZeroUsize>,
     _phantom: PhantomData<&'a [u8]>,
 }
 
 impl<'a> ArchiveValidator<'a> {
-    /// Creates a new bounds validator for the given bytes.
+    /// The default maximum subtree depth.
+    pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+    /// Creates a new bounds validator for the given bytes with the default
+    /// maximum validation depth.
     #[inline]
     pub fn new(bytes: &'a [u8]) -> Self {
-        Self::with_max_depth(bytes, None)
+        Self::with_max_depth(bytes, NonZeroUsize::new(Self::DEFAULT_MAX_DEPTH))
     }
 
-    /// Crates a new bounds validator for the given bytes with a maximum
+    /// Creates a new bounds validator for the given bytes with a maximum
     /// validation depth.
+    ///
+    /// If `max_subtree_depth` is `None`, the depth is not limited.
     #[inline]
     pub fn with_max_depth(
         bytes: &'a [u8],
@@ -112,6 +167,7 @@ impl<'a> ArchiveValidator<'a> {
     ) -> Self {
         let Range { start, end } = bytes.as_ptr_range();
         Self {
+            base: start as usize,
             subtree_range: Range {
                 start: start as usize,
                 end: end as usize,
@@ -120,6 +176,15 @@ impl<'a> ArchiveValidator<'a> {
             _phantom: PhantomData,
         }
     }
+
+    /// Sets the maximum validation depth.
+    ///
+    /// If `max_subtree_depth` is `None`, the depth is not limited. This should
+    /// be called before validation begins.
+    #[inline]
+    pub fn set_max_depth(&mut self, max_subtree_depth: Option<NonZeroUsize>) {
+        self.max_subtree_depth = max_subtree_depth;
+    }
 }
 
 unsafe impl<E: Source> ArchiveContext<E> for ArchiveValidator<'_> {
@@ -133,13 +198,15 @@ unsafe impl<E: Source> ArchiveContext<E> for ArchiveValidator<'_> {
         if start < self.subtree_range.start || end > self.subtree_range.end {
             fail!(InvalidSubtreePointer {
                 address: start,
+                pos: start.wrapping_sub(self.base) as isize,
                 size: layout.size(),
                 subtree_range: self.subtree_range.clone(),
             });
         } else if start & (layout.align() - 1) != 0 {
-            fail!(UnalignedPointer {
-                address: ptr as usize,
-                align: layout.align(),
+            fail!(Unaligned {
+                required: layout.align(),
+                actual_addr: start,
+                pos: start - self.base,
             });
         } else {
             Ok(())
@@ -179,4 +246,8 @@ unsafe impl<E: Source> ArchiveContext<E> for ArchiveValidator<'_> {
         }
         Ok(())
     }
+
+    fn archive_pos(&self, ptr: *const u8) -> Option<usize> {
+        (ptr as usize).checked_sub(self.base)
+    }
 }
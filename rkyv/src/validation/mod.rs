@@ -55,6 +55,19 @@ where
         // which has the same safety requirements.
         unsafe { self.archive.pop_subtree_range(range) }
     }
+
+    fn archive_pos(&self, ptr: *const u8) -> Option<usize> {
+        self.archive.archive_pos(ptr)
+    }
+
+    fn recover_element(
+        &mut self,
+        slice: *const u8,
+        element: *const u8,
+        error: E,
+    ) -> Result<(), E> {
+        self.archive.recover_element(slice, element, error)
+    }
 }
 
 impl<A, S, E> SharedContext<E> for Validator<A, S>
@@ -68,6 +81,22 @@ where
     ) -> Result<bool, E> {
         self.shared.register_shared_ptr(address, type_id)
     }
+
+    fn start_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        self.shared.start_shared_ptr(address, type_id)
+    }
+
+    fn finish_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<(), E> {
+        self.shared.finish_shared_ptr(address, type_id)
+    }
 }
 
 #[cfg(test)]
@@ -188,6 +217,184 @@ mod tests {
             .expect_err("expected out of bounds error");
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn error_offsets() {
+        use rancor::Error;
+
+        use crate::alloc::string::ToString;
+
+        // Invalid archive (invalid tag at offset 4)
+        let synthetic_buf = Align([
+            0u8, 0u8, 0u8, 0u8, // padding
+            2u8, 0u8, 0u8, 0u8, // invalid tag + padding
+            0u8, 0u8, 0u8, 0u8, // value
+        ]);
+
+        let error = access_pos::<ArchivedOption<Archived<u32>>, Error>(
+            &*synthetic_buf,
+            4,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("offset 0x4"));
+
+        // Unaligned
+        #[cfg(not(feature = "unaligned"))]
+        {
+            let error =
+                access_pos::<Archived<u32>, Error>(&*Align([0, 1, 2, 3, 4]), 1)
+                    .unwrap_err();
+            assert!(error.to_string().contains("offset 0x1"));
+        }
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "unaligned")))]
+    #[test]
+    fn misaligned_slice() {
+        use core::{any::Any, fmt};
+
+        use rancor::{Source, Trace};
+
+        use crate::{api::high::access_mut, validation::archive::Unaligned};
+
+        /// An error which keeps the contents of an `Unaligned` error.
+        #[derive(Debug)]
+        struct Captured(Option<(usize, usize, usize)>);
+
+        impl fmt::Display for Captured {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:?}", self.0)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for Captured {}
+
+        impl Trace for Captured {
+            fn trace<R>(self, _: R) -> Self
+            where
+                R: fmt::Debug + fmt::Display + Send + Sync + 'static,
+            {
+                self
+            }
+        }
+
+        impl Source for Captured {
+            fn new<T: rancor::StdError + Send + Sync + 'static>(
+                source: T,
+            ) -> Self {
+                let source: &dyn Any = &source;
+                Self(
+                    source
+                        .downcast_ref::<Unaligned>()
+                        .map(|e| (e.required(), e.actual_addr(), e.pos())),
+                )
+            }
+        }
+
+        let mut buf = Align([0u8, 1, 2, 3, 4, 5, 6, 7]);
+        let addr = buf[1..].as_ptr() as usize;
+        let error =
+            access_mut::<Archived<u32>, Captured>(&mut buf[1..5]).unwrap_err();
+        let (required, actual_addr, pos) =
+            error.0.expect("expected an `Unaligned` error");
+        assert_eq!(required, 4);
+        assert_eq!(actual_addr, addr);
+        assert_eq!(pos, 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn invalid_chars() {
+        use rancor::Error;
+
+        use crate::{
+            api::high::{access, to_bytes},
+            primitive::ArchivedChar,
+        };
+
+        // Archived `u32`s and `char`s have the same size, alignment, and
+        // endianness, so an archived `u32` can be validated as a `char`.
+        let bytes = to_bytes::<Error>(&u32::from('x')).unwrap();
+        let archived = access::<ArchivedChar, Error>(&bytes).unwrap();
+        assert_eq!(archived.to_native(), 'x');
+
+        let bytes = to_bytes::<Error>(&u32::from(char::MAX)).unwrap();
+        access::<ArchivedChar, Error>(&bytes).unwrap();
+
+        // A surrogate code point
+        let bytes = to_bytes::<Error>(&0xd800u32).unwrap();
+        access::<ArchivedChar, Error>(&bytes).unwrap_err();
+
+        // Larger than the largest code point
+        let bytes = to_bytes::<Error>(&0x110000u32).unwrap();
+        access::<ArchivedChar, Error>(&bytes).unwrap_err();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn max_subtree_depth() {
+        use core::num::NonZeroUsize;
+
+        use rancor::Error;
+
+        use crate::{
+            alloc::{boxed::Box, string::ToString},
+            api::{
+                access_with_context,
+                high::{access, to_bytes},
+            },
+            ser::Writer,
+            validation::{
+                archive::ArchiveValidator, shared::SharedValidator, Validator,
+            },
+            Archive, Serialize,
+        };
+
+        #[derive(Archive, Serialize)]
+        #[rkyv(
+            crate,
+            check_bytes(bounds(__C: crate::validation::ArchiveContext)),
+            serialize_bounds(__S: Writer),
+            derive(Debug),
+        )]
+        enum Node {
+            Nil,
+            Cons(#[omit_bounds] Box<Node>),
+        }
+
+        fn chain(len: usize) -> Node {
+            let mut node = Node::Nil;
+            for _ in 0..len {
+                node = Node::Cons(Box::new(node));
+            }
+            node
+        }
+
+        // The root has a depth of one and the depth must stay below the
+        // maximum, so the longest chain which can be validated has two fewer
+        // boxes than the maximum depth.
+        let max_depth = ArchiveValidator::DEFAULT_MAX_DEPTH;
+        let bytes = to_bytes::<Error>(&chain(max_depth - 2)).unwrap();
+        access::<ArchivedNode, Error>(&bytes).unwrap();
+
+        let bytes = to_bytes::<Error>(&chain(max_depth - 1)).unwrap();
+        let error = access::<ArchivedNode, Error>(&bytes).unwrap_err();
+        assert!(error.to_string().contains("maximum subtree depth"));
+
+        let mut archive = ArchiveValidator::new(&bytes);
+        archive.set_max_depth(None);
+        let mut validator = Validator::new(archive, SharedValidator::new());
+        access_with_context::<ArchivedNode, _, Error>(&bytes, &mut validator)
+            .unwrap();
+
+        let mut archive = ArchiveValidator::new(&bytes);
+        archive.set_max_depth(NonZeroUsize::new(4));
+        let mut validator = Validator::new(archive, SharedValidator::new());
+        access_with_context::<ArchivedNode, _, Error>(&bytes, &mut validator)
+            .unwrap_err();
+    }
+
     #[cfg(feature = "pointer_width_32")]
     #[test]
     fn invalid_tags() {
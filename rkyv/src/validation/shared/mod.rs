@@ -23,6 +23,35 @@ pub trait SharedContext<E = <Self as Fallible>::Error> {
         address: usize,
         type_id: TypeId,
     ) -> Result<bool, E>;
+
+    /// Registers the given `ptr` as a shared pointer with the given type and
+    /// marks it as being checked.
+    ///
+    /// Returns `true` if the pointer was newly-registered and `check_bytes`
+    /// should be called, in which case `finish_shared_ptr` must be called
+    /// once it has been checked. Returns an error if the pointer is still
+    /// being checked, which means that the shared pointers form a cycle.
+    ///
+    /// The default implementation calls `register_shared_ptr` and does not
+    /// detect cycles.
+    fn start_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        self.register_shared_ptr(address, type_id)
+    }
+
+    /// Marks the given `ptr` as finished checking.
+    ///
+    /// The default implementation does nothing.
+    fn finish_shared_ptr(
+        &mut self,
+        _address: usize,
+        _type_id: TypeId,
+    ) -> Result<(), E> {
+        Ok(())
+    }
 }
 
 impl<T, E> SharedContext<E> for Strategy<T, E>
@@ -36,4 +65,20 @@ where
     ) -> Result<bool, E> {
         T::register_shared_ptr(self, address, type_id)
     }
+
+    fn start_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        T::start_shared_ptr(self, address, type_id)
+    }
+
+    fn finish_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<(), E> {
+        T::finish_shared_ptr(self, address, type_id)
+    }
 }
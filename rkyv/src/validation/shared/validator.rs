@@ -21,6 +21,11 @@ pub enum SharedError {
         /// The current type that the location is checked as
         current: TypeId,
     },
+    /// A shared pointer points to a value which contains it
+    Cycle {
+        /// The address of the shared value
+        address: usize,
+    },
 }
 
 impl fmt::Display for SharedError {
@@ -32,6 +37,11 @@ impl fmt::Display for SharedError {
                  types ({:?} and {:?})",
                 previous, current
             ),
+            SharedError::Cycle { address } => write!(
+                f,
+                "the shared value at {:#x} contains a pointer to itself",
+                address,
+            ),
         }
     }
 }
@@ -40,7 +50,9 @@ impl fmt::Display for SharedError {
 impl std::error::Error for SharedError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            SharedError::TypeMismatch { .. } => None,
+            SharedError::TypeMismatch { .. } | SharedError::Cycle { .. } => {
+                None
+            }
         }
     }
 }
@@ -48,7 +60,14 @@ impl std::error::Error for SharedError {
 /// A validator that can verify shared pointers.
 #[derive(Debug, Default)]
 pub struct SharedValidator {
-    shared: hash_map::HashMap<usize, TypeId, BuildHasherDefault<FxHasher64>>,
+    shared:
+        hash_map::HashMap<usize, SharedState, BuildHasherDefault<FxHasher64>>,
+}
+
+#[derive(Debug)]
+struct SharedState {
+    type_id: TypeId,
+    finished: bool,
 }
 
 impl SharedValidator {
@@ -70,28 +89,120 @@ impl SharedValidator {
     }
 }
 
-impl<E: Source> SharedContext<E> for SharedValidator {
-    fn register_shared_ptr(
+impl SharedValidator {
+    fn register<E: Source>(
         &mut self,
         address: usize,
         type_id: TypeId,
+        finished: bool,
     ) -> Result<bool, E> {
         match self.shared.entry(address) {
-            hash_map::Entry::Occupied(previous_type_entry) => {
-                let previous_type_id = previous_type_entry.get();
-                if previous_type_id != &type_id {
+            hash_map::Entry::Occupied(previous_entry) => {
+                let previous = previous_entry.get();
+                if previous.type_id != type_id {
                     fail!(SharedError::TypeMismatch {
-                        previous: *previous_type_id,
+                        previous: previous.type_id,
                         current: type_id,
                     })
+                } else if !previous.finished {
+                    fail!(SharedError::Cycle { address })
                 } else {
                     Ok(false)
                 }
             }
             hash_map::Entry::Vacant(ent) => {
-                ent.insert(type_id);
+                ent.insert(SharedState { type_id, finished });
                 Ok(true)
             }
         }
     }
 }
+
+impl<E: Source> SharedContext<E> for SharedValidator {
+    fn register_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        // Pointers registered without being started are never finished, so
+        // they can't be part of a detected cycle.
+        self.register(address, type_id, true)
+    }
+
+    fn start_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<bool, E> {
+        self.register(address, type_id, false)
+    }
+
+    fn finish_shared_ptr(
+        &mut self,
+        address: usize,
+        type_id: TypeId,
+    ) -> Result<(), E> {
+        if let Some(state) = self.shared.get_mut(&address) {
+            if state.type_id == type_id {
+                state.finished = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::TypeId;
+
+    use rancor::Error;
+
+    use crate::validation::{shared::SharedValidator, SharedContext};
+
+    #[test]
+    fn detect_cycles_only_for_started_ptrs() {
+        let mut validator = SharedValidator::new();
+        let type_id = TypeId::of::<u32>();
+
+        // Pointers which are only registered are never finished
+        assert!(SharedContext::<Error>::register_shared_ptr(
+            &mut validator,
+            8,
+            type_id
+        )
+        .unwrap());
+        assert!(!SharedContext::<Error>::register_shared_ptr(
+            &mut validator,
+            8,
+            type_id
+        )
+        .unwrap());
+        assert!(!SharedContext::<Error>::start_shared_ptr(
+            &mut validator,
+            8,
+            type_id
+        )
+        .unwrap());
+
+        assert!(SharedContext::<Error>::start_shared_ptr(
+            &mut validator,
+            16,
+            type_id
+        )
+        .unwrap());
+        SharedContext::<Error>::register_shared_ptr(
+            &mut validator,
+            16,
+            type_id,
+        )
+        .unwrap_err();
+        SharedContext::<Error>::finish_shared_ptr(&mut validator, 16, type_id)
+            .unwrap();
+        assert!(!SharedContext::<Error>::start_shared_ptr(
+            &mut validator,
+            16,
+            type_id
+        )
+        .unwrap());
+    }
+}
@@ -3,9 +3,11 @@
 use core::{
     borrow::Borrow,
     cmp, fmt, hash,
+    iter::FusedIterator,
+    mem::ManuallyDrop,
     ops::{Deref, Index, IndexMut},
     pin::Pin,
-    slice::SliceIndex,
+    slice::{self, Chunks, SliceIndex, Windows},
 };
 
 use munge::munge;
@@ -56,7 +58,99 @@ impl<T> ArchivedVec<T> {
         unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 
+    /// Returns the first element of the archived vec, or `None` if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Returns the last element of the archived vec, or `None` if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns the first element of the archived vec and the rest of the
+    /// elements, or `None` if it is empty.
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_first()
+    }
+
+    /// Returns the last element of the archived vec and the rest of the
+    /// elements, or `None` if it is empty.
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_last()
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the archived vec at a
+    /// time, starting at the beginning.
+    ///
+    /// The chunks are slices and do not overlap. If `chunk_size` does not
+    /// divide the length of the archived vec, then the last chunk will be
+    /// shorter than `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        self.as_slice().chunks(chunk_size)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size` in the
+    /// archived vec.
+    ///
+    /// The windows overlap. If the archived vec is shorter than `size`, the
+    /// iterator returns no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    /// Returns an iterator over the elements which are in both this archived
+    /// vec and `other`, in ascending order.
+    ///
+    /// Both archived vecs must be sorted in ascending order. The iterator does
+    /// not allocate, and runs in `O(n + m)` time. If the archived vecs are not
+    /// sorted, the result is unspecified.
+    pub fn sorted_intersection<'a>(
+        &'a self,
+        other: &'a ArchivedVec<T>,
+    ) -> SortedIntersection<'a, T>
+    where
+        T: Ord,
+    {
+        SortedIntersection {
+            left: self.as_slice(),
+            right: other.as_slice(),
+        }
+    }
+
+    /// Returns an iterator over the elements which are in either this archived
+    /// vec or `other`, in ascending order.
+    ///
+    /// Both archived vecs must be sorted in ascending order. Elements which are
+    /// in both archived vecs are only returned once, and references to them
+    /// point into `self`. The iterator does not allocate, and runs in `O(n +
+    /// m)` time. If the archived vecs are not sorted, the result is
+    /// unspecified.
+    pub fn sorted_union<'a>(
+        &'a self,
+        other: &'a ArchivedVec<T>,
+    ) -> SortedUnion<'a, T>
+    where
+        T: Ord,
+    {
+        SortedUnion {
+            left: self.as_slice(),
+            right: other.as_slice(),
+        }
+    }
+
     /// Gets the elements of the archived vec as a pinned mutable slice.
+    ///
+    /// The elements can be modified in place, but the length of the archived
+    /// vec can't be changed.
     pub fn as_slice_pin(self: Pin<&mut Self>) -> Pin<&mut [T]> {
         let len = self.len();
         let ptr = unsafe { self.map_unchecked_mut(|s| &mut s.ptr) };
@@ -68,6 +162,19 @@ impl<T> ArchivedVec<T> {
         }
     }
 
+    /// Returns an iterator over pinned mutable references to the elements of
+    /// the archived vec.
+    ///
+    /// Each element can be modified in place while iterating.
+    pub fn iter_pin(self: Pin<&mut Self>) -> IterPin<'_, T> {
+        // SAFETY: The elements are only ever handed out as pinned references,
+        // so they are never moved.
+        let slice = unsafe { self.as_slice_pin().get_unchecked_mut() };
+        IterPin {
+            inner: slice.iter_mut(),
+        }
+    }
+
     // This method can go away once pinned slices have indexing support
     // https://github.com/rust-lang/rust/pull/78370
 
@@ -83,6 +190,54 @@ impl<T> ArchivedVec<T> {
         unsafe { self.as_slice_pin().map_unchecked_mut(|s| &mut s[index]) }
     }
 
+    /// Returns the first element of the archived vec as a pinned mutable
+    /// reference, or `None` if it is empty.
+    pub fn first_pin(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        // SAFETY: The element is only handed out as a pinned reference, so it
+        // is never moved.
+        let slice = unsafe { self.as_slice_pin().get_unchecked_mut() };
+        slice
+            .first_mut()
+            .map(|first| unsafe { Pin::new_unchecked(first) })
+    }
+
+    /// Returns the last element of the archived vec as a pinned mutable
+    /// reference, or `None` if it is empty.
+    pub fn last_pin(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        // SAFETY: The element is only handed out as a pinned reference, so it
+        // is never moved.
+        let slice = unsafe { self.as_slice_pin().get_unchecked_mut() };
+        slice
+            .last_mut()
+            .map(|last| unsafe { Pin::new_unchecked(last) })
+    }
+
+    /// Returns the first element of the archived vec and the rest of the
+    /// elements as pinned mutable references, or `None` if it is empty.
+    pub fn split_first_pin(
+        self: Pin<&mut Self>,
+    ) -> Option<(Pin<&mut T>, Pin<&mut [T]>)> {
+        // SAFETY: The elements are only handed out as pinned references, so
+        // they are never moved.
+        let slice = unsafe { self.as_slice_pin().get_unchecked_mut() };
+        slice.split_first_mut().map(|(first, rest)| unsafe {
+            (Pin::new_unchecked(first), Pin::new_unchecked(rest))
+        })
+    }
+
+    /// Returns the last element of the archived vec and the rest of the
+    /// elements as pinned mutable references, or `None` if it is empty.
+    pub fn split_last_pin(
+        self: Pin<&mut Self>,
+    ) -> Option<(Pin<&mut T>, Pin<&mut [T]>)> {
+        // SAFETY: The elements are only handed out as pinned references, so
+        // they are never moved.
+        let slice = unsafe { self.as_slice_pin().get_unchecked_mut() };
+        slice.split_last_mut().map(|(last, rest)| unsafe {
+            (Pin::new_unchecked(last), Pin::new_unchecked(rest))
+        })
+    }
+
     /// Resolves an archived `Vec` from a given slice.
     pub fn resolve_from_slice<U: Archive<Archived = T>>(
         slice: &[U],
@@ -185,6 +340,26 @@ impl<T> ArchivedVec<T> {
     }
 }
 
+impl<T> ArchivedVec<ArchivedVec<T>> {
+    /// Returns the elements of the inner archived vec at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn row(&self, index: usize) -> &[T] {
+        self[index].as_slice()
+    }
+
+    /// Returns an iterator over the elements of all of the inner archived vecs
+    /// in order.
+    pub fn flat_iter(&self) -> FlatIter<'_, T> {
+        FlatIter {
+            rows: self.iter(),
+            row: [].iter(),
+        }
+    }
+}
+
 impl<T> AsRef<[T]> for ArchivedVec<T> {
     fn as_ref(&self) -> &[T] {
         self.as_slice()
@@ -263,6 +438,18 @@ impl<T: PartialEq<U>, U> PartialEq<ArchivedVec<U>> for [T] {
     }
 }
 
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for ArchivedVec<T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice().eq(*other)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<ArchivedVec<U>> for &[T] {
+    fn eq(&self, other: &ArchivedVec<U>) -> bool {
+        (*self).eq(other.as_slice())
+    }
+}
+
 impl<T: PartialOrd> PartialOrd<ArchivedVec<T>> for ArchivedVec<T> {
     fn partial_cmp(&self, other: &ArchivedVec<T>) -> Option<cmp::Ordering> {
         self.as_slice().partial_cmp(other.as_slice())
@@ -281,6 +468,161 @@ impl<T: PartialOrd> PartialOrd<ArchivedVec<T>> for [T] {
     }
 }
 
+/// An iterator over pinned mutable references to the elements of an
+/// [`ArchivedVec`].
+///
+/// This `struct` is created by the [`ArchivedVec::iter_pin`] function.
+pub struct IterPin<'a, T> {
+    inner: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterPin<'a, T> {
+    type Item = Pin<&'a mut T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The element is part of a pinned archived vec, so it will
+        // never be moved.
+        self.inner
+            .next()
+            .map(|element| unsafe { Pin::new_unchecked(element) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterPin<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: The element is part of a pinned archived vec, so it will
+        // never be moved.
+        self.inner
+            .next_back()
+            .map(|element| unsafe { Pin::new_unchecked(element) })
+    }
+}
+
+impl<T> ExactSizeIterator for IterPin<'_, T> {}
+
+impl<T> FusedIterator for IterPin<'_, T> {}
+
+/// An iterator over the intersection of two sorted [`ArchivedVec`]s.
+///
+/// This `struct` is created by the [`ArchivedVec::sorted_intersection`]
+/// function.
+pub struct SortedIntersection<'a, T> {
+    left: &'a [T],
+    right: &'a [T],
+}
+
+impl<'a, T: Ord> Iterator for SortedIntersection<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (left, left_rest) = self.left.split_first()?;
+            let (right, right_rest) = self.right.split_first()?;
+            match left.cmp(right) {
+                cmp::Ordering::Less => self.left = left_rest,
+                cmp::Ordering::Greater => self.right = right_rest,
+                cmp::Ordering::Equal => {
+                    self.left = left_rest;
+                    self.right = right_rest;
+                    return Some(left);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(cmp::min(self.left.len(), self.right.len())))
+    }
+}
+
+impl<T: Ord> FusedIterator for SortedIntersection<'_, T> {}
+
+/// An iterator over the union of two sorted [`ArchivedVec`]s.
+///
+/// This `struct` is created by the [`ArchivedVec::sorted_union`] function.
+pub struct SortedUnion<'a, T> {
+    left: &'a [T],
+    right: &'a [T],
+}
+
+impl<'a, T: Ord> Iterator for SortedUnion<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.left.split_first(), self.right.split_first()) {
+            (Some((left, left_rest)), Some((right, right_rest))) => {
+                match left.cmp(right) {
+                    cmp::Ordering::Less => {
+                        self.left = left_rest;
+                        Some(left)
+                    }
+                    cmp::Ordering::Greater => {
+                        self.right = right_rest;
+                        Some(right)
+                    }
+                    cmp::Ordering::Equal => {
+                        self.left = left_rest;
+                        self.right = right_rest;
+                        Some(left)
+                    }
+                }
+            }
+            (Some((left, left_rest)), None) => {
+                self.left = left_rest;
+                Some(left)
+            }
+            (None, Some((right, right_rest))) => {
+                self.right = right_rest;
+                Some(right)
+            }
+            (None, None) => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left, right) = (self.left.len(), self.right.len());
+        (cmp::max(left, right), Some(left + right))
+    }
+}
+
+impl<T: Ord> FusedIterator for SortedUnion<'_, T> {}
+
+/// An iterator over the elements of nested [`ArchivedVec`]s.
+///
+/// This `struct` is created by the [`ArchivedVec::flat_iter`] function.
+pub struct FlatIter<'a, T> {
+    rows: slice::Iter<'a, ArchivedVec<T>>,
+    row: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for FlatIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.row.next() {
+                return Some(item);
+            }
+            self.row = self.rows.next()?.iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = if self.rows.len() == 0 {
+            Some(self.row.len())
+        } else {
+            None
+        };
+        (self.row.len(), upper)
+    }
+}
+
+impl<T> FusedIterator for FlatIter<'_, T> {}
+
 /// The resolver for [`ArchivedVec`].
 pub struct VecResolver {
     pos: usize,
@@ -294,8 +636,182 @@ impl VecResolver {
     }
 }
 
+/// An archived `Vec` which stores up to `N` elements inline.
+///
+/// When the length of the vec is at most `N`, the elements are stored inline
+/// in a fixed-size array. Otherwise, the elements are stored out-of-line like
+/// an [`ArchivedVec`]. This avoids an indirection for vecs which are usually
+/// short.
+///
+/// This is the archived type for [`InlineVec`](crate::with::InlineVec).
+#[repr(C)]
+pub struct ArchivedInlineVec<T, const N: usize> {
+    len: ArchivedUsize,
+    repr: InlineVecRepr<T, N>,
+}
+
+#[repr(C)]
+union InlineVecRepr<T, const N: usize> {
+    inline: ManuallyDrop<[T; N]>,
+    out_of_line: ManuallyDrop<RelPtr<T>>,
+}
+
+// SAFETY: `ArchivedInlineVec<T, N>` is `Portable` when `T` is `Portable`. All
+// of its fields are `Portable` and it doesn't have any interior mutability.
+unsafe impl<T: Portable, const N: usize> Portable for ArchivedInlineVec<T, N> {}
+
+impl<T, const N: usize> ArchivedInlineVec<T, N> {
+    /// Returns whether the elements of the archived vec are stored inline.
+    pub fn is_inline(&self) -> bool {
+        self.len() <= N
+    }
+
+    /// Returns a pointer to the first element of the archived vec.
+    pub fn as_ptr(&self) -> *const T {
+        if self.is_inline() {
+            // The inline elements are located at the start of the repr.
+            core::ptr::addr_of!(self.repr).cast::<T>()
+        } else {
+            // SAFETY: The elements are stored out-of-line.
+            unsafe { self.repr.out_of_line.as_ptr() }
+        }
+    }
+
+    /// Returns the number of elements in the archived vec.
+    pub fn len(&self) -> usize {
+        self.len.to_native() as usize
+    }
+
+    /// Returns whether the archived vec is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the elements of the archived vec as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `as_ptr` points to `len` initialized elements, whether they
+        // are stored inline or out-of-line.
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    /// Resolves an archived inline `Vec` from a given slice.
+    pub fn resolve_from_slice<U: Archive<Archived = T>>(
+        slice: &[U],
+        resolver: InlineVecResolver<U::Resolver, N>,
+        out: Place<Self>,
+    ) {
+        munge!(let ArchivedInlineVec { len, repr } = out);
+        usize::resolve(&slice.len(), (), len);
+        match resolver.inner {
+            InlineVecResolverInner::Inline(resolvers) => {
+                // SAFETY: `inline` is located at the start of the repr.
+                let inline = unsafe { repr.cast_unchecked::<[T; N]>() };
+                for (i, (value, resolver)) in slice
+                    .iter()
+                    .zip(resolvers.into_iter().flatten())
+                    .enumerate()
+                {
+                    // SAFETY: The slice has at most `N` elements, so `i` is
+                    // in-bounds.
+                    let out = unsafe { inline.index(i) };
+                    value.resolve(resolver, out);
+                }
+            }
+            InlineVecResolverInner::OutOfLine(pos) => {
+                // SAFETY: `out_of_line` is located at the start of the repr.
+                let out = unsafe { repr.cast_unchecked::<RelPtr<T>>() };
+                RelPtr::emplace(pos, out);
+            }
+        }
+    }
+
+    /// Serializes an archived inline `Vec` from a given slice.
+    pub fn serialize_from_slice<
+        U: Serialize<S, Archived = T>,
+        S: Fallible + Allocator + Writer + ?Sized,
+    >(
+        slice: &[U],
+        serializer: &mut S,
+    ) -> Result<InlineVecResolver<U::Resolver, N>, S::Error> {
+        let inner = if slice.len() <= N {
+            let mut resolvers = core::array::from_fn(|_| None);
+            for (resolver, value) in resolvers.iter_mut().zip(slice.iter()) {
+                *resolver = Some(value.serialize(serializer)?);
+            }
+            InlineVecResolverInner::Inline(resolvers)
+        } else {
+            let resolver =
+                ArchivedVec::<T>::serialize_from_slice(slice, serializer)?;
+            InlineVecResolverInner::OutOfLine(resolver.pos)
+        };
+        Ok(InlineVecResolver { inner })
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArchivedInlineVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for ArchivedInlineVec<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArchivedInlineVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> Deref for ArchivedInlineVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArchivedInlineVec<T, N> {}
+
+impl<T: hash::Hash, const N: usize> hash::Hash for ArchivedInlineVec<T, N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize>
+    PartialEq<ArchivedInlineVec<U, M>> for ArchivedInlineVec<T, N>
+{
+    fn eq(&self, other: &ArchivedInlineVec<U, M>) -> bool {
+        self.as_slice().eq(other.as_slice())
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]>
+    for ArchivedInlineVec<T, N>
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice().eq(other)
+    }
+}
+
+/// The resolver for [`ArchivedInlineVec`].
+pub struct InlineVecResolver<R, const N: usize> {
+    inner: InlineVecResolverInner<R, N>,
+}
+
+enum InlineVecResolverInner<R, const N: usize> {
+    Inline([Option<R>; N]),
+    OutOfLine(usize),
+}
+
 #[cfg(feature = "bytecheck")]
 mod verify {
+    use core::ptr::{addr_of, slice_from_raw_parts};
+
     use bytecheck::{
         rancor::{Fallible, Source},
         CheckBytes, Verify,
@@ -303,7 +819,8 @@ mod verify {
 
     use crate::{
         validation::{ArchiveContext, ArchiveContextExt},
-        vec::ArchivedVec,
+        vec::{ArchivedInlineVec, ArchivedVec},
+        RelPtr,
     };
 
     unsafe impl<T, C> Verify<C> for ArchivedVec<T>
@@ -319,8 +836,64 @@ mod verify {
             );
 
             context.in_subtree(ptr, |context| unsafe {
-                <[T]>::check_bytes(ptr, context)
+                context.check_elements(ptr)
             })
         }
     }
+
+    // SAFETY: `check_bytes` only returns `Ok` if the length is valid and every
+    // element in use is valid, whether stored inline or out-of-line.
+    unsafe impl<T, C, const N: usize> CheckBytes<C> for ArchivedInlineVec<T, N>
+    where
+        T: CheckBytes<C>,
+        C: Fallible + ArchiveContext + ?Sized,
+        C::Error: Source,
+    {
+        unsafe fn check_bytes(
+            value: *const Self,
+            context: &mut C,
+        ) -> Result<(), C::Error> {
+            // SAFETY: The length is valid for every bit pattern.
+            let len = unsafe { (*addr_of!((*value).len)).to_native() as usize };
+
+            if len <= N {
+                // SAFETY: `value` is a valid pointer, so its `repr` field is
+                // in-bounds. The inline elements are located at the start of
+                // the repr.
+                let elements = unsafe { addr_of!((*value).repr).cast::<T>() };
+                // Only the first `len` elements of the inline array are used,
+                // so only those need to be checked.
+                for i in 0..len {
+                    // SAFETY: `i` is less than `len`, which is at most `N`, so
+                    // the element is in-bounds of the inline array.
+                    unsafe {
+                        T::check_bytes(elements.add(i), context)?;
+                    }
+                }
+                Ok(())
+            } else {
+                // SAFETY: `value` is a valid pointer, so its `repr` field is
+                // in-bounds. The out-of-line pointer is located at the start of
+                // the repr.
+                let rel_ptr =
+                    unsafe { addr_of!((*value).repr).cast::<RelPtr<T>>() };
+                // SAFETY: `rel_ptr` points to the out-of-line repr, which is
+                // in-bounds of `value`.
+                unsafe {
+                    RelPtr::<T>::check_bytes(rel_ptr, context)?;
+                }
+                // SAFETY: We just checked that `rel_ptr` is valid.
+                let ptr = slice_from_raw_parts(
+                    unsafe { (*rel_ptr).as_ptr_wrapping() },
+                    len,
+                );
+
+                // SAFETY: `in_subtree` checked that `ptr` is properly aligned
+                // and points to enough bytes for `len` elements.
+                context.in_subtree(ptr, |context| unsafe {
+                    context.check_elements(ptr)
+                })
+            }
+        }
+    }
 }
@@ -5,7 +5,7 @@
 
 // mod impls;
 
-use core::marker::PhantomData;
+use core::{hash::Hasher, marker::PhantomData};
 
 use rancor::Fallible;
 
@@ -128,13 +128,32 @@ pub trait DeserializeWith<F: ?Sized, T, D: Fallible + ?Sized> {
         -> Result<T, D::Error>;
 }
 
-/// A generic wrapper that allows wrapping an `Option<T>`.
+/// A wrapper that can hash a field the same way as its archived form.
+///
+/// Hash maps are hashed before their keys are archived, so wrappers used on the
+/// keys of a hash map must implement this for the archived map to find its
+/// archived keys.
+pub trait HashKeyWith<F: ?Sized>: ArchiveWith<F> {
+    /// Feeds the field into the given hasher the same way as its archived form.
+    fn hash_key_with<H: Hasher>(field: &F, state: &mut H);
+}
+
+/// A generic wrapper that allows wrapping an `Option<T>`, `Vec<T>`, or the
+/// values of a `HashMap<K, V>` or `BTreeMap<K, V>`.
+///
+/// The wrapper `T` is applied to each element. Map keys are archived as usual.
+///
+/// `Map` can be nested to wrap the elements of nested containers. For example,
+/// `Map<Map<AsBox>>` boxes each element of a `Vec<Vec<T>>` or each element in
+/// the values of a `HashMap<K, Vec<V>>`.
 ///
 /// # Example
 ///
 /// ```
+/// use std::collections::HashMap;
+///
 /// use rkyv::{
-///     with::{InlineAsBox, Map},
+///     with::{AsBox, InlineAsBox, Map},
 ///     Archive,
 /// };
 ///
@@ -144,14 +163,40 @@ pub trait DeserializeWith<F: ?Sized, T, D: Fallible + ?Sized> {
 ///     option: Option<&'a i32>,
 ///     #[with(Map<InlineAsBox>)]
 ///     vec: Vec<&'a i32>,
+///     #[with(Map<AsBox>)]
+///     map: HashMap<String, i32>,
+///     #[with(Map<Map<AsBox>>)]
+///     nested: Vec<Vec<i32>>,
 /// }
 /// ```
 pub struct Map<T> {
     _phantom: PhantomData<T>,
 }
 
-/// A generic wrapper that allows wrapping a `HashMap<K, V>` or
-/// `BTreeMap<K, V>`.
+/// A wrapper that applies the wrapper `K` to each key and the wrapper `V` to
+/// each value of a `HashMap<K, V>` or `BTreeMap<K, V>`.
+///
+/// The key wrapper of a `HashMap` must implement [`HashKeyWith`] so that the
+/// archived keys are hashed the same way as they were when the map was
+/// serialized. The archived keys of a `BTreeMap` must be ordered the same way
+/// as their unwrapped values.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use rkyv::{
+///     with::{AsBox, MapKV, Niche},
+///     Archive,
+/// };
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(MapKV<AsBox, Niche>)]
+///     map: HashMap<String, Option<Box<str>>>,
+/// }
+/// ```
 pub struct MapKV<K, V> {
     _phantom: PhantomData<(K, V)>,
 }
@@ -170,6 +215,11 @@ pub struct SeqCst;
 ///
 /// When serializing, the specified ordering will be used to load the value from
 /// the source atomic. The underlying archived type is still a non-atomic value.
+/// When deserializing, a new atomic is created with the archived value.
+///
+/// `AtomicLoad` supports `AtomicBool` and all of the atomic integer types
+/// (including `AtomicUsize` and `AtomicIsize`) that are available on the
+/// target. The ordering may be [`Relaxed`], [`Acquire`], or [`SeqCst`].
 ///
 /// # Example
 ///
@@ -281,6 +331,67 @@ pub struct InlineAsBox;
 #[derive(Debug)]
 pub struct AsString;
 
+/// A wrapper that archives paths as UTF-8 strings.
+///
+/// `PathBuf` is archived as the raw bytes of its OS string by default, so that
+/// every path round-trips exactly. `AsPathString` archives paths as
+/// [`ArchivedString`](crate::string::ArchivedString)s instead, and fails to
+/// serialize paths which aren't valid UTF-8. It is the same wrapper as
+/// [`AsString`].
+///
+/// # Example
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rkyv::{with::AsPathString, Archive};
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(AsPathString)]
+///     path: PathBuf,
+/// }
+/// ```
+pub type AsPathString = AsString;
+
+/// A wrapper that archives a type as its string form.
+///
+/// The value is serialized by formatting it with
+/// [`Display`](core::fmt::Display) and deserialized by parsing it with
+/// [`FromStr`](core::str::FromStr). Parse errors are returned as
+/// deserialization errors. The `Display` implementation must produce the same
+/// string each time it is called on the same value.
+///
+/// # Example
+///
+/// ```
+/// use std::net::Ipv4Addr;
+///
+/// use rkyv::{
+///     rancor::Error, with::DisplayFromStr, Archive, Deserialize, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+/// #[rkyv(check_bytes)]
+/// struct Example {
+///     #[with(DisplayFromStr)]
+///     addr: Ipv4Addr,
+/// }
+///
+/// let value = Example {
+///     addr: Ipv4Addr::new(127, 0, 0, 1),
+/// };
+///
+/// let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+/// let archived = rkyv::access::<ArchivedExample, Error>(&bytes).unwrap();
+/// assert_eq!(archived.addr, "127.0.0.1");
+///
+/// let deserialized = rkyv::deserialize::<Example, Error>(archived).unwrap();
+/// assert_eq!(deserialized, value);
+/// ```
+#[derive(Debug)]
+pub struct DisplayFromStr;
+
 /// A wrapper that locks a lock and serializes the value immutably.
 ///
 /// This wrapper can panic under very specific circumstances when:
@@ -333,6 +444,87 @@ pub struct Lock<T> {
 #[derive(Debug)]
 pub struct AsOwned;
 
+/// A wrapper that serializes a `Cow<str>` as if it were owned, and borrows it
+/// from the archive when deserializing if possible.
+///
+/// When deserializing with a deserializer that implements
+/// [`Borrowing`](crate::de::Borrowing) and knows the buffer being deserialized
+/// from, the deserialized string will borrow from that buffer instead of
+/// allocating. Otherwise, the string is copied into an owned `String`. See
+/// [`Borrower`](crate::de::Borrower) for an example.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use rkyv::{with::AsCowStr, Archive};
+///
+/// #[derive(Archive)]
+/// struct Example<'a> {
+///     #[with(AsCowStr)]
+///     a: Cow<'a, str>,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsCowStr;
+
+/// A wrapper that defers deserializing a field until it is first accessed.
+///
+/// This wrapper applies to [`Deferred`](crate::util::Deferred) fields, and
+/// serializes them as their inner values. When deserializing with a
+/// deserializer that implements [`Borrowing`](crate::de::Borrowing) and knows
+/// the buffer being deserialized from, the deserialized `Deferred` holds a
+/// reference to the archived value and deserializes it when it is first
+/// accessed. Otherwise, the value is deserialized immediately.
+///
+/// Because the deferred value borrows from the buffer, the buffer must outlive
+/// the deserialized value. Errors from the deferred deserialization are
+/// returned from [`Deferred::get`](crate::util::Deferred::get), or from
+/// [`Deferred::get_with`](crate::util::Deferred::get_with) to deserialize with
+/// a deserializer of your choice. Serializing a `Deferred` which has not been
+/// accessed yet deserializes a temporary copy of its value.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     access,
+///     api::deserialize_with,
+///     de::{Borrower, Pool},
+///     rancor::Error,
+///     to_bytes,
+///     util::Deferred,
+///     with::Lazy,
+///     Archive, Archived, Deserialize, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Example<'a> {
+///     name: String,
+///     #[with(Lazy)]
+///     heavy: Deferred<'a, Vec<String>>,
+/// }
+///
+/// let value = Example {
+///     name: "example".to_string(),
+///     heavy: Deferred::new(vec!["a lot of data".to_string()]),
+/// };
+/// let bytes = to_bytes::<Error>(&value).unwrap();
+/// let archived = access::<Archived<Example>, Error>(&bytes).unwrap();
+///
+/// let mut deserializer = Borrower::new(&bytes, Pool::new());
+/// let deserialized: Example<'_> =
+///     deserialize_with::<_, _, Error>(archived, &mut deserializer).unwrap();
+/// assert!(!deserialized.heavy.is_deserialized());
+///
+/// let heavy = deserialized.heavy.get::<Error>().unwrap();
+/// assert_eq!(heavy, &["a lot of data".to_string()]);
+/// ```
+#[derive(Debug)]
+pub struct Lazy;
+
 /// A wrapper that serializes associative containers as a `Vec` of key-value
 /// pairs.
 ///
@@ -356,6 +548,199 @@ pub struct AsOwned;
 #[derive(Debug)]
 pub struct AsVec;
 
+/// A wrapper that stores the elements of a `Vec` inline when it has at most `N`
+/// elements.
+///
+/// Short vecs are archived as a fixed-size array of `N` elements, which avoids
+/// the indirection of an [`ArchivedVec`](crate::vec::ArchivedVec). Longer vecs
+/// fall back to storing their elements out-of-line. The archived type is an
+/// [`ArchivedInlineVec`](crate::vec::ArchivedInlineVec), which always reserves
+/// space for `N` elements.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{with::InlineVec, Archive};
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(InlineVec<4>)]
+///     values: Vec<u32>,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct InlineVec<const N: usize>;
+
+/// A wrapper that compresses a `Vec<u8>` with the codec `C`.
+///
+/// The bytes are compressed during serialization and archived as an
+/// [`ArchivedCompressed`](crate::compression::ArchivedCompressed), which stores
+/// the compressed bytes along with their original length and a tag identifying
+/// the codec. Accessing the bytes requires decompressing them with
+/// [`decompress_to`](crate::compression::ArchivedCompressed::decompress_to).
+///
+/// An LZ4 codec is available with the `lz4_flex` feature. Other codecs can be
+/// used by implementing [`Codec`](crate::compression::Codec).
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     access,
+///     compression::Codec,
+///     rancor::{Error, Source},
+///     to_bytes,
+///     with::Compressed,
+///     Archive, Serialize,
+/// };
+///
+/// /// A codec which doesn't compress at all.
+/// struct Identity;
+///
+/// impl Codec for Identity {
+///     const TAG: u8 = 0xff;
+///
+///     fn max_compressed_len(len: usize) -> usize {
+///         len
+///     }
+///
+///     fn max_decompressed_len(compressed_len: usize) -> usize {
+///         compressed_len
+///     }
+///
+///     fn compress(input: &[u8], output: &mut Vec<u8>) {
+///         output.extend_from_slice(input);
+///     }
+///
+///     fn decompress<E: Source>(
+///         input: &[u8],
+///         _: usize,
+///         output: &mut Vec<u8>,
+///     ) -> Result<(), E> {
+///         output.extend_from_slice(input);
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Archive, Serialize)]
+/// #[rkyv(check_bytes)]
+/// struct Example {
+///     #[with(Compressed<Identity>)]
+///     payload: Vec<u8>,
+/// }
+///
+/// let value = Example {
+///     payload: vec![1, 2, 3, 4],
+/// };
+/// let bytes = to_bytes::<Error>(&value).unwrap();
+/// let archived = access::<ArchivedExample, Error>(&bytes).unwrap();
+///
+/// let mut payload = Vec::new();
+/// archived
+///     .payload
+///     .decompress_to::<Error>(&mut payload)
+///     .unwrap();
+/// assert_eq!(payload, value.payload);
+/// ```
+#[derive(Debug)]
+pub struct Compressed<C> {
+    _phantom: PhantomData<C>,
+}
+
+/// A wrapper that archives a `HashMap` or `HashSet` using the hasher `H`.
+///
+/// Archived hash maps and sets use [`FxHasher64`](crate::hash::FxHasher64) by
+/// default, which is fast but offers no resistance to collision attacks. This
+/// wrapper archives the container with `H` instead, and records it in the
+/// archived type so that lookups use the same hasher.
+///
+/// Both serialization and lookups hash keys using `H::default()`, so `H` must
+/// be deterministic: its `Default` state must always be the same (for example,
+/// a keyed hasher with a fixed key) and it must produce the same hashes on all
+/// target platforms. Hashers which are randomly seeded or which hash `usize`
+/// differently depending on the target will produce unusable archives.
+///
+/// # Example
+///
+/// ```
+/// use std::{collections::HashMap, hash::Hasher};
+///
+/// use rkyv::{hash::FxHasher64, with::HashWith, Archive};
+///
+/// /// An FxHash variant seeded with a fixed key.
+/// struct SeededHasher(FxHasher64);
+///
+/// impl Default for SeededHasher {
+///     fn default() -> Self {
+///         let mut inner = FxHasher64::default();
+///         inner.write_u64(0x1234_5678_9abc_def0);
+///         Self(inner)
+///     }
+/// }
+///
+/// impl Hasher for SeededHasher {
+///     fn write(&mut self, bytes: &[u8]) {
+///         self.0.write(bytes);
+///     }
+///
+///     fn finish(&self) -> u64 {
+///         self.0.finish()
+///     }
+/// }
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(HashWith<SeededHasher>)]
+///     values: HashMap<String, u32>,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct HashWith<H> {
+    _phantom: PhantomData<H>,
+}
+
+/// A wrapper that archives a `HashMap` as an
+/// [`ArchivedIndexMap`](crate::collections::swiss_table::ArchivedIndexMap)
+/// with its entries sorted by key.
+///
+/// An archived hash map iterates over its entries in an order that depends on
+/// the hashes of its keys. A `HashMap` doesn't remember the order its entries
+/// were inserted in, so this wrapper sorts them by key instead. The archived
+/// index map iterates over its entries in that order, and maps with the same
+/// entries always produce the same bytes. This makes it easier to compare
+/// archives. Lookups are still O(1).
+///
+/// Sorting requires `K: Ord` and costs O(n log n) time and O(n) extra memory
+/// during serialization.
+///
+/// The entries of an archived index map are stored contiguously in iteration
+/// order, and its hash table stores the index of each entry instead of the
+/// entry itself. This costs an extra
+/// [`ArchivedUsize`](crate::primitive::ArchivedUsize) per
+/// bucket of the hash table and a level of indirection for each lookup.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use rkyv::{with::OrderedMap, Archive};
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(OrderedMap)]
+///     values: HashMap<String, u32>,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OrderedMap;
+
+/// A wrapper that archives a `HashMap` with its entries sorted by key, so that
+/// maps with the same entries always produce the same bytes.
+///
+/// This is another name for [`OrderedMap`].
+pub type DeterministicMap = OrderedMap;
+
 /// A wrapper that niches some type combinations.
 ///
 /// A common type combination is `Option<Box<T>>`. By using a null pointer, the
@@ -387,6 +772,45 @@ pub struct AsVec;
 #[derive(Debug)]
 pub struct Niche;
 
+/// A wrapper that niches an `Option<T>` using the niching strategy `N`.
+///
+/// The `None` variant is stored as a sentinel value in the bytes of the
+/// archived `T`, so the archived option takes up the same space as a `T`. The
+/// archived type is a
+/// [`NichedOption`](crate::niche::niched_option::NichedOption).
+///
+/// Niching strategies implement [`Niching`](crate::niche::niching::Niching).
+/// Rkyv provides strategies for `NonZero` integers
+/// ([`Zero`](crate::niche::niching::Zero)) and `bool`s
+/// ([`Bool`](crate::niche::niching::Bool)). See `Niching` for how to write a
+/// custom niching strategy.
+///
+/// # Example
+///
+/// ```
+/// use core::{mem::size_of, num::NonZeroU32};
+///
+/// use rkyv::{
+///     niche::niching::{Bool, Zero},
+///     with::NicheInto,
+///     Archive, Archived,
+/// };
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(NicheInto<Zero>)]
+///     id: Option<NonZeroU32>,
+///     #[with(NicheInto<Bool>)]
+///     flag: Option<bool>,
+/// }
+///
+/// assert_eq!(size_of::<Archived<Example>>(), 8);
+/// ```
+#[derive(Debug)]
+pub struct NicheInto<N> {
+    _phantom: PhantomData<N>,
+}
+
 /// A wrapper that converts a [`SystemTime`](::std::time::SystemTime) to a
 /// [`Duration`](::std::time::Duration) since
 /// [`UNIX_EPOCH`](::std::time::UNIX_EPOCH).
@@ -410,6 +834,30 @@ pub struct Niche;
 #[derive(Debug)]
 pub struct AsUnixTime;
 
+/// A wrapper that archives a [`chrono::Duration`] as an
+/// [`ArchivedDuration`](crate::time::ArchivedDuration).
+///
+/// `ArchivedDuration` can only represent non-negative durations, so serializing
+/// a negative `chrono::Duration` will fail with an error. Deserializing will
+/// also fail if the archived duration is too long to fit in a
+/// `chrono::Duration`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Duration;
+/// use rkyv::{with::AsChronoDuration, Archive};
+///
+/// #[derive(Archive)]
+/// struct Example {
+///     #[with(AsChronoDuration)]
+///     timeout: Duration,
+/// }
+/// ```
+#[cfg(feature = "chrono")]
+#[derive(Debug)]
+pub struct AsChronoDuration;
+
 /// A wrapper that allows serialize-unsafe types to be serialized.
 ///
 /// Types like `Cell` and `UnsafeCell` may contain serializable types, but have
@@ -448,6 +896,14 @@ pub struct Unsafe;
 ///
 /// Skipped fields must implement `Default` to be deserialized.
 ///
+/// Unlike `#[rkyv(skip)]`, which removes the field from the archived type
+/// entirely, `Skip` keeps the field in the archived type as a zero-sized `()`
+/// placeholder. The archived type keeps the same fields in the same order as
+/// the original type, so derived traits like `Debug` still show the field and
+/// tuple struct field indices don't shift. Neither takes up any space in the
+/// archive. Use `#[rkyv(skip)]` instead to customize the deserialized value
+/// with `default = ...`.
+///
 /// # Example
 ///
 /// ```
@@ -463,5 +919,150 @@ pub struct Unsafe;
 pub struct Skip;
 
 /// A wrapper that clones the contents of `Arc` and `Rc` pointers.
+///
+/// This archives the contents of the shared pointer inline, so it changes the
+/// archived type. To keep sharing in the archive but deserialize each shared
+/// pointer to a new allocation, use
+/// [`deserialize_unshared`](crate::api::high::deserialize_unshared) instead.
 #[derive(Debug)]
 pub struct Unshare;
+
+/// A wrapper that deduplicates equal values in the archive.
+///
+/// The first time a value is serialized with `Dedup`, it is written out of line
+/// like a shared pointer. Every later value which is equal to it points to that
+/// same serialized value instead of writing it again. The archived type is
+/// an `ArchivedRc`, so archived fields can be checked with `ptr_eq` to tell
+/// whether they were deduplicated.
+///
+/// Unlike `Rc` and `Arc`, which share serializations of the _same_ pointer,
+/// `Dedup` shares serializations of _equal_ values. The field type must
+/// implement `Hash`, `Eq`, `Clone`, `Send`, `Sync`, and be `'static`. Each
+/// value is hashed while serializing, and the first of each distinct value
+/// is cloned so that later values can be compared with it. This can be
+/// expensive for large values, so it's best used for fields which are likely
+/// to repeat.
+///
+/// Values are only deduplicated if the serializer's sharing strategy supports
+/// it. Serializers which use [`Unshare`](crate::ser::sharing::Unshare) write
+/// every value out in full.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{rancor::Error, with::Dedup, Archive, Serialize};
+///
+/// #[derive(Archive, Serialize)]
+/// #[rkyv(check_bytes)]
+/// struct Example {
+///     #[with(Dedup)]
+///     a: String,
+///     #[with(Dedup)]
+///     b: String,
+/// }
+///
+/// let value = Example {
+///     a: "a long string which only needs to be stored once".to_string(),
+///     b: "a long string which only needs to be stored once".to_string(),
+/// };
+/// let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+/// let archived = rkyv::access::<ArchivedExample, Error>(&bytes).unwrap();
+/// assert!(archived.a.ptr_eq(&archived.b));
+/// ```
+#[derive(Debug)]
+pub struct Dedup;
+
+/// A wrapper that archives a plain-old-data type by copying its bytes.
+///
+/// The field type must already be [`Portable`](crate::Portable), so its
+/// archived type is itself. Instead of resolving each field separately, the
+/// whole value is copied into the archive at once. This can be faster for large
+/// `#[repr(C)]` structs which are made only of fixed-size archived integers.
+///
+/// The field type must also be [`Initialized`](crate::place::Initialized),
+/// which is an `unsafe` trait. Implementing it promises that every byte of the
+/// type is always initialized, so it must not have any padding. Because the
+/// bytes are copied as-is, the type must also have the same representation on
+/// every platform. Use archived primitives like
+/// [`Archived<u32>`](crate::Archived) for its fields instead of native ones so
+/// that the archive doesn't depend on the endianness of the platform which
+/// wrote it.
+///
+/// When validating, the archived value is checked with its own `CheckBytes`
+/// implementation. For types made only of archived integers, this just checks
+/// that the value is in bounds and properly aligned.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     place::Initialized, with::AsBytes, Archive, Archived, Portable,
+///     Serialize,
+/// };
+///
+/// #[derive(Clone, Copy, Portable)]
+/// #[repr(C)]
+/// struct Point {
+///     x: Archived<u32>,
+///     y: Archived<u32>,
+/// }
+///
+/// // SAFETY: `Point` is made of two archived `u32`s and has no padding.
+/// unsafe impl Initialized for Point {}
+///
+/// #[derive(Archive, Serialize)]
+/// struct Example {
+///     #[with(AsBytes)]
+///     point: Point,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsBytes;
+
+/// A wrapper that archives a `Vec` of plain-old-data types by copying its
+/// elements' bytes.
+///
+/// This is like [`AsBytes`], but for a `Vec` of elements. The archived type is
+/// an [`ArchivedVec`](crate::vec::ArchivedVec) of the element type, and the
+/// elements are written into the archive with a single copy instead of being
+/// serialized and resolved one at a time. This can make serializing large
+/// vecs of simple structs much faster.
+///
+/// The element type has the same requirements as with `AsBytes`. It must
+/// already be [`Portable`](crate::Portable) and
+/// [`Initialized`](crate::place::Initialized), which is an `unsafe` trait that
+/// promises it has no padding. The bytes are copied exactly as they are in
+/// memory, so elements should only contain archived primitives like
+/// [`Archived<u32>`](crate::Archived). Using native primitives would make the
+/// archive depend on the endianness of the platform which wrote it.
+///
+/// When validating, the pointer and length of the vec are checked, and then
+/// each element is checked with its own `CheckBytes` implementation. For types
+/// made only of archived integers, this is very cheap.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     place::Initialized, with::RawVec, Archive, Archived, Portable,
+///     Serialize,
+/// };
+///
+/// #[derive(Clone, Copy, Portable)]
+/// #[repr(C)]
+/// struct Point {
+///     x: Archived<u32>,
+///     y: Archived<u32>,
+/// }
+///
+/// // SAFETY: `Point` is made of two archived `u32`s and has no padding.
+/// unsafe impl Initialized for Point {}
+///
+/// #[derive(Archive, Serialize)]
+/// struct Example {
+///     #[with(RawVec)]
+///     points: Vec<Point>,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RawVec;
@@ -1,5 +1,6 @@
 mod r#enum;
 mod printing;
+mod schema;
 mod r#struct;
 
 use core::fmt::Display;
@@ -86,12 +87,30 @@ fn derive_archive_impl(
     input: &mut DeriveInput,
     attributes: &Attributes,
 ) -> Result<TokenStream, Error> {
+    let schema_hash = schema::schema_hash(input, attributes);
+    let name = &input.ident;
+    let rkyv_path = attributes.crate_path();
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let schema_impl = quote! {
+        #[automatically_derived]
+        impl #impl_generics #rkyv_path::traits::SchemaHash
+            for #name #ty_generics
+        #where_clause
+        {
+            const SCHEMA_HASH: u64 = #schema_hash;
+        }
+    };
+
     let where_clause = input.generics.make_where_clause();
     if let Some(ref bounds) = attributes.archive_bounds {
         for bound in bounds {
             where_clause.predicates.push(bound.clone());
         }
     }
+    for bound in attributes.bounds_for(None) {
+        where_clause.predicates.push(bound.clone());
+    }
 
     let printing = printing::Printing::new(input, attributes)?;
 
@@ -106,11 +125,11 @@ fn derive_archive_impl(
         }
     };
 
-    let rkyv_path = &printing.rkyv_path;
-
     Ok(quote! {
         #archive_types
 
+        #schema_impl
+
         #[automatically_derived]
         const _: () = {
             use core::marker::PhantomData;
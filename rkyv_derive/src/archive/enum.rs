@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, Span, TokenStream};
 use quote::quote;
 use syn::{
     parse_quote, spanned::Spanned as _, Data, DataEnum, DeriveInput, Error,
@@ -12,6 +12,7 @@ use crate::{
         resolver_variant_doc, variant_doc,
     },
     attributes::Attributes,
+    repr::{Primitive, Repr},
     util::{
         archive_bound, archived, is_not_omitted, members_starting_at, resolve,
         resolver, strip_raw,
@@ -28,15 +29,100 @@ pub fn impl_enum(
         _ => unreachable!(),
     };
 
-    if data.variants.len() > 256 {
-        return Err(Error::new_spanned(
-            &input.ident,
-            "enums with more than 256 variants cannot derive Archive",
-        ));
-    }
-
     let rkyv_path = &printing.rkyv_path;
 
+    // Archived tags follow an explicit fixed-size `repr` so that they have the
+    // same size and sign as the discriminants of the labeled type. Otherwise,
+    // they are a single byte when possible so that they are the same on every
+    // platform, and enums with more variants than fit in a byte get a wider
+    // tag. Tags wider than a byte have discriminants chosen so that the tag is
+    // stored with the endianness of the archive.
+    let repr_primitive = match Repr::from_attrs(&input.attrs)? {
+        Repr::Primitive(primitive)
+        | Repr::C {
+            primitive: Some(primitive),
+            ..
+        } => Some(primitive),
+        _ => None,
+    };
+    let variant_count = data.variants.len();
+    let tag_primitive = match repr_primitive {
+        Some(primitive @ (Primitive::I8 | Primitive::U8)) => {
+            if variant_count > 256 {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    format!(
+                        "enums with `repr({})` cannot have more than 256 \
+                         variants",
+                        primitive.as_str(),
+                    ),
+                ));
+            }
+            primitive
+        }
+        // The sizes of `isize` and `usize` depend on the platform, so they
+        // don't choose the tag.
+        Some(primitive)
+            if !matches!(primitive, Primitive::Isize | Primitive::Usize) =>
+        {
+            primitive
+        }
+        _ if variant_count <= 256 => Primitive::U8,
+        _ if variant_count <= 65536 => Primitive::U16,
+        _ => Primitive::U32,
+    };
+    let tag_ident = Ident::new(tag_primitive.as_str(), Span::call_site());
+    let tag_repr = quote! { #tag_ident };
+    let wide_tag_fn = (!matches!(tag_primitive, Primitive::I8 | Primitive::U8))
+        .then(|| {
+            Ident::new(
+                &format!("archived_tag_{}", tag_primitive.as_str()),
+                Span::call_site(),
+            )
+        });
+
+    // The discriminant of each variant in the labeled type.
+    let mut last_explicit = None;
+    let mut offset = 0usize;
+    let discriminant_values = data
+        .variants
+        .iter()
+        .map(|v| {
+            if let Some((_, expr)) = &v.discriminant {
+                last_explicit = Some(expr);
+                offset = 0;
+            }
+            let offset_lit = Literal::usize_unsuffixed(offset);
+            offset += 1;
+            match last_explicit {
+                Some(expr) if offset == 1 => quote! { #expr },
+                Some(expr) => quote! { (#expr) + #offset_lit },
+                None => quote! { #offset_lit },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // The discriminant of each variant in the archived type.
+    let tag_discriminants = match &wide_tag_fn {
+        None => data
+            .variants
+            .iter()
+            .map(|v| {
+                v.discriminant
+                    .as_ref()
+                    .map(|(eq, expr)| quote! { #eq #expr })
+            })
+            .collect::<Vec<_>>(),
+        Some(tag_fn) => discriminant_values
+            .iter()
+            .map(|value| {
+                Some(quote! {
+                    = #rkyv_path::primitive::#tag_fn((#value) as #tag_repr)
+                })
+            })
+            .collect(),
+    };
+
     let where_clause = input.generics.make_where_clause();
 
     for field in data
@@ -57,24 +143,42 @@ pub fn impl_enum(
     let archived_def = attributes
         .archive_as
         .is_none()
-        .then(|| generate_archived_def(input, attributes, printing, data))
+        .then(|| {
+            generate_archived_def(
+                input,
+                attributes,
+                printing,
+                data,
+                &tag_repr,
+                &tag_discriminants,
+            )
+        })
         .transpose()?;
 
     let resolver_def = generate_resolver_def(input, printing, data)?;
     let resolve_arms = generate_resolve_arms(input, printing, data)?;
 
-    let archived_variant_tags = data.variants.iter().map(|v| {
-        let variant = &v.ident;
-        let discriminant = v
-            .discriminant
-            .as_ref()
-            .map(|(eq, expr)| quote! { #eq #expr });
-        quote! { #variant #discriminant }
-    });
+    let archived_variant_tags =
+        data.variants.iter().zip(tag_discriminants.iter()).map(
+            |(v, discriminant)| {
+                let variant = &v.ident;
+                quote! { #variant #discriminant }
+            },
+        );
 
     let archived_variant_structs =
         generate_variant_structs(input, printing, data)?;
 
+    let variant_info_impl = attributes.archive_as.is_none().then(|| {
+        generate_variant_info_impl(
+            input,
+            printing,
+            data,
+            repr_primitive.unwrap_or(Primitive::Isize),
+            &discriminant_values,
+        )
+    });
+
     let mut partial_eq_impl = None;
     let mut partial_ord_impl = None;
     if let Some(ref compares) = attributes.compares {
@@ -83,8 +187,27 @@ pub fn impl_enum(
                 partial_eq_impl =
                     Some(generate_partial_eq_impl(input, data, printing)?);
             } else if compare.is_ident("PartialOrd") {
-                partial_ord_impl =
-                    Some(generate_partial_ord_impl(input, data, printing)?);
+                // Wide tags don't compare in variant order, so compare the
+                // discriminants of the labeled type instead.
+                let tag_orders = data
+                    .variants
+                    .iter()
+                    .zip(discriminant_values.iter())
+                    .map(|(v, value)| {
+                        let variant = &v.ident;
+                        if wide_tag_fn.is_some() {
+                            quote! { (#value) as #tag_repr }
+                        } else {
+                            quote! { ArchivedTag::#variant }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                partial_ord_impl = Some(generate_partial_ord_impl(
+                    input,
+                    data,
+                    printing,
+                    &tag_orders,
+                )?);
             } else {
                 return Err(Error::new_spanned(
                     compare,
@@ -106,7 +229,7 @@ pub fn impl_enum(
         },
         quote! {
             #[derive(PartialEq, PartialOrd)]
-            #[repr(u8)]
+            #[repr(#tag_repr)]
             enum ArchivedTag {
                 #(#archived_variant_tags,)*
             }
@@ -131,17 +254,71 @@ pub fn impl_enum(
                 }
             }
 
+            #variant_info_impl
             #partial_eq_impl
             #partial_ord_impl
         },
     ))
 }
 
+fn generate_variant_info_impl(
+    input: &DeriveInput,
+    printing: &Printing,
+    data: &DataEnum,
+    repr: Primitive,
+    discriminant_values: &[TokenStream],
+) -> TokenStream {
+    let rkyv_path = &printing.rkyv_path;
+    let archived_type = &printing.archived_type;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+
+    // `i128` can hold every discriminant of every `repr`, so converting to it
+    // sign-extends negative discriminants without losing large unsigned ones.
+    let repr_ty = Ident::new(repr.as_str(), Span::call_site());
+
+    let name_arms = data.variants.iter().map(|v| {
+        let variant = &v.ident;
+        let variant_name = strip_raw(variant);
+        quote! { Self::#variant { .. } => #variant_name }
+    });
+    let discriminant_arms =
+        data.variants
+            .iter()
+            .zip(discriminant_values)
+            .map(|(v, value)| {
+                let variant = &v.ident;
+                quote! {
+                    Self::#variant { .. } =>
+                        ((#value) as #repr_ty) as i128
+                }
+            });
+
+    quote! {
+        impl #impl_generics #rkyv_path::traits::ArchivedEnum for #archived_type
+        #where_clause
+        {
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(#name_arms,)*
+                }
+            }
+
+            fn discriminant(&self) -> i128 {
+                match *self {
+                    #(#discriminant_arms,)*
+                }
+            }
+        }
+    }
+}
+
 fn generate_archived_def(
     input: &DeriveInput,
     attributes: &Attributes,
     printing: &Printing,
     data: &DataEnum,
+    tag_repr: &TokenStream,
+    tag_discriminants: &[Option<TokenStream>],
 ) -> Result<TokenStream, Error> {
     let name = &input.ident;
     let rkyv_path = &printing.rkyv_path;
@@ -149,12 +326,9 @@ fn generate_archived_def(
     let archived_variants = data
         .variants
         .iter()
-        .map(|v| {
+        .zip(tag_discriminants.iter())
+        .map(|(v, discriminant)| {
             let variant = &v.ident;
-            let discriminant = v
-                .discriminant
-                .as_ref()
-                .map(|(eq, expr)| quote! { #eq #expr });
 
             let variant_doc = variant_doc(name, variant);
 
@@ -237,7 +411,7 @@ fn generate_archived_def(
         #[automatically_derived]
         #[doc = #archived_doc]
         #(#archive_attrs)*
-        #[repr(u8)]
+        #[repr(#tag_repr)]
         #vis enum #archived_name #generics #where_clause {
             #(#archived_variants,)*
         }
@@ -474,8 +648,8 @@ fn generate_resolve_arms(
                         let out = unsafe {
                             out.cast_unchecked::<ArchivedTag>()
                         };
-                        // SAFETY: `ArchivedTag` is `repr(u8)` and so is always
-                        // initialized.
+                        // SAFETY: `ArchivedTag` has a primitive integer repr
+                        // and so is always initialized.
                         unsafe {
                             out.write_unchecked(ArchivedTag::#variant);
                         }
@@ -654,6 +828,7 @@ fn generate_partial_ord_impl(
     input: &DeriveInput,
     data: &DataEnum,
     printing: &Printing,
+    tag_orders: &[TokenStream],
 ) -> Result<TokenStream, Error> {
     let mut partial_ord_where =
         input.generics.where_clause.as_ref().unwrap().clone();
@@ -676,34 +851,42 @@ fn generate_partial_ord_impl(
     let name = &input.ident;
     let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
 
-    let self_disc = data.variants.iter().map(|v| {
-        let variant = &v.ident;
-        match v.fields {
-            Fields::Named(_) => quote! {
-                #name::#variant { .. } => ArchivedTag::#variant
-            },
-            Fields::Unnamed(_) => quote! {
-                #name::#variant ( .. ) => ArchivedTag::#variant
-            },
-            Fields::Unit => quote! {
-                #name::#variant => ArchivedTag::#variant
-            },
-        }
-    });
-    let other_disc = data.variants.iter().map(|v| {
-        let variant = &v.ident;
-        match v.fields {
-            Fields::Named(_) => quote! {
-                #archived_name::#variant { .. } => ArchivedTag::#variant
-            },
-            Fields::Unnamed(_) => quote! {
-                #archived_name::#variant ( .. ) => ArchivedTag::#variant
-            },
-            Fields::Unit => quote! {
-                #archived_name::#variant => ArchivedTag::#variant
-            },
-        }
-    });
+    let self_disc =
+        data.variants
+            .iter()
+            .zip(tag_orders.iter())
+            .map(|(v, order)| {
+                let variant = &v.ident;
+                match v.fields {
+                    Fields::Named(_) => quote! {
+                        #name::#variant { .. } => #order
+                    },
+                    Fields::Unnamed(_) => quote! {
+                        #name::#variant ( .. ) => #order
+                    },
+                    Fields::Unit => quote! {
+                        #name::#variant => #order
+                    },
+                }
+            });
+    let other_disc =
+        data.variants
+            .iter()
+            .zip(tag_orders.iter())
+            .map(|(v, order)| {
+                let variant = &v.ident;
+                match v.fields {
+                    Fields::Named(_) => quote! {
+                        #archived_name::#variant { .. } => #order
+                    },
+                    Fields::Unnamed(_) => quote! {
+                        #archived_name::#variant ( .. ) => #order
+                    },
+                    Fields::Unit => quote! {
+                        #archived_name::#variant => #order
+                    },
+                }
+            });
 
     let variant_impls = data.variants.iter().map(|v| {
         let variant = &v.ident;
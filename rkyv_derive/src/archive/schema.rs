@@ -0,0 +1,255 @@
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, GenericParam, Path,
+    PathArguments, Type,
+};
+
+use crate::{
+    attributes::Attributes,
+    util::{is_not_skipped, strip_raw},
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Hashes a canonical description of a type definition.
+///
+/// Types are described by their structure instead of by their printed tokens,
+/// so the hash doesn't depend on how `proc_macro2` formats tokens.
+struct SchemaHasher {
+    state: u64,
+}
+
+impl SchemaHasher {
+    fn new() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+
+    fn write(&mut self, s: &str) {
+        // Each part is length-prefixed so that adjacent parts can't run
+        // together and collide.
+        for byte in (s.len() as u64).to_le_bytes().iter().chain(s.as_bytes()) {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&len.to_string());
+    }
+
+    // Used for the parts of a definition which don't have any more structure,
+    // like expressions. Each token is written separately so that the spacing
+    // between tokens is ignored.
+    fn write_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Group(group) => {
+                    self.write(match group.delimiter() {
+                        Delimiter::Parenthesis => "(",
+                        Delimiter::Brace => "{",
+                        Delimiter::Bracket => "[",
+                        Delimiter::None => "group",
+                    });
+                    self.write_tokens(group.stream());
+                    self.write("end");
+                }
+                TokenTree::Ident(ident) => self.write(&strip_raw(&ident)),
+                TokenTree::Punct(punct) => {
+                    self.write(punct.as_char().encode_utf8(&mut [0; 4]))
+                }
+                TokenTree::Literal(literal) => self.write(&literal.to_string()),
+            }
+        }
+    }
+
+    fn write_path(&mut self, path: &Path) {
+        self.write(if path.leading_colon.is_some() {
+            "absolute"
+        } else {
+            "relative"
+        });
+        self.write_len(path.segments.len());
+        for segment in path.segments.iter() {
+            self.write(&strip_raw(&segment.ident));
+            match &segment.arguments {
+                PathArguments::None => self.write("none"),
+                PathArguments::AngleBracketed(arguments) => {
+                    self.write("angle");
+                    self.write_len(arguments.args.len());
+                    for argument in arguments.args.iter() {
+                        self.write_generic_argument(argument);
+                    }
+                }
+                PathArguments::Parenthesized(arguments) => {
+                    self.write("paren");
+                    self.write_tokens(arguments.to_token_stream());
+                }
+            }
+        }
+    }
+
+    fn write_generic_argument(&mut self, argument: &GenericArgument) {
+        match argument {
+            // Lifetimes don't affect the archived representation.
+            GenericArgument::Lifetime(_) => self.write("lifetime"),
+            GenericArgument::Type(ty) => {
+                self.write("type");
+                self.write_type(ty);
+            }
+            GenericArgument::Const(expr) => {
+                self.write("const");
+                self.write_tokens(expr.to_token_stream());
+            }
+            GenericArgument::AssocType(assoc) => {
+                self.write("assoc");
+                self.write(&strip_raw(&assoc.ident));
+                self.write_type(&assoc.ty);
+            }
+            argument => {
+                self.write("argument");
+                self.write_tokens(argument.to_token_stream());
+            }
+        }
+    }
+
+    fn write_type(&mut self, ty: &Type) {
+        match ty {
+            Type::Array(array) => {
+                self.write("array");
+                self.write_type(&array.elem);
+                self.write_tokens(array.len.to_token_stream());
+            }
+            Type::Group(group) => self.write_type(&group.elem),
+            Type::Never(_) => self.write("never"),
+            Type::Paren(paren) => self.write_type(&paren.elem),
+            Type::Path(path) => {
+                self.write("path");
+                if let Some(qself) = &path.qself {
+                    self.write("qself");
+                    self.write_type(&qself.ty);
+                    self.write_len(qself.position);
+                }
+                self.write_path(&path.path);
+            }
+            Type::Ptr(ptr) => {
+                self.write(if ptr.mutability.is_some() {
+                    "ptr_mut"
+                } else {
+                    "ptr_const"
+                });
+                self.write_type(&ptr.elem);
+            }
+            Type::Reference(reference) => {
+                self.write(if reference.mutability.is_some() {
+                    "ref_mut"
+                } else {
+                    "ref"
+                });
+                self.write_type(&reference.elem);
+            }
+            Type::Slice(slice) => {
+                self.write("slice");
+                self.write_type(&slice.elem);
+            }
+            Type::Tuple(tuple) => {
+                self.write("tuple");
+                self.write_len(tuple.elems.len());
+                for elem in tuple.elems.iter() {
+                    self.write_type(elem);
+                }
+            }
+            ty => {
+                self.write("other");
+                self.write_tokens(ty.to_token_stream());
+            }
+        }
+    }
+
+    fn write_fields(&mut self, fields: &Fields) {
+        match fields {
+            Fields::Named(_) => self.write("named"),
+            Fields::Unnamed(_) => self.write("unnamed"),
+            Fields::Unit => self.write("unit"),
+        }
+        for field in fields.iter().filter(is_not_skipped) {
+            match &field.ident {
+                Some(ident) => self.write(&strip_raw(ident)),
+                None => self.write(""),
+            }
+            self.write_type(&field.ty);
+            for attr in field.attrs.iter() {
+                if attr.path().is_ident("with") {
+                    self.write_tokens(attr.meta.to_token_stream());
+                }
+            }
+        }
+    }
+}
+
+/// Computes a hash of the schema of the given type.
+///
+/// The hash covers the name and generic parameters of the type, the names,
+/// types, and wrappers of its fields, its variants and discriminants, and any
+/// `repr` attributes. Other attributes passed through to the archived type
+/// (e.g. derives) don't affect its layout and so are not included. The hash is
+/// computed from the type definition alone, so it is the same on every platform
+/// and for every compilation.
+pub fn schema_hash(input: &DeriveInput, attributes: &Attributes) -> u64 {
+    let mut hasher = SchemaHasher::new();
+
+    hasher.write(&strip_raw(&input.ident));
+    for param in input.generics.params.iter() {
+        match param {
+            GenericParam::Lifetime(_) => hasher.write("lifetime"),
+            GenericParam::Type(param) => {
+                hasher.write("type");
+                hasher.write(&strip_raw(&param.ident));
+            }
+            GenericParam::Const(param) => {
+                hasher.write("const");
+                hasher.write(&strip_raw(&param.ident));
+                hasher.write_type(&param.ty);
+            }
+        }
+    }
+    for attr in attributes.attrs.iter() {
+        if attr.path().is_ident("repr") {
+            hasher.write_tokens(attr.to_token_stream());
+        }
+    }
+    if let Some(archive_as) = &attributes.archive_as {
+        hasher.write(&archive_as.value());
+    }
+    if attributes.pack_bools.is_some() {
+        hasher.write("pack_bools");
+    }
+
+    match &input.data {
+        Data::Struct(data) => {
+            hasher.write("struct");
+            hasher.write_fields(&data.fields);
+        }
+        Data::Enum(data) => {
+            hasher.write("enum");
+            for attr in input.attrs.iter() {
+                if attr.path().is_ident("repr") {
+                    hasher.write_tokens(attr.meta.to_token_stream());
+                }
+            }
+            for variant in data.variants.iter() {
+                hasher.write(&strip_raw(&variant.ident));
+                if let Some((_, discriminant)) = &variant.discriminant {
+                    hasher.write_tokens(discriminant.to_token_stream());
+                }
+                hasher.write_fields(&variant.fields);
+            }
+        }
+        Data::Union(_) => hasher.write("union"),
+    }
+
+    hasher.state
+}
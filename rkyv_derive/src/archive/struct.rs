@@ -1,8 +1,8 @@
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
 use syn::{
     parse_quote, punctuated::Punctuated, Data, DeriveInput, Error, Fields,
-    FieldsNamed, FieldsUnnamed,
+    FieldsNamed, FieldsUnnamed, GenericParam, Ident, Index, Member,
 };
 
 use crate::{
@@ -12,7 +12,9 @@ use crate::{
     },
     attributes::Attributes,
     util::{
-        archive_bound, archived, is_not_omitted, members, resolve, resolver,
+        archive_bound, archived, archived_members, field_ref, is_not_omitted,
+        is_not_skipped, is_packed_bool, packed_bools, packed_bools_ident,
+        packed_bools_len, resolve, resolver, strip_raw,
     },
 };
 
@@ -30,7 +32,7 @@ pub fn impl_struct(
 
     let where_clause = input.generics.make_where_clause();
 
-    for field in fields.iter().filter(is_not_omitted) {
+    for field in fields.iter().filter(is_not_omitted).filter(is_not_skipped) {
         where_clause
             .predicates
             .push(archive_bound(rkyv_path, field)?);
@@ -46,32 +48,53 @@ pub fn impl_struct(
         .then(|| generate_archived_def(input, attributes, printing, fields))
         .transpose()?;
 
-    let resolver_def = generate_resolver_def(input, printing, fields)?;
+    let resolver_def =
+        generate_resolver_def(input, attributes, printing, fields)?;
 
-    let resolve_statements = members(fields)
-        .map(|(member, field)| {
+    // Remote types are resolved from the remote value instead of `self`.
+    let this = if attributes.remote.is_some() {
+        quote! { field }
+    } else {
+        quote! { self }
+    };
+
+    let mut resolve_statements = archived_members(fields)
+        .filter(|(_, _, field)| !is_packed_bool(attributes, field))
+        .filter_map(|(member, archived_member, field)| {
+            Some((member, archived_member?, field))
+        })
+        .map(|(member, archived_member, field)| {
             let resolves = resolve(rkyv_path, field)?;
+            let field_ref = field_ref(&this, &member, field)?;
             Ok(quote! {
                 let field_ptr = unsafe {
-                    ::core::ptr::addr_of_mut!((*out.ptr()).#member)
+                    ::core::ptr::addr_of_mut!((*out.ptr()).#archived_member)
                 };
                 let out_field = unsafe {
                     #rkyv_path::Place::from_field_unchecked(out, field_ptr)
                 };
-                #resolves(&self.#member, resolver.#member, out_field);
+                #resolves(
+                    #field_ref,
+                    resolver.#archived_member,
+                    out_field,
+                );
             })
         })
         .collect::<Result<Vec<_>, Error>>()?;
+    resolve_statements
+        .extend(resolve_packed_bools(attributes, printing, fields, &this)?);
 
     let mut partial_eq_impl = None;
     let mut partial_ord_impl = None;
     for compare in attributes.compares.iter().flat_map(Punctuated::iter) {
         if compare.is_ident("PartialEq") {
-            partial_eq_impl =
-                Some(generate_partial_eq_impl(input, fields, printing)?);
+            partial_eq_impl = Some(generate_partial_eq_impl(
+                input, attributes, fields, printing,
+            )?);
         } else if compare.is_ident("PartialOrd") {
-            partial_ord_impl =
-                Some(generate_partial_ord_impl(input, fields, printing)?);
+            partial_ord_impl = Some(generate_partial_ord_impl(
+                input, attributes, fields, printing,
+            )?);
         } else {
             return Err(Error::new_spanned(
                 compare,
@@ -85,11 +108,28 @@ pub fn impl_struct(
     let archived_type = &printing.archived_type;
     let resolver_name = &printing.resolver_name;
 
-    Ok((
+    let archive_impl = if let Some(remote) = &attributes.remote {
         quote! {
-            #archived_def
-            #resolver_def
-        },
+            impl #impl_generics #rkyv_path::with::ArchiveWith<#remote>
+                for #name #ty_generics
+            #where_clause
+            {
+                type Archived = #archived_type;
+                type Resolver = #resolver_name #ty_generics;
+
+                // Some resolvers will be (), this allow is to prevent clippy
+                // from complaining.
+                #[allow(clippy::unit_arg)]
+                fn resolve_with(
+                    field: &#remote,
+                    resolver: Self::Resolver,
+                    out: #rkyv_path::Place<Self::Archived>,
+                ) {
+                    #(#resolve_statements)*
+                }
+            }
+        }
+    } else {
         quote! {
             impl #impl_generics #rkyv_path::Archive for #name #ty_generics
             #where_clause
@@ -108,6 +148,16 @@ pub fn impl_struct(
                     #(#resolve_statements)*
                 }
             }
+        }
+    };
+
+    Ok((
+        quote! {
+            #archived_def
+            #resolver_def
+        },
+        quote! {
+            #archive_impl
 
             #partial_eq_impl
             #partial_ord_impl
@@ -136,9 +186,14 @@ fn generate_archived_def(
     let (impl_generics, ty_generics, where_clause) =
         input.generics.split_for_impl();
 
+    let packed_bools_impl =
+        generate_packed_bools_impl(input, attributes, printing, fields);
+
     Ok(quote! {
         #archived_def
 
+        #packed_bools_impl
+
         // SAFETY: As long as the `Archive` impl holds, the archived
         // type is guaranteed to be `Portable`.
         unsafe impl #impl_generics #rkyv_path::Portable
@@ -156,9 +211,11 @@ fn generate_archived_def_named(
 ) -> Result<TokenStream, Error> {
     let rkyv_path = &printing.rkyv_path;
 
-    let archived_fields = fields
+    let mut archived_fields = fields
         .named
         .iter()
+        .filter(is_not_skipped)
+        .filter(|field| !is_packed_bool(attributes, field))
         .map(|field| {
             let field_ty = archived(rkyv_path, field)?;
             let vis = &field.vis;
@@ -174,6 +231,14 @@ fn generate_archived_def_named(
         })
         .collect::<Result<Vec<_>, Error>>()?;
 
+    let packed_bools_len = packed_bools_len(attributes, &fields.named);
+    if packed_bools_len > 0 {
+        let packed_field = packed_bools_ident();
+        archived_fields.push(quote! {
+            #packed_field: [u8; #packed_bools_len]
+        });
+    }
+
     let archived_doc = archived_doc(&input.ident);
     let archive_attrs = &printing.archive_attrs;
     let vis = &input.vis;
@@ -204,6 +269,7 @@ fn generate_archived_def_unnamed(
         .unnamed
         .iter()
         .enumerate()
+        .filter(|(_, field)| is_not_skipped(field))
         .map(|(i, field)| {
             let field_doc = struct_field_doc(&input.ident, &i);
             let archive_attrs = field_archive_attrs(attributes, field);
@@ -258,12 +324,13 @@ fn generate_archived_def_unit(
 
 fn generate_resolver_def(
     input: &DeriveInput,
+    attributes: &Attributes,
     printing: &Printing,
     fields: &Fields,
 ) -> Result<TokenStream, Error> {
     match fields {
         Fields::Named(fields) => {
-            generate_resolver_def_named(input, printing, fields)
+            generate_resolver_def_named(input, attributes, printing, fields)
         }
         Fields::Unnamed(fields) => {
             generate_resolver_def_unnamed(input, printing, fields)
@@ -274,6 +341,7 @@ fn generate_resolver_def(
 
 fn generate_resolver_def_named(
     input: &DeriveInput,
+    attributes: &Attributes,
     printing: &Printing,
     fields: &FieldsNamed,
 ) -> Result<TokenStream, Error> {
@@ -287,6 +355,8 @@ fn generate_resolver_def_named(
     let resolver_fields = fields
         .named
         .iter()
+        .filter(is_not_skipped)
+        .filter(|field| !is_packed_bool(attributes, field))
         .map(|field| {
             let field_name = &field.ident;
             let resolver_ty = resolver(rkyv_path, field)?;
@@ -295,12 +365,191 @@ fn generate_resolver_def_named(
         })
         .collect::<Result<Vec<_>, Error>>()?;
 
+    let resolver_builder_def = attributes
+        .resolver_builder
+        .is_some()
+        .then(|| {
+            generate_resolver_builder_def(input, attributes, printing, fields)
+        })
+        .transpose()?;
+
     Ok(quote! {
         #[automatically_derived]
         #[doc = #resolver_doc]
         #vis struct #resolver_name #generics #where_clause {
             #(#resolver_fields,)*
         }
+
+        #resolver_builder_def
+    })
+}
+
+fn generate_resolver_builder_def(
+    input: &DeriveInput,
+    attributes: &Attributes,
+    printing: &Printing,
+    fields: &FieldsNamed,
+) -> Result<TokenStream, Error> {
+    let rkyv_path = &printing.rkyv_path;
+    let resolver_name = &printing.resolver_name;
+    let builder_name = Ident::new(
+        &format!("{}Builder", strip_raw(resolver_name)),
+        resolver_name.span(),
+    );
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let where_clause = where_clause.unwrap();
+
+    let fields = fields
+        .named
+        .iter()
+        .filter(is_not_skipped)
+        .filter(|field| !is_packed_bool(attributes, field))
+        .map(|field| {
+            let name = field.ident.as_ref().unwrap();
+            if strip_raw(name) == "build" {
+                return Err(Error::new_spanned(
+                    name,
+                    "fields named `build` conflict with the `build` method of \
+                     the resolver builder",
+                ));
+            }
+            Ok((name, resolver(rkyv_path, field)?))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    // The builder has one type parameter for each field. It starts out as
+    // `PhantomData<R>` and becomes the resolver type `R` once the field is
+    // set, so `build` is only available after every field has been set.
+    let states = (0..fields.len())
+        .map(|i| Ident::new(&format!("__R{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    let tys = fields.iter().map(|(_, ty)| ty).collect::<Vec<_>>();
+
+    let generic_args = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut builder_generics = generics.clone();
+    for param in builder_generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Const(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Lifetime(_) => (),
+        }
+    }
+    builder_generics.params.extend(states.iter().map(
+        |state| -> GenericParam {
+            parse_quote! { #state }
+        },
+    ));
+    let (builder_impl_generics, ..) = builder_generics.split_for_impl();
+
+    let phantom = Index::from(fields.len());
+    let setters = fields.iter().enumerate().map(|(i, (name, ty))| {
+        let doc = format!(
+            "Sets the resolver for [`{}::{}`]",
+            input.ident,
+            strip_raw(name),
+        );
+        let next_states = states.iter().enumerate().map(|(j, state)| {
+            if i == j {
+                ty.clone()
+            } else {
+                state.to_token_stream()
+            }
+        });
+        let next_fields = (0..fields.len()).map(|j| {
+            if i == j {
+                quote! { resolver }
+            } else {
+                let index = Index::from(j);
+                quote! { self.#index }
+            }
+        });
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #name(
+                self,
+                resolver: #ty,
+            ) -> #builder_name<#(#generic_args,)* #(#next_states),*> {
+                #builder_name(#(#next_fields,)* self.#phantom)
+            }
+        }
+    });
+    let built_fields = fields.iter().enumerate().map(|(i, (name, _))| {
+        let index = Index::from(i);
+        quote! { #name: self.#index }
+    });
+    let unset_fields = fields
+        .iter()
+        .map(|_| quote! { ::core::marker::PhantomData });
+
+    let builder_doc = format!(
+        "A builder for [`{}`]\n\n`build` is only available once the resolver \
+         for every field has been set.",
+        resolver_name,
+    );
+    let builder_fn_doc =
+        format!("Returns a builder for a new `{}`.", resolver_name);
+
+    Ok(quote! {
+        #[automatically_derived]
+        #[doc = #builder_doc]
+        #vis struct #builder_name #builder_generics (
+            #(#states,)*
+            ::core::marker::PhantomData<#resolver_name #ty_generics>,
+        )
+        #where_clause;
+
+        #[automatically_derived]
+        impl #impl_generics #resolver_name #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #[inline]
+            #vis fn builder() -> #builder_name<
+                #(#generic_args,)*
+                #(::core::marker::PhantomData<#tys>),*
+            > {
+                #builder_name(#(#unset_fields,)* ::core::marker::PhantomData)
+            }
+        }
+
+        #[automatically_derived]
+        impl #builder_impl_generics #builder_name<
+            #(#generic_args,)*
+            #(#states),*
+        >
+        #where_clause
+        {
+            #(#setters)*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #builder_name<#(#generic_args,)* #(#tys),*>
+        #where_clause
+        {
+            /// Builds the resolver.
+            #[inline]
+            #vis fn build(self) -> #resolver_name #ty_generics {
+                #resolver_name {
+                    #(#built_fields,)*
+                }
+            }
+        }
     })
 }
 
@@ -319,6 +568,7 @@ fn generate_resolver_def_unnamed(
     let resolver_fields = fields
         .unnamed
         .iter()
+        .filter(is_not_skipped)
         .map(|field| {
             let resolver_ty = resolver(rkyv_path, field)?;
             Ok(quote! { #resolver_ty })
@@ -351,15 +601,113 @@ fn generate_resolver_def_unit(
     })
 }
 
+fn resolve_packed_bools(
+    attributes: &Attributes,
+    printing: &Printing,
+    fields: &Fields,
+    this: &TokenStream,
+) -> Result<Option<TokenStream>, Error> {
+    let packed_bools_len = packed_bools_len(attributes, fields);
+    if packed_bools_len == 0 {
+        return Ok(None);
+    }
+
+    let rkyv_path = &printing.rkyv_path;
+    let packed_field = packed_bools_ident();
+    let set_bits = packed_bools(attributes, fields)
+        .map(|(bit, field)| {
+            let member = Member::Named(field.ident.clone().unwrap());
+            let field_ref = field_ref(this, &member, field)?;
+            let byte = bit / 8;
+            let mask = 1u8 << (bit % 8);
+            Ok(quote! {
+                if *#field_ref {
+                    packed[#byte] |= #mask;
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(Some(quote! {
+        let field_ptr = unsafe {
+            ::core::ptr::addr_of_mut!((*out.ptr()).#packed_field)
+        };
+        let out_field = unsafe {
+            #rkyv_path::Place::from_field_unchecked(out, field_ptr)
+        };
+        let mut packed = [0u8; #packed_bools_len];
+        #(#set_bits)*
+        out_field.write(packed);
+    }))
+}
+
+fn generate_packed_bools_impl(
+    input: &DeriveInput,
+    attributes: &Attributes,
+    printing: &Printing,
+    fields: &Fields,
+) -> Option<TokenStream> {
+    if packed_bools_len(attributes, fields) == 0 {
+        return None;
+    }
+
+    let packed_field = packed_bools_ident();
+    let accessors = packed_bools(attributes, fields).map(|(bit, field)| {
+        let vis = &field.vis;
+        let name = field.ident.as_ref().unwrap();
+        let doc = struct_field_doc(&input.ident, name);
+        let byte = bit / 8;
+        let mask = 1u8 << (bit % 8);
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #vis fn #name(&self) -> bool {
+                self.#packed_field[#byte] & #mask != 0
+            }
+        }
+    });
+
+    let archived_name = &printing.archived_name;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Some(quote! {
+        #[automatically_derived]
+        impl #impl_generics #archived_name #ty_generics #where_clause {
+            #(#accessors)*
+        }
+    })
+}
+
+/// Returns the members of the given fields along with expressions which access
+/// their archived counterparts from `other`.
+fn compared_members(
+    attributes: &Attributes,
+    fields: &Fields,
+) -> (Vec<Member>, Vec<TokenStream>) {
+    archived_members(fields)
+        .filter_map(|(member, archived_member, field)| {
+            let archived_member = archived_member?;
+            let archived = if is_packed_bool(attributes, field) {
+                quote! { other.#archived_member() }
+            } else {
+                quote! { other.#archived_member }
+            };
+            Some((member, archived))
+        })
+        .unzip()
+}
+
 fn generate_partial_eq_impl(
     input: &DeriveInput,
+    attributes: &Attributes,
     fields: &Fields,
     printing: &Printing,
 ) -> Result<TokenStream, Error> {
     let mut partial_eq_where =
         input.generics.where_clause.as_ref().unwrap().clone();
 
-    for field in fields.iter().filter(is_not_omitted) {
+    for field in fields.iter().filter(is_not_omitted).filter(is_not_skipped) {
         let ty = &field.ty;
         let archived_ty = archived(&printing.rkyv_path, field)?;
         partial_eq_where
@@ -367,7 +715,7 @@ fn generate_partial_eq_impl(
             .push(parse_quote! { #archived_ty: PartialEq<#ty> });
     }
 
-    let members = members(fields).map(|(member, _)| member);
+    let (members, archived) = compared_members(attributes, fields);
 
     let archived_type = &printing.archived_type;
     let name = &input.ident;
@@ -378,7 +726,7 @@ fn generate_partial_eq_impl(
         #partial_eq_where
         {
             fn eq(&self, other: &#archived_type) -> bool {
-                true #(&& other.#members.eq(&self.#members))*
+                true #(&& #archived.eq(&self.#members))*
             }
         }
 
@@ -394,13 +742,14 @@ fn generate_partial_eq_impl(
 
 fn generate_partial_ord_impl(
     input: &DeriveInput,
+    attributes: &Attributes,
     fields: &Fields,
     printing: &Printing,
 ) -> Result<TokenStream, Error> {
     let mut partial_ord_where =
         input.generics.where_clause.as_ref().unwrap().clone();
 
-    for field in fields.iter().filter(is_not_omitted) {
+    for field in fields.iter().filter(is_not_omitted).filter(is_not_skipped) {
         let ty = &field.ty;
         let archived_ty = archived(&printing.rkyv_path, field)?;
         partial_ord_where
@@ -408,7 +757,7 @@ fn generate_partial_ord_impl(
             .push(parse_quote! { #archived_ty: PartialOrd<#ty> });
     }
 
-    let members = members(fields).map(|(member, _)| member);
+    let (members, archived) = compared_members(attributes, fields);
 
     let archived_type = &printing.archived_type;
     let name = &input.ident;
@@ -423,7 +772,7 @@ fn generate_partial_ord_impl(
                 other: &#archived_type,
             ) -> Option<::core::cmp::Ordering> {
                 #(
-                    match other.#members.partial_cmp(&self.#members) {
+                    match #archived.partial_cmp(&self.#members) {
                         Some(::core::cmp::Ordering::Equal) => (),
                         x => return x.map(::core::cmp::Ordering::reverse),
                     }
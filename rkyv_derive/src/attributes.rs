@@ -1,9 +1,10 @@
-use proc_macro2::TokenTree;
+use proc_macro2::{TokenStream, TokenTree};
 use quote::ToTokens;
 use syn::{
     meta::ParseNestedMeta, parenthesized, parse::Parse, parse_quote,
-    punctuated::Punctuated, token, AttrStyle, DeriveInput, Error, Ident,
-    LitStr, MacroDelimiter, Meta, MetaList, Path, Token, WherePredicate,
+    punctuated::Punctuated, token, AttrStyle, Data, DeriveInput, Error, Expr,
+    Field, Fields, Ident, LitStr, MacroDelimiter, Meta, MetaList, Path, Token,
+    WherePredicate,
 };
 
 fn try_set_attribute<T: ToTokens>(
@@ -22,6 +23,18 @@ fn try_set_attribute<T: ToTokens>(
     }
 }
 
+// The generic parameters added by the derived impls. A `bound(...)` predicate
+// which mentions one of these only applies to the impl which declares it.
+const IMPL_PARAMS: [&str; 2] = ["__S", "__D"];
+
+fn mentions_ident(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions_ident(group.stream(), name),
+        _ => false,
+    })
+}
+
 #[derive(Default)]
 pub struct Attributes {
     pub archive_as: Option<LitStr>,
@@ -32,8 +45,12 @@ pub struct Attributes {
     pub archive_bounds: Option<Punctuated<WherePredicate, Token![,]>>,
     pub serialize_bounds: Option<Punctuated<WherePredicate, Token![,]>>,
     pub deserialize_bounds: Option<Punctuated<WherePredicate, Token![,]>>,
+    pub bounds: Option<Punctuated<WherePredicate, Token![,]>>,
     pub check_bytes: Option<Meta>,
     pub crate_path: Option<Path>,
+    pub pack_bools: Option<Path>,
+    pub resolver_builder: Option<Path>,
+    pub remote: Option<Path>,
 }
 
 impl Attributes {
@@ -72,6 +89,31 @@ impl Attributes {
             parenthesized!(traits in meta.input);
             let traits = traits.parse_terminated(Path::parse, Token![,])?;
             try_set_attribute(&mut self.compares, traits, "compare")
+        } else if meta.path.is_ident("bound") {
+            let bounds;
+            parenthesized!(bounds in meta.input);
+            let clauses =
+                bounds.parse_terminated(WherePredicate::parse, Token![,])?;
+            for clause in clauses.iter() {
+                let tokens = clause.to_token_stream();
+                if mentions_ident(tokens.clone(), "__C") {
+                    return Err(Error::new_spanned(
+                        clause,
+                        "bounds on `__C` must be specified with \
+                         `check_bytes(bounds(...))`",
+                    ));
+                }
+                if IMPL_PARAMS
+                    .iter()
+                    .all(|p| mentions_ident(tokens.clone(), p))
+                {
+                    return Err(Error::new_spanned(
+                        clause,
+                        "a bound cannot mention both `__S` and `__D`",
+                    ));
+                }
+            }
+            try_set_attribute(&mut self.bounds, clauses, "bound")
         } else if meta.path.is_ident("archive_bounds") {
             let bounds;
             parenthesized!(bounds in meta.input);
@@ -143,6 +185,20 @@ impl Attributes {
                     .map(|meta| parse_quote! { derive(#meta) }),
             );
             Ok(())
+        } else if meta.path.is_ident("pack_bools") {
+            try_set_attribute(&mut self.pack_bools, meta.path, "pack_bools")
+        } else if meta.path.is_ident("resolver_builder") {
+            try_set_attribute(
+                &mut self.resolver_builder,
+                meta.path,
+                "resolver_builder",
+            )
+        } else if meta.path.is_ident("remote") {
+            try_set_attribute(
+                &mut self.remote,
+                meta.value()?.parse()?,
+                "remote",
+            )
         } else if meta.path.is_ident("attr") {
             let metas;
             parenthesized!(metas in meta.input);
@@ -183,6 +239,89 @@ impl Attributes {
             }
         }
 
+        let is_named_struct = matches!(
+            &input.data,
+            Data::Struct(data) if matches!(data.fields, Fields::Named(_)),
+        );
+
+        if let Some(resolver_builder) = &result.resolver_builder {
+            if !is_named_struct {
+                return Err(Error::new_spanned(
+                    resolver_builder,
+                    "resolver_builder is only supported on structs with named \
+                     fields",
+                ));
+            }
+        }
+
+        if let Some(remote) = &result.remote {
+            if !matches!(input.data, Data::Struct(_)) {
+                return Err(Error::new_spanned(
+                    remote,
+                    "remote is only supported on structs",
+                ));
+            }
+            if result.archive_as.is_some() {
+                return Err(Error::new_spanned(
+                    remote,
+                    "remote may not be used with as = \"...\" because no type \
+                     is generated",
+                ));
+            }
+        }
+
+        if let Some(pack_bools) = &result.pack_bools {
+            if !is_named_struct {
+                return Err(Error::new_spanned(
+                    pack_bools,
+                    "pack_bools is only supported on structs with named fields",
+                ));
+            }
+            if result.archive_as.is_some() {
+                return Err(Error::new_spanned(
+                    pack_bools,
+                    "pack_bools may not be used with as = \"...\" because no \
+                     type is generated",
+                ));
+            }
+        }
+
+        match &input.data {
+            Data::Struct(data) => {
+                for field in data.fields.iter() {
+                    let field_attributes = FieldAttributes::parse(field)?;
+                    if let Some(getter) = &field_attributes.getter {
+                        if result.remote.is_none() {
+                            return Err(Error::new_spanned(
+                                getter,
+                                "getter = ... may only be used with remote = \
+                                 ...",
+                            ));
+                        }
+                    }
+                }
+            }
+            Data::Enum(data) => {
+                for field in data.variants.iter().flat_map(|v| v.fields.iter())
+                {
+                    let field_attributes = FieldAttributes::parse(field)?;
+                    if let Some(skip) = field_attributes.skip {
+                        return Err(Error::new_spanned(
+                            skip,
+                            "skip is only supported on struct fields",
+                        ));
+                    }
+                    if let Some(getter) = field_attributes.getter {
+                        return Err(Error::new_spanned(
+                            getter,
+                            "getter is only supported on struct fields",
+                        ));
+                    }
+                }
+            }
+            Data::Union(_) => (),
+        }
+
         if result.archive_as.is_some() && result.bytecheck_enabled() {
             Err(Error::new_spanned(
                 result.check_bytes.unwrap(),
@@ -200,7 +339,74 @@ impl Attributes {
             .unwrap_or_else(|| parse_quote! { ::rkyv })
     }
 
+    /// Returns the `bound(...)` predicates which apply to the impl with the
+    /// given generic parameter, or to the `Archive` impl if `param` is `None`.
+    pub fn bounds_for<'a>(
+        &'a self,
+        param: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a WherePredicate> + 'a {
+        self.bounds.iter().flatten().filter(move |bound| {
+            IMPL_PARAMS
+                .iter()
+                .filter(|p| Some(**p) != param)
+                .all(|p| !mentions_ident(bound.to_token_stream(), p))
+        })
+    }
+
     pub fn bytecheck_enabled(&self) -> bool {
         cfg!(feature = "bytecheck") && self.check_bytes.is_some()
     }
 }
+
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub skip: Option<Path>,
+    pub default: Option<Expr>,
+    pub getter: Option<Path>,
+}
+
+impl FieldAttributes {
+    fn parse_meta(&mut self, meta: ParseNestedMeta<'_>) -> Result<(), Error> {
+        if meta.path.is_ident("skip") {
+            try_set_attribute(&mut self.skip, meta.path, "skip")
+        } else if meta.path.is_ident("default") {
+            try_set_attribute(
+                &mut self.default,
+                meta.value()?.parse()?,
+                "default",
+            )
+        } else if meta.path.is_ident("getter") {
+            try_set_attribute(
+                &mut self.getter,
+                meta.value()?.parse()?,
+                "getter",
+            )
+        } else {
+            Err(meta.error("unrecognized archive field argument"))
+        }
+    }
+
+    pub fn parse(field: &Field) -> Result<FieldAttributes, Error> {
+        let mut result = FieldAttributes::default();
+        for attr in field.attrs.iter() {
+            if attr.path().is_ident("archive") || attr.path().is_ident("rkyv") {
+                attr.parse_nested_meta(|meta| result.parse_meta(meta))?;
+            }
+        }
+
+        if let (Some(_), Some(getter)) = (&result.skip, &result.getter) {
+            return Err(Error::new_spanned(
+                getter,
+                "getter = ... may not be used with skip",
+            ));
+        }
+
+        match (&result.skip, &result.default) {
+            (None, Some(default)) => Err(Error::new_spanned(
+                default,
+                "default = ... may only be used with skip",
+            )),
+            _ => Ok(result),
+        }
+    }
+}
@@ -2,12 +2,16 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
     parse_quote, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput,
-    Error, Fields, Generics, Ident, Index,
+    Error, Fields, Generics, Ident, WhereClause,
 };
 
 use crate::{
     attributes::Attributes,
-    util::{archive_bound, deserialize, deserialize_bound, is_not_omitted},
+    util::{
+        archive_bound, archived_members, default_bound, default_value,
+        deserialize, deserialize_bound, is_not_omitted, is_packed_bool,
+        strip_raw,
+    },
 };
 
 pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
@@ -32,6 +36,9 @@ fn derive_deserialize_impl(
             where_clause.predicates.push(bound.clone());
         }
     }
+    for bound in attributes.bounds_for(Some("__D")) {
+        where_clause.predicates.push(bound.clone());
+    }
 
     let mut impl_input_params = Punctuated::default();
     impl_input_params
@@ -51,116 +58,132 @@ fn derive_deserialize_impl(
     let (_, ty_generics, where_clause) = input.generics.split_for_impl();
     let where_clause = where_clause.unwrap();
 
-    let deserialize_impl = match input.data {
-        Data::Struct(ref data) => match data.fields {
-            Fields::Named(ref fields) => {
-                let mut deserialize_where = where_clause.clone();
-                for field in fields.named.iter().filter(is_not_omitted) {
-                    deserialize_where
-                        .predicates
-                        .push(archive_bound(&rkyv_path, field)?);
-                    deserialize_where
-                        .predicates
-                        .push(deserialize_bound(&rkyv_path, field)?);
-                }
-
-                let deserialize_fields = fields
-                    .named
-                    .iter()
-                    .map(|field| {
-                        let name = &field.ident;
-                        let deserialize = deserialize(&rkyv_path, field)?;
-                        Ok(quote! {
-                            #name: #deserialize(&self.#name, deserializer)?
-                        })
-                    })
-                    .collect::<Result<Vec<_>, Error>>()?;
-
-                quote! {
-                    impl #impl_generics
-                        #rkyv_path::Deserialize<#name #ty_generics, __D>
-                        for #rkyv_path::Archived<#name #ty_generics>
-                    #deserialize_where
-                    {
-                        fn deserialize(
-                            &self,
-                            deserializer: &mut __D,
-                        ) -> ::core::result::Result<
-                            #name #ty_generics,
-                            <__D as #rkyv_path::rancor::Fallible>::Error,
-                        > {
-                            Ok(#name {
-                                #(#deserialize_fields,)*
-                            })
-                        }
-                    }
-                }
-            }
-            Fields::Unnamed(ref fields) => {
-                let mut deserialize_where = where_clause.clone();
-                for field in fields.unnamed.iter().filter(is_not_omitted) {
-                    deserialize_where
-                        .predicates
-                        .push(archive_bound(&rkyv_path, field)?);
-                    deserialize_where
-                        .predicates
-                        .push(deserialize_bound(&rkyv_path, field)?);
-                }
+    // Remote types are deserialized from an archived value which is not
+    // `Archived<Self>`, and are converted from `Self` at the end.
+    let this = if attributes.remote.is_some() {
+        quote! { field }
+    } else {
+        quote! { self }
+    };
 
-                let deserialize_fields = fields
-                    .unnamed
-                    .iter()
-                    .enumerate()
-                    .map(|(i, field)| {
-                        let index = Index::from(i);
-                        let deserialize = deserialize(&rkyv_path, field)?;
-                        Ok(quote! {
-                            #deserialize(
-                                &self.#index,
-                                deserializer,
-                            )?
-                        })
-                    })
-                    .collect::<Result<Vec<_>, Error>>()?;
+    let impl_deserialize = |mut deserialize_where: WhereClause,
+                            value: TokenStream| {
+        if let Some(remote) = &attributes.remote {
+            let archived_name = attributes.archived.as_ref().map_or_else(
+                || {
+                    Ident::new(
+                        &format!("Archived{}", strip_raw(name)),
+                        name.span(),
+                    )
+                },
+                |value| value.clone(),
+            );
+            deserialize_where.predicates.push(parse_quote! {
+                #remote: ::core::convert::From<#name #ty_generics>
+            });
 
-                quote! {
-                    impl #impl_generics
-                        #rkyv_path::Deserialize<#name #ty_generics, __D>
-                        for #rkyv_path::Archived<#name #ty_generics>
-                    #deserialize_where
-                    {
-                        fn deserialize(
-                            &self,
-                            deserializer: &mut __D,
-                        ) -> ::core::result::Result<
-                            #name #ty_generics,
-                            <__D as #rkyv_path::rancor::Fallible>::Error,
-                        > {
-                            Ok(#name(
-                                #(#deserialize_fields,)*
-                            ))
-                        }
+            quote! {
+                impl #impl_generics
+                    #rkyv_path::with::DeserializeWith<
+                        #archived_name #ty_generics,
+                        #remote,
+                        __D,
+                    >
+                    for #name #ty_generics
+                #deserialize_where
+                {
+                    fn deserialize_with(
+                        field: &#archived_name #ty_generics,
+                        deserializer: &mut __D,
+                    ) -> ::core::result::Result<
+                        #remote,
+                        <__D as #rkyv_path::rancor::Fallible>::Error,
+                    > {
+                        Ok(::core::convert::From::from(#value))
                     }
                 }
             }
-            Fields::Unit => quote! {
+        } else {
+            quote! {
                 impl #impl_generics
                     #rkyv_path::Deserialize<#name #ty_generics, __D>
                     for #rkyv_path::Archived<#name #ty_generics>
-                #where_clause
+                #deserialize_where
                 {
                     fn deserialize(
                         &self,
-                        _: &mut __D,
+                        deserializer: &mut __D,
                     ) -> ::core::result::Result<
                         #name #ty_generics,
                         <__D as #rkyv_path::rancor::Fallible>::Error,
                     > {
-                        Ok(#name)
+                        Ok(#value)
                     }
                 }
-            },
-        },
+            }
+        }
+    };
+
+    let deserialize_impl = match input.data {
+        Data::Struct(ref data) => {
+            let mut deserialize_where = where_clause.clone();
+            for (_, archived_member, field) in archived_members(&data.fields) {
+                if !is_not_omitted(&field) {
+                    continue;
+                }
+                if archived_member.is_some() {
+                    deserialize_where
+                        .predicates
+                        .push(archive_bound(&rkyv_path, field)?);
+                    deserialize_where
+                        .predicates
+                        .push(deserialize_bound(&rkyv_path, field)?);
+                } else if let Some(bound) = default_bound(field)? {
+                    deserialize_where.predicates.push(bound);
+                }
+            }
+
+            let deserialize_fields = archived_members(&data.fields)
+                .map(|(member, archived_member, field)| {
+                    let value = match archived_member {
+                        None => default_value(field)?,
+                        Some(archived_member) => {
+                            if is_packed_bool(attributes, field) {
+                                quote! { #this.#archived_member() }
+                            } else {
+                                let deserialize =
+                                    deserialize(&rkyv_path, field)?;
+                                quote! {
+                                    #deserialize(
+                                        &#this.#archived_member,
+                                        deserializer,
+                                    )?
+                                }
+                            }
+                        }
+                    };
+                    Ok((member, value))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            let value = match data.fields {
+                Fields::Named(_) => {
+                    let fields =
+                        deserialize_fields.iter().map(|(member, value)| {
+                            quote! { #member: #value }
+                        });
+                    quote! { #name { #(#fields,)* } }
+                }
+                Fields::Unnamed(_) => {
+                    let fields =
+                        deserialize_fields.iter().map(|(_, value)| value);
+                    quote! { #name(#(#fields,)*) }
+                }
+                Fields::Unit => quote! { #name },
+            };
+
+            impl_deserialize(deserialize_where, value)
+        }
         Data::Enum(ref data) => {
             let mut deserialize_where = where_clause.clone();
             for variant in data.variants.iter() {
@@ -0,0 +1,181 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse_quote, punctuated::Punctuated, Data, DeriveInput, Error, Fields,
+    Generics,
+};
+
+use crate::{
+    attributes::Attributes,
+    util::{
+        archive_bound, archived, archived_members, default_bound,
+        default_value, deserialize, deserialize_into_bound, is_not_omitted,
+        is_packed_bool, map_with_or_else,
+    },
+};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let attributes = Attributes::parse(&input)?;
+    derive_deserialize_into_impl(input, &attributes)
+}
+
+fn derive_deserialize_into_impl(
+    mut input: DeriveInput,
+    attributes: &Attributes,
+) -> Result<TokenStream, Error> {
+    if let Some(remote) = &attributes.remote {
+        return Err(Error::new_spanned(
+            remote,
+            "DeserializeInto cannot be derived for remote types",
+        ));
+    }
+
+    let rkyv_path = attributes.crate_path();
+
+    let where_clause = input.generics.make_where_clause();
+    if let Some(ref bounds) = attributes.archive_bounds {
+        for bound in bounds {
+            where_clause.predicates.push(bound.clone());
+        }
+    }
+    if let Some(ref bounds) = attributes.deserialize_bounds {
+        for bound in bounds {
+            where_clause.predicates.push(bound.clone());
+        }
+    }
+    for bound in attributes.bounds_for(Some("__D")) {
+        where_clause.predicates.push(bound.clone());
+    }
+
+    let mut impl_input_params = Punctuated::default();
+    impl_input_params
+        .push(parse_quote! { __D: #rkyv_path::rancor::Fallible + ?Sized });
+    for param in input.generics.params.iter() {
+        impl_input_params.push(param.clone());
+    }
+    let impl_input_generics = Generics {
+        lt_token: Some(Default::default()),
+        params: impl_input_params,
+        gt_token: Some(Default::default()),
+        where_clause: input.generics.where_clause.clone(),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ..) = impl_input_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut deserialize_where = where_clause.unwrap().clone();
+    deserialize_where.predicates.push(parse_quote! {
+        #rkyv_path::Archived<#name #ty_generics>:
+            #rkyv_path::Deserialize<#name #ty_generics, __D>
+    });
+
+    let body = match input.data {
+        Data::Struct(ref data) => {
+            for (_, archived_member, field) in archived_members(&data.fields) {
+                if !is_not_omitted(&field) {
+                    continue;
+                }
+                if archived_member.is_some() {
+                    deserialize_where
+                        .predicates
+                        .push(archive_bound(&rkyv_path, field)?);
+                    deserialize_where
+                        .predicates
+                        .push(deserialize_into_bound(&rkyv_path, field)?);
+                } else if let Some(bound) = default_bound(field)? {
+                    deserialize_where.predicates.push(bound);
+                }
+            }
+
+            let deserialize_fields = archived_members(&data.fields)
+                .map(|(member, archived_member, field)| {
+                    let Some(archived_member) = archived_member else {
+                        let default = default_value(field)?;
+                        return Ok(quote! { place.#member = #default; });
+                    };
+                    if is_packed_bool(attributes, field) {
+                        return Ok(quote! {
+                            place.#member = self.#archived_member();
+                        });
+                    }
+                    let ty = &field.ty;
+                    let archived = archived(&rkyv_path, field)?;
+                    let deserialize = deserialize(&rkyv_path, field)?;
+                    map_with_or_else(
+                        field,
+                        // Wrapped fields can't deserialize into an existing
+                        // value, so they are deserialized and assigned
+                        // instead.
+                        |_| {
+                            quote! {
+                                place.#member = #deserialize(
+                                    &self.#archived_member,
+                                    deserializer,
+                                )?;
+                            }
+                        },
+                        || {
+                            quote! {
+                                <
+                                    #archived as #rkyv_path::DeserializeInto<
+                                        #ty,
+                                        __D,
+                                    >
+                                >::deserialize_into(
+                                    &self.#archived_member,
+                                    &mut place.#member,
+                                    deserializer,
+                                )?;
+                            }
+                        },
+                    )
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            let deserializer = if matches!(data.fields, Fields::Unit) {
+                quote! { _ }
+            } else {
+                quote! { deserializer }
+            };
+            let place = if matches!(data.fields, Fields::Unit) {
+                quote! { _ }
+            } else {
+                quote! { place }
+            };
+
+            quote! {
+                fn deserialize_into(
+                    &self,
+                    #place: &mut #name #ty_generics,
+                    #deserializer: &mut __D,
+                ) -> ::core::result::Result<
+                    (),
+                    <__D as #rkyv_path::rancor::Fallible>::Error,
+                > {
+                    #(#deserialize_fields)*
+                    Ok(())
+                }
+            }
+        }
+        // Enums may change variants, so they use the default implementation
+        // which deserializes a new value and assigns it to `place`.
+        Data::Enum(_) => quote! {},
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "DeserializeInto cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics
+            #rkyv_path::DeserializeInto<#name #ty_generics, __D>
+            for #rkyv_path::Archived<#name #ty_generics>
+        #deserialize_where
+        {
+            #body
+        }
+    })
+}
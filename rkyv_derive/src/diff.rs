@@ -0,0 +1,163 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error};
+
+use crate::{
+    attributes::Attributes,
+    util::{archived_members, is_not_omitted, is_packed_bool},
+};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let attributes = Attributes::parse(&input)?;
+    derive_diff_impl(input, &attributes)
+}
+
+fn derive_diff_impl(
+    mut input: DeriveInput,
+    attributes: &Attributes,
+) -> Result<TokenStream, Error> {
+    if let Some(remote) = &attributes.remote {
+        return Err(Error::new_spanned(
+            remote,
+            "Diff cannot be derived for remote types",
+        ));
+    }
+
+    let rkyv_path = attributes.crate_path();
+
+    let where_clause = input.generics.make_where_clause();
+    if let Some(ref bounds) = attributes.archive_bounds {
+        for bound in bounds {
+            where_clause.predicates.push(bound.clone());
+        }
+    }
+    for bound in attributes.bounds_for(None) {
+        where_clause.predicates.push(bound.clone());
+    }
+
+    let data = match input.data {
+        Data::Struct(ref data) => data,
+        Data::Enum(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "Diff cannot be derived for enums",
+            ))
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "Diff cannot be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let mut diff_where = where_clause.unwrap().clone();
+
+    let mut diff_fields = Vec::new();
+    let mut diff_all_fields = Vec::new();
+    let mut apply_fields = Vec::new();
+    for (index, (member, archived_member, field)) in
+        archived_members(&data.fields).enumerate()
+    {
+        // Skipped fields aren't archived, so they can't change.
+        let Some(archived_member) = archived_member else {
+            continue;
+        };
+        if field.attrs.iter().any(|attr| attr.path().is_ident("with")) {
+            return Err(Error::new_spanned(
+                field,
+                "Diff cannot be derived for fields with wrappers",
+            ));
+        }
+
+        let ty = &field.ty;
+        if is_not_omitted(&field) {
+            diff_where
+                .predicates
+                .push(parse_quote! { #ty: #rkyv_path::diff::Diff });
+        }
+
+        let index = index as u64;
+        // Packed bools are read with accessors, and their archived type is
+        // `bool`.
+        let (a, b) = if is_packed_bool(attributes, field) {
+            (
+                quote! { &a.#archived_member() },
+                quote! { &b.#archived_member() },
+            )
+        } else {
+            (
+                quote! { &a.#archived_member },
+                quote! { &b.#archived_member },
+            )
+        };
+        diff_fields.push(quote! {
+            differ.field(#index, |differ| {
+                <#ty as #rkyv_path::diff::Diff>::diff(#a, #b, differ)
+            });
+        });
+        diff_all_fields.push(quote! {
+            differ.field(#index, |differ| {
+                <#ty as #rkyv_path::diff::Diff>::diff_all(#b, differ)
+            });
+        });
+        apply_fields.push(quote! {
+            ::core::option::Option::Some((&#index, rest)) => {
+                <#ty as #rkyv_path::diff::Diff>::apply(
+                    &mut self.#member,
+                    rest,
+                    value,
+                )
+            }
+        });
+    }
+
+    let (a, b, differ, value) = if diff_fields.is_empty() {
+        (quote! { _ }, quote! { _ }, quote! { _ }, quote! { _ })
+    } else {
+        (
+            quote! { a },
+            quote! { b },
+            quote! { differ },
+            quote! { value },
+        )
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #rkyv_path::diff::Diff for #name #ty_generics
+        #diff_where
+        {
+            fn diff(
+                #a: &#rkyv_path::Archived<Self>,
+                #b: &#rkyv_path::Archived<Self>,
+                #differ: &mut #rkyv_path::diff::Differ,
+            ) {
+                #(#diff_fields)*
+            }
+
+            fn diff_all(
+                #b: &#rkyv_path::Archived<Self>,
+                #differ: &mut #rkyv_path::diff::Differ,
+            ) {
+                #(#diff_all_fields)*
+            }
+
+            fn apply<__E: #rkyv_path::rancor::Source>(
+                &mut self,
+                path: &[u64],
+                #value: &#rkyv_path::diff::Value,
+            ) -> ::core::result::Result<(), __E> {
+                match path.split_first() {
+                    #(#apply_fields)*
+                    _ => #rkyv_path::rancor::fail!(
+                        #rkyv_path::diff::PatchMismatch
+                    ),
+                }
+            }
+        }
+    })
+}
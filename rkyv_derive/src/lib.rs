@@ -9,6 +9,8 @@
 mod archive;
 mod attributes;
 mod deserialize;
+mod deserialize_into;
+mod diff;
 mod portable;
 mod repr;
 mod serde;
@@ -54,12 +56,18 @@ pub fn derive_portable(
 /// - `compare(...)`: Implements common comparison operators between the
 ///   original and archived types. Supported comparisons are `PartialEq` and
 ///   `PartialOrd` (i.e. `#[rkyv(compare(PartialEq, PartialOrd))]`).
-/// - `bound(...)`: Adds additional bounds to trait implementations. This can be
-///   especially useful when dealing with recursive structures, where bounds may
-///   need to be omitted to prevent recursive type definitions. Use `archive =
-///   "..."` to specify `Archive` bounds, `serialize = "..."` to specify
-///   `Serialize` bounds, and `deserialize = "..."` to specify `Deserialize`
-///   bounds.
+/// - `archive_bounds(...)`, `serialize_bounds(...)`, `deserialize_bounds(...)`:
+///   Adds additional bounds to trait implementations. This can be especially
+///   useful when dealing with recursive structures, where bounds may need to be
+///   omitted to prevent recursive type definitions. `archive_bounds` are added
+///   to every implementation, `serialize_bounds` to `Serialize`, and
+///   `deserialize_bounds` to `Deserialize` and `DeserializeInto`.
+/// - `bound(...)`: Shorthand which adds each bound to every implementation it
+///   applies to. Bounds which mention the serializer (`__S`) are only added to
+///   `Serialize`, bounds which mention the deserializer (`__D`) are only added
+///   to `Deserialize` and `DeserializeInto`, and all other bounds are added to
+///   every implementation. Bounds which mention the validation context (`__C`)
+///   must be specified with `check_bytes(bounds(...))` instead.
 /// - `check_bytes`: Derive `CheckBytes` on the archived type, in order to
 ///   enable safe deserialization. Requires `validation` feature. Not compatible
 ///   with `as = "..."`. In that case, use `#[derive(CheckBytes)]` on the
@@ -68,12 +76,45 @@ pub fn derive_portable(
 ///   will archive as the named type. This is useful for types which are generic
 ///   over their parameters.
 /// - `crate = "..."`: Chooses an alternative crate path to import rkyv from.
+/// - `pack_bools`: Packs all of the `bool` fields of a struct with named fields
+///   into a bitfield in the archived type, using one bit per field. Packed
+///   fields are replaced with accessor methods of the same name which return
+///   `bool` (i.e. `archived.field_name()`). Fields with wrappers are not
+///   packed. This changes the archived format, so it must be opted into.
+/// - `resolver_builder`: Generates a builder for the resolver of a struct with
+///   named fields, which is returned by `Resolver::builder()`. The builder has
+///   a setter method for each field's resolver, and `build()` returns the
+///   resolver. This makes it easier to implement `Serialize` manually without
+///   constructing the resolver field by field. `build()` can only be called
+///   once every resolver has been set, so forgetting one is a compile error.
+///   Fields named `build` are not supported.
+/// - `remote = ...`: Derives for a local mirror of a remote type (i.e. a type
+///   from another crate which doesn't implement `Archive`), like serde's remote
+///   derive. Instead of implementing `Archive`, `Serialize`, and `Deserialize`
+///   for the mirror, the mirror becomes a wrapper which implements
+///   `ArchiveWith`, `SerializeWith`, and `DeserializeWith` for the remote type.
+///   The mirror must be a struct with the same fields as the remote type, and
+///   the remote type must implement `From<Mirror>` to be deserialized. Fields
+///   of the remote type are read directly unless they have a `getter`. Use it
+///   on fields of the remote type with `#[with(Mirror)]`.
 ///
 /// There are also shorthand attributes:
 ///
 /// - `#[rkyv_attr(...)]` is shorthand for `#[rkyv(attr(...))]`.
 /// - `#[rkyv_derive(...)]` is shorthand for `#[rkyv(derive(...))]`.
 ///
+/// Struct fields also accept `#[rkyv(...)]` with the following arguments:
+///
+/// - `skip`: Omits the field from the archived type and resolver. The field is
+///   not serialized, compared, or checked, and is set to `Default::default()`
+///   when deserializing.
+/// - `default = ...`: Sets a skipped field to the given expression instead of
+///   `Default::default()` when deserializing. Requires `skip`.
+/// - `getter = ...`: Reads the field of a remote type by calling the given
+///   function with a reference to the remote value, for fields which are
+///   private. The function may return the field by value or by reference.
+///   Requires `remote = ...`.
+///
 /// # Recursive types
 ///
 /// This derive macro automatically adds a type bound `field: Archive` for each
@@ -85,15 +126,43 @@ pub fn derive_portable(
 /// types, in which case additional type bounds may be required with
 /// `bound(...)`.
 ///
+/// # Enum discriminants
+///
+/// Archived enums keep any explicit discriminants of the labeled type, so an
+/// archived variant has the same tag value as the original variant. Enums
+/// marked with a fixed-size integer `repr` like `#[repr(i8)]` or
+/// `#[repr(u16)]` are archived with a tag of that type. Other enums (including
+/// those marked `#[repr(isize)]` or `#[repr(usize)]`) are archived with a `u8`
+/// tag if they have at most 256 variants, a `u16` tag if they have at most
+/// 65536 variants, and a `u32` tag otherwise.
+///
+/// Tags wider than a byte are stored with the endianness of the archive, so
+/// they are the same on every platform. Wide tags are not supported with the
+/// `unaligned` feature.
+///
+/// Archived enums also implement `rkyv::traits::ArchivedEnum`, which returns
+/// the name and the discriminant in the labeled type of the active variant.
+/// This is not implemented when the archived type is set with `as = ...`.
+///
 /// # Wrappers
 ///
 /// Wrappers transparently customize archived types by providing different
 /// implementations of core traits. For example, references cannot be archived,
 /// but the `Inline` wrapper serializes a reference as if it were a field of the
-/// struct. Wrappers can be applied to fields using the `#[with(...)]`
-/// attribute. Multiple wrappers can be used, and they are applied in reverse
-/// order (i.e. `#[with(A, B, C)]` will archive `MyType` as
-/// `With<With<With<MyType, C>, B, A>`).
+/// struct. Wrappers can be applied to fields of structs and enum variants
+/// using the `#[with(...)]` attribute. Multiple wrappers can be used, and they
+/// are applied in reverse order (i.e. `#[with(A, B, C)]` will archive `MyType`
+/// as `With<With<With<MyType, C>, B, A>`).
+///
+/// # Schema hash
+///
+/// This derive macro also implements `SchemaHash` for the labeled type. Its
+/// `SCHEMA_HASH` is a hash of the type's name, its fields' names, types, and
+/// wrappers, its variants, and any `repr` attributes passed to the archived
+/// type. It is computed from the structure of the type definition when the
+/// macro runs, so it is the same on every platform. Writers can store it
+/// alongside their archives so that readers can reject archives that were
+/// written with a different definition.
 #[proc_macro_derive(
     Archive,
     attributes(
@@ -151,3 +220,50 @@ pub fn derive_deserialize(
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+/// Derives `DeserializeInto` for the labeled type.
+///
+/// Each field of a struct is deserialized into the corresponding field of the
+/// existing value, so the type of each field must implement `DeserializeInto`.
+/// It is implemented for primitives, strings, boxes, vecs, options, arrays,
+/// tuples, and the standard maps and sets, and can be implemented with its
+/// default method for any other type which implements `Deserialize`. Fields
+/// with wrappers are deserialized and assigned instead. Enums use the default
+/// implementation, which deserializes a new value and assigns it.
+///
+/// This macro also supports the `#[archive]`, `#[omit_bounds]`, and `#[with]`
+/// attributes. See [`Archive`] for more information.
+#[proc_macro_derive(
+    DeserializeInto,
+    attributes(archive, rkyv, omit_bounds, with)
+)]
+pub fn derive_deserialize_into(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut derive_input = parse_macro_input!(input as DeriveInput);
+    serde::receiver::replace_receiver(&mut derive_input);
+
+    match deserialize_into::derive(derive_input) {
+        Ok(result) => result.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derives `Diff` for the labeled type.
+///
+/// Each field of a struct is diffed and patched separately, and is identified
+/// in patches by its index. Skipped fields are ignored. Enums, unions, and
+/// fields with wrappers are not supported.
+///
+/// This macro also supports the `#[archive]` and `#[omit_bounds]` attributes.
+/// See [`Archive`] for more information.
+#[proc_macro_derive(Diff, attributes(archive, rkyv, omit_bounds, with))]
+pub fn derive_diff(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut derive_input = parse_macro_input!(input as DeriveInput);
+    serde::receiver::replace_receiver(&mut derive_input);
+
+    match diff::derive(derive_input) {
+        Ok(result) => result.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
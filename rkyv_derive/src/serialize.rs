@@ -2,12 +2,15 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
     parse_quote, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput,
-    Error, Fields, Generics, Ident, Index,
+    Error, Fields, Generics, Ident, WhereClause,
 };
 
 use crate::{
     attributes::Attributes,
-    util::{is_not_omitted, serialize, serialize_bound, strip_raw},
+    util::{
+        field_ref, is_not_omitted, is_not_skipped, is_packed_bool, members,
+        serialize, serialize_bound, strip_raw,
+    },
 };
 
 pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
@@ -32,6 +35,9 @@ fn derive_serialize_impl(
             where_clause.predicates.push(bound.clone());
         }
     }
+    for bound in attributes.bounds_for(Some("__S")) {
+        where_clause.predicates.push(bound.clone());
+    }
 
     let mut impl_input_params = Punctuated::default();
     impl_input_params
@@ -56,97 +62,117 @@ fn derive_serialize_impl(
         |value| value.clone(),
     );
 
+    // Remote types are serialized from the remote value instead of `self`.
+    let this = if attributes.remote.is_some() {
+        quote! { field }
+    } else {
+        quote! { self }
+    };
+
+    let impl_serialize =
+        |serialize_where: &WhereClause, resolver_value: TokenStream| {
+            if let Some(remote) = &attributes.remote {
+                quote! {
+                    impl #impl_generics
+                        #rkyv_path::with::SerializeWith<#remote, __S>
+                        for #name #ty_generics
+                    #serialize_where
+                    {
+                        fn serialize_with(
+                            field: &#remote,
+                            serializer: &mut __S,
+                        ) -> ::core::result::Result<
+                            <
+                                Self as #rkyv_path::with::ArchiveWith<#remote>
+                            >::Resolver,
+                            <__S as #rkyv_path::rancor::Fallible>::Error,
+                        > {
+                            Ok(#resolver_value)
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    impl #impl_generics #rkyv_path::Serialize<__S>
+                        for #name #ty_generics
+                    #serialize_where
+                    {
+                        fn serialize(
+                            &self,
+                            serializer: &mut __S,
+                        ) -> ::core::result::Result<
+                            Self::Resolver,
+                            <__S as #rkyv_path::rancor::Fallible>::Error,
+                        > {
+                            Ok(#resolver_value)
+                        }
+                    }
+                }
+            }
+        };
+
     let serialize_impl =
         match input.data {
             Data::Struct(ref data) => match data.fields {
                 Fields::Named(ref fields) => {
                     let mut serialize_where = where_clause.clone();
-                    for field in fields.named.iter().filter(is_not_omitted) {
+                    for field in fields
+                        .named
+                        .iter()
+                        .filter(is_not_omitted)
+                        .filter(is_not_skipped)
+                    {
                         serialize_where
                             .predicates
                             .push(serialize_bound(&rkyv_path, field)?);
                     }
 
-                    let resolver_values = fields.named.iter().map(|field| {
-                    let name = &field.ident;
-                    let serialize = serialize(&rkyv_path, field)?;
-                    Ok(quote! { #name: #serialize(&self.#name, serializer)? })
-                }).collect::<Result<Vec<_>, Error>>()?;
+                    let resolver_values = members(&data.fields)
+                        .filter(|(_, field)| is_not_skipped(field))
+                        .filter(|(_, field)| !is_packed_bool(attributes, field))
+                        .map(|(member, field)| {
+                            let serialize = serialize(&rkyv_path, field)?;
+                            let field_ref = field_ref(&this, &member, field)?;
+                            Ok(quote! {
+                                #member: #serialize(#field_ref, serializer)?
+                            })
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
 
-                    quote! {
-                        impl #impl_generics #rkyv_path::Serialize<__S>
-                            for #name #ty_generics
-                        #serialize_where
-                        {
-                            fn serialize(
-                                &self,
-                                serializer: &mut __S
-                            ) -> ::core::result::Result<
-                                Self::Resolver,
-                                <__S as #rkyv_path::rancor::Fallible>::Error,
-                            > {
-                                Ok(#resolver {
-                                    #(#resolver_values,)*
-                                })
-                            }
-                        }
-                    }
+                    impl_serialize(
+                        &serialize_where,
+                        quote! { #resolver { #(#resolver_values,)* } },
+                    )
                 }
                 Fields::Unnamed(ref fields) => {
                     let mut serialize_where = where_clause.clone();
-                    for field in fields.unnamed.iter().filter(is_not_omitted) {
+                    for field in fields
+                        .unnamed
+                        .iter()
+                        .filter(is_not_omitted)
+                        .filter(is_not_skipped)
+                    {
                         serialize_where
                             .predicates
                             .push(serialize_bound(&rkyv_path, field)?);
                     }
 
-                    let resolver_values = fields
-                        .unnamed
-                        .iter()
-                        .enumerate()
-                        .map(|(i, field)| {
-                            let index = Index::from(i);
+                    let resolver_values = members(&data.fields)
+                        .filter(|(_, field)| is_not_skipped(field))
+                        .map(|(member, field)| {
                             let serialize = serialize(&rkyv_path, field)?;
-                            Ok(quote! { #serialize(&self.#index, serializer)? })
+                            let field_ref = field_ref(&this, &member, field)?;
+                            Ok(quote! { #serialize(#field_ref, serializer)? })
                         })
                         .collect::<Result<Vec<_>, Error>>()?;
 
-                    quote! {
-                        impl #impl_generics #rkyv_path::Serialize<__S>
-                            for #name #ty_generics
-                        #serialize_where
-                        {
-                            fn serialize(
-                                &self,
-                                serializer: &mut __S,
-                            ) -> ::core::result::Result<
-                                Self::Resolver,
-                                <__S as #rkyv_path::rancor::Fallible>::Error,
-                            > {
-                                Ok(#resolver(
-                                    #(#resolver_values,)*
-                                ))
-                            }
-                        }
-                    }
+                    impl_serialize(
+                        &serialize_where,
+                        quote! { #resolver(#(#resolver_values,)*) },
+                    )
                 }
                 Fields::Unit => {
-                    quote! {
-                        impl #impl_generics #rkyv_path::Serialize<__S>
-                            for #name #ty_generics
-                        #where_clause
-                        {
-                            fn serialize(
-                                &self,
-                                serializer: &mut __S,
-                            ) -> ::core::result::Result<
-                                Self::Resolver,
-                                <__S as #rkyv_path::rancor::Fallible>::Error,
-                            > {
-                                Ok(#resolver)
-                            }
-                        }
-                    }
+                    impl_serialize(where_clause, quote! { #resolver })
                 }
             },
             Data::Enum(ref data) => {
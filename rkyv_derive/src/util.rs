@@ -5,6 +5,8 @@ use syn::{
     WherePredicate,
 };
 
+use crate::attributes::{Attributes, FieldAttributes};
+
 pub fn strip_raw(ident: &Ident) -> String {
     let as_string = ident.to_string();
     as_string
@@ -14,6 +16,15 @@ pub fn strip_raw(ident: &Ident) -> String {
 }
 
 pub fn is_not_omitted(f: &&Field) -> bool {
+    // `PhantomData` implements everything unconditionally, so its bounds are
+    // always omitted. Bounds on it would also keep its archived type from
+    // normalizing.
+    if is_phantom_data(&f.ty)
+        && !f.attrs.iter().any(|attr| attr.meta.path().is_ident("with"))
+    {
+        return false;
+    }
+
     f.attrs.iter().all(|attr| {
         if let Meta::Path(path) = &attr.meta {
             !path.is_ident("omit_bounds")
@@ -23,6 +34,56 @@ pub fn is_not_omitted(f: &&Field) -> bool {
     })
 }
 
+pub fn is_not_skipped(f: &&Field) -> bool {
+    // Invalid field attributes are reported when the container attributes are
+    // parsed.
+    FieldAttributes::parse(f).map_or(true, |attrs| attrs.skip.is_none())
+}
+
+/// Returns whether the given field is a `bool` which is packed into the
+/// bitfield of the archived type. Fields with wrappers are never packed.
+pub fn is_packed_bool(attributes: &Attributes, field: &Field) -> bool {
+    attributes.pack_bools.is_some()
+        && field.ident.is_some()
+        && is_not_skipped(&field)
+        && !field.attrs.iter().any(|attr| attr.path().is_ident("with"))
+        && matches!(
+            &field.ty,
+            Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("bool"),
+        )
+}
+
+/// Returns the packed `bool` fields of the given fields along with the index
+/// of the bit each one is stored in.
+pub fn packed_bools<'a>(
+    attributes: &'a Attributes,
+    fields: &'a Fields,
+) -> impl 'a + Iterator<Item = (usize, &'a Field)> {
+    fields
+        .iter()
+        .filter(|field| is_packed_bool(attributes, field))
+        .enumerate()
+}
+
+/// Returns the number of bytes needed to hold the packed `bool` fields of the
+/// given fields.
+pub fn packed_bools_len<'a>(
+    attributes: &Attributes,
+    fields: impl IntoIterator<Item = &'a Field>,
+) -> usize {
+    let count = fields
+        .into_iter()
+        .filter(|field| is_packed_bool(attributes, field))
+        .count();
+    count.div_ceil(8)
+}
+
+/// Returns the ident of the archived field which holds the packed `bool`
+/// fields.
+pub fn packed_bools_ident() -> Ident {
+    Ident::new("__rkyv_packed_bools", Span::call_site())
+}
+
 pub fn members_starting_at(
     fields: &Fields,
     start: usize,
@@ -41,6 +102,28 @@ pub fn members(fields: &Fields) -> impl Iterator<Item = (Member, &Field)> {
     members_starting_at(fields, 0)
 }
 
+/// Returns the members of the given fields along with the members of their
+/// archived counterparts. Skipped fields have no archived counterpart, so the
+/// archived members of tuple fields may have lower indices.
+pub fn archived_members(
+    fields: &Fields,
+) -> impl Iterator<Item = (Member, Option<Member>, &Field)> {
+    let mut archived_index = 0;
+    members(fields).map(move |(member, field)| {
+        let archived_member = is_not_skipped(&field).then(|| {
+            let archived_member = match &member {
+                Member::Named(_) => member.clone(),
+                Member::Unnamed(_) => {
+                    Member::Unnamed(Index::from(archived_index))
+                }
+            };
+            archived_index += 1;
+            archived_member
+        });
+        (member, archived_member, field)
+    })
+}
+
 pub fn map_with_or_else<T>(
     field: &Field,
     f: impl FnOnce(Type) -> T,
@@ -122,6 +205,29 @@ pub fn deserialize_bound(
     )
 }
 
+pub fn deserialize_into_bound(
+    rkyv_path: &Path,
+    field: &Field,
+) -> Result<WherePredicate, Error> {
+    let ty = &field.ty;
+
+    let archived = archived(rkyv_path, field)?;
+
+    map_with_or_else(
+        field,
+        |with_ty| {
+            parse_quote! {
+                #with_ty: #rkyv_path::with::DeserializeWith<#archived, #ty, __D>
+            }
+        },
+        || {
+            parse_quote! {
+                #archived: #rkyv_path::DeserializeInto<#ty, __D>
+            }
+        },
+    )
+}
+
 fn archive_item(
     rkyv_path: &Path,
     field: &Field,
@@ -147,8 +253,40 @@ fn archive_item(
     )
 }
 
+// `PhantomData` always archives as itself, so its archived type is spelled out
+// instead of projected.
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            type_path.qself.is_none()
+                && type_path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "PhantomData")
+        }
+        _ => false,
+    }
+}
+
 pub fn archived(rkyv_path: &Path, field: &Field) -> Result<TokenStream, Error> {
-    archive_item(rkyv_path, field, "Archived", "Archived")
+    let ty = &field.ty;
+
+    map_with_or_else(
+        field,
+        |with_ty| {
+            quote! {
+                <#with_ty as #rkyv_path::with::ArchiveWith<#ty>>::Archived
+            }
+        },
+        || {
+            if is_phantom_data(ty) {
+                quote! { #ty }
+            } else {
+                quote! { <#ty as #rkyv_path::Archive>::Archived }
+            }
+        },
+    )
 }
 
 pub fn resolver(rkyv_path: &Path, field: &Field) -> Result<TokenStream, Error> {
@@ -210,3 +348,37 @@ pub fn deserialize(
         },
     )
 }
+
+/// Returns an expression which borrows the given field of `this`.
+///
+/// Fields of remote types may be read with a getter instead of accessing them
+/// directly.
+pub fn field_ref(
+    this: &TokenStream,
+    member: &Member,
+    field: &Field,
+) -> Result<TokenStream, Error> {
+    let ty = &field.ty;
+    Ok(match FieldAttributes::parse(field)?.getter {
+        Some(getter) => quote! {
+            ::core::borrow::Borrow::<#ty>::borrow(&#getter(#this))
+        },
+        None => quote! { &#this.#member },
+    })
+}
+
+pub fn default_value(field: &Field) -> Result<TokenStream, Error> {
+    Ok(FieldAttributes::parse(field)?.default.map_or_else(
+        || quote! { ::core::default::Default::default() },
+        |default| quote! { #default },
+    ))
+}
+
+pub fn default_bound(field: &Field) -> Result<Option<WherePredicate>, Error> {
+    let ty = &field.ty;
+
+    Ok(FieldAttributes::parse(field)?
+        .default
+        .is_none()
+        .then(|| parse_quote! { #ty: ::core::default::Default }))
+}
@@ -0,0 +1,157 @@
+use core::{fmt, ops::Deref};
+
+use munge::munge;
+use rancor::{fail, Fallible, Source};
+use rkyv::{Place, Portable, RawRelPtr};
+
+use crate::{
+    ArchivedDynMetadata, AsDynDeserializer, AsDynSerializer, DynTrait, ImplId,
+    InvalidImplId,
+};
+
+/// An archived `Box<dyn Trait>`.
+///
+/// This is the [`ImplId`] of the type of the boxed value, followed by a
+/// relative pointer to the archived value.
+#[derive(Portable)]
+#[cfg_attr(
+    feature = "bytecheck",
+    derive(::bytecheck::CheckBytes),
+    check_bytes(verify)
+)]
+#[repr(C)]
+pub struct ArchivedDynBox<T: ?Sized> {
+    metadata: ArchivedDynMetadata<T>,
+    ptr: RawRelPtr,
+}
+
+impl<T: ?Sized> ArchivedDynBox<T> {
+    /// Returns the impl ID of the type of the boxed value.
+    pub fn impl_id(&self) -> ImplId {
+        self.metadata.impl_id()
+    }
+
+    /// Resolves an archived dyn box from a resolver.
+    pub fn resolve_from_resolver(resolver: DynBoxResolver, out: Place<Self>) {
+        munge!(let ArchivedDynBox { metadata, ptr } = out);
+        metadata.write(ArchivedDynMetadata::new(resolver.impl_id));
+        RawRelPtr::emplace(resolver.pos, ptr);
+    }
+}
+
+impl<T: DynTrait + ?Sized> ArchivedDynBox<T> {
+    /// Returns a reference to the boxed value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the impl ID is not registered for `T`. This can only happen
+    /// if the archive was not validated.
+    pub fn get(&self) -> &T {
+        let metadata = self.metadata.lookup_metadata();
+        // SAFETY: The archived value is valid, so `ptr` points to an archived
+        // value of the type with the impl ID of this box.
+        let ptr = unsafe { self.ptr.as_ptr() };
+        // SAFETY: `metadata` is the metadata for that archived type as a `T`.
+        unsafe { &*ptr_meta::from_raw_parts(ptr, metadata) }
+    }
+
+    /// Serializes the given trait object and returns a resolver for it.
+    ///
+    /// Returns an error if the type of the value is not registered for `T`.
+    pub fn serialize_from_ref<S>(
+        value: &T,
+        serializer: &mut S,
+    ) -> Result<DynBoxResolver, S::Error>
+    where
+        S: Fallible + AsDynSerializer<S::Error> + ?Sized,
+        S::Error: Source,
+    {
+        let Some(impl_id) = T::impl_id(value) else {
+            fail!(UnregisteredImpl);
+        };
+        let Some(dyn_impl) = T::lookup::<S::Error>(impl_id) else {
+            fail!(InvalidImplId { impl_id });
+        };
+        // SAFETY: `impl_id` is the impl ID of the type of `value`, so
+        // `dyn_impl` was created for that type.
+        let pos = unsafe {
+            dyn_impl.serialize(value, serializer.as_dyn_serializer())?
+        };
+        Ok(DynBoxResolver { impl_id, pos })
+    }
+
+    /// Deserializes the boxed value into a new box.
+    pub fn deserialize<D>(
+        &self,
+        deserializer: &mut D,
+    ) -> Result<Box<T>, D::Error>
+    where
+        D: Fallible + AsDynDeserializer<D::Error> + ?Sized,
+        D::Error: Source,
+    {
+        let impl_id = self.impl_id();
+        let Some(dyn_impl) = T::lookup::<D::Error>(impl_id) else {
+            fail!(InvalidImplId { impl_id });
+        };
+        // SAFETY: The archived value is valid, so `ptr` points to an archived
+        // value of the type with the impl ID of this box.
+        unsafe {
+            let ptr = self.ptr.as_ptr();
+            dyn_impl.deserialize(ptr, deserializer.as_dyn_deserializer())
+        }
+    }
+}
+
+impl<T: DynTrait + ?Sized> Deref for ArchivedDynBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+/// The resolver for an [`ArchivedDynBox`].
+pub struct DynBoxResolver {
+    impl_id: ImplId,
+    pos: usize,
+}
+
+#[derive(Debug)]
+struct UnregisteredImpl;
+
+impl fmt::Display for UnregisteredImpl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the type of the trait object is not registered")
+    }
+}
+
+impl std::error::Error for UnregisteredImpl {}
+
+#[cfg(feature = "bytecheck")]
+mod verify {
+    use bytecheck::{
+        rancor::{fail, Fallible, Source},
+        Verify,
+    };
+
+    use super::ArchivedDynBox;
+    use crate::{AsDynContext, DynTrait, InvalidImplId};
+
+    // SAFETY: `verify` only succeeds if the archived value that `ptr` points
+    // to was checked as the archived type of the box's impl ID.
+    unsafe impl<T, C> Verify<C> for ArchivedDynBox<T>
+    where
+        T: DynTrait + ?Sized,
+        C: Fallible + AsDynContext<C::Error> + ?Sized,
+        C::Error: Source,
+    {
+        fn verify(&self, context: &mut C) -> Result<(), C::Error> {
+            let impl_id = self.impl_id();
+            let Some(dyn_impl) = T::lookup::<C::Error>(impl_id) else {
+                fail!(InvalidImplId { impl_id });
+            };
+            let ptr = self.ptr.as_ptr_wrapping();
+            dyn_impl.check_bytes(ptr, context.as_dyn_context())
+        }
+    }
+}
@@ -1,87 +1,105 @@
 //! Validation implementations and helper types.
 
-use crate::{ArchivedDynMetadata, register::Registered};
-use bytecheck::{CheckBytes, rancor::{Fallible, fail, Error}, Verify};
-use core::{
-    alloc::Layout,
-    fmt,
-    marker::PhantomData,
+use bytecheck::{
+    rancor::{fail, Fallible, Source},
+    CheckBytes, Verify,
 };
-use rkyv::validation::ArchiveContext;
-use std::collections::HashMap;
+use ptr_meta::{DynMetadata, Pointee};
+use rkyv::{
+    validation::{ArchiveContext, ArchiveContextExt, SharedContext},
+    Archive,
+};
+
+use crate::{ArchivedDynMetadata, DynImpl, DynTrait, InvalidImplId};
 
-#[doc(hidden)]
-#[derive(Copy, Clone)]
-pub struct ImplValidation<E> {
-    pub layout: Layout,
-    pub check_bytes: fn(*const (), &mut dyn ArchiveContext<E>) -> Result<(), E>,
+/// An object-safe version of a validation context.
+///
+/// Archived trait objects are checked through a `DynContext`. Any context that
+/// implements `ArchiveContext` and `SharedContext` automatically implements
+/// `DynContext`.
+pub trait DynContext<E>: ArchiveContext<E> + SharedContext<E> {}
+
+impl<E> Fallible for dyn DynContext<E> + '_ {
+    type Error = E;
 }
 
-#[derive(Debug)]
-struct InvalidImplId {
-    type_id: u64,
+impl<C: ArchiveContext<E> + SharedContext<E> + ?Sized, E> DynContext<E> for C {}
+
+/// A validation context that can be converted into a [`DynContext`].
+pub trait AsDynContext<E> {
+    /// Returns this context as a `DynContext`.
+    fn as_dyn_context(&mut self) -> &mut dyn DynContext<E>;
 }
 
-impl fmt::Display for InvalidImplId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "invalid impl id: {} not registered", self.type_id)
+impl<C: DynContext<E>, E> AsDynContext<E> for C {
+    fn as_dyn_context(&mut self) -> &mut dyn DynContext<E> {
+        self as &mut dyn DynContext<E>
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidImplId {}
-
-unsafe impl<T, C> Verify<C> for ArchivedDynMetadata<T>
-where
-    T: ?Sized,
-    C: Fallible + ?Sized,
-    C::Error: Source,
-{
-    fn verify(&self, context: &mut C) -> Result<(), C::Error> {
-        if let Some(_) = IMPL_REGISTRY.get::<T>(self.type_id.to_native()) {
-            Ok(())
-        } else {
-            fail!(InvalidImplId {
-                type_id: self.type_id.to_native(),
-            });
-        }
+impl<E> AsDynContext<E> for dyn DynContext<E> + '_ {
+    fn as_dyn_context(&mut self) -> &mut dyn DynContext<E> {
+        self
     }
 }
 
-#[derive(Debug)]
-struct InvalidMetadata {
-    metadata: u64,
+/// An archived type which can be checked through a [`DynContext`].
+pub trait DynCheckBytes<E>: for<'a> CheckBytes<dyn DynContext<E> + 'a> {}
+
+impl<T, E> DynCheckBytes<E> for T where
+    T: for<'a> CheckBytes<dyn DynContext<E> + 'a> + ?Sized
+{
 }
 
-impl fmt::Display for InvalidMetadata {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "invalid metadata: {}", self.metadata)
+impl<T, E> DynImpl<T, E>
+where
+    T: Pointee<Metadata = DynMetadata<T>> + ?Sized,
+    E: Source,
+{
+    /// Checks that `ptr` points to a valid archived value of the type that this
+    /// `DynImpl` was created for.
+    pub(crate) fn check_bytes(
+        &self,
+        ptr: *const (),
+        context: &mut dyn DynContext<E>,
+    ) -> Result<(), E> {
+        // SAFETY: The check function claims the subtree of the archived value
+        // before checking it, so it is safe to call with any pointer.
+        unsafe { (self.check_bytes)(ptr, context) }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidMetadata {}
-
-#[doc(hidden)]
-pub struct CheckBytesEntry<E> {
-    vtable: usize,
-    validation: ImplValidation<E>,
+pub(crate) unsafe fn check_bytes_impl<U, E>(
+    ptr: *const (),
+    context: &mut dyn DynContext<E>,
+) -> Result<(), E>
+where
+    U: Archive,
+    U::Archived: DynCheckBytes<E>,
+    E: Source,
+{
+    let ptr = ptr.cast::<U::Archived>();
+    context.in_subtree(ptr, |context| {
+        // SAFETY: `in_subtree` checked that `ptr` is properly aligned and
+        // points to enough bytes for a `U::Archived`.
+        unsafe { U::Archived::check_bytes(ptr, context) }
+    })
 }
 
-impl<E> CheckBytesEntry<E> {
-    #[doc(hidden)]
-    pub fn new<Ty, Tr>(check_bytes_dyn: CheckBytesDyn<E>) -> Self
-    where
-        Ty: RegisteredImpl<Tr>,
-        Tr: ?Sized,
-    {
-        Self {
-            vtable: <Ty as RegisteredImpl<Tr>>::vtable(),
-            validation: ImplValidation {
-                layout: Layout::new::<Ty>(),
-                check_bytes_dyn,
-            },
+// SAFETY: `verify` only succeeds if the impl ID refers to an impl registered
+// for `T`, so `lookup_metadata` will not panic.
+unsafe impl<T, C> Verify<C> for ArchivedDynMetadata<T>
+where
+    T: DynTrait + ?Sized,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        let impl_id = self.impl_id();
+        if T::archived_metadata(impl_id).is_some() {
+            Ok(())
+        } else {
+            fail!(InvalidImplId { impl_id });
         }
     }
 }
-
@@ -1,39 +1,120 @@
 //! Trait object serialization for rkyv.
 //!
-//! With `rkyv_dyn`, trait objects can be serialized with rkyv then the methods
-//! can be called without deserializing. All it takes is some macro magic.
+//! With `rkyv_dyn`, boxed trait objects can be serialized with rkyv and their
+//! methods can be called without deserializing.
 //!
-//! See [`SerializeDyn`] for an example of how to use rkyv_dyn.
+//! Each type which implements a trait is registered for that trait with
+//! [`register_impl`], which gives it a stable [`ImplId`]. When a trait object
+//! is serialized, the impl ID of its type is written as a type tag alongside
+//! the archived value. Fields of type `Box<dyn Trait>` can then be archived
+//! with the [`DynBox`](with::DynBox) wrapper.
 //!
 //! ## Features
 //!
 //! - `bytecheck`: Enables validation support through `bytecheck`.
+//!
+//! # Example
+//!
+//! ```
+//! use core::any::Any;
+//!
+//! use rkyv::{rancor::Error, with::Map, Archive, Deserialize, Serialize};
+//! use rkyv_dyn::{register_impl, with::DynBox};
+//!
+//! #[ptr_meta::pointee]
+//! trait Message: Any {
+//!     fn describe(&self) -> String;
+//! }
+//!
+//! #[derive(Archive, Serialize, Deserialize)]
+//! #[rkyv(check_bytes)]
+//! struct Ping {
+//!     seq: u32,
+//! }
+//!
+//! impl Message for Ping {
+//!     fn describe(&self) -> String {
+//!         format!("ping {}", self.seq)
+//!     }
+//! }
+//!
+//! impl Message for ArchivedPing {
+//!     fn describe(&self) -> String {
+//!         format!("ping {}", self.seq)
+//!     }
+//! }
+//!
+//! #[derive(Archive, Serialize, Deserialize)]
+//! #[rkyv(check_bytes)]
+//! struct Reply {
+//!     text: String,
+//! }
+//!
+//! impl Message for Reply {
+//!     fn describe(&self) -> String {
+//!         format!("reply {}", self.text)
+//!     }
+//! }
+//!
+//! impl Message for ArchivedReply {
+//!     fn describe(&self) -> String {
+//!         format!("reply {}", self.text)
+//!     }
+//! }
+//!
+//! register_impl!(dyn Message: Ping = 1, Reply = 2);
+//!
+//! #[derive(Archive, Serialize, Deserialize)]
+//! #[rkyv(check_bytes)]
+//! struct Inbox {
+//!     #[with(Map<DynBox>)]
+//!     messages: Vec<Box<dyn Message>>,
+//! }
+//!
+//! let inbox = Inbox {
+//!     messages: vec![
+//!         Box::new(Ping { seq: 1 }),
+//!         Box::new(Reply {
+//!             text: "hello".to_string(),
+//!         }),
+//!     ],
+//! };
+//!
+//! let bytes = rkyv::to_bytes::<Error>(&inbox).unwrap();
+//! let archived = rkyv::access::<ArchivedInbox, Error>(&bytes).unwrap();
+//! assert_eq!(archived.messages[0].describe(), "ping 1");
+//! assert_eq!(archived.messages[1].describe(), "reply hello");
+//!
+//! let deserialized = rkyv::deserialize::<Inbox, Error>(archived).unwrap();
+//! assert_eq!(deserialized.messages[1].describe(), "reply hello");
+//! ```
 
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
-mod lazy_static;
-// TODO: re-enable
-// #[cfg(feature = "bytecheck")]
-// mod bytecheck;
+mod boxed;
+#[cfg(feature = "bytecheck")]
+mod bytecheck;
+pub mod with;
 
-use core::{hash, marker::PhantomData};
+use core::{fmt, hash, marker::PhantomData};
 
-pub use lazy_static::LazyStatic;
 use ptr_meta::{DynMetadata, Pointee};
-use rancor::Fallible;
+use rancor::{Fallible, Source};
 use rkyv::{
     de::Pooling,
     place::Initialized,
-    primitive::FixedUsize,
     ser::{Allocator, Sharing, Writer},
-    Archived, Portable, Serialize, SerializeUnsized,
+    Archive, Archived, Deserialize, Portable, Serialize, SerializeUnsized,
 };
-pub use rkyv_dyn_derive::archive_dyn;
+
+pub use self::boxed::*;
+#[cfg(feature = "bytecheck")]
+pub use self::bytecheck::*;
 
 /// The type of trait impl IDs.
-pub type ImplId = FixedUsize;
+pub type ImplId = u64;
 
 /// An object-safe version of `Serializer`.
 ///
@@ -48,9 +129,9 @@ impl<E> Fallible for dyn DynSerializer<E> + '_ {
 
 impl<S: Writer<E> + Allocator<E> + Sharing<E>, E> DynSerializer<E> for S {}
 
-/// TODO
+/// A serializer that can be converted into a [`DynSerializer`].
 pub trait AsDynSerializer<E> {
-    /// TODO
+    /// Returns this serializer as a `DynSerializer`.
     fn as_dyn_serializer(&mut self) -> &mut dyn DynSerializer<E>;
 }
 
@@ -60,129 +141,12 @@ impl<S: DynSerializer<E>, E> AsDynSerializer<E> for S {
     }
 }
 
-impl<E> AsDynSerializer<E> for dyn DynSerializer<E> {
+impl<E> AsDynSerializer<E> for dyn DynSerializer<E> + '_ {
     fn as_dyn_serializer(&mut self) -> &mut dyn DynSerializer<E> {
         self
     }
 }
 
-/// A trait object that can be archived.
-///
-/// To add archive support for a trait object:
-///
-/// 1. Add [`archive_dyn`](macro@archive_dyn) on your trait to make a
-///    serializable version of it. By default, it will be named "Serialize" +
-///    your trait name. To rename the trait, pass the argument `serialize =
-///    "..."` as a parameter.
-/// 2. Implement your trait for your type and add the attribute `#[archive_dyn]`
-///    to it. Make sure to implement your trait for your archived type as well.
-///    This invocation must have the same attributes as the trait invocation.
-/// 3. If deserialization support is desired, add `deserialize` or `deserialize
-///    = "..."` as parameters and implement `Deserialize` for the type. By
-///    default, the deserialize trait will be named "Deserialize" + your trait
-///    name. Passing a trait name will use that name instead.
-///
-/// Then you're ready to serialize boxed trait objects!
-///
-/// Even though your deserialized values are boxed as serialize trait objects,
-/// your archived values are boxed as regular trait objects. This is because
-/// your deserialized values have to implement `SerializeDyn` but your archived
-/// values do not.
-///
-/// # Examples
-///
-/// See [`archive_dyn`](macro@archive_dyn) for customization options.
-///
-/// ```
-/// use rkyv::{
-///     archived_value,
-///     ser::{serializers::AllocSerializer, Serializer},
-///     AlignedVec, Archive, Archived, Deserialize, Infallible, Serialize,
-/// };
-/// use rkyv_dyn::archive_dyn;
-///
-/// #[archive_dyn(deserialize)]
-/// trait ExampleTrait {
-///     fn value(&self) -> String;
-/// }
-///
-/// #[derive(Archive, Serialize, Deserialize)]
-/// struct StringStruct(String);
-///
-/// #[archive_dyn(deserialize)]
-/// impl ExampleTrait for StringStruct {
-///     fn value(&self) -> String {
-///         self.0.clone()
-///     }
-/// }
-///
-/// impl ExampleTrait for ArchivedStringStruct {
-///     fn value(&self) -> String {
-///         self.0.as_str().to_string()
-///     }
-/// }
-///
-/// #[derive(Archive, Serialize, Deserialize)]
-/// struct IntStruct(i32);
-///
-/// #[archive_dyn(deserialize)]
-/// impl ExampleTrait for IntStruct {
-///     fn value(&self) -> String {
-///         format!("{}", self.0)
-///     }
-/// }
-///
-/// impl ExampleTrait for ArchivedIntStruct {
-///     fn value(&self) -> String {
-///         format!("{}", self.0)
-///     }
-/// }
-///
-/// let boxed_int = Box::new(IntStruct(42)) as Box<dyn SerializeExampleTrait>;
-/// let boxed_string = Box::new(StringStruct("hello world".to_string()))
-///     as Box<dyn SerializeExampleTrait>;
-/// let mut serializer = AllocSerializer::<256>::default();
-/// let int_pos = serializer
-///     .serialize_value(&boxed_int)
-///     .expect("failed to archive boxed int");
-/// let str_pos = serializer
-///     .serialize_value(&boxed_string)
-///     .expect("failed to archive boxed string");
-/// let buf = serializer.into_serializer().into_inner();
-/// let archived_int = unsafe {
-///     archived_value::<Box<dyn SerializeExampleTrait>>(buf.as_ref(), int_pos)
-/// };
-/// let archived_string = unsafe {
-///     archived_value::<Box<dyn SerializeExampleTrait>>(buf.as_ref(), str_pos)
-/// };
-/// assert_eq!(archived_int.value(), "42");
-/// assert_eq!(archived_string.value(), "hello world");
-///
-/// let deserialized_int: Box<dyn SerializeExampleTrait> =
-///     archived_int.deserialize(&mut Infallible).unwrap();
-/// let deserialized_string: Box<dyn SerializeExampleTrait> =
-///     archived_string.deserialize(&mut Infallible).unwrap();
-/// assert_eq!(deserialized_int.value(), "42");
-/// assert_eq!(deserialized_string.value(), "hello world");
-/// ```
-// TODO: This is just `for<'a> SerializeUnsized<dyn DynSerializer<E>> + 'a`
-pub trait SerializeDyn<E> {
-    /// Serializes this value and returns the position it is located at.
-    fn serialize_dyn(
-        &self,
-        serializer: &mut dyn DynSerializer<E>,
-    ) -> Result<usize, E>;
-}
-
-impl<T: for<'a> Serialize<dyn DynSerializer<E> + 'a>, E> SerializeDyn<E> for T {
-    fn serialize_dyn(
-        &self,
-        serializer: &mut dyn DynSerializer<E>,
-    ) -> Result<usize, E> {
-        self.serialize_unsized(serializer)
-    }
-}
-
 /// An object-safe version of `Deserializer`.
 pub trait DynDeserializer<E>: Pooling<E> {}
 
@@ -192,9 +156,9 @@ impl<E> Fallible for dyn DynDeserializer<E> + '_ {
 
 impl<D, E> DynDeserializer<E> for D where D: Pooling<E> {}
 
-/// TODO
+/// A deserializer that can be converted into a [`DynDeserializer`].
 pub trait AsDynDeserializer<E> {
-    /// TODO
+    /// Returns this deserializer as a `DynDeserializer`.
     fn as_dyn_deserializer(&mut self) -> &mut dyn DynDeserializer<E>;
 }
 
@@ -204,28 +168,175 @@ impl<S: DynDeserializer<E>, E> AsDynDeserializer<E> for S {
     }
 }
 
-impl<E> AsDynDeserializer<E> for dyn DynDeserializer<E> {
+impl<E> AsDynDeserializer<E> for dyn DynDeserializer<E> + '_ {
     fn as_dyn_deserializer(&mut self) -> &mut dyn DynDeserializer<E> {
         self
     }
 }
 
-/// A trait object that can be deserialized.
+/// A trait object type with a table of registered impls.
+///
+/// This is implemented for `dyn Trait` by [`register_impl`]. Each registered
+/// impl has an [`ImplId`] which is unique among the impls of the trait, and
+/// which stays the same between builds.
+///
+/// # Safety
+///
+/// For each registered impl ID:
+///
+/// - `impl_id` must return the impl ID for values of the registered type.
+/// - `archived_metadata` must return the metadata of the archived type as a
+///   `Self`.
+/// - `lookup` must return the [`DynImpl`] of the registered type.
+///
+/// `archived_metadata` and `lookup` must return `None` for every other impl
+/// ID.
+pub unsafe trait DynTrait:
+    Pointee<Metadata = DynMetadata<Self>>
+{
+    /// Returns the impl ID of the type of the given value, or `None` if its
+    /// type is not registered.
+    fn impl_id(value: &Self) -> Option<ImplId>;
+
+    /// Returns the metadata of the archived type with the given impl ID as a
+    /// `Self`, or `None` if the impl ID is not registered.
+    fn archived_metadata(impl_id: ImplId) -> Option<DynMetadata<Self>>;
+
+    /// Returns the functions for the type with the given impl ID, or `None` if
+    /// the impl ID is not registered.
+    fn lookup<E: Source>(impl_id: ImplId) -> Option<DynImpl<Self, E>>;
+}
+
+/// An archived type which can be checked through a `DynContext`.
 ///
-/// See [`SerializeDyn`] for more information.
-pub trait DeserializeDyn<T: Pointee + ?Sized, E> {
-    /// Deserializes this value into the given out pointer.
-    fn deserialize_dyn(
+/// Without the `bytecheck` feature, this is implemented for all types.
+#[cfg(not(feature = "bytecheck"))]
+pub trait DynCheckBytes<E> {}
+
+#[cfg(not(feature = "bytecheck"))]
+impl<T: ?Sized, E> DynCheckBytes<E> for T {}
+
+/// The functions used to serialize, validate, and deserialize a registered
+/// impl of a trait object.
+///
+/// These are created by [`register_impl`].
+pub struct DynImpl<T: ?Sized, E> {
+    metadata: DynMetadata<T>,
+    serialize: unsafe fn(&T, &mut dyn DynSerializer<E>) -> Result<usize, E>,
+    #[cfg(feature = "bytecheck")]
+    check_bytes: unsafe fn(*const (), &mut dyn DynContext<E>) -> Result<(), E>,
+    deserialize:
+        unsafe fn(*const (), &mut dyn DynDeserializer<E>) -> Result<*mut (), E>,
+}
+
+impl<T, E> DynImpl<T, E>
+where
+    T: Pointee<Metadata = DynMetadata<T>> + ?Sized,
+    E: Source,
+{
+    /// Creates the `DynImpl` for the type `U`.
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must be a pointer to a `U` which was cast to a `T`.
+    pub unsafe fn new<U>(pointer: *const T) -> Self
+    where
+        U: Archive + for<'a> Serialize<dyn DynSerializer<E> + 'a>,
+        U::Archived: DynCheckBytes<E>
+            + for<'a> Deserialize<U, dyn DynDeserializer<E> + 'a>,
+    {
+        Self {
+            metadata: ptr_meta::metadata(pointer),
+            serialize: serialize_impl::<T, U, E>,
+            #[cfg(feature = "bytecheck")]
+            check_bytes: self::bytecheck::check_bytes_impl::<U, E>,
+            deserialize: deserialize_impl::<U, E>,
+        }
+    }
+
+    /// Serializes the given value and returns its position.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be of the type that this `DynImpl` was created for.
+    unsafe fn serialize(
         &self,
+        value: &T,
+        serializer: &mut dyn DynSerializer<E>,
+    ) -> Result<usize, E> {
+        // SAFETY: The caller has guaranteed that `value` is of the type this
+        // `DynImpl` was created for.
+        unsafe { (self.serialize)(value, serializer) }
+    }
+
+    /// Deserializes the archived value at `ptr` into a new box.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid archived value of the type that this
+    /// `DynImpl` was created for.
+    unsafe fn deserialize(
+        &self,
+        ptr: *const (),
         deserializer: &mut dyn DynDeserializer<E>,
-        out: *mut T,
-    ) -> Result<(), E>;
+    ) -> Result<Box<T>, E> {
+        // SAFETY: The caller has guaranteed that `ptr` points to a valid
+        // archived value of the same type.
+        let ptr = unsafe { (self.deserialize)(ptr, deserializer)? };
+        let ptr = ptr_meta::from_raw_parts_mut(ptr, self.metadata);
+        // SAFETY: `deserialize` returns a pointer from `Box::into_raw`, and
+        // `metadata` is the metadata of that type as a `T`.
+        Ok(unsafe { Box::from_raw(ptr) })
+    }
+}
 
-    /// Returns the pointer metadata for the deserialized form of this type.
-    fn deserialized_pointer_metadata(&self) -> DynMetadata<T>;
+unsafe fn serialize_impl<T, U, E>(
+    value: &T,
+    serializer: &mut dyn DynSerializer<E>,
+) -> Result<usize, E>
+where
+    T: ?Sized,
+    U: for<'a> Serialize<dyn DynSerializer<E> + 'a>,
+    E: Source,
+{
+    // SAFETY: The caller has guaranteed that `value` is a `U`.
+    let value = unsafe { &*(value as *const T).cast::<U>() };
+    value.serialize_unsized(serializer)
+}
+
+unsafe fn deserialize_impl<U, E>(
+    ptr: *const (),
+    deserializer: &mut dyn DynDeserializer<E>,
+) -> Result<*mut (), E>
+where
+    U: Archive,
+    U::Archived: for<'a> Deserialize<U, dyn DynDeserializer<E> + 'a>,
+{
+    // SAFETY: The caller has guaranteed that `ptr` points to a valid
+    // `U::Archived`.
+    let archived = unsafe { &*ptr.cast::<U::Archived>() };
+    let value = archived.deserialize(deserializer)?;
+    Ok(Box::into_raw(Box::new(value)).cast())
 }
 
+#[derive(Debug)]
+struct InvalidImplId {
+    impl_id: ImplId,
+}
+
+impl fmt::Display for InvalidImplId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid impl id: {} is not registered", self.impl_id)
+    }
+}
+
+impl std::error::Error for InvalidImplId {}
+
 /// The archived version of `DynMetadata`.
+///
+/// This is stored as the [`ImplId`] of the type that the trait object was
+/// created from. When validating, the impl ID is checked against the impls
+/// registered for `T` with [`register_impl`].
 #[derive(Portable)]
 #[cfg_attr(
     feature = "bytecheck",
@@ -252,11 +363,7 @@ unsafe impl<T: ?Sized> Sync for ArchivedDynMetadata<T> {}
 impl<T: ?Sized> Unpin for ArchivedDynMetadata<T> {}
 
 impl<T: ?Sized> ArchivedDynMetadata<T> {
-    /// Creates a new `ArchivedDynMetadata` for the given type.
-    ///
-    /// # Safety
-    ///
-    /// `out` must point to a valid location for an `ArchivedDynMetadata<T>`.
+    /// Creates a new `ArchivedDynMetadata` for the given impl ID.
     pub fn new(impl_id: ImplId) -> Self {
         Self {
             impl_id: Archived::<ImplId>::from_native(impl_id),
@@ -268,17 +375,19 @@ impl<T: ?Sized> ArchivedDynMetadata<T> {
     pub fn impl_id(&self) -> ImplId {
         self.impl_id.to_native()
     }
+}
 
+impl<T: DynTrait + ?Sized> ArchivedDynMetadata<T> {
     /// Returns the pointer metadata for the trait object this metadata refers
     /// to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the impl ID is not registered for `T`. This can only happen
+    /// if the archive was not validated.
     pub fn lookup_metadata(&self) -> DynMetadata<T> {
-        unsafe {
-            TRAIT_IMPLS
-                .get()
-                .expect("TRAIT_IMPLS was not initialized for rkyv_dyn")
-                [self.impl_id() as usize]
-                .downcast_metadata()
-        }
+        T::archived_metadata(self.impl_id())
+            .expect("impl ID was not registered for rkyv_dyn")
     }
 }
 
@@ -314,169 +423,326 @@ impl<T: ?Sized> Ord for ArchivedDynMetadata<T> {
     }
 }
 
-/// The trait object metadata for a trait implementation.
-#[derive(Clone, Copy, Debug)]
-pub struct TraitImpl {
-    // The type of this `DynMetadata` is erased. Whatever uses it will
-    // transmute it to the correct `DynMetadata<T>`.
-    metadata: DynMetadata<()>,
-}
-
-impl TraitImpl {
-    /// Creates a new trait impl from a trait object pointer.
-    ///
-    /// # Safety
-    ///
-    /// `pointer` must have valid metadata.
-    pub unsafe fn from_pointer<
-        T: Pointee<Metadata = DynMetadata<T>> + ?Sized,
-    >(
-        pointer: *const T,
-    ) -> Self {
-        Self::from_metadata(ptr_meta::metadata(pointer))
-    }
-
-    /// Creates a new trait impl from its trait object metadata.
-    pub fn from_metadata<T: ?Sized>(metadata: DynMetadata<T>) -> Self {
-        Self {
-            // SAFETY: All `DynMetadata<T>` have the same layout and validity.
-            // They all contain a single erased `&'static VTable` reference and
-            // a `PhantomData<T>`.
-            metadata: unsafe { core::mem::transmute(metadata) },
-        }
-    }
-
-    /// Returns the trait object metadata of this trait implementation downcast
-    /// to the given type.
-    ///
-    /// # Safety
-    ///
-    /// `T` must be the `dyn Trait` that this `TraitImpl` corresponds to.
-    pub unsafe fn downcast_metadata<T: ?Sized>(&self) -> DynMetadata<T> {
-        unsafe { core::mem::transmute(self.metadata) }
-    }
-}
-
-/// Creates a new [`TraitImpl`] from the given type and dyn trait.
+/// Registers types as impls of a trait object.
+///
+/// `register_impl!(dyn Trait: TypeA = 1, TypeB = 2)` implements [`DynTrait`]
+/// for `dyn Trait` with the given types and [`ImplId`]s. The impl IDs are
+/// written to archives, so they must stay the same for an archive to be read
+/// back. Using the same impl ID for two types is a compile error.
 ///
-/// See [`register_trait_impls`] for a macro that registers these trait impls
-/// globally.
+/// The trait must be annotated with `#[ptr_meta::pointee]` and have `Any` as a
+/// supertrait, which is used to find the impl ID of a value when it is
+/// serialized. Each type must implement `Archive`, `Serialize`, and
+/// `Deserialize`, and both the type and its archived type must implement the
+/// trait. With the `bytecheck` feature, the archived types must also support
+/// validation.
 ///
 /// # Example
+///
 /// ```
-/// struct MyType;
+/// use core::any::Any;
+///
+/// use rkyv::{Archive, Deserialize, Serialize};
+/// use rkyv_dyn::{register_impl, DynTrait};
+///
+/// #[ptr_meta::pointee]
+/// trait Shape: Any {
+///     fn area(&self) -> f32;
+/// }
 ///
-/// trait MyTrait {}
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Square(f32);
 ///
-/// impl MyTrait for MyType {}
+/// impl Shape for Square {
+///     fn area(&self) -> f32 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// impl Shape for ArchivedSquare {
+///     fn area(&self) -> f32 {
+///         self.0 * self.0
+///     }
+/// }
 ///
-/// let trait_impl = trait_impl!(MyType as dyn MyTrait);
+/// register_impl!(dyn Shape: Square = 0x5157_4152);
+///
+/// let square: Box<dyn Shape> = Box::new(Square(2.0));
+/// assert_eq!(<dyn Shape>::impl_id(&*square), Some(0x5157_4152));
 /// ```
 #[macro_export]
-macro_rules! trait_impl {
-    ($type:ty as $trait:ty) => {
-        // SAFETY: The given pointer is guaranteed to have valid metadata
-        // because we just made them.
-        unsafe {
-            $crate::TraitImpl::from_pointer(
-                ::core::ptr::null::<$type>() as *const $trait
-            )
-        }
+macro_rules! register_impl {
+    ($trait:ty : $($type:ty = $id:expr),+ $(,)?) => {
+        const _: () = {
+            // Overlapping impls of `Registered` reject duplicate impl IDs.
+            #[allow(dead_code)]
+            struct ImplIds;
+
+            #[allow(dead_code)]
+            trait Registered<const ID: $crate::ImplId> {}
+
+            $(impl Registered<{ $id }> for ImplIds {})+
+
+            // SAFETY: Each impl ID is unique, and the metadata and functions
+            // returned for each impl ID are for the type with that impl ID.
+            unsafe impl $crate::DynTrait for $trait {
+                fn impl_id(
+                    value: &Self,
+                ) -> ::core::option::Option<$crate::ImplId> {
+                    let value: &dyn ::core::any::Any = value;
+                    let type_id = ::core::any::Any::type_id(value);
+                    $(
+                        if type_id == ::core::any::TypeId::of::<$type>() {
+                            return ::core::option::Option::Some($id);
+                        }
+                    )+
+                    ::core::option::Option::None
+                }
+
+                fn archived_metadata(
+                    impl_id: $crate::ImplId,
+                ) -> ::core::option::Option<
+                    ::rkyv::ptr_meta::DynMetadata<Self>,
+                > {
+                    $(
+                        if impl_id == $id {
+                            return ::core::option::Option::Some(
+                                ::rkyv::ptr_meta::metadata(
+                                    ::core::ptr::null::<
+                                        ::rkyv::Archived<$type>,
+                                    >() as *const Self,
+                                ),
+                            );
+                        }
+                    )+
+                    ::core::option::Option::None
+                }
+
+                fn lookup<E: ::rkyv::rancor::Source>(
+                    impl_id: $crate::ImplId,
+                ) -> ::core::option::Option<$crate::DynImpl<Self, E>> {
+                    $(
+                        if impl_id == $id {
+                            // SAFETY: The pointer is a pointer to the type
+                            // being registered cast to `Self`.
+                            return ::core::option::Option::Some(unsafe {
+                                $crate::DynImpl::new::<$type>(
+                                    ::core::ptr::null::<$type>() as *const Self,
+                                )
+                            });
+                        }
+                    )+
+                    ::core::option::Option::None
+                }
+            }
+        };
     };
 }
 
-/// All registered trait impls for `rkyv_dyn`.
-///
-/// This can be initialized with
-pub static TRAIT_IMPLS: LazyStatic<&'static [TraitImpl]> = LazyStatic::new();
+#[cfg(all(test, feature = "bytecheck"))]
+mod tests {
+    use core::any::Any;
 
-/// Globally registers the given trait impls. This macro performs three basic
-/// functions:
-///
-/// 1. Generating `impl RegisteredImpl<$trait> for $type` definitions with valid
-///    impl IDs.
-/// 2. Creating and initializing a static array of [`TraitImpl`]s, one for each
-///    trait impl argument.
-/// 3. Initializing [`TRAIT_IMPLS`] with a reference to the array of
-///    [`TraitImpl`]s.
-#[macro_export]
-macro_rules! register_trait_impls {
-    ($($type:ty as $trait:ty $(= $id:expr)?),* $(,)?) => {
-        let _: () = {
-            $crate::register_trait_impls!(
-                @register $($type as $trait $(= $id)?,)*
-            );
-            const TRAIT_IMPL_COUNT: usize = 0
-                $(+ { let _ = ::core::marker::PhantomData::<$type>; 1 })*;
-            static TRAIT_IMPLS: $crate::LazyStatic<[
-                $crate::TraitImpl;
-                TRAIT_IMPL_COUNT
-            ]> = $crate::LazyStatic::new();
-            let trait_impls = TRAIT_IMPLS.init([
-                $(
-                    $crate::trait_impl!($type as $trait),
-                )*
-            ]).unwrap();
-            $crate::TRAIT_IMPLS.init(trait_impls).unwrap();
-        };
+    use rancor::{Error, Failure};
+    use rkyv::{
+        access, deserialize, to_bytes, with::Map, Archive, Archived,
+        Deserialize, Serialize,
     };
-    (
-        @register
-        $first_type:ty as $first_trait:ty $(= $first_id:expr)?,
-        $($rest_type:ty as $rest_trait:ty $(= $rest_id:expr)?,)*
-    ) => {
-        struct ImplIds;
-
-        trait Registered<const ID: $crate::ImplId> {}
-
-        unsafe impl $crate::RegisteredImpl<$first_trait> for $first_type {
-            const IMPL_ID: $crate::ImplId =
-                $crate::register_trait_impls!(@choose_id 0, $($first_id)?);
+
+    use crate::{with::DynBox, ArchivedDynBox, DynTrait};
+
+    #[ptr_meta::pointee]
+    trait Shape: Any {
+        fn area(&self) -> u32;
+    }
+
+    #[ptr_meta::pointee]
+    trait Label: Any {
+        fn label(&self) -> String;
+    }
+
+    #[derive(Archive, Serialize, Deserialize)]
+    #[rkyv(check_bytes)]
+    struct Square {
+        side: u32,
+        filled: bool,
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> u32 {
+            self.side * self.side
         }
-        impl Registered<
-            { <$first_type as $crate::RegisteredImpl<$first_trait>>::IMPL_ID }
-        > for ImplIds {}
-
-        $crate::register_trait_impls!(
-            @register_rest $first_type as $first_trait,
-            $($rest_type:ty as $rest_trait:ty $(= $rest_id:expr)?,)*
-        );
-    };
-    (@register_rest $prev_type:ty as $prev_trait:ty,) => {};
-    (
-        @register_rest
-        $prev_type:ty as $prev_trait:ty,
-        $type:ty as $trait:ty $(= $id:expr)?,
-        $($rest_type:ty as $rest_trait:ty $(= $rest_id:expr)?,)*
-    ) => {
-        unsafe impl $crate::RegisteredImpl<$trait> for $type {
-            const IMPL_ID: $crate::ImplId = $crate::register_trait_impls!(
-                @choose_id
-                <
-                    $prev_type as $crate::RegisteredImpl<$prev_trait>
-                >::IMPL_ID + 1,
-                $($id)?
-            );
+    }
+
+    impl Shape for ArchivedSquare {
+        fn area(&self) -> u32 {
+            self.side * self.side
         }
-        impl Registered<{
-            <$type as $crate::RegisteredImpl<$trait>>::IMPL_ID
-        }> for ImplIds {}
-
-        $crate::register_trait_impls!(
-            @register_rest $type as $trait,
-            $($rest_type as $rest_trait $(= $rest_id)?,)*
-        );
-    };
-    (@choose_id $default:expr, $explicit:expr) => { $explicit };
-    (@choose_id $default:expr,) => { $default };
-}
+    }
 
-/// A trait impl that has a globally-unique ID.
-///
-/// # Safety
-///
-/// `IMPL_ID` must be globally unique.
-pub unsafe trait RegisteredImpl<T: ?Sized> {
-    /// The ID of this trait impl.
-    const IMPL_ID: ImplId;
+    #[derive(Archive, Serialize, Deserialize)]
+    #[rkyv(check_bytes)]
+    struct Group {
+        #[with(Map<DynBox>)]
+        shapes: Vec<Box<dyn Shape>>,
+    }
+
+    impl Shape for Group {
+        fn area(&self) -> u32 {
+            self.shapes.iter().map(|shape| shape.area()).sum()
+        }
+    }
+
+    impl Shape for ArchivedGroup {
+        fn area(&self) -> u32 {
+            self.shapes.iter().map(|shape| shape.area()).sum()
+        }
+    }
+
+    #[derive(Archive, Serialize, Deserialize)]
+    #[rkyv(check_bytes)]
+    struct Name(String);
+
+    impl Label for Name {
+        fn label(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    impl Label for ArchivedName {
+        fn label(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    struct Circle;
+
+    impl Shape for Circle {
+        fn area(&self) -> u32 {
+            3
+        }
+    }
+
+    const SQUARE_ID: u64 = 0x0123_4567_89ab_cdef;
+    const GROUP_ID: u64 = 0x1111_2222_3333_4444;
+    const NAME_ID: u64 = 0x5555_6666_7777_8888;
+
+    register_impl!(dyn Shape: Square = SQUARE_ID, Group = GROUP_ID);
+    register_impl!(dyn Label: Name = NAME_ID);
+
+    #[derive(Archive, Serialize, Deserialize)]
+    #[rkyv(check_bytes)]
+    struct Drawing {
+        #[with(DynBox)]
+        shape: Box<dyn Shape>,
+        #[with(DynBox)]
+        label: Box<dyn Label>,
+    }
+
+    fn drawing() -> Drawing {
+        Drawing {
+            shape: Box::new(Group {
+                shapes: vec![
+                    Box::new(Square {
+                        side: 2,
+                        filled: true,
+                    }),
+                    Box::new(Square {
+                        side: 3,
+                        filled: false,
+                    }),
+                ],
+            }),
+            label: Box::new(Name("house".to_string())),
+        }
+    }
+
+    fn find(bytes: &[u8], needle: &[u8]) -> usize {
+        bytes
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .unwrap()
+    }
+
+    fn tag(impl_id: u64) -> [u8; 8] {
+        let archived = Archived::<u64>::from_native(impl_id);
+        // SAFETY: `Archived<u64>` is eight bytes without any padding.
+        unsafe { core::mem::transmute(archived) }
+    }
+
+    #[test]
+    fn roundtrip_dyn_box() {
+        let bytes = to_bytes::<Error>(&drawing()).unwrap();
+        let archived = access::<ArchivedDrawing, Error>(&bytes).unwrap();
+        assert_eq!(archived.shape.impl_id(), GROUP_ID);
+        assert_eq!(archived.shape.area(), 13);
+        assert_eq!(archived.label.label(), "house");
+
+        let deserialized = deserialize::<Drawing, Error>(archived).unwrap();
+        assert_eq!(deserialized.shape.area(), 13);
+        assert_eq!(deserialized.label.label(), "house");
+        assert_eq!(<dyn Shape>::impl_id(&*deserialized.shape), Some(GROUP_ID));
+    }
+
+    #[test]
+    fn reject_unregistered_impl_id() {
+        let mut bytes = to_bytes::<Error>(&drawing()).unwrap();
+        let pos = find(&bytes, &tag(SQUARE_ID));
+        bytes[pos..pos + 8].copy_from_slice(&tag(0xdead));
+
+        assert!(access::<ArchivedDrawing, Failure>(&bytes).is_err());
+    }
+
+    #[test]
+    fn reject_impl_id_of_other_trait() {
+        let mut bytes = to_bytes::<Error>(&drawing()).unwrap();
+        let pos = find(&bytes, &tag(NAME_ID));
+        bytes[pos..pos + 8].copy_from_slice(&tag(SQUARE_ID));
+
+        let pos = find(&bytes, &tag(GROUP_ID));
+        bytes[pos..pos + 8].copy_from_slice(&tag(NAME_ID));
+
+        assert!(access::<ArchivedDrawing, Failure>(&bytes).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_payload() {
+        let value = Square {
+            side: 0x0a0b_0c0d,
+            filled: true,
+        };
+        let drawing = Drawing {
+            shape: Box::new(value),
+            label: Box::new(Name("square".to_string())),
+        };
+        let mut bytes = to_bytes::<Error>(&drawing).unwrap();
+        access::<ArchivedDrawing, Error>(&bytes).unwrap();
+
+        // The bool follows the `u32` side in the archived square.
+        let side = Archived::<u32>::from_native(0x0a0b_0c0d);
+        // SAFETY: `Archived<u32>` is four bytes without any padding.
+        let side =
+            unsafe { core::mem::transmute::<Archived<u32>, [u8; 4]>(side) };
+        let pos = find(&bytes, &side) + 4;
+        assert_eq!(bytes[pos], 1);
+        bytes[pos] = 2;
+
+        assert!(access::<ArchivedDrawing, Failure>(&bytes).is_err());
+    }
+
+    #[test]
+    fn reject_unregistered_type() {
+        let drawing = Drawing {
+            shape: Box::new(Circle),
+            label: Box::new(Name("circle".to_string())),
+        };
+        to_bytes::<Failure>(&drawing).unwrap_err();
+    }
+
+    #[test]
+    fn archived_dyn_box_is_portable() {
+        fn assert_portable<T: rkyv::Portable + ?Sized>() {}
+        assert_portable::<ArchivedDynBox<dyn Shape>>();
+    }
 }
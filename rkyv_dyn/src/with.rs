@@ -0,0 +1,109 @@
+//! Wrapper types for trait objects.
+
+use rancor::{Fallible, Source};
+use rkyv::{
+    with::{ArchiveWith, DeserializeWith, SerializeWith},
+    Place,
+};
+
+use crate::{
+    ArchivedDynBox, AsDynDeserializer, AsDynSerializer, DynBoxResolver,
+    DynTrait,
+};
+
+/// A wrapper that archives a `Box<dyn Trait>` with the registered impls of the
+/// trait.
+///
+/// The trait's impls must be registered with
+/// [`register_impl`](crate::register_impl). Serializing a value whose type is
+/// not registered fails. The archived value is an [`ArchivedDynBox`], which
+/// dereferences to the archived value as a `dyn Trait`.
+///
+/// # Example
+///
+/// ```
+/// use core::any::Any;
+///
+/// use rkyv::{rancor::Error, Archive, Deserialize, Serialize};
+/// use rkyv_dyn::{register_impl, with::DynBox};
+///
+/// #[ptr_meta::pointee]
+/// trait Plugin: Any {
+///     fn name(&self) -> &str;
+/// }
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Logger;
+///
+/// impl Plugin for Logger {
+///     fn name(&self) -> &str {
+///         "logger"
+///     }
+/// }
+///
+/// impl Plugin for ArchivedLogger {
+///     fn name(&self) -> &str {
+///         "logger"
+///     }
+/// }
+///
+/// register_impl!(dyn Plugin: Logger = 1);
+///
+/// #[derive(Archive, Serialize, Deserialize)]
+/// #[rkyv(check_bytes)]
+/// struct Config {
+///     #[with(DynBox)]
+///     plugin: Box<dyn Plugin>,
+/// }
+///
+/// let config = Config {
+///     plugin: Box::new(Logger),
+/// };
+/// let bytes = rkyv::to_bytes::<Error>(&config).unwrap();
+/// let archived = rkyv::access::<ArchivedConfig, Error>(&bytes).unwrap();
+/// assert_eq!(archived.plugin.name(), "logger");
+/// ```
+#[derive(Debug)]
+pub struct DynBox;
+
+impl<T: DynTrait + ?Sized> ArchiveWith<Box<T>> for DynBox {
+    type Archived = ArchivedDynBox<T>;
+    type Resolver = DynBoxResolver;
+
+    fn resolve_with(
+        _: &Box<T>,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        ArchivedDynBox::resolve_from_resolver(resolver, out);
+    }
+}
+
+impl<T, S> SerializeWith<Box<T>, S> for DynBox
+where
+    T: DynTrait + ?Sized,
+    S: Fallible + AsDynSerializer<S::Error> + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(
+        field: &Box<T>,
+        serializer: &mut S,
+    ) -> Result<Self::Resolver, S::Error> {
+        ArchivedDynBox::serialize_from_ref(&**field, serializer)
+    }
+}
+
+impl<T, D> DeserializeWith<ArchivedDynBox<T>, Box<T>, D> for DynBox
+where
+    T: DynTrait + ?Sized,
+    D: Fallible + AsDynDeserializer<D::Error> + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(
+        field: &ArchivedDynBox<T>,
+        deserializer: &mut D,
+    ) -> Result<Box<T>, D::Error> {
+        field.deserialize(deserializer)
+    }
+}